@@ -277,7 +277,17 @@ impl BytecodeAnalyzer {
             Stmt::Expr { expr, .. } => {
                 self.collect_calls_in_expr(expr, calls);
             }
-            Stmt::Assign { value, .. } | Stmt::AddAssign { value, .. } => {
+            Stmt::Assign { value, .. }
+            | Stmt::AddAssign { value, .. }
+            | Stmt::SubAssign { value, .. }
+            | Stmt::MulAssign { value, .. }
+            | Stmt::DivAssign { value, .. }
+            | Stmt::FldAssign { value, .. }
+            | Stmt::PowAssign { value, .. }
+            | Stmt::BitAndAssign { value, .. }
+            | Stmt::BitOrAssign { value, .. }
+            | Stmt::BitXorAssign { value, .. }
+            | Stmt::BroadcastAssign { value, .. } => {
                 self.collect_calls_in_expr(value, calls);
             }
             Stmt::If {
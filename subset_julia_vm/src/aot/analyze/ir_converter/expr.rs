@@ -564,6 +564,17 @@ impl<'a> IrConverter<'a> {
                     .map(|a| self.convert_expr(a))
                     .collect::<AotResult<_>>()?;
 
+                // `throw(DimensionMismatch(...))` etc: the inner struct
+                // constructor call already lowered straight to
+                // `AotExpr::ThrowTyped` above, so the raise has already
+                // happened - unwrap instead of wrapping it in a second,
+                // redundant call to `throw` (Issue chunk428-1).
+                if function == "throw" && aot_args.len() == 1 {
+                    if let AotExpr::ThrowTyped { .. } = &aot_args[0] {
+                        return Ok(aot_args.into_iter().next().unwrap());
+                    }
+                }
+
                 // Special handling for convert(Type, value) calls
                 // These are generated by the lowering phase for return type coercion
                 // Convert them to AotExpr::Convert for proper static type casting
@@ -577,6 +588,7 @@ impl<'a> IrConverter<'a> {
                             return Ok(AotExpr::Convert {
                                 value: Box::new(value),
                                 target_ty,
+                                checked: true,
                             });
                         }
                     }
@@ -590,6 +602,7 @@ impl<'a> IrConverter<'a> {
                         return Ok(AotExpr::Convert {
                             value: Box::new(value),
                             target_ty,
+                            checked: true,
                         });
                     }
                 }
@@ -630,6 +643,18 @@ impl<'a> IrConverter<'a> {
                     });
                 }
 
+                // Base exception types are always constructed to be thrown
+                // immediately (Issue chunk428-1), the same lowering
+                // `convert_literal` applies to `Literal::Struct` - applied
+                // here too since ordinary source code constructs them via
+                // a plain call (`DimensionMismatch(msg)`), not a literal.
+                if let Some(kind) = AotExceptionKind::from_type_name(function) {
+                    return Ok(AotExpr::ThrowTyped {
+                        kind,
+                        fields: aot_args,
+                    });
+                }
+
                 // Check if it's a struct constructor
                 if let Some(_struct_info) = self.typed.get_struct(function) {
                     return Ok(AotExpr::StructNew {
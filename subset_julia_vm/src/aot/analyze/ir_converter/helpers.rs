@@ -102,6 +102,17 @@ impl<'a> IrConverter<'a> {
                     converted_fields.push(self.convert_literal(field)?);
                 }
 
+                // Base exception types are always constructed to be thrown
+                // immediately (Issue chunk428-1); lower the construction
+                // itself to a typed raise instead of an inert `StructNew`
+                // so generated code can discriminate on exception type.
+                if let Some(kind) = AotExceptionKind::from_type_name(&normalized_name) {
+                    return Ok(AotExpr::ThrowTyped {
+                        kind,
+                        fields: converted_fields,
+                    });
+                }
+
                 Ok(AotExpr::StructNew {
                     name: normalized_name,
                     fields: converted_fields,
@@ -124,6 +135,14 @@ impl<'a> IrConverter<'a> {
         match name {
             "Int64" | "Int" => Some(StaticType::I64),
             "Int32" => Some(StaticType::I32),
+            "Int16" => Some(StaticType::I16),
+            "Int8" => Some(StaticType::I8),
+            "Int128" => Some(StaticType::I128),
+            "UInt64" => Some(StaticType::U64),
+            "UInt32" => Some(StaticType::U32),
+            "UInt16" => Some(StaticType::U16),
+            "UInt8" => Some(StaticType::U8),
+            "UInt128" => Some(StaticType::U128),
             "Float64" => Some(StaticType::F64),
             "Float32" => Some(StaticType::F32),
             "Bool" => Some(StaticType::Bool),
@@ -161,6 +180,14 @@ impl<'a> IrConverter<'a> {
         match jt {
             JT::Int64 => StaticType::I64,
             JT::Int32 => StaticType::I32,
+            JT::Int16 => StaticType::I16,
+            JT::Int8 => StaticType::I8,
+            JT::Int128 => StaticType::I128,
+            JT::UInt64 => StaticType::U64,
+            JT::UInt32 => StaticType::U32,
+            JT::UInt16 => StaticType::U16,
+            JT::UInt8 => StaticType::U8,
+            JT::UInt128 => StaticType::U128,
             JT::Float64 => StaticType::F64,
             JT::Float32 => StaticType::F32,
             JT::Bool => StaticType::Bool,
@@ -192,8 +219,8 @@ impl<'a> IrConverter<'a> {
             "exp" | "log" | "floor" | "ceil" | "round" | "trunc" |
             "min" | "max" | "clamp" | "sign" | "copysign" |
             // Type constructors (handled as casts)
-            "Int64" | "Int32" | "Int16" | "Int8" |
-            "UInt64" | "UInt32" | "UInt16" | "UInt8" |
+            "Int64" | "Int32" | "Int16" | "Int8" | "Int128" |
+            "UInt64" | "UInt32" | "UInt16" | "UInt8" | "UInt128" |
             "Float64" | "Float32" | "Bool" |
             // Array operations
             "length" | "size" | "ndims" | "push!" | "pop!" |
@@ -5,8 +5,8 @@
 
 use super::super::inference::{TypeInferenceEngine, TypedProgram};
 use super::super::ir::{
-    AotBinOp, AotBuiltinOp, AotEnum, AotExpr, AotFunction, AotGlobal, AotProgram, AotStmt,
-    AotStruct, AotUnaryOp,
+    AotBinOp, AotBuiltinOp, AotEnum, AotExceptionKind, AotExpr, AotFunction, AotGlobal,
+    AotProgram, AotStmt, AotStruct, AotUnaryOp,
 };
 use super::super::types::StaticType;
 use super::super::AotResult;
@@ -270,6 +270,7 @@ impl<'a> IrConverter<'a> {
                                 Ok(AotExpr::Convert {
                                     value: Box::new(expr),
                                     target_ty: return_ty.clone(),
+                                    checked: false,
                                 })
                             } else {
                                 Ok(expr)
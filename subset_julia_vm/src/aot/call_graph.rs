@@ -139,7 +139,16 @@ impl CallGraph {
             Stmt::Assign { value, .. } => {
                 self.collect_calls_in_expr(value, calls);
             }
-            Stmt::AddAssign { value, .. } => {
+            Stmt::AddAssign { value, .. }
+            | Stmt::SubAssign { value, .. }
+            | Stmt::MulAssign { value, .. }
+            | Stmt::DivAssign { value, .. }
+            | Stmt::FldAssign { value, .. }
+            | Stmt::PowAssign { value, .. }
+            | Stmt::BitAndAssign { value, .. }
+            | Stmt::BitOrAssign { value, .. }
+            | Stmt::BitXorAssign { value, .. }
+            | Stmt::BroadcastAssign { value, .. } => {
                 self.collect_calls_in_expr(value, calls);
             }
             Stmt::If {
@@ -425,7 +434,16 @@ impl CallGraph {
             Stmt::Block(block) => self.collect_struct_refs_in_block(block),
             Stmt::Expr { expr, .. } => self.collect_struct_refs_in_expr(expr),
             Stmt::Assign { value, .. } => self.collect_struct_refs_in_expr(value),
-            Stmt::AddAssign { value, .. } => self.collect_struct_refs_in_expr(value),
+            Stmt::AddAssign { value, .. }
+            | Stmt::SubAssign { value, .. }
+            | Stmt::MulAssign { value, .. }
+            | Stmt::DivAssign { value, .. }
+            | Stmt::FldAssign { value, .. }
+            | Stmt::PowAssign { value, .. }
+            | Stmt::BitAndAssign { value, .. }
+            | Stmt::BitOrAssign { value, .. }
+            | Stmt::BitXorAssign { value, .. }
+            | Stmt::BroadcastAssign { value, .. } => self.collect_struct_refs_in_expr(value),
             Stmt::If {
                 condition,
                 then_branch,
@@ -511,7 +529,16 @@ impl CallGraph {
             Stmt::Block(block) => self.collect_module_refs_in_block(block),
             Stmt::Expr { expr, .. } => self.collect_module_refs_in_expr(expr),
             Stmt::Assign { value, .. } => self.collect_module_refs_in_expr(value),
-            Stmt::AddAssign { value, .. } => self.collect_module_refs_in_expr(value),
+            Stmt::AddAssign { value, .. }
+            | Stmt::SubAssign { value, .. }
+            | Stmt::MulAssign { value, .. }
+            | Stmt::DivAssign { value, .. }
+            | Stmt::FldAssign { value, .. }
+            | Stmt::PowAssign { value, .. }
+            | Stmt::BitAndAssign { value, .. }
+            | Stmt::BitOrAssign { value, .. }
+            | Stmt::BitXorAssign { value, .. }
+            | Stmt::BroadcastAssign { value, .. } => self.collect_module_refs_in_expr(value),
             Stmt::If {
                 condition,
                 then_branch,
@@ -753,7 +780,18 @@ impl CallGraph {
             Stmt::Block(block) => self.collect_struct_refs_in_block_to_set(block, refs),
             Stmt::Expr { expr, .. } => self.collect_struct_refs_in_expr_to_set(expr, refs),
             Stmt::Assign { value, .. } => self.collect_struct_refs_in_expr_to_set(value, refs),
-            Stmt::AddAssign { value, .. } => self.collect_struct_refs_in_expr_to_set(value, refs),
+            Stmt::AddAssign { value, .. }
+            | Stmt::SubAssign { value, .. }
+            | Stmt::MulAssign { value, .. }
+            | Stmt::DivAssign { value, .. }
+            | Stmt::FldAssign { value, .. }
+            | Stmt::PowAssign { value, .. }
+            | Stmt::BitAndAssign { value, .. }
+            | Stmt::BitOrAssign { value, .. }
+            | Stmt::BitXorAssign { value, .. }
+            | Stmt::BroadcastAssign { value, .. } => {
+                self.collect_struct_refs_in_expr_to_set(value, refs)
+            }
             Stmt::If {
                 condition,
                 then_branch,
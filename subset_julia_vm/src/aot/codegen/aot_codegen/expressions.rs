@@ -115,12 +115,17 @@ impl AotCodeGenerator {
                 ))
             }
 
-            AotExpr::CallBuiltin { builtin, args, .. } => {
+            AotExpr::CallBuiltin {
+                builtin,
+                args,
+                return_ty,
+            } => {
+                let arg_tys: Vec<_> = args.iter().map(|a| a.get_type()).collect();
                 let args_str: Vec<_> = args
                     .iter()
                     .map(|a| self.emit_expr_to_string(a))
                     .collect::<AotResult<_>>()?;
-                self.emit_builtin_call(builtin, &args_str)
+                self.emit_builtin_call(builtin, &args_str, &arg_tys, return_ty)
             }
 
             // Array literal (1D or multidimensional)
@@ -237,6 +242,22 @@ impl AotCodeGenerator {
                 Ok(format!("{}::new({})", name, fields_str.join(", ")))
             }
 
+            // Typed exception raise (Issue chunk428-1): construct the
+            // prelude's dedicated struct for `kind` and hand it to the
+            // generic `throw` so it still panics, but with a type the
+            // generated code can tell apart from every other exception.
+            AotExpr::ThrowTyped { kind, fields } => {
+                let fields_str: Vec<_> = fields
+                    .iter()
+                    .map(|f| self.emit_expr_to_string(f))
+                    .collect::<AotResult<_>>()?;
+                Ok(format!(
+                    "throw({}::new({}))",
+                    kind.struct_name(),
+                    fields_str.join(", ")
+                ))
+            }
+
             // Field access
             AotExpr::FieldAccess { object, field, .. } => {
                 let obj_str = self.emit_expr_to_string(object)?;
@@ -273,11 +294,28 @@ impl AotCodeGenerator {
             }
 
             // Type conversion/coercion
-            AotExpr::Convert { value, target_ty } => {
+            AotExpr::Convert {
+                value,
+                target_ty,
+                checked,
+            } => {
                 let value_str = self.emit_expr_to_string(value)?;
                 let value_ty = value.get_type();
                 let ty_str = self.type_to_rust(target_ty);
 
+                // An explicit `convert(T, x)`/type-constructor call raises
+                // InexactError instead of silently truncating/rounding
+                // (Issue chunk428-3); compose from the widest-type
+                // (f64/i64) checked helpers so every numeric pair reuses
+                // the same four prelude functions.
+                if *checked {
+                    if let Some(checked_expr) =
+                        self.emit_checked_numeric_convert(&value_str, &value_ty, target_ty)
+                    {
+                        return Ok(checked_expr);
+                    }
+                }
+
                 // Handle type conversions appropriately
                 match (&value_ty, target_ty) {
                     // Same type - no conversion needed
@@ -322,6 +360,68 @@ impl AotCodeGenerator {
         }
     }
 
+    /// Emit a checked numeric conversion for an explicit `convert(T, x)`/
+    /// type-constructor call, composed from the widest-type (`f64`/`i64`)
+    /// checked helpers emitted into the prelude. Returns `None` for pairs
+    /// that are always exact (widening, or non-numeric/non-float16
+    /// conversions), letting the caller fall back to a plain `as` cast.
+    fn emit_checked_numeric_convert(
+        &self,
+        value_str: &str,
+        value_ty: &StaticType,
+        target_ty: &StaticType,
+    ) -> Option<String> {
+        match (value_ty, target_ty) {
+            // Already exact - widening conversions stay plain `as` casts.
+            (StaticType::I32, StaticType::I64)
+            | (StaticType::F32, StaticType::F64)
+            | (StaticType::I32, StaticType::F64) => None,
+
+            // Narrowing float -> int: widen to f64 first, then check once.
+            (StaticType::F64, StaticType::I64) => {
+                Some(format!("__checked_f64_to_i64({})", value_str))
+            }
+            (StaticType::F32, StaticType::I64) => {
+                Some(format!("__checked_f64_to_i64(({} as f64))", value_str))
+            }
+            (StaticType::F64, StaticType::I32) => Some(format!(
+                "__checked_i64_to_i32(__checked_f64_to_i64({}))",
+                value_str
+            )),
+            (StaticType::F32, StaticType::I32) => Some(format!(
+                "__checked_i64_to_i32(__checked_f64_to_i64(({} as f64)))",
+                value_str
+            )),
+
+            // Narrowing int -> narrower int.
+            (StaticType::I64, StaticType::I32) => {
+                Some(format!("__checked_i64_to_i32({})", value_str))
+            }
+
+            // Widening int -> float that is not always exact once the
+            // magnitude exceeds f32's/f64's integer precision.
+            (StaticType::I64, StaticType::F32) => Some(format!(
+                "__checked_f64_to_f32(__checked_i64_to_f64({}))",
+                value_str
+            )),
+            (StaticType::I64, StaticType::F64) => {
+                Some(format!("__checked_i64_to_f64({})", value_str))
+            }
+            (StaticType::I32, StaticType::F32) => {
+                Some(format!("__checked_f64_to_f32(({} as f64))", value_str))
+            }
+
+            // Narrowing float -> narrower float.
+            (StaticType::F64, StaticType::F32) => {
+                Some(format!("__checked_f64_to_f32({})", value_str))
+            }
+
+            // Bool-to-numeric and identical-type conversions are always
+            // exact; everything else falls back to the unchecked path.
+            _ => None,
+        }
+    }
+
     /// Emit lambda/closure expression
     ///
     /// Generates Rust closure syntax from Julia lambda expressions.
@@ -371,7 +471,13 @@ impl AotCodeGenerator {
     }
 
     /// Emit builtin function call
-    fn emit_builtin_call(&self, builtin: &AotBuiltinOp, args: &[String]) -> AotResult<String> {
+    fn emit_builtin_call(
+        &self,
+        builtin: &AotBuiltinOp,
+        args: &[String],
+        arg_tys: &[StaticType],
+        return_ty: &StaticType,
+    ) -> AotResult<String> {
         match builtin {
             // Basic math functions - use Rust's f64 methods
             AotBuiltinOp::Sqrt => Ok(format!("{}.sqrt()", args[0])),
@@ -708,6 +814,28 @@ impl AotCodeGenerator {
                     Ok("/* fptosi: missing args */ 0_i64".to_string())
                 }
             }
+
+            // Dedicated conversion-op family (Issue chunk428-5): a typed
+            // registry of conversion intrinsics, replacing ad-hoc `convert`
+            // name matching. Checked narrowing reuses the same
+            // `__checked_*` prelude helpers as `AotExpr::Convert { checked:
+            // true, .. }` (Issue chunk428-3) wherever the pair is covered,
+            // falling back to a plain `as` cast for pairs those helpers
+            // don't compose (e.g. narrow-to-narrow integer pairs).
+            AotBuiltinOp::IntToBool => Ok(format!("({} != 0)", args[0])),
+            AotBuiltinOp::BoolToInt | AotBuiltinOp::WidenInt | AotBuiltinOp::IntToFloat => {
+                Ok(format!("({} as {})", args[0], self.type_to_rust(return_ty)))
+            }
+            AotBuiltinOp::NarrowIntChecked | AotBuiltinOp::FloatToIntChecked => {
+                if let Some(src_ty) = arg_tys.first() {
+                    if let Some(checked) =
+                        self.emit_checked_numeric_convert(&args[0], src_ty, return_ty)
+                    {
+                        return Ok(checked);
+                    }
+                }
+                Ok(format!("({} as {})", args[0], self.type_to_rust(return_ty)))
+            }
         }
     }
 }
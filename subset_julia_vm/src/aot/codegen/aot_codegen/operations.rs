@@ -24,10 +24,10 @@ impl AotCodeGenerator {
             AotBinOp::Div => self.emit_div(left_str, right_str, left_ty, right_ty),
 
             // Integer division: Julia's ÷ returns integer
-            AotBinOp::IntDiv => self.emit_intdiv(left_str, right_str, left_ty, right_ty),
+            AotBinOp::IntDiv => self.emit_intdiv(left_str, right_str, left_ty, right_ty, result_ty),
 
             // Modulo operation
-            AotBinOp::Mod => self.emit_mod(left_str, right_str, left_ty, right_ty),
+            AotBinOp::Mod => self.emit_mod(left_str, right_str, left_ty, right_ty, result_ty),
 
             // Standard arithmetic with type promotion
             AotBinOp::Add | AotBinOp::Sub | AotBinOp::Mul => {
@@ -50,12 +50,17 @@ impl AotCodeGenerator {
             // Logical operations
             AotBinOp::And | AotBinOp::Or => self.emit_logical(op, left_str, right_str),
 
-            // Bitwise operations
-            AotBinOp::BitAnd
-            | AotBinOp::BitOr
-            | AotBinOp::BitXor
-            | AotBinOp::Shl
-            | AotBinOp::Shr => self.emit_bitwise(op, left_str, right_str),
+            // Bitwise AND/OR/XOR require matching operand types in Rust.
+            AotBinOp::BitAnd | AotBinOp::BitOr | AotBinOp::BitXor => {
+                self.emit_bitwise(op, left_str, right_str, left_ty, right_ty)
+            }
+
+            // Shl/Shr: Rust implements shifts with a heterogeneous RHS
+            // width, and native `>>` is already arithmetic (sign-extending)
+            // on signed types and logical (zero-filling) on unsigned types
+            // per Rust's per-type semantics, matching Julia's `>>`/`>>>`
+            // distinction for free — no casting needed.
+            AotBinOp::Shl | AotBinOp::Shr => self.emit_shift(op, left_str, right_str),
         }
     }
 
@@ -124,10 +129,14 @@ impl AotCodeGenerator {
         right_str: &str,
         left_ty: &StaticType,
         right_ty: &StaticType,
+        result_ty: &StaticType,
     ) -> AotResult<String> {
-        // If both are integers, simple division (Rust integer division truncates)
+        // If both are integers, cast mismatched widths/signedness to the
+        // inferred result type first (e.g. UInt8 ÷ Int64 -> Int64), then
+        // let Rust's native integer division truncate toward zero.
         if left_ty.is_integer() && right_ty.is_integer() {
-            Ok(format!("({} / {})", left_str, right_str))
+            let (left, right) = self.cast_int_pair_to(left_str, left_ty, right_str, right_ty, result_ty);
+            Ok(format!("({} / {})", left, right))
         }
         // If floats involved, convert to integer first, then divide
         else if left_ty.is_float() && right_ty.is_float() {
@@ -148,10 +157,12 @@ impl AotCodeGenerator {
         right_str: &str,
         left_ty: &StaticType,
         right_ty: &StaticType,
+        result_ty: &StaticType,
     ) -> AotResult<String> {
         // Integer modulo
         if left_ty.is_integer() && right_ty.is_integer() {
-            Ok(format!("({} % {})", left_str, right_str))
+            let (left, right) = self.cast_int_pair_to(left_str, left_ty, right_str, right_ty, result_ty);
+            Ok(format!("({} % {})", left, right))
         }
         // Float modulo (uses rem_euclid for Julia-like behavior, but % works too)
         else if left_ty.is_float() && right_ty.is_float() {
@@ -175,6 +186,36 @@ impl AotCodeGenerator {
         }
     }
 
+    /// Cast a pair of integer operands to a common Rust integer type so
+    /// mixed-width/signedness operations (e.g. `UInt8 / Int64`) compile —
+    /// Rust, unlike Julia, refuses to mix distinct integer types in a
+    /// single arithmetic expression. Operands already matching `target_ty`
+    /// are left untouched.
+    fn cast_int_pair_to(
+        &self,
+        left_str: &str,
+        left_ty: &StaticType,
+        right_str: &str,
+        right_ty: &StaticType,
+        target_ty: &StaticType,
+    ) -> (String, String) {
+        if left_ty == right_ty {
+            return (left_str.to_string(), right_str.to_string());
+        }
+        let target = self.type_to_rust(target_ty);
+        let left = if left_ty == target_ty {
+            left_str.to_string()
+        } else {
+            format!("({} as {})", left_str, target)
+        };
+        let right = if right_ty == target_ty {
+            right_str.to_string()
+        } else {
+            format!("({} as {})", right_str, target)
+        };
+        (left, right)
+    }
+
     /// Generate standard arithmetic with type promotion
     fn emit_arithmetic(
         &self,
@@ -207,6 +248,13 @@ impl AotCodeGenerator {
             return Ok(format!("({} {} {})", left, op_str, right));
         }
 
+        // Mismatched integer widths/signedness: cast both operands to the
+        // inferred result type (e.g. UInt8 + Int64 -> Int64).
+        if left_ty.is_integer() && right_ty.is_integer() {
+            let (left, right) = self.cast_int_pair_to(left_str, left_ty, right_str, right_ty, result_ty);
+            return Ok(format!("({} {} {})", left, op_str, right));
+        }
+
         // Default: no casting
         Ok(format!("({} {} {})", left_str, op_str, right_str))
     }
@@ -246,6 +294,15 @@ impl AotCodeGenerator {
             return Ok(format!("({} {} {})", left, op_str, right));
         }
 
+        // Mismatched integer widths/signedness: cast to their Julia-style
+        // common type so e.g. `UInt8 < Int64` compiles.
+        if left_ty.is_integer() && right_ty.is_integer() {
+            let common_ty = left_ty.promote_with(right_ty);
+            let (left, right) =
+                self.cast_int_pair_to(left_str, left_ty, right_str, right_ty, &common_ty);
+            return Ok(format!("({} {} {})", left, op_str, right));
+        }
+
         // Default: direct comparison
         Ok(format!("({} {} {})", left_str, op_str, right_str))
     }
@@ -293,8 +350,29 @@ impl AotCodeGenerator {
         Ok(format!("({} {} {})", left_str, op_str, right_str))
     }
 
-    /// Generate bitwise operations
-    fn emit_bitwise(&self, op: AotBinOp, left_str: &str, right_str: &str) -> AotResult<String> {
+    /// Generate bitwise AND/OR/XOR, casting mismatched integer
+    /// widths/signedness to their Julia-style common type first since Rust
+    /// (unlike `<<`/`>>`) requires identical operand types for `&`/`|`/`^`.
+    fn emit_bitwise(
+        &self,
+        op: AotBinOp,
+        left_str: &str,
+        right_str: &str,
+        left_ty: &StaticType,
+        right_ty: &StaticType,
+    ) -> AotResult<String> {
+        let op_str = op.to_rust_op();
+        if left_ty.is_integer() && right_ty.is_integer() && left_ty != right_ty {
+            let common_ty = left_ty.promote_with(right_ty);
+            let (left, right) =
+                self.cast_int_pair_to(left_str, left_ty, right_str, right_ty, &common_ty);
+            return Ok(format!("({} {} {})", left, op_str, right));
+        }
+        Ok(format!("({} {} {})", left_str, op_str, right_str))
+    }
+
+    /// Generate shift operations (Julia's `<<`/`>>`)
+    fn emit_shift(&self, op: AotBinOp, left_str: &str, right_str: &str) -> AotResult<String> {
         let op_str = op.to_rust_op();
         Ok(format!("({} {} {})", left_str, op_str, right_str))
     }
@@ -253,6 +253,97 @@ impl AotCodeGenerator {
         self.write_line("fn throw<T: std::fmt::Debug>(e: T) -> ! { panic!(\"{:?}\", e); }");
         self.blank_line();
 
+        // Typed Base exception structs (Issue chunk428-1). Giving each
+        // recognized exception kind its own Debug-derived struct, rather
+        // than flattening all of them into ErrorException, lets generated
+        // code distinguish exception types by their Rust type instead of
+        // losing that identity the moment they're thrown.
+        for (name, fields) in [
+            ("DimensionMismatch", &["msg"][..]),
+            ("KeyError", &["key"][..]),
+            ("BoundsError", &["object", "index"][..]),
+            ("SystemError", &["prefix", "errnum"][..]),
+            ("InexactError", &["msg"][..]),
+        ] {
+            self.write_line("#[derive(Debug)]");
+            self.write_line(&format!(
+                "struct {} {{ {} }}",
+                name,
+                fields
+                    .iter()
+                    .map(|f| format!("{}: String", f))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+            self.write_line(&format!("impl {} {{", name));
+            self.indent();
+            self.write_line(&format!(
+                "fn new({}) -> Self {{ {} {{ {} }} }}",
+                fields
+                    .iter()
+                    .map(|f| format!("{}: String", f))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                name,
+                fields.join(", ")
+            ));
+            self.dedent();
+            self.write_line("}");
+            self.blank_line();
+        }
+
+        // Checked numeric conversions for explicit `convert(T, x)`/type
+        // constructor calls (Issue chunk428-3). Unlike a bare `as` cast,
+        // each of these raises `InexactError` instead of silently
+        // truncating/rounding, matching Julia's `convert` semantics.
+        // Narrower conversions are expressed in terms of the f64/i64
+        // widest-type checks so every (source, target) pair composes from
+        // just these four: float->int uses round-toward-zero and rejects
+        // anything non-integral or out of range; int/float->narrower-float
+        // rejects anything that doesn't round-trip back exactly.
+        self.write_line("fn __checked_f64_to_i64(v: f64) -> i64 {");
+        self.indent();
+        self.write_line("let t = v.trunc();");
+        self.write_line(
+            "if !v.is_finite() || t != v || t < -9223372036854775808.0 || t >= 9223372036854775808.0 { throw(InexactError::new(format!(\"{:?}\", v))); }",
+        );
+        self.write_line("t as i64");
+        self.dedent();
+        self.write_line("}");
+        self.blank_line();
+
+        self.write_line("fn __checked_i64_to_i32(v: i64) -> i32 {");
+        self.indent();
+        self.write_line(
+            "if v < i32::MIN as i64 || v > i32::MAX as i64 { throw(InexactError::new(format!(\"{:?}\", v))); }",
+        );
+        self.write_line("v as i32");
+        self.dedent();
+        self.write_line("}");
+        self.blank_line();
+
+        self.write_line("fn __checked_i64_to_f64(v: i64) -> f64 {");
+        self.indent();
+        self.write_line("let f = v as f64;");
+        self.write_line(
+            "if f >= 9223372036854775808.0 || f as i64 != v { throw(InexactError::new(format!(\"{:?}\", v))); }",
+        );
+        self.write_line("f");
+        self.dedent();
+        self.write_line("}");
+        self.blank_line();
+
+        self.write_line("fn __checked_f64_to_f32(v: f64) -> f32 {");
+        self.indent();
+        self.write_line("let f = v as f32;");
+        self.write_line(
+            "if !v.is_nan() && (f as f64) != v { throw(InexactError::new(format!(\"{:?}\", v))); }",
+        );
+        self.write_line("f");
+        self.dedent();
+        self.write_line("}");
+        self.blank_line();
+
         // linspace: linearly spaced vector (replacement for range(start,stop;length=n)) (Issue #3413)
         self.write_line("fn linspace(start: f64, stop: f64, n: i64) -> Vec<f64> {");
         self.indent();
@@ -8,14 +8,16 @@
 //! - **Rust**: Generates Rust source code that can be compiled with `rustc`
 //! - **Cranelift** (optional): Generates native code directly using Cranelift JIT
 
+pub mod aot_backend;
 pub mod aot_codegen;
 pub mod ir_codegen;
+pub mod wasm_codegen;
 
 #[cfg(feature = "cranelift")]
 pub mod cranelift;
 
 use super::ir::{IrFunction, IrModule};
-use super::AotResult;
+use super::{Artifact, AotResult, AotStats};
 
 /// Trait for code generators
 pub trait CodeGenerator {
@@ -29,6 +31,60 @@ pub trait CodeGenerator {
     fn generate_module(&mut self, module: &IrModule) -> AotResult<String>;
 }
 
+/// Output format a [`Backend`] produces.
+///
+/// `compile_from_bytecode` takes a target of this type, but returns an
+/// error for every target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CodegenTarget {
+    /// Rust source, compiled afterwards with `rustc` (the original, and
+    /// still default, pipeline).
+    RustSource,
+    /// WebAssembly text format (WAT) module.
+    Wasm,
+}
+
+/// A pluggable AoT backend: lowers an already-optimized [`IrModule`] to a
+/// target-specific [`Artifact`].
+///
+/// Backends record what they had to fall back on in `stats` (e.g.
+/// `dynamic_fallbacks` for IR nodes with no static lowering) rather than
+/// failing outright, mirroring how the rest of the AoT pipeline treats
+/// Level 3 dynamic dispatch as a supported, if slower, path.
+pub trait Backend {
+    /// Which target this backend emits.
+    fn target(&self) -> CodegenTarget;
+
+    /// Lower `module` to this backend's artifact format.
+    fn emit(&mut self, module: &IrModule, stats: &mut AotStats) -> AotResult<Artifact>;
+}
+
+/// Adapts [`ir_codegen::RustCodeGenerator`] to the [`Backend`] trait.
+#[derive(Debug, Default)]
+pub struct RustBackend {
+    config: CodegenConfig,
+}
+
+impl RustBackend {
+    /// Create a new Rust backend with the given configuration.
+    pub fn new(config: CodegenConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Backend for RustBackend {
+    fn target(&self) -> CodegenTarget {
+        CodegenTarget::RustSource
+    }
+
+    fn emit(&mut self, module: &IrModule, stats: &mut AotStats) -> AotResult<Artifact> {
+        stats.functions_compiled += module.functions.len();
+        let mut codegen = ir_codegen::RustCodeGenerator::new(self.config.clone());
+        let code = codegen.generate_module(module)?;
+        Ok(Artifact::RustSource(code))
+    }
+}
+
 /// Configuration for code generation
 #[derive(Debug, Clone)]
 pub struct CodegenConfig {
@@ -116,4 +172,24 @@ mod tests {
         assert!(!config.emit_comments);
         assert!(config.pure_rust);
     }
+
+    #[test]
+    fn test_rust_backend_target() {
+        let backend = RustBackend::default();
+        assert_eq!(backend.target(), CodegenTarget::RustSource);
+    }
+
+    #[test]
+    fn test_rust_backend_emit() {
+        use crate::aot::ir::IrModule;
+
+        let mut backend = RustBackend::default();
+        let mut stats = AotStats::new();
+        let module = IrModule::new("test".to_string());
+        let artifact = backend.emit(&module, &mut stats).unwrap();
+        match artifact {
+            Artifact::RustSource(code) => assert!(code.contains("Auto-generated")),
+            Artifact::Wat(_) => panic!("RustBackend should emit RustSource"),
+        }
+    }
 }
@@ -0,0 +1,514 @@
+//! Low-level IR to WebAssembly (WAT) code generator.
+//!
+//! This module implements `WasmCodeGenerator` / `WasmBackend`, which lower
+//! the same low-level IR (`IrFunction`, `IrModule`) that [`super::ir_codegen`]
+//! turns into Rust source into a WebAssembly text-format module instead.
+//!
+//! Only the scalar subset of the IR has a direct wasm encoding: locals typed
+//! `Bool`/narrow ints widen to `i32`, `Int64`/`UInt64` map to `i64`, and the
+//! `Float32`/`Float64` families map to `f32`/`f64`; locals of any other type
+//! (strings, arrays, structs, unresolved `Any`/`Unknown`) are declared as
+//! `i32` handles. The IR instructions with no linear-memory representation
+//! here — `GetIndex`, `SetIndex`, `GetField`, `SetField`, and a `BinOp`/
+//! `UnaryOp` with no wasm opcode for its type — are lowered to a call into a
+//! host-imported runtime function and counted in
+//! [`crate::aot::AotStats::dynamic_fallbacks`], the same counter the rest of
+//! the AoT pipeline uses for Level 3 dynamic dispatch. String and `nothing`
+//! constants are also counted there, but become `i32.const 0` with no call.
+//! `TypeAssert` is emitted as a plain local copy and `Phi` only as a
+//! comment; neither is counted as a fallback.
+
+use super::{Backend, CodegenConfig, CodegenTarget};
+use crate::aot::ir::{
+    BasicBlock, BinOpKind, ConstValue, Instruction, IrFunction, IrModule, Terminator, UnaryOpKind,
+    VarRef,
+};
+use crate::aot::types::JuliaType;
+use crate::aot::{Artifact, AotError, AotResult, AotStats};
+
+/// Name of the host import every dynamic-fallback instruction calls into.
+/// The host environment is expected to provide `(func (param i32) (result
+/// i32))` that dispatches on a boxed `Value` handle.
+const DYNAMIC_IMPORT: &str = "$julia_rt_dynamic";
+
+/// WebAssembly text-format (WAT) code generator.
+#[derive(Debug)]
+pub struct WasmCodeGenerator {
+    config: CodegenConfig,
+    output: String,
+    indent_level: usize,
+    /// Count of instructions that had no scalar wasm encoding and were
+    /// lowered to a call into [`DYNAMIC_IMPORT`] instead.
+    dynamic_fallbacks: usize,
+}
+
+impl WasmCodeGenerator {
+    /// Create a new wasm code generator.
+    pub fn new(config: CodegenConfig) -> Self {
+        Self {
+            config,
+            output: String::new(),
+            indent_level: 0,
+            dynamic_fallbacks: 0,
+        }
+    }
+
+    /// Create with default configuration.
+    pub fn default_config() -> Self {
+        Self::new(CodegenConfig::default())
+    }
+
+    fn write_line(&mut self, line: &str) {
+        for _ in 0..self.indent_level {
+            self.output.push_str(&self.config.indent);
+        }
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    fn indent(&mut self) {
+        self.indent_level += 1;
+    }
+
+    fn dedent(&mut self) {
+        if self.indent_level > 0 {
+            self.indent_level -= 1;
+        }
+    }
+
+    fn local_name(&self, var: &VarRef) -> String {
+        if var.version == 0 {
+            format!("${}", var.name)
+        } else {
+            format!("${}_{}", var.name, var.version)
+        }
+    }
+
+    /// Wasm type for `var`, falling back to `i32` (a boxed `Value` handle)
+    /// for anything without a scalar encoding.
+    fn wasm_type(ty: &JuliaType) -> &'static str {
+        ty.to_wasm_type().unwrap_or("i32")
+    }
+
+    fn emit_dynamic_fallback(&mut self, dest: Option<&VarRef>, reason: &str) {
+        self.dynamic_fallbacks += 1;
+        if self.config.emit_comments {
+            self.write_line(&format!(";; dynamic fallback: {reason}"));
+        }
+        self.write_line("i32.const 0");
+        self.write_line(&format!("call {DYNAMIC_IMPORT}"));
+        if let Some(dest) = dest {
+            self.write_line(&format!("local.set {}", self.local_name(dest)));
+        } else {
+            self.write_line("drop");
+        }
+    }
+
+    fn emit_const(&mut self, value: &ConstValue) {
+        match value {
+            ConstValue::Int64(v) => self.write_line(&format!("i64.const {v}")),
+            ConstValue::Int32(v) => self.write_line(&format!("i32.const {v}")),
+            ConstValue::Float64(v) => self.write_line(&format!("f64.const {v}")),
+            ConstValue::Float32(v) => self.write_line(&format!("f32.const {v}")),
+            ConstValue::Bool(v) => self.write_line(&format!("i32.const {}", *v as i32)),
+            ConstValue::Char(c) => self.write_line(&format!("i32.const {}", *c as u32)),
+            ConstValue::String(_) | ConstValue::Nothing => {
+                self.dynamic_fallbacks += 1;
+                if self.config.emit_comments {
+                    self.write_line(&format!(";; dynamic fallback: unsupported constant {value:?}"));
+                }
+                self.write_line("i32.const 0");
+            }
+        }
+    }
+
+    fn generate_instruction(&mut self, inst: &Instruction) {
+        match inst {
+            Instruction::LoadConst { dest, value } => {
+                self.emit_const(value);
+                self.write_line(&format!("local.set {}", self.local_name(dest)));
+            }
+            Instruction::Copy { dest, src } => {
+                self.write_line(&format!("local.get {}", self.local_name(src)));
+                self.write_line(&format!("local.set {}", self.local_name(dest)));
+            }
+            Instruction::BinOp {
+                dest,
+                op,
+                left,
+                right,
+            } => {
+                let ty = Self::wasm_type(&dest.ty);
+                if let Some(op_str) = binop_to_wasm(ty, *op) {
+                    self.write_line(&format!("local.get {}", self.local_name(left)));
+                    self.write_line(&format!("local.get {}", self.local_name(right)));
+                    self.write_line(op_str.as_str());
+                    self.write_line(&format!("local.set {}", self.local_name(dest)));
+                } else {
+                    self.emit_dynamic_fallback(Some(dest), &format!("{op:?} on {ty}"));
+                }
+            }
+            Instruction::UnaryOp { dest, op, operand } => {
+                let ty = Self::wasm_type(&dest.ty);
+                match unaryop_to_wasm(ty, *op) {
+                    Some(lines) => {
+                        self.write_line(&format!("local.get {}", self.local_name(operand)));
+                        for line in lines {
+                            self.write_line(&line);
+                        }
+                        self.write_line(&format!("local.set {}", self.local_name(dest)));
+                    }
+                    None => self.emit_dynamic_fallback(Some(dest), &format!("{op:?} on {ty}")),
+                }
+            }
+            Instruction::Call { dest, func, args } => {
+                for arg in args {
+                    self.write_line(&format!("local.get {}", self.local_name(arg)));
+                }
+                self.write_line(&format!("call ${func}"));
+                if let Some(dest) = dest {
+                    self.write_line(&format!("local.set {}", self.local_name(dest)));
+                }
+            }
+            Instruction::GetIndex { dest, .. } => {
+                self.emit_dynamic_fallback(Some(dest), "array indexing has no linear-memory layout yet")
+            }
+            Instruction::SetIndex { .. } => {
+                self.emit_dynamic_fallback(None, "array indexing has no linear-memory layout yet")
+            }
+            Instruction::GetField { dest, .. } => {
+                self.emit_dynamic_fallback(Some(dest), "struct layout has no linear-memory layout yet")
+            }
+            Instruction::SetField { .. } => {
+                self.emit_dynamic_fallback(None, "struct layout has no linear-memory layout yet")
+            }
+            Instruction::TypeAssert { dest, src, .. } => {
+                self.write_line(&format!("local.get {}", self.local_name(src)));
+                self.write_line(&format!("local.set {}", self.local_name(dest)));
+            }
+            Instruction::Phi { dest, incoming } => {
+                if self.config.emit_comments {
+                    let sources: Vec<_> = incoming
+                        .iter()
+                        .map(|(label, var)| format!("{}: {}", label, self.local_name(var)))
+                        .collect();
+                    self.write_line(&format!(
+                        ";; phi {} = [{}]",
+                        self.local_name(dest),
+                        sources.join(", ")
+                    ));
+                }
+            }
+        }
+    }
+
+    fn generate_terminator(&mut self, term: &Terminator) {
+        match term {
+            Terminator::Return(Some(var)) => {
+                self.write_line(&format!("local.get {}", self.local_name(var)));
+                self.write_line("return");
+            }
+            Terminator::Return(None) => self.write_line("return"),
+            Terminator::Jump(label) => self.write_line(&format!(";; goto {label}")),
+            Terminator::Branch {
+                cond,
+                then_block,
+                else_block,
+            } => {
+                self.write_line(&format!("local.get {}", self.local_name(cond)));
+                self.write_line(&format!(
+                    ";; if => goto {then_block} else goto {else_block}"
+                ));
+                self.write_line("drop");
+            }
+            Terminator::Switch { value, default, .. } => {
+                self.write_line(&format!("local.get {}", self.local_name(value)));
+                self.write_line(&format!(";; switch, default => goto {default}"));
+                self.write_line("drop");
+            }
+        }
+    }
+
+    fn generate_block(&mut self, block: &BasicBlock) {
+        if self.config.emit_comments {
+            self.write_line(&format!(";; block: {}", block.label));
+        }
+        for inst in &block.instructions {
+            self.generate_instruction(inst);
+        }
+        if let Some(term) = &block.terminator {
+            self.generate_terminator(term);
+        }
+    }
+
+    /// Locals declared by `func` beyond its parameters: every distinct
+    /// `dest`/assignment target seen across its blocks.
+    fn collect_locals(func: &IrFunction) -> Vec<VarRef> {
+        let mut seen = std::collections::HashSet::new();
+        let mut locals = Vec::new();
+        for (name, ty) in &func.params {
+            if seen.insert((name.clone(), 0usize)) {
+                locals.push(VarRef::new(name.clone(), ty.clone()));
+            }
+        }
+        for block in &func.blocks {
+            for inst in &block.instructions {
+                let dest = match inst {
+                    Instruction::LoadConst { dest, .. }
+                    | Instruction::Copy { dest, .. }
+                    | Instruction::BinOp { dest, .. }
+                    | Instruction::UnaryOp { dest, .. }
+                    | Instruction::GetIndex { dest, .. }
+                    | Instruction::GetField { dest, .. }
+                    | Instruction::TypeAssert { dest, .. }
+                    | Instruction::Phi { dest, .. } => Some(dest),
+                    Instruction::Call { dest, .. } => dest.as_ref(),
+                    _ => None,
+                };
+                if let Some(dest) = dest {
+                    if seen.insert((dest.name.clone(), dest.version)) {
+                        locals.push(dest.clone());
+                    }
+                }
+            }
+        }
+        locals
+    }
+
+    /// Generate a `(func ...)` definition for `func`.
+    pub fn generate_function(&mut self, func: &IrFunction) -> AotResult<String> {
+        self.output.clear();
+        self.indent_level = 0;
+
+        let param_names: std::collections::HashSet<_> =
+            func.params.iter().map(|(name, _)| name.as_str()).collect();
+
+        let params: Vec<_> = func
+            .params
+            .iter()
+            .map(|(name, ty)| format!("(param ${} {})", name, Self::wasm_type(ty)))
+            .collect();
+        let result = Self::wasm_type(&func.return_type);
+
+        self.write_line(&format!(
+            "(func ${} {} (result {})",
+            func.name,
+            params.join(" "),
+            result
+        ));
+        self.indent();
+
+        for local in Self::collect_locals(func) {
+            if param_names.contains(local.name.as_str()) && local.version == 0 {
+                continue;
+            }
+            self.write_line(&format!(
+                "(local {} {})",
+                self.local_name(&local),
+                Self::wasm_type(&local.ty)
+            ));
+        }
+
+        for block in &func.blocks {
+            self.generate_block(block);
+        }
+
+        self.dedent();
+        self.write_line(")");
+
+        Ok(std::mem::take(&mut self.output))
+    }
+
+    /// Generate a complete `(module ...)` for `module`.
+    pub fn generate_module(&mut self, module: &IrModule) -> AotResult<String> {
+        self.output.clear();
+        self.indent_level = 0;
+        self.dynamic_fallbacks = 0;
+
+        self.write_line("(module");
+        self.indent();
+        if self.config.emit_comments {
+            self.write_line(";; Auto-generated by SubsetJuliaVM AoT compiler (wasm target)");
+        }
+        self.write_line(&format!(
+            "(import \"env\" \"julia_rt_dynamic\" (func {DYNAMIC_IMPORT} (param i32) (result i32)))"
+        ));
+
+        // `generate_function` clears `self.output` to build each function in
+        // isolation, so stash the header written so far and splice it back
+        // in afterwards (mirrors `RustCodeGenerator::generate_module`).
+        let header = std::mem::take(&mut self.output);
+        let header_indent = self.indent_level;
+
+        let mut functions_code = String::new();
+        for func in &module.functions {
+            functions_code.push_str(&self.generate_function(func)?);
+        }
+
+        self.output = header;
+        self.indent_level = header_indent;
+        for line in functions_code.lines() {
+            self.write_line(line);
+        }
+
+        self.dedent();
+        self.write_line(")");
+
+        Ok(std::mem::take(&mut self.output))
+    }
+}
+
+fn binop_to_wasm(ty: &str, op: BinOpKind) -> Option<String> {
+    let is_float = ty == "f32" || ty == "f64";
+    let instr = match op {
+        BinOpKind::Add => format!("{ty}.add"),
+        BinOpKind::Sub => format!("{ty}.sub"),
+        BinOpKind::Mul => format!("{ty}.mul"),
+        BinOpKind::Div if is_float => format!("{ty}.div"),
+        BinOpKind::Div => format!("{ty}.div_s"),
+        BinOpKind::Rem if is_float => return None,
+        BinOpKind::Rem => format!("{ty}.rem_s"),
+        BinOpKind::Pow => return None,
+        BinOpKind::Eq => format!("{ty}.eq"),
+        BinOpKind::Ne => format!("{ty}.ne"),
+        BinOpKind::Lt if is_float => format!("{ty}.lt"),
+        BinOpKind::Lt => format!("{ty}.lt_s"),
+        BinOpKind::Le if is_float => format!("{ty}.le"),
+        BinOpKind::Le => format!("{ty}.le_s"),
+        BinOpKind::Gt if is_float => format!("{ty}.gt"),
+        BinOpKind::Gt => format!("{ty}.gt_s"),
+        BinOpKind::Ge if is_float => format!("{ty}.ge"),
+        BinOpKind::Ge => format!("{ty}.ge_s"),
+        BinOpKind::BitAnd if is_float => return None,
+        BinOpKind::BitAnd => format!("{ty}.and"),
+        BinOpKind::BitOr if is_float => return None,
+        BinOpKind::BitOr => format!("{ty}.or"),
+        BinOpKind::BitXor if is_float => return None,
+        BinOpKind::BitXor => format!("{ty}.xor"),
+        BinOpKind::Shl if is_float => return None,
+        BinOpKind::Shl => format!("{ty}.shl"),
+        BinOpKind::Shr if is_float => return None,
+        BinOpKind::Shr => format!("{ty}.shr_s"),
+        BinOpKind::And if is_float => return None,
+        BinOpKind::And => format!("{ty}.and"),
+        BinOpKind::Or if is_float => return None,
+        BinOpKind::Or => format!("{ty}.or"),
+    };
+    Some(instr)
+}
+
+fn unaryop_to_wasm(ty: &str, op: UnaryOpKind) -> Option<Vec<String>> {
+    let is_float = ty == "f32" || ty == "f64";
+    match op {
+        UnaryOpKind::Neg if is_float => Some(vec![format!("{ty}.neg")]),
+        UnaryOpKind::Neg => Some(vec![
+            format!("{ty}.const -1"),
+            format!("{ty}.mul"),
+        ]),
+        UnaryOpKind::Not if !is_float => Some(vec![format!("{ty}.eqz")]),
+        UnaryOpKind::Not => None,
+        UnaryOpKind::BitNot if !is_float => Some(vec![format!("{ty}.const -1"), format!("{ty}.xor")]),
+        UnaryOpKind::BitNot => None,
+    }
+}
+
+/// Adapts [`WasmCodeGenerator`] to the [`Backend`] trait.
+#[derive(Debug, Default)]
+pub struct WasmBackend {
+    config: CodegenConfig,
+}
+
+impl WasmBackend {
+    /// Create a new wasm backend with the given configuration.
+    pub fn new(config: CodegenConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Backend for WasmBackend {
+    fn target(&self) -> CodegenTarget {
+        CodegenTarget::Wasm
+    }
+
+    fn emit(&mut self, module: &IrModule, stats: &mut AotStats) -> AotResult<Artifact> {
+        let mut codegen = WasmCodeGenerator::new(self.config.clone());
+        let wat = codegen
+            .generate_module(module)
+            .map_err(|e| AotError::CodegenError(format!("wasm codegen failed: {e}")))?;
+        stats.functions_compiled += module.functions.len();
+        stats.dynamic_fallbacks += codegen.dynamic_fallbacks;
+        Ok(Artifact::Wat(wat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aot::ir::{IrFunction, IrModule, Terminator, VarRef};
+    use crate::aot::types::JuliaType;
+
+    #[test]
+    fn test_wasm_codegen_simple_function() {
+        let mut codegen = WasmCodeGenerator::default_config();
+
+        let mut func = IrFunction::new(
+            "add_one".to_string(),
+            vec![("x".to_string(), JuliaType::Int64)],
+            JuliaType::Int64,
+        );
+        func.entry_block_mut()
+            .unwrap()
+            .set_terminator(Terminator::Return(Some(VarRef::new(
+                "x".to_string(),
+                JuliaType::Int64,
+            ))));
+
+        let result = codegen.generate_function(&func).unwrap();
+        assert!(result.contains("(func $add_one (param $x i64) (result i64)"));
+        assert!(result.contains("local.get $x"));
+        assert!(result.contains("return"));
+    }
+
+    #[test]
+    fn test_wasm_codegen_module_has_dynamic_import() {
+        let mut codegen = WasmCodeGenerator::default_config();
+        let mut module = IrModule::new("test".to_string());
+        let mut func = IrFunction::new("main".to_string(), vec![], JuliaType::Nothing);
+        func.entry_block_mut()
+            .unwrap()
+            .set_terminator(Terminator::Return(None));
+        module.add_function(func);
+
+        let result = codegen.generate_module(&module).unwrap();
+        assert!(result.contains("(module"));
+        assert!(result.contains("julia_rt_dynamic"));
+        assert!(result.contains("(func $main"));
+    }
+
+    #[test]
+    fn test_wasm_backend_target() {
+        let backend = WasmBackend::default();
+        assert_eq!(backend.target(), CodegenTarget::Wasm);
+    }
+
+    #[test]
+    fn test_wasm_backend_emit_counts_dynamic_fallback() {
+        use crate::aot::ir::Instruction;
+
+        let mut backend = WasmBackend::default();
+        let mut stats = AotStats::new();
+        let mut module = IrModule::new("test".to_string());
+        let mut func = IrFunction::new("uses_string".to_string(), vec![], JuliaType::Nothing);
+        let entry = func.entry_block_mut().unwrap();
+        entry.push(Instruction::LoadConst {
+            dest: VarRef::new("s".to_string(), JuliaType::String),
+            value: ConstValue::String("hi".to_string()),
+        });
+        entry.set_terminator(Terminator::Return(None));
+        module.add_function(func);
+
+        let artifact = backend.emit(&module, &mut stats).unwrap();
+        assert!(matches!(artifact, Artifact::Wat(_)));
+        assert_eq!(stats.dynamic_fallbacks, 1);
+    }
+}
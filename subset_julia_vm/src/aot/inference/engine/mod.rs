@@ -1616,6 +1616,14 @@ impl TypeInferenceEngine {
         match name {
             "Int64" | "Int" => StaticType::I64,
             "Int32" => StaticType::I32,
+            "Int16" => StaticType::I16,
+            "Int8" => StaticType::I8,
+            "Int128" => StaticType::I128,
+            "UInt64" => StaticType::U64,
+            "UInt32" => StaticType::U32,
+            "UInt16" => StaticType::U16,
+            "UInt8" => StaticType::U8,
+            "UInt128" => StaticType::U128,
             "Float64" => StaticType::F64,
             "Float32" => StaticType::F32,
             "Bool" => StaticType::Bool,
@@ -1630,6 +1638,14 @@ impl TypeInferenceEngine {
         match name {
             "Int" | "Int64" => StaticType::I64,
             "Int32" => StaticType::I32,
+            "Int16" => StaticType::I16,
+            "Int8" => StaticType::I8,
+            "Int128" => StaticType::I128,
+            "UInt64" => StaticType::U64,
+            "UInt32" => StaticType::U32,
+            "UInt16" => StaticType::U16,
+            "UInt8" => StaticType::U8,
+            "UInt128" => StaticType::U128,
             "Float64" => StaticType::F64,
             "Float32" => StaticType::F32,
             "Bool" => StaticType::Bool,
@@ -17,73 +17,7 @@ impl TypeInferenceEngine {
             return right.clone();
         }
 
-        fn numeric_rank(ty: &StaticType) -> Option<i32> {
-            match ty {
-                StaticType::Bool => Some(0),
-                StaticType::I8 => Some(1),
-                StaticType::U8 => Some(2),
-                StaticType::I16 => Some(3),
-                StaticType::U16 => Some(4),
-                StaticType::I32 => Some(5),
-                StaticType::U32 => Some(6),
-                StaticType::I64 => Some(7),
-                StaticType::U64 => Some(8),
-                StaticType::F32 => Some(100),
-                StaticType::F64 => Some(101),
-                _ => None,
-            }
-        }
-
-        fn is_float(ty: &StaticType) -> bool {
-            matches!(ty, StaticType::F32 | StaticType::F64)
-        }
-
-        let left_rank = numeric_rank(left);
-        let right_rank = numeric_rank(right);
-
-        match (left_rank, right_rank) {
-            (Some(l), Some(r)) => {
-                if is_float(left) && is_float(right) {
-                    if l >= r {
-                        left.clone()
-                    } else {
-                        right.clone()
-                    }
-                } else if is_float(left) {
-                    left.clone()
-                } else if is_float(right) {
-                    right.clone()
-                } else {
-                    let max_rank = l.max(r);
-                    if max_rank <= 0 {
-                        StaticType::I64
-                    } else if max_rank >= 7 {
-                        if l >= r {
-                            left.clone()
-                        } else {
-                            right.clone()
-                        }
-                    } else {
-                        StaticType::I64
-                    }
-                }
-            }
-            (Some(_), None) => {
-                if left.is_numeric() {
-                    left.clone()
-                } else {
-                    StaticType::Any
-                }
-            }
-            (None, Some(_)) => {
-                if right.is_numeric() {
-                    right.clone()
-                } else {
-                    StaticType::Any
-                }
-            }
-            _ => StaticType::Any,
-        }
+        left.promote_with(right)
     }
 
     /// Get common integer type for integer division and modulo.
@@ -896,6 +896,7 @@ fn test_call_site_array_specialization_single_type() {
             type_annotation: None,
             is_varargs: false,
             vararg_count: None,
+            nospecialize: false,
             span: test_span(),
         }],
         kwparams: vec![],
@@ -951,6 +952,7 @@ fn test_call_site_array_specialization_multiple_numeric_types() {
             type_annotation: None,
             is_varargs: false,
             vararg_count: None,
+            nospecialize: false,
             span: test_span(),
         }],
         kwparams: vec![],
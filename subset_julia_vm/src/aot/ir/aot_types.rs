@@ -10,6 +10,62 @@ use std::fmt;
 // Higher-Level AoT IR (for code generation)
 // ============================================================================
 
+/// Julia `Base` exception types that the AoT backend lowers to a typed
+/// Rust struct instead of flattening into an untyped `ErrorException`
+/// (Issue chunk428-1). Giving each kind its own Rust type at codegen time
+/// preserves enough identity for generated `catch`/`isa` code to tell them
+/// apart, instead of every exception collapsing into the same
+/// `panic!("{:?}", e)` shape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AotExceptionKind {
+    DimensionMismatch,
+    KeyError,
+    BoundsError,
+    SystemError,
+    InexactError,
+}
+
+impl AotExceptionKind {
+    /// Recognize a Julia `Base` exception constructor name, e.g. the
+    /// `DimensionMismatch` in `DimensionMismatch("message")`.
+    pub fn from_type_name(name: &str) -> Option<Self> {
+        match name {
+            "DimensionMismatch" => Some(Self::DimensionMismatch),
+            "KeyError" => Some(Self::KeyError),
+            "BoundsError" => Some(Self::BoundsError),
+            "SystemError" => Some(Self::SystemError),
+            "InexactError" => Some(Self::InexactError),
+            _ => None,
+        }
+    }
+
+    /// The Rust struct name emitted for this kind in the codegen prelude.
+    pub fn struct_name(&self) -> &'static str {
+        match self {
+            Self::DimensionMismatch => "DimensionMismatch",
+            Self::KeyError => "KeyError",
+            Self::BoundsError => "BoundsError",
+            Self::SystemError => "SystemError",
+            Self::InexactError => "InexactError",
+        }
+    }
+
+    /// Number of constructor fields, matching the corresponding `Base`
+    /// exception's field list (e.g. `BoundsError(a, i)` carries both the
+    /// object and the offending index). `InexactError`'s field count isn't
+    /// spelled out by the request; one (the attempted value) mirrors how
+    /// `DimensionMismatch`/`KeyError` carry a single descriptive payload.
+    pub fn field_count(&self) -> usize {
+        match self {
+            Self::DimensionMismatch => 1,
+            Self::KeyError => 1,
+            Self::BoundsError => 2,
+            Self::SystemError => 2,
+            Self::InexactError => 1,
+        }
+    }
+}
+
 /// AoT program representation
 ///
 /// Contains all functions, globals, structs, enums, and the main execution block.
@@ -801,6 +857,16 @@ pub enum AotExpr {
     // ========== Structs ==========
     /// Struct construction
     StructNew { name: String, fields: Vec<AotExpr> },
+    /// Construct and immediately raise a recognized `Base` exception type
+    /// (Issue chunk428-1). Unlike `StructNew` + a generic `throw(...)`
+    /// call, this keeps the exception's identity (`kind`) attached to the
+    /// IR node itself, so codegen can emit a distinctly-typed Rust struct
+    /// for `catch`/`isa` to discriminate on rather than folding every
+    /// `Base` exception into the same untyped panic payload.
+    ThrowTyped {
+        kind: AotExceptionKind,
+        fields: Vec<AotExpr>,
+    },
     /// Field access
     FieldAccess {
         object: Box<AotExpr>,
@@ -842,6 +908,13 @@ pub enum AotExpr {
     Convert {
         value: Box<AotExpr>,
         target_ty: StaticType,
+        /// True for an explicit `convert(T, x)`/type-constructor call
+        /// (`Int64(x)`), which Julia raises `InexactError` from when `x`
+        /// doesn't fit `T` exactly (Issue chunk428-3). False for a
+        /// compiler-inserted return-type coercion, which AoT type
+        /// inference has already proven safe and which should stay a
+        /// plain, infallible cast.
+        checked: bool,
     },
 
     // ========== Closures ==========
@@ -909,6 +982,10 @@ impl AotExpr {
                 type_id: 0,
                 name: name.clone(),
             },
+            // `throw` never returns; its static type is irrelevant to any
+            // consumer since control never reaches a point that could use
+            // the value, same treatment as `Box`'s type-erased result.
+            AotExpr::ThrowTyped { .. } => StaticType::Any,
             AotExpr::FieldAccess { field_ty, .. } => field_ty.clone(),
             AotExpr::Ternary { result_ty, .. } => result_ty.clone(),
             AotExpr::Box(_) => StaticType::Any,
@@ -15,7 +15,8 @@ mod tests;
 
 // Re-export all public types
 pub use aot_types::{
-    AotEnum, AotExpr, AotFunction, AotGlobal, AotProgram, AotStmt, AotStruct, DynamicOpDiagnostic,
+    AotEnum, AotExceptionKind, AotExpr, AotFunction, AotGlobal, AotProgram, AotStmt, AotStruct,
+    DynamicOpDiagnostic,
 };
 pub use basic_types::{
     BasicBlock, BinOpKind, ConstValue, Instruction, IrFunction, IrModule, Terminator, UnaryOpKind,
@@ -344,6 +344,29 @@ pub enum AotBuiltinOp {
     // Type conversion intrinsics
     Sitofp, // Signed int to floating point
     Fptosi, // Floating point to signed int
+
+    // Dedicated conversion-op family (Issue chunk428-5), modeled on HUGR's
+    // `conversions` extension: a typed registry of intrinsics the IR
+    // converter can emit directly for the common int/bool/float cases,
+    // instead of routing everything through the generic `convert` name
+    // match in `is_aot_builtin_function`. The widening ops are total; the
+    // checked ops raise `InexactError` on out-of-range/inexact input,
+    // composed from the same `__checked_*` prelude helpers used by
+    // `AotExpr::Convert { checked: true, .. }` (Issue chunk428-3).
+    /// Integer (or Bool) to Bool — `x != 0`, total.
+    IntToBool,
+    /// Bool to integer — `false`/`true` as 0/1, total.
+    BoolToInt,
+    /// Widen an integer to a wider integer type, total.
+    WidenInt,
+    /// Narrow an integer to a narrower/differently-signed integer type,
+    /// raising `InexactError` if the value doesn't round-trip.
+    NarrowIntChecked,
+    /// Integer to floating point, total.
+    IntToFloat,
+    /// Floating point to integer, raising `InexactError` if the value is
+    /// non-integral or out of the target type's range.
+    FloatToIntChecked,
 }
 
 impl AotBuiltinOp {
@@ -457,6 +480,17 @@ impl AotBuiltinOp {
             // Type conversion intrinsics
             AotBuiltinOp::Sitofp => StaticType::F64,
             AotBuiltinOp::Fptosi => StaticType::I64,
+
+            // Dedicated conversion-op family (Issue chunk428-5). These
+            // defaults are placeholders like `Sitofp`/`Fptosi` above — the
+            // actual target width/signedness is set explicitly on the
+            // `AotExpr::CallBuiltin.return_ty` at the construction site.
+            AotBuiltinOp::IntToBool => StaticType::Bool,
+            AotBuiltinOp::BoolToInt => StaticType::I64,
+            AotBuiltinOp::WidenInt => StaticType::I64,
+            AotBuiltinOp::NarrowIntChecked => StaticType::I32,
+            AotBuiltinOp::IntToFloat => StaticType::F64,
+            AotBuiltinOp::FloatToIntChecked => StaticType::I64,
         }
     }
 
@@ -538,6 +572,14 @@ impl AotBuiltinOp {
             // Type conversion intrinsics
             "sitofp" => Some(AotBuiltinOp::Sitofp),
             "fptosi" => Some(AotBuiltinOp::Fptosi),
+
+            // Dedicated conversion-op family (Issue chunk428-5)
+            "itobool" => Some(AotBuiltinOp::IntToBool),
+            "booltoi" => Some(AotBuiltinOp::BoolToInt),
+            "widenint" => Some(AotBuiltinOp::WidenInt),
+            "narrowintchecked" => Some(AotBuiltinOp::NarrowIntChecked),
+            "itofp" => Some(AotBuiltinOp::IntToFloat),
+            "fptoichecked" => Some(AotBuiltinOp::FloatToIntChecked),
             _ => None,
         }
     }
@@ -624,6 +666,14 @@ impl fmt::Display for AotBuiltinOp {
             // Type conversion intrinsics
             AotBuiltinOp::Sitofp => "sitofp",
             AotBuiltinOp::Fptosi => "fptosi",
+
+            // Dedicated conversion-op family (Issue chunk428-5)
+            AotBuiltinOp::IntToBool => "itobool",
+            AotBuiltinOp::BoolToInt => "booltoi",
+            AotBuiltinOp::WidenInt => "widenint",
+            AotBuiltinOp::NarrowIntChecked => "narrowintchecked",
+            AotBuiltinOp::IntToFloat => "itofp",
+            AotBuiltinOp::FloatToIntChecked => "fptoichecked",
         };
         write!(f, "{}", name)
     }
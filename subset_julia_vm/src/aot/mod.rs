@@ -99,11 +99,24 @@ impl AotStats {
     }
 }
 
+/// A compiled AoT artifact, in whichever format the selected
+/// [`codegen::Backend`] emits.
+#[derive(Debug, Clone)]
+pub enum Artifact {
+    /// UTF-8 Rust source, to be compiled with `rustc`.
+    RustSource(String),
+    /// WebAssembly text format (WAT) module. Emitted as text rather than
+    /// an encoded `.wasm` binary since this crate takes on no
+    /// wasm-encoding dependency; assemble it with `wat2wasm` or any
+    /// wasm toolchain that accepts the text format.
+    Wat(String),
+}
+
 /// Output from AoT compilation
 #[derive(Debug)]
 pub struct AotOutput {
-    /// Generated Rust code
-    pub rust_code: String,
+    /// The generated artifact (Rust source, WAT module, ...)
+    pub artifact: Artifact,
     /// Compilation statistics
     pub stats: AotStats,
     /// Warnings generated during compilation
@@ -112,9 +125,9 @@ pub struct AotOutput {
 
 impl AotOutput {
     /// Create a new AoT output
-    pub fn new(rust_code: String, stats: AotStats) -> Self {
+    pub fn new(artifact: Artifact, stats: AotStats) -> Self {
         Self {
-            rust_code,
+            artifact,
             stats,
             warnings: Vec::new(),
         }
@@ -126,18 +139,22 @@ impl AotOutput {
     }
 }
 
-/// Compile bytecode to Rust code
+/// Compile bytecode to the given codegen target.
 ///
 /// This is the main entry point for AoT compilation.
 ///
 /// # Arguments
 ///
 /// * `bytecode` - The bytecode to compile
+/// * `target` - Which [`codegen::Backend`] to lower the optimized IR to
 ///
 /// # Returns
 ///
-/// Returns `AotOutput` containing the generated Rust code and statistics.
-pub fn compile_from_bytecode(_bytecode: &[u8]) -> AotResult<AotOutput> {
+/// Returns `AotOutput` containing the generated artifact and statistics.
+pub fn compile_from_bytecode(
+    _bytecode: &[u8],
+    _target: codegen::CodegenTarget,
+) -> AotResult<AotOutput> {
     // TODO(Issue #3132): Implement compile_from_bytecode in Phase 2
     Err(AotError::InternalError(
         "AoT compilation not yet implemented".to_string(),
@@ -188,15 +205,18 @@ mod tests {
     #[test]
     fn test_aot_output_new() {
         let stats = AotStats::new();
-        let output = AotOutput::new("fn main() {}".to_string(), stats);
-        assert_eq!(output.rust_code, "fn main() {}");
+        let output = AotOutput::new(Artifact::RustSource("fn main() {}".to_string()), stats);
+        match output.artifact {
+            Artifact::RustSource(code) => assert_eq!(code, "fn main() {}"),
+            Artifact::Wat(_) => panic!("expected RustSource"),
+        }
         assert!(output.warnings.is_empty());
     }
 
     #[test]
     fn test_aot_output_add_warning() {
         let stats = AotStats::new();
-        let mut output = AotOutput::new(String::new(), stats);
+        let mut output = AotOutput::new(Artifact::RustSource(String::new()), stats);
         output.add_warning("unused variable".to_string());
         assert_eq!(output.warnings.len(), 1);
         assert_eq!(output.warnings[0], "unused variable");
@@ -204,7 +224,13 @@ mod tests {
 
     #[test]
     fn test_compile_not_implemented() {
-        let result = compile_from_bytecode(&[]);
+        let result = compile_from_bytecode(&[], codegen::CodegenTarget::RustSource);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_compile_not_implemented_wasm() {
+        let result = compile_from_bytecode(&[], codegen::CodegenTarget::Wasm);
         assert!(result.is_err());
     }
 }
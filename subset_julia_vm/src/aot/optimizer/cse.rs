@@ -1,28 +1,93 @@
 //! Common Subexpression Elimination (CSE) for AoT IR
 //!
-//! This module implements CSE optimization that identifies expressions
-//! computed multiple times and replaces them with references to a single computation.
+//! This module implements a two-pass, Halide-style extracting CSE: a first
+//! pass walks every expression in a straight-line region and counts how many
+//! times each structurally-distinct, side-effect-free subexpression occurs
+//! (not just whole right-hand sides, but subexpressions buried inside larger
+//! ones); a second pass synthesizes a `_cse_N` temporary for every
+//! subexpression seen 2+ times, at the point of its first occurrence, and
+//! rewrites every occurrence -- including that first one -- into a
+//! reference to it.
+//!
+//! # Example
+//! ```julia
+//! x = a + b
+//! y = a + b  # same expression
+//! ```
+//! Becomes:
+//! ```julia
+//! _cse_0 = a + b
+//! x = _cse_0
+//! y = _cse_0
+//! ```
+//!
+//! This also catches subexpressions nested inside *different* outer
+//! expressions:
+//! ```julia
+//! x = (a + b) * c
+//! y = (a + b) * d
+//! ```
+//! Becomes:
+//! ```julia
+//! _cse_0 = a + b
+//! x = _cse_0 * c
+//! y = _cse_0 * d
+//! ```
+//!
+//! Loads through `a[i]`/`p.x` are CSE-able too, under a conservative alias
+//! model: such a key is invalidated the moment its base variable is written
+//! to directly, written to through an index/field target (`a[i] = ...`,
+//! `p.x = ...`), or an opaque (non-pure-whitelisted) call appears anywhere
+//! in the region, since that call might mutate any array or struct it can
+//! reach.
 
-use crate::aot::ir::{AotBuiltinOp, AotExpr, AotProgram, AotStmt};
+use crate::aot::ir::{AotBinOp, AotBuiltinOp, AotExpr, AotProgram, AotStmt};
 use crate::aot::types::StaticType;
 use std::collections::{HashMap, HashSet};
 
+/// Sentinel inserted into `modified_vars` meaning "every `index(...)`/
+/// `field(...)` load key is tainted" -- used once an opaque call (one that
+/// isn't on the pure whitelist) appears, since it might mutate any array or
+/// struct reachable through it, not just ones we can name a base for.
+const ALL_ARRAYS_TAINTED: &str = "*";
+
+/// Occurrence bookkeeping for one canonical subexpression key, built by the
+/// counting pass and consulted by the rewrite pass.
+struct Occurrence {
+    /// How many times this exact subexpression was seen in the region.
+    count: usize,
+    /// Index (within the region) of the statement containing the first
+    /// occurrence -- the synthesized `_cse_N = ...` binding is inserted
+    /// immediately before it, so it dominates every use.
+    first_stmt_idx: usize,
+}
+
+/// How aggressively `AotCSE` looks for shareable subexpressions, mirroring
+/// the classic None/Simple/Full optimization-level tiers used by embedded
+/// script-language optimizers (e.g. Rhai's `OptimizationLevel`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptimizationLevel {
+    /// CSE is skipped entirely.
+    None,
+    /// Only the hardcoded pure-builtin/math whitelist participates --
+    /// today's behavior.
+    Simple,
+    /// `Simple`, plus a whole-program interprocedural purity analysis that
+    /// lets repeated calls to provably-pure user functions (`CallStatic`
+    /// and `CallDynamic`) be shared as well.
+    Full,
+}
+
+impl Default for OptimizationLevel {
+    fn default() -> Self {
+        OptimizationLevel::Simple
+    }
+}
+
 /// Common Subexpression Elimination for AoT IR
 ///
 /// This optimization identifies expressions that are computed multiple times
 /// and replaces them with references to a single computation.
-///
-/// # Example
-/// ```julia
-/// x = a + b
-/// y = a + b  # same expression
-/// ```
-/// Becomes:
-/// ```julia
-/// _cse_0 = a + b
-/// x = _cse_0
-/// y = _cse_0
-/// ```
 #[derive(Debug)]
 pub struct AotCSE {
     /// Counter for generating unique CSE variable names
@@ -31,6 +96,11 @@ pub struct AotCSE {
     elimination_count: usize,
     /// Set of pure builtin functions that can be CSE'd
     pure_builtins: HashSet<String>,
+    /// How aggressively to look for shareable subexpressions
+    level: OptimizationLevel,
+    /// Names of user functions proven pure by `analyze_purity`. Only
+    /// populated (and only consulted) at `OptimizationLevel::Full`.
+    pure_functions: HashSet<String>,
 }
 
 impl Default for AotCSE {
@@ -40,8 +110,13 @@ impl Default for AotCSE {
 }
 
 impl AotCSE {
-    /// Create a new CSE optimizer
+    /// Create a new CSE optimizer at the default `Simple` level
     pub fn new() -> Self {
+        Self::with_level(OptimizationLevel::default())
+    }
+
+    /// Create a new CSE optimizer at a specific optimization level
+    pub fn with_level(level: OptimizationLevel) -> Self {
         let mut pure_builtins = HashSet::new();
         // Math functions that are pure (no side effects)
         for name in &[
@@ -54,6 +129,8 @@ impl AotCSE {
             var_counter: 0,
             elimination_count: 0,
             pure_builtins,
+            level,
+            pure_functions: HashSet::new(),
         }
     }
 
@@ -71,6 +148,14 @@ impl AotCSE {
 
     /// Optimize an AoT program with CSE
     pub fn optimize_program(&mut self, program: &mut AotProgram) -> usize {
+        if self.level == OptimizationLevel::None {
+            return 0;
+        }
+
+        if self.level == OptimizationLevel::Full {
+            self.pure_functions = self.analyze_purity(program);
+        }
+
         let mut total_eliminations = 0;
 
         // Optimize each function
@@ -84,191 +169,826 @@ impl AotCSE {
         total_eliminations
     }
 
-    /// Optimize a list of statements
-    fn optimize_stmts(&mut self, stmts: &mut Vec<AotStmt>) -> usize {
-        // Map from expression canonical form to (variable name, type)
-        let mut expr_map: HashMap<String, (String, StaticType)> = HashMap::new();
-        // Track which variables have been modified (invalidates expressions using them)
-        let mut modified_vars: HashSet<String> = HashSet::new();
+    /// Fixpoint interprocedural purity analysis (`Full` level only).
+    ///
+    /// Optimistically assumes every function is pure, then repeatedly scans
+    /// `program.functions` revoking any whose body turns out to require
+    /// impurity -- given the *current* tentative pure set -- until nothing
+    /// changes. This correctly handles (mutual) recursion: a self-recursive
+    /// call is checked against a `pure` set that still contains the
+    /// function's own name, so recursion alone never disqualifies it.
+    fn analyze_purity(&self, program: &AotProgram) -> HashSet<String> {
+        let mut pure: HashSet<String> = program.functions.iter().map(|f| f.name.clone()).collect();
 
-        let mut eliminations = 0;
-        let mut i = 0;
+        loop {
+            let mut changed = false;
+            for func in &program.functions {
+                if !pure.contains(&func.name) {
+                    continue;
+                }
+                let mut locals: HashSet<String> = func.params.iter().map(|(name, _)| name.clone()).collect();
+                if !self.stmts_are_pure(&func.body, &mut locals, &pure) {
+                    pure.remove(&func.name);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
 
-        while i < stmts.len() {
-            // Clone the statement to avoid borrow conflicts
-            let stmt = stmts[i].clone();
+        pure
+    }
 
+    /// Whether every statement in `stmts` is free of externally-visible side
+    /// effects, given `pure_funcs` (the current tentative pure set) and
+    /// `locals` (variables declared within this function so far -- mutating
+    /// one of these is fine; mutating anything else is a write to outer
+    /// state). `locals` accumulates `Let`-bound names as it walks forward,
+    /// matching the order they actually come into scope.
+    fn stmts_are_pure(&self, stmts: &[AotStmt], locals: &mut HashSet<String>, pure_funcs: &HashSet<String>) -> bool {
+        for stmt in stmts {
             match stmt {
-                AotStmt::Let {
-                    ref name,
-                    ref ty,
-                    ref value,
-                    is_mutable,
+                AotStmt::Let { name, value, .. } => {
+                    if !self.expr_is_pure(value, pure_funcs) {
+                        return false;
+                    }
+                    locals.insert(name.clone());
+                }
+                AotStmt::Assign { target, value } | AotStmt::CompoundAssign { target, value, .. } => {
+                    if !self.expr_is_pure(value, pure_funcs) {
+                        return false;
+                    }
+                    match target {
+                        // Reassigning a local is fine; anything else
+                        // (a captured/global variable) is a write to outer
+                        // state.
+                        AotExpr::Var { name, .. } if locals.contains(name) => {}
+                        // Array/tuple/field mutation through an index or
+                        // field target -- also outer-visible.
+                        _ => return false,
+                    }
+                }
+                AotStmt::Expr(value) => {
+                    if !self.expr_is_pure(value, pure_funcs) {
+                        return false;
+                    }
+                }
+                AotStmt::Return(Some(value)) => {
+                    if !self.expr_is_pure(value, pure_funcs) {
+                        return false;
+                    }
+                }
+                AotStmt::Return(None) | AotStmt::Break | AotStmt::Continue => {}
+                AotStmt::If {
+                    condition,
+                    then_branch,
+                    else_branch,
                 } => {
-                    // Check if this expression can be CSE'd
-                    if let Some(canonical) = self.expr_canonical_form(value, &modified_vars) {
-                        if let Some((existing_var, _)) = expr_map.get(&canonical) {
-                            // Replace with reference to existing computation
-                            let new_value = AotExpr::Var {
-                                name: existing_var.clone(),
-                                ty: ty.clone(),
-                            };
-                            stmts[i] = AotStmt::Let {
-                                name: name.clone(),
-                                ty: ty.clone(),
-                                value: new_value,
-                                is_mutable,
-                            };
-                            eliminations += 1;
-                            self.elimination_count += 1;
-                        } else {
-                            // Record this expression for future CSE
-                            expr_map.insert(canonical, (name.clone(), ty.clone()));
+                    if !self.expr_is_pure(condition, pure_funcs) {
+                        return false;
+                    }
+                    let mut then_locals = locals.clone();
+                    if !self.stmts_are_pure(then_branch, &mut then_locals, pure_funcs) {
+                        return false;
+                    }
+                    if let Some(else_stmts) = else_branch {
+                        let mut else_locals = locals.clone();
+                        if !self.stmts_are_pure(else_stmts, &mut else_locals, pure_funcs) {
+                            return false;
                         }
                     }
-
-                    // If mutable, track it
-                    if is_mutable {
-                        modified_vars.insert(name.clone());
-                        // Invalidate any expressions using this variable
-                        self.invalidate_expr_map(&mut expr_map, name);
+                }
+                AotStmt::While { condition, body } => {
+                    if !self.expr_is_pure(condition, pure_funcs) {
+                        return false;
+                    }
+                    let mut body_locals = locals.clone();
+                    if !self.stmts_are_pure(body, &mut body_locals, pure_funcs) {
+                        return false;
                     }
                 }
-                AotStmt::Assign {
-                    ref target,
-                    ref value,
-                } => {
-                    // Track variable modification
-                    if let AotExpr::Var { ref name, .. } = target {
-                        modified_vars.insert(name.clone());
-                        // Invalidate expressions using this variable
-                        self.invalidate_expr_map(&mut expr_map, name);
+                AotStmt::ForRange { var, start, stop, step, body } => {
+                    if !self.expr_is_pure(start, pure_funcs) || !self.expr_is_pure(stop, pure_funcs) {
+                        return false;
                     }
-
-                    // Check if value expression can be CSE'd
-                    if let Some(canonical) = self.expr_canonical_form(value, &modified_vars) {
-                        if let Some((existing_var, ty)) = expr_map.get(&canonical).cloned() {
-                            // Replace value with reference
-                            let new_value = AotExpr::Var {
-                                name: existing_var,
-                                ty,
-                            };
-                            stmts[i] = AotStmt::Assign {
-                                target: target.clone(),
-                                value: new_value,
-                            };
-                            eliminations += 1;
-                            self.elimination_count += 1;
+                    if let Some(step) = step {
+                        if !self.expr_is_pure(step, pure_funcs) {
+                            return false;
                         }
                     }
+                    let mut body_locals = locals.clone();
+                    body_locals.insert(var.clone());
+                    if !self.stmts_are_pure(body, &mut body_locals, pure_funcs) {
+                        return false;
+                    }
                 }
-                AotStmt::CompoundAssign { ref target, .. } => {
-                    // Compound assignment modifies the target
-                    if let AotExpr::Var { ref name, .. } = target {
-                        modified_vars.insert(name.clone());
-                        self.invalidate_expr_map(&mut expr_map, name);
+                AotStmt::ForEach { var, iter, body } => {
+                    if !self.expr_is_pure(iter, pure_funcs) {
+                        return false;
+                    }
+                    let mut body_locals = locals.clone();
+                    body_locals.insert(var.clone());
+                    if !self.stmts_are_pure(body, &mut body_locals, pure_funcs) {
+                        return false;
                     }
                 }
-                AotStmt::If {
-                    ref condition,
-                    ref then_branch,
-                    ref else_branch,
-                } => {
-                    // Recursively optimize branches (with fresh scope)
-                    let mut then_stmts = then_branch.clone();
-                    eliminations += self.optimize_stmts(&mut then_stmts);
+            }
+        }
+        true
+    }
 
-                    let mut else_stmts = else_branch.clone().unwrap_or_default();
-                    if !else_stmts.is_empty() {
-                        eliminations += self.optimize_stmts(&mut else_stmts);
+    /// Whether evaluating `expr` is free of externally-visible side
+    /// effects: it mutates no state outside the expression, does no I/O,
+    /// and dispatches only to functions proven (or assumed, for recursion)
+    /// pure.
+    fn expr_is_pure(&self, expr: &AotExpr, pure_funcs: &HashSet<String>) -> bool {
+        match expr {
+            AotExpr::LitI64(_)
+            | AotExpr::LitI32(_)
+            | AotExpr::LitF64(_)
+            | AotExpr::LitF32(_)
+            | AotExpr::LitBool(_)
+            | AotExpr::LitStr(_)
+            | AotExpr::LitChar(_)
+            | AotExpr::LitNothing
+            | AotExpr::Var { .. } => true,
+            AotExpr::BinOpStatic { left, right, .. } | AotExpr::BinOpDynamic { left, right, .. } => {
+                self.expr_is_pure(left, pure_funcs) && self.expr_is_pure(right, pure_funcs)
+            }
+            AotExpr::UnaryOp { operand, .. } => self.expr_is_pure(operand, pure_funcs),
+            AotExpr::CallStatic { function, args, .. } => {
+                (pure_funcs.contains(function) || self.pure_builtins.contains(function))
+                    && args.iter().all(|a| self.expr_is_pure(a, pure_funcs))
+            }
+            // Multiple dispatch could route to any method sharing this
+            // name; `pure_funcs` is keyed by name, so this only passes once
+            // *every* overload named `function` has been proven pure.
+            AotExpr::CallDynamic { function, args } => {
+                pure_funcs.contains(function) && args.iter().all(|a| self.expr_is_pure(a, pure_funcs))
+            }
+            AotExpr::CallBuiltin { builtin, args, .. } => {
+                !Self::is_impure_builtin(builtin) && args.iter().all(|a| self.expr_is_pure(a, pure_funcs))
+            }
+            AotExpr::ArrayLit { elements, .. } | AotExpr::TupleLit { elements } => {
+                elements.iter().all(|e| self.expr_is_pure(e, pure_funcs))
+            }
+            AotExpr::Index { array, indices, .. } => {
+                self.expr_is_pure(array, pure_funcs) && indices.iter().all(|i| self.expr_is_pure(i, pure_funcs))
+            }
+            AotExpr::Range { start, stop, step, .. } => {
+                self.expr_is_pure(start, pure_funcs)
+                    && self.expr_is_pure(stop, pure_funcs)
+                    && step.as_deref().map(|s| self.expr_is_pure(s, pure_funcs)).unwrap_or(true)
+            }
+            AotExpr::StructNew { fields, .. } => fields.iter().all(|f| self.expr_is_pure(f, pure_funcs)),
+            // Raising an exception is observable control flow -- treat like
+            // a side effect so two `throw`-capable calls are never merged.
+            AotExpr::ThrowTyped { .. } => false,
+            AotExpr::FieldAccess { object, .. } => self.expr_is_pure(object, pure_funcs),
+            AotExpr::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+                ..
+            } => {
+                self.expr_is_pure(condition, pure_funcs)
+                    && self.expr_is_pure(then_expr, pure_funcs)
+                    && self.expr_is_pure(else_expr, pure_funcs)
+            }
+            AotExpr::Box(inner) | AotExpr::Unbox { value: inner, .. } | AotExpr::Convert { value: inner, .. } => {
+                self.expr_is_pure(inner, pure_funcs)
+            }
+            // Closures may capture and later mutate outer state in ways
+            // this analysis doesn't track -- conservatively impure.
+            AotExpr::Lambda { .. } => false,
+        }
+    }
+
+    /// Builtins with an externally-visible effect: I/O or array mutation
+    /// through a reference the caller can still observe.
+    fn is_impure_builtin(builtin: &AotBuiltinOp) -> bool {
+        matches!(
+            builtin,
+            AotBuiltinOp::Print
+                | AotBuiltinOp::Println
+                | AotBuiltinOp::Push
+                | AotBuiltinOp::Pop
+                | AotBuiltinOp::PushFirst
+                | AotBuiltinOp::PopFirst
+                | AotBuiltinOp::Insert
+                | AotBuiltinOp::DeleteAt
+                | AotBuiltinOp::Append
+        )
+    }
+
+    /// Optimize a list of statements.
+    ///
+    /// `modified_vars` is threaded monotonically across the whole region
+    /// (never cleared): once a variable has been written to, no
+    /// subexpression reading it is extractable for the remainder of the
+    /// region, matching Halide's dominance requirement that an extracted
+    /// temporary's inputs must still hold the value they had at every site
+    /// being rewritten. Control-flow statements (`If`/`While`/`ForRange`/
+    /// `ForEach`) act as extraction barriers -- we don't know what their
+    /// bodies mutate, so straight-line runs before and after one are
+    /// counted as two independent regions -- but their own head
+    /// expression(s) (other than a `While` condition) and nested bodies
+    /// are still optimized.
+    fn optimize_stmts(&mut self, stmts: &mut Vec<AotStmt>) -> usize {
+        let mut eliminations = 0;
+        let mut modified_vars: HashSet<String> = HashSet::new();
+        let mut output: Vec<AotStmt> = Vec::with_capacity(stmts.len());
+        let mut block: Vec<AotStmt> = Vec::new();
+
+        for stmt in stmts.drain(..) {
+            if Self::is_cse_barrier(&stmt) {
+                eliminations += self.extract_block(std::mem::take(&mut block), &mut modified_vars, &mut output);
+                eliminations += self.flatten_barrier(stmt, &mut modified_vars, &mut output);
+            } else {
+                block.push(stmt);
+            }
+        }
+        eliminations += self.extract_block(std::mem::take(&mut block), &mut modified_vars, &mut output);
+
+        *stmts = output;
+        eliminations
+    }
+
+    fn is_cse_barrier(stmt: &AotStmt) -> bool {
+        matches!(
+            stmt,
+            AotStmt::If { .. } | AotStmt::While { .. } | AotStmt::ForRange { .. } | AotStmt::ForEach { .. }
+        )
+    }
+
+    /// Run the two-pass extractor over a straight-line run of statements
+    /// (no nested control flow), updating `modified_vars` in place as
+    /// mutations are encountered, and appending the result -- including any
+    /// synthesized `_cse_N` bindings -- onto `output`.
+    fn extract_block(
+        &mut self,
+        block: Vec<AotStmt>,
+        modified_vars: &mut HashSet<String>,
+        output: &mut Vec<AotStmt>,
+    ) -> usize {
+        if block.is_empty() {
+            return 0;
+        }
+
+        // Pass 1: assign each structurally-distinct extractable
+        // subexpression a canonical key and count its occurrences.
+        let mut counts: HashMap<String, Occurrence> = HashMap::new();
+        {
+            let mut scan_vars = modified_vars.clone();
+            for (idx, stmt) in block.iter().enumerate() {
+                self.collect_stmt_subexprs(stmt, &scan_vars, idx, &mut counts);
+                self.apply_mutation(stmt, &mut scan_vars);
+            }
+        }
+
+        // Pass 2: replay the same walk; any key with count >= 2 gets a
+        // `_cse_N` synthesized at its first occurrence, and every
+        // occurrence becomes a reference to it.
+        let mut eliminations = 0;
+        let mut emitted: HashMap<String, String> = HashMap::new();
+        let mut pending_lets: HashMap<usize, Vec<AotStmt>> = HashMap::new();
+        let mut rewritten_stmts = Vec::with_capacity(block.len());
+        for (idx, stmt) in block.into_iter().enumerate() {
+            let stmt = self.rewrite_stmt_exprs(
+                stmt,
+                modified_vars,
+                idx,
+                &counts,
+                &mut emitted,
+                &mut pending_lets,
+                &mut eliminations,
+            );
+            self.apply_mutation(&stmt, modified_vars);
+            rewritten_stmts.push(stmt);
+        }
+
+        for (idx, stmt) in rewritten_stmts.into_iter().enumerate() {
+            if let Some(lets) = pending_lets.remove(&idx) {
+                output.extend(lets);
+            }
+            output.push(stmt);
+        }
+
+        eliminations
+    }
+
+    /// Record that `stmt` modifies a variable -- or invalidates some set of
+    /// `index(...)`/`field(...)` load keys -- if it does. The same rule pass
+    /// 1 and pass 2 must apply identically so their canonical keys line up.
+    fn apply_mutation(&self, stmt: &AotStmt, modified_vars: &mut HashSet<String>) {
+        match stmt {
+            AotStmt::Let { name, value, is_mutable, .. } => {
+                if *is_mutable {
+                    modified_vars.insert(name.clone());
+                }
+                if self.expr_has_opaque_call(value) {
+                    modified_vars.insert(ALL_ARRAYS_TAINTED.to_string());
+                }
+            }
+            AotStmt::Assign { target, value } | AotStmt::CompoundAssign { target, value, .. } => {
+                match target {
+                    AotExpr::Var { name, .. } => {
+                        modified_vars.insert(name.clone());
+                    }
+                    // A write through an index/field target doesn't
+                    // reassign the array/struct variable itself, but it
+                    // invalidates any load keyed off the same base -- walk
+                    // down to find it (falling back to tainting every load
+                    // if the base isn't a plain variable, e.g. a call
+                    // result we can't name).
+                    AotExpr::Index { .. } | AotExpr::FieldAccess { .. } => {
+                        let base = Self::write_target_base(target).unwrap_or_else(|| ALL_ARRAYS_TAINTED.to_string());
+                        modified_vars.insert(base);
                     }
+                    _ => {}
+                }
+                if self.expr_has_opaque_call(value) {
+                    modified_vars.insert(ALL_ARRAYS_TAINTED.to_string());
+                }
+            }
+            AotStmt::Expr(value) | AotStmt::Return(Some(value)) => {
+                if self.expr_has_opaque_call(value) {
+                    modified_vars.insert(ALL_ARRAYS_TAINTED.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
 
-                    stmts[i] = AotStmt::If {
-                        condition: condition.clone(),
-                        then_branch: then_stmts,
-                        else_branch: if else_stmts.is_empty() {
-                            None
-                        } else {
-                            Some(else_stmts)
-                        },
-                    };
-
-                    // After if/else, we can't rely on previous expressions
-                    // (variables might have been modified in branches)
-                    expr_map.clear();
-                }
-                AotStmt::While {
-                    ref condition,
-                    ref body,
-                } => {
-                    // Optimize loop body with fresh scope
-                    let mut body_stmts = body.clone();
-                    eliminations += self.optimize_stmts(&mut body_stmts);
+    /// Resolve the root variable an index/field write ultimately targets
+    /// (`a[i]` -> `a`, `p.x` -> `p`, `a[i].x` -> `a`), so the write only
+    /// invalidates that base's load keys instead of every one. `None` means
+    /// the base isn't a plain variable (e.g. the result of a call), which
+    /// the caller treats as "can't tell, invalidate everything".
+    fn write_target_base(expr: &AotExpr) -> Option<String> {
+        match expr {
+            AotExpr::Var { name, .. } => Some(name.clone()),
+            AotExpr::Index { array, .. } => Self::write_target_base(array),
+            AotExpr::FieldAccess { object, .. } => Self::write_target_base(object),
+            _ => None,
+        }
+    }
 
-                    stmts[i] = AotStmt::While {
-                        condition: condition.clone(),
-                        body: body_stmts,
-                    };
+    /// Whether `expr` might invoke something whose effect on outer arrays or
+    /// structs we can't vouch for -- any call that isn't on the
+    /// pure-builtin/pure-function whitelist. A single such call invalidates
+    /// every `index(...)`/`field(...)` CSE key, since it could mutate any
+    /// array or struct reachable through it, not just ones we can name.
+    fn expr_has_opaque_call(&self, expr: &AotExpr) -> bool {
+        match expr {
+            AotExpr::BinOpStatic { left, right, .. } | AotExpr::BinOpDynamic { left, right, .. } => {
+                self.expr_has_opaque_call(left) || self.expr_has_opaque_call(right)
+            }
+            AotExpr::UnaryOp { operand, .. } => self.expr_has_opaque_call(operand),
+            AotExpr::CallStatic { function, args, .. } => {
+                (!self.pure_builtins.contains(function) && !self.pure_functions.contains(function))
+                    || args.iter().any(|a| self.expr_has_opaque_call(a))
+            }
+            AotExpr::CallDynamic { function, args } => {
+                !self.pure_functions.contains(function) || args.iter().any(|a| self.expr_has_opaque_call(a))
+            }
+            AotExpr::CallBuiltin { builtin, args, .. } => {
+                !self.is_pure_builtin(builtin) || args.iter().any(|a| self.expr_has_opaque_call(a))
+            }
+            AotExpr::Index { array, indices, .. } => {
+                self.expr_has_opaque_call(array) || indices.iter().any(|i| self.expr_has_opaque_call(i))
+            }
+            AotExpr::FieldAccess { object, .. } => self.expr_has_opaque_call(object),
+            AotExpr::ArrayLit { elements, .. } | AotExpr::TupleLit { elements } => {
+                elements.iter().any(|e| self.expr_has_opaque_call(e))
+            }
+            AotExpr::Range { start, stop, step, .. } => {
+                self.expr_has_opaque_call(start)
+                    || self.expr_has_opaque_call(stop)
+                    || step.as_deref().map(|s| self.expr_has_opaque_call(s)).unwrap_or(false)
+            }
+            AotExpr::StructNew { fields, .. } => fields.iter().any(|f| self.expr_has_opaque_call(f)),
+            AotExpr::Ternary { condition, then_expr, else_expr, .. } => {
+                self.expr_has_opaque_call(condition)
+                    || self.expr_has_opaque_call(then_expr)
+                    || self.expr_has_opaque_call(else_expr)
+            }
+            AotExpr::Box(inner) | AotExpr::Unbox { value: inner, .. } | AotExpr::Convert { value: inner, .. } => {
+                self.expr_has_opaque_call(inner)
+            }
+            _ => false,
+        }
+    }
 
-                    // After loop, clear expression map
-                    expr_map.clear();
+    /// Optimize a control-flow statement's own head expression(s) (a
+    /// `While` condition is left as is), recurse
+    /// into its nested body/bodies as independent regions, and push the
+    /// result onto `output`.
+    fn flatten_barrier(
+        &mut self,
+        stmt: AotStmt,
+        modified_vars: &mut HashSet<String>,
+        output: &mut Vec<AotStmt>,
+    ) -> usize {
+        let mut eliminations = 0;
+        let rewritten = match stmt {
+            AotStmt::If {
+                condition,
+                mut then_branch,
+                mut else_branch,
+            } => {
+                let condition = self.extract_single_expr(condition, modified_vars, output, &mut eliminations);
+                eliminations += self.optimize_stmts(&mut then_branch);
+                if let Some(ref mut else_stmts) = else_branch {
+                    eliminations += self.optimize_stmts(else_stmts);
+                }
+                AotStmt::If {
+                    condition,
+                    then_branch,
+                    else_branch,
                 }
+            }
+            AotStmt::While { condition, mut body } => {
+                // The condition is re-evaluated on every iteration, after the
+                // body may have changed its inputs, so nothing is extracted
+                // from it into a binding ahead of the loop.
+                eliminations += self.optimize_stmts(&mut body);
+                AotStmt::While { condition, body }
+            }
+            AotStmt::ForRange {
+                var,
+                start,
+                stop,
+                step,
+                mut body,
+            } => {
+                let start = self.extract_single_expr(start, modified_vars, output, &mut eliminations);
+                let stop = self.extract_single_expr(stop, modified_vars, output, &mut eliminations);
+                let step = step.map(|s| self.extract_single_expr(s, modified_vars, output, &mut eliminations));
+                modified_vars.insert(var.clone());
+                eliminations += self.optimize_stmts(&mut body);
                 AotStmt::ForRange {
-                    ref var,
-                    ref start,
-                    ref stop,
-                    ref step,
-                    ref body,
-                } => {
-                    // Loop variable is modified
-                    modified_vars.insert(var.clone());
-
-                    // Optimize loop body
-                    let mut body_stmts = body.clone();
-                    eliminations += self.optimize_stmts(&mut body_stmts);
-
-                    stmts[i] = AotStmt::ForRange {
-                        var: var.clone(),
-                        start: start.clone(),
-                        stop: stop.clone(),
-                        step: step.clone(),
-                        body: body_stmts,
-                    };
-
-                    // After loop, clear expression map
-                    expr_map.clear();
-                }
-                AotStmt::ForEach {
-                    ref var,
-                    ref iter,
-                    ref body,
-                } => {
-                    modified_vars.insert(var.clone());
+                    var,
+                    start,
+                    stop,
+                    step,
+                    body,
+                }
+            }
+            AotStmt::ForEach { var, iter, mut body } => {
+                let iter = self.extract_single_expr(iter, modified_vars, output, &mut eliminations);
+                modified_vars.insert(var.clone());
+                eliminations += self.optimize_stmts(&mut body);
+                AotStmt::ForEach { var, iter, body }
+            }
+            other => other,
+        };
+        output.push(rewritten);
+        eliminations
+    }
 
-                    let mut body_stmts = body.clone();
-                    eliminations += self.optimize_stmts(&mut body_stmts);
+    /// Extract repeated subexpressions that occur *within a single
+    /// expression* (e.g. an `if` condition referencing the same subterm
+    /// twice), pushing any synthesized `_cse_N` binding onto `output` ahead
+    /// of the statement that will hold `expr`.
+    fn extract_single_expr(
+        &mut self,
+        expr: AotExpr,
+        modified_vars: &HashSet<String>,
+        output: &mut Vec<AotStmt>,
+        eliminations: &mut usize,
+    ) -> AotExpr {
+        let mut counts = HashMap::new();
+        self.collect_subexpr_counts(&expr, modified_vars, 0, &mut counts);
+        let mut emitted = HashMap::new();
+        let mut pending_lets: HashMap<usize, Vec<AotStmt>> = HashMap::new();
+        let rewritten = self.rewrite_expr(expr, modified_vars, 0, &counts, &mut emitted, &mut pending_lets, eliminations);
+        if let Some(lets) = pending_lets.remove(&0) {
+            output.extend(lets);
+        }
+        rewritten
+    }
 
-                    stmts[i] = AotStmt::ForEach {
-                        var: var.clone(),
-                        iter: iter.clone(),
-                        body: body_stmts,
-                    };
+    /// Dispatch to `collect_subexpr_counts` for the expression(s) held by a
+    /// single statement in a straight-line block.
+    fn collect_stmt_subexprs(
+        &self,
+        stmt: &AotStmt,
+        modified_vars: &HashSet<String>,
+        stmt_idx: usize,
+        counts: &mut HashMap<String, Occurrence>,
+    ) {
+        match stmt {
+            AotStmt::Let { value, .. }
+            | AotStmt::Assign { value, .. }
+            | AotStmt::CompoundAssign { value, .. }
+            | AotStmt::Expr(value) => {
+                self.collect_subexpr_counts(value, modified_vars, stmt_idx, counts);
+            }
+            AotStmt::Return(Some(value)) => {
+                self.collect_subexpr_counts(value, modified_vars, stmt_idx, counts);
+            }
+            AotStmt::Return(None) | AotStmt::Break | AotStmt::Continue => {}
+            // Control flow never appears inside a straight-line block --
+            // `optimize_stmts` splits those out before calling here.
+            _ => {}
+        }
+    }
 
-                    expr_map.clear();
+    /// Rewrite the expression(s) held by a single statement, given the
+    /// occurrence counts gathered for the enclosing block.
+    fn rewrite_stmt_exprs(
+        &mut self,
+        stmt: AotStmt,
+        modified_vars: &HashSet<String>,
+        stmt_idx: usize,
+        counts: &HashMap<String, Occurrence>,
+        emitted: &mut HashMap<String, String>,
+        pending_lets: &mut HashMap<usize, Vec<AotStmt>>,
+        eliminations: &mut usize,
+    ) -> AotStmt {
+        match stmt {
+            AotStmt::Let {
+                name,
+                ty,
+                value,
+                is_mutable,
+            } => AotStmt::Let {
+                name,
+                ty,
+                value: self.rewrite_expr(value, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations),
+                is_mutable,
+            },
+            AotStmt::Assign { target, value } => AotStmt::Assign {
+                target,
+                value: self.rewrite_expr(value, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations),
+            },
+            AotStmt::CompoundAssign { target, op, value } => AotStmt::CompoundAssign {
+                target,
+                op,
+                value: self.rewrite_expr(value, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations),
+            },
+            AotStmt::Expr(value) => AotStmt::Expr(self.rewrite_expr(
+                value,
+                modified_vars,
+                stmt_idx,
+                counts,
+                emitted,
+                pending_lets,
+                eliminations,
+            )),
+            AotStmt::Return(Some(value)) => AotStmt::Return(Some(self.rewrite_expr(
+                value,
+                modified_vars,
+                stmt_idx,
+                counts,
+                emitted,
+                pending_lets,
+                eliminations,
+            ))),
+            other => other,
+        }
+    }
+
+    /// Pass-1 worker: walk every node of `expr` bottom-up, bumping the
+    /// occurrence count of each extractable node's canonical key. Always
+    /// recurses into operands/args -- even of an impure call -- since a
+    /// pure subexpression nested inside an otherwise-unshareable call can
+    /// still be a valid CSE candidate on its own.
+    fn collect_subexpr_counts(
+        &self,
+        expr: &AotExpr,
+        modified_vars: &HashSet<String>,
+        stmt_idx: usize,
+        counts: &mut HashMap<String, Occurrence>,
+    ) {
+        match expr {
+            AotExpr::BinOpStatic { left, right, .. } | AotExpr::BinOpDynamic { left, right, .. } => {
+                self.collect_subexpr_counts(left, modified_vars, stmt_idx, counts);
+                self.collect_subexpr_counts(right, modified_vars, stmt_idx, counts);
+            }
+            AotExpr::UnaryOp { operand, .. } => {
+                self.collect_subexpr_counts(operand, modified_vars, stmt_idx, counts);
+            }
+            AotExpr::CallStatic { args, .. } | AotExpr::CallBuiltin { args, .. } | AotExpr::CallDynamic { args, .. } => {
+                for arg in args {
+                    self.collect_subexpr_counts(arg, modified_vars, stmt_idx, counts);
                 }
-                AotStmt::Expr(_) | AotStmt::Return(_) | AotStmt::Break | AotStmt::Continue => {
-                    // No variables modified, no CSE opportunity for standalone expr
+            }
+            AotExpr::Index { array, indices, .. } => {
+                self.collect_subexpr_counts(array, modified_vars, stmt_idx, counts);
+                for index in indices {
+                    self.collect_subexpr_counts(index, modified_vars, stmt_idx, counts);
                 }
             }
+            AotExpr::FieldAccess { object, .. } => {
+                self.collect_subexpr_counts(object, modified_vars, stmt_idx, counts);
+            }
+            // Container literals may alias mutable storage in ways we don't
+            // track a base variable for -- opaque to CSE, same as in
+            // `expr_canonical_form` below, so we don't descend into them.
+            _ => {}
+        }
 
-            i += 1;
+        if Self::should_extract(expr) {
+            if let Some(key) = self.expr_canonical_form(expr, modified_vars) {
+                let occ = counts.entry(key).or_insert(Occurrence {
+                    count: 0,
+                    first_stmt_idx: stmt_idx,
+                });
+                occ.count += 1;
+            }
         }
+    }
 
-        eliminations
+    /// Pass-2 worker: rewrite `expr` bottom-up. A node whose canonical key
+    /// (computed on its *original*, pre-rewrite shape, so it matches what
+    /// pass 1 counted) has count >= 2 is replaced by a `_cse_N` reference,
+    /// synthesizing the binding -- built from the already-rewritten
+    /// children, so nested common subexpressions aren't recomputed -- the
+    /// first time it's seen.
+    fn rewrite_expr(
+        &mut self,
+        expr: AotExpr,
+        modified_vars: &HashSet<String>,
+        stmt_idx: usize,
+        counts: &HashMap<String, Occurrence>,
+        emitted: &mut HashMap<String, String>,
+        pending_lets: &mut HashMap<usize, Vec<AotStmt>>,
+        eliminations: &mut usize,
+    ) -> AotExpr {
+        let key_opt = self.expr_canonical_form(&expr, modified_vars);
+
+        let rewritten = match expr {
+            AotExpr::BinOpStatic { op, left, right, result_ty } => AotExpr::BinOpStatic {
+                op,
+                left: Box::new(self.rewrite_expr(*left, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+                right: Box::new(self.rewrite_expr(*right, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+                result_ty,
+            },
+            AotExpr::BinOpDynamic { op, left, right } => AotExpr::BinOpDynamic {
+                op,
+                left: Box::new(self.rewrite_expr(*left, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+                right: Box::new(self.rewrite_expr(*right, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+            },
+            AotExpr::UnaryOp { op, operand, result_ty } => AotExpr::UnaryOp {
+                op,
+                operand: Box::new(self.rewrite_expr(*operand, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+                result_ty,
+            },
+            AotExpr::CallStatic { function, args, return_ty } => AotExpr::CallStatic {
+                function,
+                args: args
+                    .into_iter()
+                    .map(|a| self.rewrite_expr(a, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations))
+                    .collect(),
+                return_ty,
+            },
+            AotExpr::CallBuiltin { builtin, args, return_ty } => AotExpr::CallBuiltin {
+                builtin,
+                args: args
+                    .into_iter()
+                    .map(|a| self.rewrite_expr(a, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations))
+                    .collect(),
+                return_ty,
+            },
+            AotExpr::CallDynamic { function, args } => AotExpr::CallDynamic {
+                function,
+                args: args
+                    .into_iter()
+                    .map(|a| self.rewrite_expr(a, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations))
+                    .collect(),
+            },
+            AotExpr::Index { array, indices, elem_ty, is_tuple } => AotExpr::Index {
+                array: Box::new(self.rewrite_expr(*array, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+                indices: indices
+                    .into_iter()
+                    .map(|i| self.rewrite_expr(i, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations))
+                    .collect(),
+                elem_ty,
+                is_tuple,
+            },
+            AotExpr::FieldAccess { object, field, field_ty } => AotExpr::FieldAccess {
+                object: Box::new(self.rewrite_expr(*object, modified_vars, stmt_idx, counts, emitted, pending_lets, eliminations)),
+                field,
+                field_ty,
+            },
+            other => other,
+        };
+
+        let Some(key) = key_opt else {
+            return rewritten;
+        };
+        if !counts.get(&key).map(|occ| occ.count >= 2).unwrap_or(false) {
+            return rewritten;
+        }
+
+        let ty = rewritten.get_type();
+        if let Some(existing) = emitted.get(&key) {
+            *eliminations += 1;
+            self.elimination_count += 1;
+            return AotExpr::Var { name: existing.clone(), ty };
+        }
+
+        let var_name = self.gen_cse_var();
+        emitted.insert(key.clone(), var_name.clone());
+        let first_idx = counts.get(&key).expect("presence just checked above").first_stmt_idx;
+        pending_lets.entry(first_idx).or_default().push(AotStmt::Let {
+            name: var_name.clone(),
+            ty: ty.clone(),
+            value: rewritten,
+            is_mutable: false,
+        });
+        AotExpr::Var { name: var_name, ty }
+    }
+
+    /// Whether an expression node is ever worth naming -- literals and bare
+    /// variable reads are free to recompute, so only compound,
+    /// (potentially) pure operations qualify.
+    fn should_extract(expr: &AotExpr) -> bool {
+        matches!(
+            expr,
+            AotExpr::BinOpStatic { .. }
+                | AotExpr::BinOpDynamic { .. }
+                | AotExpr::UnaryOp { .. }
+                | AotExpr::CallStatic { .. }
+                | AotExpr::CallBuiltin { .. }
+                | AotExpr::CallDynamic { .. }
+                | AotExpr::Index { .. }
+                | AotExpr::FieldAccess { .. }
+        )
+    }
+
+    /// Whether swapping `op`'s two operands leaves its value unchanged.
+    fn is_commutative(op: AotBinOp) -> bool {
+        matches!(
+            op,
+            AotBinOp::Add
+                | AotBinOp::Mul
+                | AotBinOp::And
+                | AotBinOp::Or
+                | AotBinOp::BitAnd
+                | AotBinOp::BitOr
+                | AotBinOp::BitXor
+                | AotBinOp::Eq
+                | AotBinOp::Ne
+                | AotBinOp::Egal
+                | AotBinOp::NotEgal
+        )
     }
 
-    /// Generate a canonical string form of an expression for comparison
-    /// Returns None if the expression cannot be CSE'd (has side effects or uses modified vars)
+    /// Whether a chain of `op` nodes (e.g. `(a+b)+c` vs `a+(b+c)`) can be
+    /// flattened into a single sorted multiset key regardless of how it's
+    /// nested. A strict subset of `is_commutative`: equality operators are
+    /// commutative pairwise but don't chain associatively.
+    fn is_associative_for_flattening(op: AotBinOp) -> bool {
+        matches!(
+            op,
+            AotBinOp::Add | AotBinOp::Mul | AotBinOp::And | AotBinOp::Or | AotBinOp::BitAnd | AotBinOp::BitOr | AotBinOp::BitXor
+        )
+    }
+
+    /// Whether reordering/flattening `op`'s operands is safe for a value of
+    /// type `ty`. Every op here is exactly commutative for integers and
+    /// bools, but IEEE 754 float Add/Mul are not associative under
+    /// rounding, so those two are only normalized when `ty` is an integer.
+    fn reassociation_safe(op: AotBinOp, ty: &StaticType) -> bool {
+        match op {
+            AotBinOp::Add | AotBinOp::Mul => ty.is_integer(),
+            _ => true,
+        }
+    }
+
+    /// Flatten a chain of same-`op`, same-`ty` `BinOpStatic` nodes rooted at
+    /// `expr` into its leaf operands' canonical forms. Returns `None` if any
+    /// leaf isn't itself extractable (impure or reads a modified variable).
+    fn flatten_associative_chain(
+        &self,
+        expr: &AotExpr,
+        op: AotBinOp,
+        ty: &StaticType,
+        modified_vars: &HashSet<String>,
+    ) -> Option<Vec<String>> {
+        if let AotExpr::BinOpStatic {
+            op: child_op,
+            left,
+            right,
+            result_ty,
+        } = expr
+        {
+            if *child_op == op && result_ty == ty {
+                let mut leaves = self.flatten_associative_chain(left, op, ty, modified_vars)?;
+                leaves.extend(self.flatten_associative_chain(right, op, ty, modified_vars)?);
+                return Some(leaves);
+            }
+        }
+        Some(vec![self.expr_operand_form(expr, modified_vars)?])
+    }
+
+    /// Generate a canonical string form of an expression for comparison.
+    /// Returns None if the expression cannot be CSE'd (has side effects or
+    /// uses modified vars).
     fn expr_canonical_form(
         &self,
         expr: &AotExpr,
@@ -299,10 +1019,26 @@ impl AotCSE {
                 op,
                 left,
                 right,
-                result_ty: _,
+                result_ty,
             } => {
-                let left_form = self.expr_operand_form(left, modified_vars)?;
-                let right_form = self.expr_operand_form(right, modified_vars)?;
+                // Associative ops (Add/Mul/And/Or/bitwise) form chains like
+                // `(a+b)+c` vs `a+(b+c)` that are structurally different
+                // trees but the same value; flatten the whole chain into a
+                // sorted multiset so both shapes hash identically. Gated
+                // behind `reassociation_safe` so f64 Add/Mul -- where
+                // reordering changes rounding -- are never touched.
+                if Self::is_associative_for_flattening(*op) && Self::reassociation_safe(*op, result_ty) {
+                    if let Some(mut leaves) = self.flatten_associative_chain(expr, *op, result_ty, modified_vars) {
+                        leaves.sort();
+                        return Some(format!("assoc({:?},[{}])", op, leaves.join(",")));
+                    }
+                }
+
+                let mut left_form = self.expr_operand_form(left, modified_vars)?;
+                let mut right_form = self.expr_operand_form(right, modified_vars)?;
+                if Self::is_commutative(*op) && Self::reassociation_safe(*op, result_ty) && right_form < left_form {
+                    std::mem::swap(&mut left_form, &mut right_form);
+                }
                 Some(format!("binop({:?},{},{})", op, left_form, right_form))
             }
 
@@ -328,7 +1064,7 @@ impl AotCSE {
                 args,
                 return_ty: _,
             } => {
-                if !self.pure_builtins.contains(function) {
+                if !self.pure_builtins.contains(function) && !self.pure_functions.contains(function) {
                     return None;
                 }
                 let mut args_form = Vec::new();
@@ -351,17 +1087,59 @@ impl AotCSE {
                 for arg in args {
                     args_form.push(self.expr_operand_form(arg, modified_vars)?);
                 }
+                // min(a,b) == min(b,a), same for max -- sort so both orders
+                // hash identically.
+                if matches!(builtin, AotBuiltinOp::Min | AotBuiltinOp::Max) && args_form.len() == 2 {
+                    args_form.sort();
+                }
                 Some(format!("builtin({:?},{})", builtin, args_form.join(",")))
             }
 
-            // Dynamic calls - not safe to CSE (may have side effects)
-            AotExpr::CallDynamic { .. } => None,
+            // Dynamic calls - only safe to CSE (Full level) once every
+            // overload sharing this name has been proven pure; multiple
+            // dispatch means we can't tell which one a given call resolves
+            // to ahead of time.
+            AotExpr::CallDynamic { function, args } => {
+                if !self.pure_functions.contains(function) {
+                    return None;
+                }
+                let mut args_form = Vec::new();
+                for arg in args {
+                    args_form.push(self.expr_operand_form(arg, modified_vars)?);
+                }
+                Some(format!("call_dyn({},{})", function, args_form.join(",")))
+            }
+
+            // Array/tuple literals aren't worth CSE'ing as a whole and don't
+            // carry a base variable to invalidate against a later write, so
+            // they stay opaque.
+            AotExpr::ArrayLit { .. } | AotExpr::TupleLit { .. } => None,
 
-            // Array/Index operations - not safe to CSE (array might be modified)
-            AotExpr::ArrayLit { .. } | AotExpr::Index { .. } | AotExpr::TupleLit { .. } => None,
+            // Loads: `a[i]`/`p.x` are CSE-able like any pure read, but a load
+            // aliases whatever storage `a`/`p` names, so it's invalidated
+            // the moment that storage might have changed -- either because
+            // we lost track of it entirely (an opaque call appeared; see
+            // `apply_mutation`) or because a write specifically targeted it.
+            AotExpr::Index { array, indices, is_tuple, .. } => {
+                if modified_vars.contains(ALL_ARRAYS_TAINTED) {
+                    return None;
+                }
+                let array_form = self.expr_operand_form(array, modified_vars)?;
+                let mut indices_form = Vec::new();
+                for index in indices {
+                    indices_form.push(self.expr_operand_form(index, modified_vars)?);
+                }
+                let op_name = if *is_tuple { "tindex" } else { "index" };
+                Some(format!("{}({},[{}])", op_name, array_form, indices_form.join(",")))
+            }
 
-            // Field access - could be CSE'd but complex
-            AotExpr::FieldAccess { .. } => None,
+            AotExpr::FieldAccess { object, field, .. } => {
+                if modified_vars.contains(ALL_ARRAYS_TAINTED) {
+                    return None;
+                }
+                let object_form = self.expr_operand_form(object, modified_vars)?;
+                Some(format!("field({},{})", object_form, field))
+            }
 
             // Other expressions - skip
             _ => None,
@@ -393,33 +1171,59 @@ impl AotCSE {
 
     /// Check if a builtin operation is pure (no side effects)
     fn is_pure_builtin(&self, builtin: &AotBuiltinOp) -> bool {
-        matches!(
-            builtin,
-            AotBuiltinOp::Sqrt
-                | AotBuiltinOp::Abs
-                | AotBuiltinOp::Sin
-                | AotBuiltinOp::Cos
-                | AotBuiltinOp::Tan
-                | AotBuiltinOp::Exp
-                | AotBuiltinOp::Log
-                | AotBuiltinOp::Floor
-                | AotBuiltinOp::Ceil
-                | AotBuiltinOp::Round
-                | AotBuiltinOp::Min
-                | AotBuiltinOp::Max
-                | AotBuiltinOp::Length
-        )
+        is_pure_builtin_op(builtin)
     }
 
-    /// Invalidate expressions in the map that use a modified variable
-    fn invalidate_expr_map(&self, expr_map: &mut HashMap<String, (String, StaticType)>, var: &str) {
-        let var_pattern = format!("var:{}", var);
-        expr_map.retain(|canonical, _| !canonical.contains(&var_pattern));
+    /// Interprocedural purity set for `program`, independent of any CSE run.
+    /// Exposed so other passes (e.g. the loop optimizer's LICM) can treat a
+    /// call to a proven-pure function as invariant without duplicating this
+    /// analysis.
+    pub(super) fn pure_functions_for(program: &AotProgram) -> HashSet<String> {
+        Self::with_level(OptimizationLevel::Full).analyze_purity(program)
     }
 }
 
-/// Optimize an AoT program with Common Subexpression Elimination
+/// Check if a builtin operation is pure (no side effects). Standalone so the
+/// loop optimizer's LICM pass can share this whitelist instead of keeping its
+/// own copy.
+pub(super) fn is_pure_builtin_op(builtin: &AotBuiltinOp) -> bool {
+    matches!(
+        builtin,
+        AotBuiltinOp::Sqrt
+            | AotBuiltinOp::Abs
+            | AotBuiltinOp::Sin
+            | AotBuiltinOp::Cos
+            | AotBuiltinOp::Tan
+            | AotBuiltinOp::Exp
+            | AotBuiltinOp::Log
+            | AotBuiltinOp::Floor
+            | AotBuiltinOp::Ceil
+            | AotBuiltinOp::Round
+            | AotBuiltinOp::Min
+            | AotBuiltinOp::Max
+            | AotBuiltinOp::Length
+            | AotBuiltinOp::Sitofp
+            | AotBuiltinOp::Fptosi
+            | AotBuiltinOp::IntToBool
+            | AotBuiltinOp::BoolToInt
+            | AotBuiltinOp::WidenInt
+            | AotBuiltinOp::IntToFloat
+        // NarrowIntChecked/FloatToIntChecked can raise InexactError,
+        // so (like Div/Mod/Rem, also absent from this list) they're excluded here even
+        // though they're otherwise side-effect-free.
+    )
+}
+
+/// Optimize an AoT program with Common Subexpression Elimination at the
+/// default `OptimizationLevel::Simple` level
 pub fn optimize_aot_program_with_cse(program: &mut AotProgram) -> usize {
     let mut cse = AotCSE::new();
     cse.optimize_program(program)
 }
+
+/// Optimize an AoT program with Common Subexpression Elimination at a
+/// specific `OptimizationLevel`
+pub fn optimize_aot_program_with_cse_level(program: &mut AotProgram, level: OptimizationLevel) -> usize {
+    let mut cse = AotCSE::with_level(level);
+    cse.optimize_program(program)
+}
@@ -0,0 +1,306 @@
+//! Dead binding elimination for AoT IR
+//!
+//! `dce.rs` removes unreachable statements and constant-condition
+//! branches, but it never looks at whether a `Let` binding is actually
+//! read again. CSE and LICM both synthesize `_cse_N`/`_licm_N`
+//! temporaries that can end up unused once later passes fold or rewrite
+//! their uses away; this pass removes any `Let` whose bound name has no
+//! remaining reads, computed via a use-count scan over the rest of its
+//! block.
+
+use super::cse::is_pure_builtin_op;
+use crate::aot::ir::{AotExpr, AotProgram, AotStmt};
+use std::collections::HashMap;
+
+/// Dead binding eliminator for AoT IR
+///
+/// Removes `Let` bindings whose value is side-effect-free and whose
+/// bound name is never read.
+#[derive(Debug, Default)]
+pub struct AotDeadBindingEliminator {
+    /// Number of bindings eliminated
+    elimination_count: usize,
+}
+
+impl AotDeadBindingEliminator {
+    /// Create a new dead binding eliminator
+    pub fn new() -> Self {
+        Self {
+            elimination_count: 0,
+        }
+    }
+
+    /// Get the number of eliminations performed
+    pub fn elimination_count(&self) -> usize {
+        self.elimination_count
+    }
+
+    /// Optimize an AoT program with dead binding elimination
+    pub fn optimize_program(&mut self, program: &mut AotProgram) -> usize {
+        let mut total = 0;
+
+        for func in &mut program.functions {
+            total += self.optimize_stmts(&mut func.body);
+        }
+        total += self.optimize_stmts(&mut program.main);
+
+        total
+    }
+
+    /// Optimize a list of statements
+    fn optimize_stmts(&mut self, stmts: &mut Vec<AotStmt>) -> usize {
+        let mut total = 0;
+
+        // Recurse into nested blocks first.
+        for stmt in stmts.iter_mut() {
+            match stmt {
+                AotStmt::If {
+                    then_branch,
+                    else_branch,
+                    ..
+                } => {
+                    total += self.optimize_stmts(then_branch);
+                    if let Some(else_b) = else_branch {
+                        total += self.optimize_stmts(else_b);
+                    }
+                }
+                AotStmt::While { body, .. }
+                | AotStmt::ForRange { body, .. }
+                | AotStmt::ForEach { body, .. } => {
+                    total += self.optimize_stmts(body);
+                }
+                _ => {}
+            }
+        }
+
+        // Removing one dead binding can orphan another (e.g. `let a = b + 1`
+        // becomes dead once nothing reads `a`, which may make `b`'s only
+        // read disappear too), so keep scanning until a pass removes nothing.
+        loop {
+            let mut use_counts: HashMap<String, usize> = HashMap::new();
+            for stmt in stmts.iter() {
+                Self::count_uses_in_stmt(stmt, &mut use_counts);
+            }
+
+            let dead_idx = stmts.iter().position(|stmt| match stmt {
+                AotStmt::Let { name, value, .. } => {
+                    use_counts.get(name).copied().unwrap_or(0) == 0 && Self::expr_is_pure(value)
+                }
+                _ => false,
+            });
+
+            match dead_idx {
+                Some(idx) => {
+                    stmts.remove(idx);
+                    total += 1;
+                }
+                None => break,
+            }
+        }
+
+        self.elimination_count += total;
+        total
+    }
+
+    fn count_uses_in_stmt(stmt: &AotStmt, counts: &mut HashMap<String, usize>) {
+        match stmt {
+            AotStmt::Let { value, .. } => Self::count_uses_in_expr(value, counts),
+            AotStmt::Assign { target, value } | AotStmt::CompoundAssign { target, value, .. } => {
+                Self::count_uses_in_expr(target, counts);
+                Self::count_uses_in_expr(value, counts);
+            }
+            AotStmt::Expr(value) | AotStmt::Return(Some(value)) => {
+                Self::count_uses_in_expr(value, counts)
+            }
+            AotStmt::Return(None) | AotStmt::Break | AotStmt::Continue => {}
+            AotStmt::If {
+                condition,
+                then_branch,
+                else_branch,
+            } => {
+                Self::count_uses_in_expr(condition, counts);
+                for s in then_branch {
+                    Self::count_uses_in_stmt(s, counts);
+                }
+                if let Some(else_b) = else_branch {
+                    for s in else_b {
+                        Self::count_uses_in_stmt(s, counts);
+                    }
+                }
+            }
+            AotStmt::While { condition, body } => {
+                Self::count_uses_in_expr(condition, counts);
+                for s in body {
+                    Self::count_uses_in_stmt(s, counts);
+                }
+            }
+            AotStmt::ForRange {
+                start,
+                stop,
+                step,
+                body,
+                ..
+            } => {
+                Self::count_uses_in_expr(start, counts);
+                Self::count_uses_in_expr(stop, counts);
+                if let Some(step_expr) = step {
+                    Self::count_uses_in_expr(step_expr, counts);
+                }
+                for s in body {
+                    Self::count_uses_in_stmt(s, counts);
+                }
+            }
+            AotStmt::ForEach { iter, body, .. } => {
+                Self::count_uses_in_expr(iter, counts);
+                for s in body {
+                    Self::count_uses_in_stmt(s, counts);
+                }
+            }
+        }
+    }
+
+    fn count_uses_in_expr(expr: &AotExpr, counts: &mut HashMap<String, usize>) {
+        match expr {
+            AotExpr::Var { name, .. } => {
+                *counts.entry(name.clone()).or_insert(0) += 1;
+            }
+            AotExpr::BinOpStatic { left, right, .. } | AotExpr::BinOpDynamic { left, right, .. } => {
+                Self::count_uses_in_expr(left, counts);
+                Self::count_uses_in_expr(right, counts);
+            }
+            AotExpr::UnaryOp { operand, .. } => Self::count_uses_in_expr(operand, counts),
+            AotExpr::CallStatic { args, .. }
+            | AotExpr::CallDynamic { args, .. }
+            | AotExpr::CallBuiltin { args, .. } => {
+                for a in args {
+                    Self::count_uses_in_expr(a, counts);
+                }
+            }
+            AotExpr::ArrayLit { elements, .. } | AotExpr::TupleLit { elements } => {
+                for e in elements {
+                    Self::count_uses_in_expr(e, counts);
+                }
+            }
+            AotExpr::Index {
+                array, indices, ..
+            } => {
+                Self::count_uses_in_expr(array, counts);
+                for i in indices {
+                    Self::count_uses_in_expr(i, counts);
+                }
+            }
+            AotExpr::Range {
+                start, stop, step, ..
+            } => {
+                Self::count_uses_in_expr(start, counts);
+                Self::count_uses_in_expr(stop, counts);
+                if let Some(s) = step {
+                    Self::count_uses_in_expr(s, counts);
+                }
+            }
+            AotExpr::StructNew { fields, .. } | AotExpr::ThrowTyped { fields, .. } => {
+                for f in fields {
+                    Self::count_uses_in_expr(f, counts);
+                }
+            }
+            AotExpr::FieldAccess { object, .. } => Self::count_uses_in_expr(object, counts),
+            AotExpr::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+                ..
+            } => {
+                Self::count_uses_in_expr(condition, counts);
+                Self::count_uses_in_expr(then_expr, counts);
+                Self::count_uses_in_expr(else_expr, counts);
+            }
+            AotExpr::Box(inner)
+            | AotExpr::Unbox { value: inner, .. }
+            | AotExpr::Convert { value: inner, .. } => Self::count_uses_in_expr(inner, counts),
+            AotExpr::Lambda {
+                body, captures, ..
+            } => {
+                // The body only runs when the lambda is called, but its
+                // reads of the enclosing block's bindings are still
+                // counted as uses, conservatively; each capture also
+                // names a binding from the enclosing scope that the
+                // closure keeps alive.
+                Self::count_uses_in_expr(body, counts);
+                for (name, _) in captures {
+                    *counts.entry(name.clone()).or_insert(0) += 1;
+                }
+            }
+            AotExpr::LitI64(_)
+            | AotExpr::LitI32(_)
+            | AotExpr::LitF64(_)
+            | AotExpr::LitF32(_)
+            | AotExpr::LitBool(_)
+            | AotExpr::LitStr(_)
+            | AotExpr::LitChar(_)
+            | AotExpr::LitNothing => {}
+        }
+    }
+
+    /// Whether removing this expression (because nothing reads its
+    /// binding) is observably safe, i.e. it can't throw or call into
+    /// anything other than a known-pure builtin.
+    fn expr_is_pure(expr: &AotExpr) -> bool {
+        match expr {
+            AotExpr::LitI64(_)
+            | AotExpr::LitI32(_)
+            | AotExpr::LitF64(_)
+            | AotExpr::LitF32(_)
+            | AotExpr::LitBool(_)
+            | AotExpr::LitStr(_)
+            | AotExpr::LitChar(_)
+            | AotExpr::LitNothing
+            | AotExpr::Var { .. } => true,
+            AotExpr::BinOpStatic { left, right, .. } | AotExpr::BinOpDynamic { left, right, .. } => {
+                Self::expr_is_pure(left) && Self::expr_is_pure(right)
+            }
+            AotExpr::UnaryOp { operand, .. } => Self::expr_is_pure(operand),
+            AotExpr::CallBuiltin { builtin, args, .. } => {
+                is_pure_builtin_op(builtin) && args.iter().all(Self::expr_is_pure)
+            }
+            // Static/dynamic calls may have arbitrary side effects; we have
+            // no interprocedural purity info here, so be conservative.
+            AotExpr::CallStatic { .. } | AotExpr::CallDynamic { .. } => false,
+            // Raising an exception is itself the side effect.
+            AotExpr::ThrowTyped { .. } => false,
+            AotExpr::ArrayLit { elements, .. } | AotExpr::TupleLit { elements } => {
+                elements.iter().all(Self::expr_is_pure)
+            }
+            AotExpr::Index { array, indices, .. } => {
+                Self::expr_is_pure(array) && indices.iter().all(Self::expr_is_pure)
+            }
+            AotExpr::Range { start, stop, step, .. } => {
+                Self::expr_is_pure(start)
+                    && Self::expr_is_pure(stop)
+                    && step.as_deref().map(Self::expr_is_pure).unwrap_or(true)
+            }
+            AotExpr::StructNew { fields, .. } => fields.iter().all(Self::expr_is_pure),
+            AotExpr::FieldAccess { object, .. } => Self::expr_is_pure(object),
+            AotExpr::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+                ..
+            } => {
+                Self::expr_is_pure(condition)
+                    && Self::expr_is_pure(then_expr)
+                    && Self::expr_is_pure(else_expr)
+            }
+            AotExpr::Box(inner)
+            | AotExpr::Unbox { value: inner, .. }
+            | AotExpr::Convert { value: inner, .. } => Self::expr_is_pure(inner),
+            // Building a closure has no effect until it's called.
+            AotExpr::Lambda { .. } => true,
+        }
+    }
+}
+
+/// Optimize an AoT program with dead binding elimination
+pub fn optimize_aot_program_with_dead_binding_elimination(program: &mut AotProgram) -> usize {
+    let mut eliminator = AotDeadBindingEliminator::new();
+    eliminator.optimize_program(program)
+}
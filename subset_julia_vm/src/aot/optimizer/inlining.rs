@@ -273,6 +273,9 @@ impl AotInliner {
             } => elements
                 .iter()
                 .any(|e| Self::expr_calls_function(target, e, program, visited)),
+            AotExpr::ThrowTyped { fields, .. } => fields
+                .iter()
+                .any(|f| Self::expr_calls_function(target, f, program, visited)),
             AotExpr::Ternary {
                 condition,
                 then_expr,
@@ -404,6 +407,9 @@ impl AotInliner {
                 fields: elements, ..
             } => elements.iter().all(Self::expr_is_pure),
 
+            // Throwing is always a side effect, regardless of its fields.
+            AotExpr::ThrowTyped { .. } => false,
+
             AotExpr::Index { array, indices, .. } => {
                 Self::expr_is_pure(array) && indices.iter().all(Self::expr_is_pure)
             }
@@ -623,6 +629,7 @@ impl AotInliner {
                     AotExpr::Convert {
                         value: Box::new(arg.clone()),
                         target_ty: param_ty.clone(),
+                        checked: false,
                     }
                 } else {
                     arg.clone()
@@ -903,6 +910,13 @@ impl AotInliner {
                     .map(|f| self.rename_variables_in_expr(f, rename_map))
                     .collect(),
             },
+            AotExpr::ThrowTyped { kind, fields } => AotExpr::ThrowTyped {
+                kind: *kind,
+                fields: fields
+                    .iter()
+                    .map(|f| self.rename_variables_in_expr(f, rename_map))
+                    .collect(),
+            },
             AotExpr::FieldAccess {
                 object,
                 field,
@@ -930,9 +944,14 @@ impl AotInliner {
                 value: Box::new(self.rename_variables_in_expr(value, rename_map)),
                 target_ty: target_ty.clone(),
             },
-            AotExpr::Convert { value, target_ty } => AotExpr::Convert {
+            AotExpr::Convert {
+                value,
+                target_ty,
+                checked,
+            } => AotExpr::Convert {
                 value: Box::new(self.rename_variables_in_expr(value, rename_map)),
                 target_ty: target_ty.clone(),
+                checked: *checked,
             },
             AotExpr::Lambda {
                 params,
@@ -4,7 +4,8 @@
 //! - Loop Invariant Code Motion (LICM)
 //! - Loop unrolling for constant bounds
 
-use crate::aot::ir::{AotBuiltinOp, AotExpr, AotProgram, AotStmt};
+use super::cse::{is_pure_builtin_op, AotCSE};
+use crate::aot::ir::{AotExpr, AotProgram, AotStmt};
 use crate::aot::types::StaticType;
 use std::collections::HashSet;
 
@@ -42,6 +43,11 @@ pub struct AotLoopOptimizer {
     /// Statistics
     licm_count: usize,
     unroll_count: usize,
+    /// Names of user functions proven pure by `AotCSE`'s interprocedural
+    /// purity analysis, reused so a call to a provably pure function can be
+    /// hoisted out of a loop like any other pure subexpression. Populated
+    /// once per `optimize_program` call, not per loop.
+    pure_functions: HashSet<String>,
 }
 
 impl AotLoopOptimizer {
@@ -57,6 +63,7 @@ impl AotLoopOptimizer {
             var_counter: 0,
             licm_count: 0,
             unroll_count: 0,
+            pure_functions: HashSet::new(),
         }
     }
 
@@ -64,6 +71,10 @@ impl AotLoopOptimizer {
     pub fn optimize_program(&mut self, program: &mut AotProgram) -> usize {
         let mut total_optimized = 0;
 
+        if self.config.enable_licm {
+            self.pure_functions = AotCSE::pure_functions_for(program);
+        }
+
         // Optimize functions
         for func in &mut program.functions {
             total_optimized += self.optimize_stmts(&mut func.body);
@@ -116,9 +127,12 @@ impl AotLoopOptimizer {
                         }
                     }
 
-                    // Try LICM
+                    // Try LICM. The barrier set is everything assigned
+                    // anywhere in the body, plus the loop variable itself.
                     if self.config.enable_licm {
-                        let hoisted = self.try_hoist_invariants(var, body);
+                        let mut barrier_vars = Self::collect_modified_vars(body);
+                        barrier_vars.insert(var.clone());
+                        let hoisted = self.try_hoist_invariants(body, &barrier_vars);
                         if !hoisted.is_empty() {
                             let num_hoisted = hoisted.len();
                             // Insert hoisted statements before the loop
@@ -138,7 +152,9 @@ impl AotLoopOptimizer {
 
                     // Try LICM
                     if self.config.enable_licm {
-                        let hoisted = self.try_hoist_invariants(var, body);
+                        let mut barrier_vars = Self::collect_modified_vars(body);
+                        barrier_vars.insert(var.clone());
+                        let hoisted = self.try_hoist_invariants(body, &barrier_vars);
                         if !hoisted.is_empty() {
                             let num_hoisted = hoisted.len();
                             for (j, stmt) in hoisted.into_iter().enumerate() {
@@ -155,8 +171,23 @@ impl AotLoopOptimizer {
                     // Recursively optimize body
                     total_optimized += self.optimize_stmts(body);
 
-                    // For while loops, we can't easily determine invariants
-                    // because we don't have a clear loop variable
+                    // A `While` loop has no distinguished loop variable, but
+                    // the barrier is the same idea: anything assigned inside
+                    // the body (which is how the loop ever terminates or
+                    // changes behavior across iterations) blocks hoisting.
+                    if self.config.enable_licm {
+                        let barrier_vars = Self::collect_modified_vars(body);
+                        let hoisted = self.try_hoist_invariants(body, &barrier_vars);
+                        if !hoisted.is_empty() {
+                            let num_hoisted = hoisted.len();
+                            for (j, stmt) in hoisted.into_iter().enumerate() {
+                                stmts.insert(i + j, stmt);
+                            }
+                            self.licm_count += 1;
+                            total_optimized += 1;
+                            i += num_hoisted;
+                        }
+                    }
                 }
                 AotStmt::If {
                     condition: _,
@@ -244,63 +275,187 @@ impl AotLoopOptimizer {
         Some(unrolled)
     }
 
-    /// Try to hoist loop invariants out of a loop
-    fn try_hoist_invariants(&mut self, loop_var: &str, body: &mut Vec<AotStmt>) -> Vec<AotStmt> {
+    /// Try to hoist loop invariants out of a loop body. `barrier_vars` is the
+    /// set of names that disqualify a subexpression from hoisting -- the
+    /// loop variable plus everything `collect_modified_vars` finds assigned
+    /// anywhere in `body`. Walks into `Let`/`Assign`/`CompoundAssign`/`Expr`/
+    /// `Return`/`If`-condition positions (not just whole top-level `Let`
+    /// values), so an invariant subexpression buried inside a larger one is
+    /// hoisted too, with its in-body occurrence replaced by a `Var`.
+    fn try_hoist_invariants(
+        &mut self,
+        body: &mut [AotStmt],
+        barrier_vars: &HashSet<String>,
+    ) -> Vec<AotStmt> {
         let mut hoisted = Vec::new();
-        let mut i = 0;
+        // `collect_modified_vars` only reports mutable `Let`s, but an
+        // immutable `Let` in the body is rebound every iteration too, so
+        // its name becomes a barrier for the statements after it.
+        let mut barrier_vars = barrier_vars.clone();
 
-        while i < body.len() {
-            if let AotStmt::Let {
-                name,
-                ty,
-                value,
-                is_mutable,
-            } = &body[i]
-            {
-                // Skip simple variable references - they're already efficient and hoisting
-                // them would just add indirection. This also prevents infinite loops where
-                // a hoisted variable reference itself gets hoisted repeatedly.
-                let is_simple_var = matches!(value, AotExpr::Var { .. });
-
-                // Check if the value is loop invariant and worth hoisting
-                if !is_simple_var && self.is_loop_invariant(value, loop_var, body) && !is_mutable {
-                    // Create a new unique name for the hoisted variable
-                    let new_name = format!("_licm{}_{}", self.var_counter, name);
-                    self.var_counter += 1;
-
-                    // Add the hoisted statement
-                    hoisted.push(AotStmt::Let {
-                        name: new_name.clone(),
-                        ty: ty.clone(),
-                        value: value.clone(),
-                        is_mutable: false,
-                    });
-
-                    // Replace the original statement with a reference to the hoisted variable
-                    body[i] = AotStmt::Let {
-                        name: name.clone(),
-                        ty: ty.clone(),
-                        value: AotExpr::Var {
-                            name: new_name,
-                            ty: ty.clone(),
-                        },
-                        is_mutable: false,
-                    };
+        for stmt in body.iter_mut() {
+            match stmt {
+                AotStmt::Let { name, value, .. } => {
+                    let taken = std::mem::replace(value, AotExpr::LitNothing);
+                    *value = self.hoist_expr(taken, &barrier_vars, &mut hoisted);
+                    barrier_vars.insert(name.clone());
+                }
+                AotStmt::Assign { value, .. }
+                | AotStmt::CompoundAssign { value, .. }
+                | AotStmt::Expr(value)
+                | AotStmt::Return(Some(value)) => {
+                    let taken = std::mem::replace(value, AotExpr::LitNothing);
+                    *value = self.hoist_expr(taken, &barrier_vars, &mut hoisted);
                 }
+                AotStmt::If { condition, .. } => {
+                    let taken = std::mem::replace(condition, AotExpr::LitNothing);
+                    *condition = self.hoist_expr(taken, &barrier_vars, &mut hoisted);
+                }
+                _ => {}
             }
-            i += 1;
         }
 
         hoisted
     }
 
-    /// Check if an expression is loop invariant
-    fn is_loop_invariant(&self, expr: &AotExpr, loop_var: &str, body: &[AotStmt]) -> bool {
-        // Collect all variables modified in the loop
-        let modified_vars = Self::collect_modified_vars(body);
+    /// Recursively hoist the maximal pure, loop-invariant subexpressions out
+    /// of `expr`. Each hoisted subexpression becomes a `_licm_N` `Let`
+    /// appended to `hoisted`, and its occurrence in `expr` is replaced by a
+    /// `Var` reference to that binding. Once a subexpression is hoisted in
+    /// full, its children aren't also hoisted separately -- CSE (which runs
+    /// after loop optimization) is what then shares identical hoisted
+    /// computations across loops.
+    fn hoist_expr(
+        &mut self,
+        expr: AotExpr,
+        barrier_vars: &HashSet<String>,
+        hoisted: &mut Vec<AotStmt>,
+    ) -> AotExpr {
+        if !Self::is_trivial(&expr) && Self::expr_is_invariant(&expr, barrier_vars, &self.pure_functions) {
+            let ty = expr.get_type();
+            let new_name = format!("_licm_{}", self.var_counter);
+            self.var_counter += 1;
+            hoisted.push(AotStmt::Let {
+                name: new_name.clone(),
+                ty: ty.clone(),
+                value: expr,
+                is_mutable: false,
+            });
+            return AotExpr::Var { name: new_name, ty };
+        }
 
-        // Check if the expression only depends on invariant values
-        Self::expr_is_invariant(expr, loop_var, &modified_vars)
+        match expr {
+            AotExpr::BinOpStatic { op, left, right, result_ty } => AotExpr::BinOpStatic {
+                op,
+                left: Box::new(self.hoist_expr(*left, barrier_vars, hoisted)),
+                right: Box::new(self.hoist_expr(*right, barrier_vars, hoisted)),
+                result_ty,
+            },
+            AotExpr::BinOpDynamic { op, left, right } => AotExpr::BinOpDynamic {
+                op,
+                left: Box::new(self.hoist_expr(*left, barrier_vars, hoisted)),
+                right: Box::new(self.hoist_expr(*right, barrier_vars, hoisted)),
+            },
+            AotExpr::UnaryOp { op, operand, result_ty } => AotExpr::UnaryOp {
+                op,
+                operand: Box::new(self.hoist_expr(*operand, barrier_vars, hoisted)),
+                result_ty,
+            },
+            AotExpr::CallStatic { function, args, return_ty } => AotExpr::CallStatic {
+                function,
+                args: args.into_iter().map(|a| self.hoist_expr(a, barrier_vars, hoisted)).collect(),
+                return_ty,
+            },
+            AotExpr::CallDynamic { function, args } => AotExpr::CallDynamic {
+                function,
+                args: args.into_iter().map(|a| self.hoist_expr(a, barrier_vars, hoisted)).collect(),
+            },
+            AotExpr::CallBuiltin { builtin, args, return_ty } => AotExpr::CallBuiltin {
+                builtin,
+                args: args.into_iter().map(|a| self.hoist_expr(a, barrier_vars, hoisted)).collect(),
+                return_ty,
+            },
+            AotExpr::ArrayLit { elements, elem_ty, shape } => AotExpr::ArrayLit {
+                elements: elements.into_iter().map(|e| self.hoist_expr(e, barrier_vars, hoisted)).collect(),
+                elem_ty,
+                shape,
+            },
+            AotExpr::TupleLit { elements } => AotExpr::TupleLit {
+                elements: elements.into_iter().map(|e| self.hoist_expr(e, barrier_vars, hoisted)).collect(),
+            },
+            AotExpr::Index { array, indices, elem_ty, is_tuple } => AotExpr::Index {
+                array: Box::new(self.hoist_expr(*array, barrier_vars, hoisted)),
+                indices: indices.into_iter().map(|idx| self.hoist_expr(idx, barrier_vars, hoisted)).collect(),
+                elem_ty,
+                is_tuple,
+            },
+            AotExpr::Range { start, stop, step, elem_ty } => AotExpr::Range {
+                start: Box::new(self.hoist_expr(*start, barrier_vars, hoisted)),
+                stop: Box::new(self.hoist_expr(*stop, barrier_vars, hoisted)),
+                step: step.map(|s| Box::new(self.hoist_expr(*s, barrier_vars, hoisted))),
+                elem_ty,
+            },
+            AotExpr::StructNew { name, fields } => AotExpr::StructNew {
+                name,
+                fields: fields.into_iter().map(|f| self.hoist_expr(f, barrier_vars, hoisted)).collect(),
+            },
+            AotExpr::FieldAccess { object, field, field_ty } => AotExpr::FieldAccess {
+                object: Box::new(self.hoist_expr(*object, barrier_vars, hoisted)),
+                field,
+                field_ty,
+            },
+            AotExpr::Ternary { condition, then_expr, else_expr, result_ty } => AotExpr::Ternary {
+                condition: Box::new(self.hoist_expr(*condition, barrier_vars, hoisted)),
+                then_expr: Box::new(self.hoist_expr(*then_expr, barrier_vars, hoisted)),
+                else_expr: Box::new(self.hoist_expr(*else_expr, barrier_vars, hoisted)),
+                result_ty,
+            },
+            AotExpr::Box(inner) => AotExpr::Box(Box::new(self.hoist_expr(*inner, barrier_vars, hoisted))),
+            AotExpr::Unbox { value, target_ty } => AotExpr::Unbox {
+                value: Box::new(self.hoist_expr(*value, barrier_vars, hoisted)),
+                target_ty,
+            },
+            AotExpr::Convert { value, target_ty, checked } => AotExpr::Convert {
+                value: Box::new(self.hoist_expr(*value, barrier_vars, hoisted)),
+                target_ty,
+                checked,
+            },
+            // `ThrowTyped` is a side effect, and `Lambda` captures aren't
+            // analyzed -- leave both (and their children) alone.
+            other => other,
+        }
+    }
+
+    /// Literals and bare variable references are already as cheap as a
+    /// hoisted binding would be -- hoisting them would just add a layer of
+    /// indirection (and, for a `Var`, risks re-hoisting the same name
+    /// forever).
+    fn is_trivial(expr: &AotExpr) -> bool {
+        matches!(
+            expr,
+            AotExpr::LitI64(_)
+                | AotExpr::LitI32(_)
+                | AotExpr::LitF64(_)
+                | AotExpr::LitF32(_)
+                | AotExpr::LitBool(_)
+                | AotExpr::LitStr(_)
+                | AotExpr::LitChar(_)
+                | AotExpr::LitNothing
+                | AotExpr::Var { .. }
+        )
+    }
+
+    /// Resolve the root variable an index/field write target ultimately names
+    /// (`a[i]` -> `a`, `p.x` -> `p`, `a[i].x` -> `a`). `None` means the base isn't a plain
+    /// variable (e.g. the result of a call), in which case there's no named variable whose
+    /// invariance needs barring.
+    fn write_target_base(expr: &AotExpr) -> Option<String> {
+        match expr {
+            AotExpr::Var { name, .. } => Some(name.clone()),
+            AotExpr::Index { array, .. } => Self::write_target_base(array),
+            AotExpr::FieldAccess { object, .. } => Self::write_target_base(object),
+            _ => None,
+        }
     }
 
     /// Collect all variables that are modified in a list of statements
@@ -316,14 +471,14 @@ impl AotLoopOptimizer {
                         modified.insert(name.clone());
                     }
                 }
-                AotStmt::Assign { target, .. } => {
-                    if let AotExpr::Var { name, .. } = target {
-                        modified.insert(name.clone());
-                    }
-                }
-                AotStmt::CompoundAssign { target, .. } => {
-                    if let AotExpr::Var { name, .. } = target {
-                        modified.insert(name.clone());
+                AotStmt::Assign { target, .. } | AotStmt::CompoundAssign { target, .. } => {
+                    // A write through an index/field target (`arr[i] = ...`, `p.x = ...`)
+                    // doesn't rebind the array/struct variable itself, but any load of that
+                    // same base is no longer loop-invariant -- walk down to the base variable
+                    // and bar it too, matching the alias-invalidation CSE already does for
+                    // this exact hazard (Issue chunk429-5's `cse.rs::apply_mutation`).
+                    if let Some(base) = Self::write_target_base(target) {
+                        modified.insert(base);
                     }
                 }
                 AotStmt::If {
@@ -348,8 +503,17 @@ impl AotLoopOptimizer {
         modified
     }
 
-    /// Check if an expression is invariant with respect to loop variables
-    fn expr_is_invariant(expr: &AotExpr, loop_var: &str, modified_vars: &HashSet<String>) -> bool {
+    /// Check if an expression is loop invariant: every `Var` it reads is
+    /// outside `barrier_vars` (the loop variable, if any, plus everything
+    /// assigned anywhere in the body), and every call it makes is either a
+    /// builtin on `cse`'s pure-builtin whitelist or a user function already
+    /// proven pure by `pure_functions` -- the same purity story CSE uses for
+    /// straight-line code, reused here instead of duplicated.
+    fn expr_is_invariant(
+        expr: &AotExpr,
+        barrier_vars: &HashSet<String>,
+        pure_functions: &HashSet<String>,
+    ) -> bool {
         match expr {
             // Literals are always invariant
             AotExpr::LitI64(_)
@@ -361,73 +525,68 @@ impl AotLoopOptimizer {
             | AotExpr::LitChar(_)
             | AotExpr::LitNothing => true,
 
-            // Variable is invariant if it's not the loop variable and not modified in the loop
-            AotExpr::Var { name, .. } => name != loop_var && !modified_vars.contains(name),
+            // Variable is invariant if it's not assigned anywhere in the loop
+            AotExpr::Var { name, .. } => !barrier_vars.contains(name),
 
             // Binary operations are invariant if both operands are invariant
             AotExpr::BinOpStatic { left, right, .. }
             | AotExpr::BinOpDynamic { left, right, .. } => {
-                Self::expr_is_invariant(left, loop_var, modified_vars)
-                    && Self::expr_is_invariant(right, loop_var, modified_vars)
+                Self::expr_is_invariant(left, barrier_vars, pure_functions)
+                    && Self::expr_is_invariant(right, barrier_vars, pure_functions)
             }
 
             // Unary operations are invariant if operand is invariant
             AotExpr::UnaryOp { operand, .. } => {
-                Self::expr_is_invariant(operand, loop_var, modified_vars)
+                Self::expr_is_invariant(operand, barrier_vars, pure_functions)
             }
 
             // Pure builtin calls are invariant if all args are invariant
             AotExpr::CallBuiltin { builtin, args, .. } => {
-                let is_pure = matches!(
-                    builtin,
-                    AotBuiltinOp::Sqrt
-                        | AotBuiltinOp::Sin
-                        | AotBuiltinOp::Cos
-                        | AotBuiltinOp::Tan
-                        | AotBuiltinOp::Abs
-                        | AotBuiltinOp::Floor
-                        | AotBuiltinOp::Ceil
-                        | AotBuiltinOp::Round
-                        | AotBuiltinOp::Min
-                        | AotBuiltinOp::Max
-                        | AotBuiltinOp::Length
-                );
-                is_pure
+                is_pure_builtin_op(builtin)
                     && args
                         .iter()
-                        .all(|a| Self::expr_is_invariant(a, loop_var, modified_vars))
+                        .all(|a| Self::expr_is_invariant(a, barrier_vars, pure_functions))
             }
 
-            // Function calls are generally not invariant (may have side effects)
-            AotExpr::CallStatic { .. } | AotExpr::CallDynamic { .. } => false,
+            // Calls to functions proven pure by the interprocedural analysis
+            // are invariant as long as their arguments are too. `CallDynamic`
+            // is keyed purely by name, like CSE's check, so it only passes
+            // once every overload sharing that name is proven pure.
+            AotExpr::CallStatic { function, args, .. }
+            | AotExpr::CallDynamic { function, args } => {
+                pure_functions.contains(function)
+                    && args
+                        .iter()
+                        .all(|a| Self::expr_is_invariant(a, barrier_vars, pure_functions))
+            }
 
             // Array literals are invariant if all elements are invariant
             AotExpr::ArrayLit { elements, .. } | AotExpr::TupleLit { elements } => elements
                 .iter()
-                .all(|e| Self::expr_is_invariant(e, loop_var, modified_vars)),
+                .all(|e| Self::expr_is_invariant(e, barrier_vars, pure_functions)),
 
             // Array access is not invariant if the array or index depends on loop var
             AotExpr::Index { array, indices, .. } => {
-                Self::expr_is_invariant(array, loop_var, modified_vars)
+                Self::expr_is_invariant(array, barrier_vars, pure_functions)
                     && indices
                         .iter()
-                        .all(|i| Self::expr_is_invariant(i, loop_var, modified_vars))
+                        .all(|i| Self::expr_is_invariant(i, barrier_vars, pure_functions))
             }
 
             // Range is invariant if bounds are invariant
             AotExpr::Range {
                 start, stop, step, ..
             } => {
-                Self::expr_is_invariant(start, loop_var, modified_vars)
-                    && Self::expr_is_invariant(stop, loop_var, modified_vars)
+                Self::expr_is_invariant(start, barrier_vars, pure_functions)
+                    && Self::expr_is_invariant(stop, barrier_vars, pure_functions)
                     && step.as_ref().map_or(true, |s| {
-                        Self::expr_is_invariant(s, loop_var, modified_vars)
+                        Self::expr_is_invariant(s, barrier_vars, pure_functions)
                     })
             }
 
             // Field access is invariant if object is invariant
             AotExpr::FieldAccess { object, .. } => {
-                Self::expr_is_invariant(object, loop_var, modified_vars)
+                Self::expr_is_invariant(object, barrier_vars, pure_functions)
             }
 
             // Ternary is invariant if all parts are invariant
@@ -437,25 +596,28 @@ impl AotLoopOptimizer {
                 else_expr,
                 ..
             } => {
-                Self::expr_is_invariant(condition, loop_var, modified_vars)
-                    && Self::expr_is_invariant(then_expr, loop_var, modified_vars)
-                    && Self::expr_is_invariant(else_expr, loop_var, modified_vars)
+                Self::expr_is_invariant(condition, barrier_vars, pure_functions)
+                    && Self::expr_is_invariant(then_expr, barrier_vars, pure_functions)
+                    && Self::expr_is_invariant(else_expr, barrier_vars, pure_functions)
             }
 
             // Struct creation is invariant if all fields are invariant
             AotExpr::StructNew { fields, .. } => fields
                 .iter()
-                .all(|f| Self::expr_is_invariant(f, loop_var, modified_vars)),
+                .all(|f| Self::expr_is_invariant(f, barrier_vars, pure_functions)),
+
+            // Throwing is a side effect - never hoist out of the loop
+            AotExpr::ThrowTyped { .. } => false,
 
             // Box/Unbox/Convert are invariant if inner is invariant
             AotExpr::Box(inner)
             | AotExpr::Unbox { value: inner, .. }
             | AotExpr::Convert { value: inner, .. } => {
-                Self::expr_is_invariant(inner, loop_var, modified_vars)
+                Self::expr_is_invariant(inner, barrier_vars, pure_functions)
             }
 
-            // Lambdas are invariant (the definition itself doesn't change)
-            AotExpr::Lambda { .. } => true,
+            // Lambda captures aren't analyzed -- conservatively non-invariant.
+            AotExpr::Lambda { .. } => false,
         }
     }
 
@@ -703,6 +865,13 @@ impl AotLoopOptimizer {
                     .map(|f| self.substitute_var_in_expr(f, var, value))
                     .collect(),
             },
+            AotExpr::ThrowTyped { kind, fields } => AotExpr::ThrowTyped {
+                kind: *kind,
+                fields: fields
+                    .iter()
+                    .map(|f| self.substitute_var_in_expr(f, var, value))
+                    .collect(),
+            },
             AotExpr::FieldAccess {
                 object,
                 field,
@@ -736,9 +905,11 @@ impl AotLoopOptimizer {
             AotExpr::Convert {
                 value: inner,
                 target_ty,
+                checked,
             } => AotExpr::Convert {
                 value: Box::new(self.substitute_var_in_expr(inner, var, value)),
                 target_ty: target_ty.clone(),
+                checked: *checked,
             },
             AotExpr::Lambda {
                 params,
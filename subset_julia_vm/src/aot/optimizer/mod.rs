@@ -6,15 +6,18 @@
 mod constant_folding;
 mod cse;
 mod dce;
+mod dead_binding;
 mod inlining;
 mod loop_opt;
 mod pass;
+mod pipeline;
 mod strength_reduction;
 
 // Re-exports
 pub use constant_folding::{optimize_aot_program_with_constant_folding, AotConstantFolder};
-pub use cse::{optimize_aot_program_with_cse, AotCSE};
+pub use cse::{optimize_aot_program_with_cse, optimize_aot_program_with_cse_level, AotCSE, OptimizationLevel};
 pub use dce::{optimize_aot_program_with_dce, AotDeadCodeEliminator};
+pub use dead_binding::{optimize_aot_program_with_dead_binding_elimination, AotDeadBindingEliminator};
 pub use inlining::{optimize_aot_program_with_inlining, AotInliner, InlineCandidate};
 pub use loop_opt::{
     optimize_aot_program_with_loops, optimize_aot_program_with_loops_config, AotLoopOptimizer,
@@ -24,6 +27,7 @@ pub use pass::{
     CommonSubexpressionElimination, ConstantFolding, DeadCodeElimination, Inlining,
     LoopInvariantCodeMotion, StrengthReduction,
 };
+pub use pipeline::{optimize_aot_program_with_pass_pipeline, AotPass, AotPassPipeline};
 pub use strength_reduction::{optimize_aot_program_with_strength_reduction, AotStrengthReducer};
 
 use super::ir::{AotProgram, IrFunction, IrModule};
@@ -77,6 +81,10 @@ pub fn optimize_aot_program_full(program: &mut AotProgram) -> usize {
     total += optimize_aot_program_with_cse(program);
     total += optimize_aot_program_with_dce(program);
 
+    // 8. Fold/CSE/dead-binding-elimination to a fixpoint, to clean up the
+    //    _cse_N/_licm_N temporaries that earlier passes can orphan
+    total += optimize_aot_program_with_pass_pipeline(program);
+
     total
 }
 
@@ -585,4 +593,129 @@ mod tests {
             program.main.len()
         );
     }
+
+    // ========== AoT Dead Binding Eliminator Tests ==========
+
+    #[test]
+    fn test_dead_binding_eliminator_creation() {
+        let dbe = AotDeadBindingEliminator::new();
+        assert_eq!(dbe.elimination_count(), 0);
+    }
+
+    #[test]
+    fn test_dead_binding_removes_unused_let() {
+        let mut program = AotProgram::new();
+
+        // let tmp = 1 + 2; return 0;  (tmp is never read, should be removed)
+        program.main = vec![
+            AotStmt::Let {
+                name: "tmp".to_string(),
+                ty: StaticType::I64,
+                value: AotExpr::BinOpStatic {
+                    op: AotBinOp::Add,
+                    left: Box::new(AotExpr::LitI64(1)),
+                    right: Box::new(AotExpr::LitI64(2)),
+                    result_ty: StaticType::I64,
+                },
+                is_mutable: false,
+            },
+            AotStmt::Return(Some(AotExpr::LitI64(0))),
+        ];
+
+        let eliminations = optimize_aot_program_with_dead_binding_elimination(&mut program);
+        assert_eq!(eliminations, 1, "Expected 1 elimination");
+        assert_eq!(program.main.len(), 1, "Expected dead let to be removed");
+    }
+
+    #[test]
+    fn test_dead_binding_keeps_used_let() {
+        let mut program = AotProgram::new();
+
+        // let x = 1; return x;  (x is read, must be kept)
+        program.main = vec![
+            AotStmt::Let {
+                name: "x".to_string(),
+                ty: StaticType::I64,
+                value: AotExpr::LitI64(1),
+                is_mutable: false,
+            },
+            AotStmt::Return(Some(AotExpr::Var {
+                name: "x".to_string(),
+                ty: StaticType::I64,
+            })),
+        ];
+
+        let eliminations = optimize_aot_program_with_dead_binding_elimination(&mut program);
+        assert_eq!(eliminations, 0, "Used binding should not be eliminated");
+        assert_eq!(program.main.len(), 2);
+    }
+
+    #[test]
+    fn test_dead_binding_keeps_effectful_let() {
+        let mut program = AotProgram::new();
+
+        // let _unused = some_function(); return 0;
+        // Even though `_unused` is never read, its value may have side
+        // effects, so the binding must stay.
+        program.main = vec![
+            AotStmt::Let {
+                name: "_unused".to_string(),
+                ty: StaticType::I64,
+                value: AotExpr::CallStatic {
+                    function: "some_function".to_string(),
+                    args: vec![],
+                    return_ty: StaticType::I64,
+                },
+                is_mutable: false,
+            },
+            AotStmt::Return(Some(AotExpr::LitI64(0))),
+        ];
+
+        let eliminations = optimize_aot_program_with_dead_binding_elimination(&mut program);
+        assert_eq!(eliminations, 0, "Effectful binding must not be eliminated");
+        assert_eq!(program.main.len(), 2);
+    }
+
+    // ========== AoT Pass Pipeline Tests ==========
+
+    #[test]
+    fn test_aot_pass_pipeline_default_iterations() {
+        let mut program = AotProgram::new();
+        program.main = vec![AotStmt::Return(Some(AotExpr::LitI64(1)))];
+
+        let pipeline = AotPassPipeline::new();
+        let changes = pipeline.run(&mut program);
+        assert_eq!(changes, 0, "Nothing to optimize should report 0 changes");
+    }
+
+    #[test]
+    fn test_aot_pass_pipeline_folds_and_cleans_up() {
+        let mut program = AotProgram::new();
+
+        // let tmp = 2 + 3; return 0;
+        // Folding turns `tmp`'s value into a literal, and since `tmp` is
+        // never read, dead binding elimination should remove it too.
+        program.main = vec![
+            AotStmt::Let {
+                name: "tmp".to_string(),
+                ty: StaticType::I64,
+                value: AotExpr::BinOpStatic {
+                    op: AotBinOp::Add,
+                    left: Box::new(AotExpr::LitI64(2)),
+                    right: Box::new(AotExpr::LitI64(3)),
+                    result_ty: StaticType::I64,
+                },
+                is_mutable: false,
+            },
+            AotStmt::Return(Some(AotExpr::LitI64(0))),
+        ];
+
+        let changes = optimize_aot_program_with_pass_pipeline(&mut program);
+        assert!(changes > 0, "Expected at least the fold and the dead-binding removal");
+        assert_eq!(
+            program.main.len(),
+            1,
+            "Expected the dead let to be cleaned up, leaving just the return"
+        );
+    }
 }
@@ -0,0 +1,113 @@
+//! A composable pipeline of whole-program AoT optimization passes
+//!
+//! `OptimizationPass` (see [`super::pass`]) operates on the lower-level
+//! `IrFunction`/`IrModule` basic-block IR. `AotPass` is its counterpart
+//! for the `AotProgram`/`AotStmt`/`AotExpr` IR that `AotCSE` and
+//! `AotLoopOptimizer` already work on, so passes like constant folding,
+//! CSE, and dead binding elimination can be driven together to a
+//! fixpoint: folding surfaces more shared subexpressions for CSE to
+//! merge, and CSE's `_cse_N` temporaries (along with `AotLoopOptimizer`'s
+//! `_licm_N` ones) are exactly the kind of binding dead binding
+//! elimination is meant to clean up once later rewrites stop reading
+//! them.
+
+use super::constant_folding::AotConstantFolder;
+use super::cse::AotCSE;
+use super::dead_binding::AotDeadBindingEliminator;
+use crate::aot::ir::AotProgram;
+
+/// A single whole-program optimization pass
+pub trait AotPass {
+    /// Human-readable name, used only for diagnostics
+    fn name(&self) -> &str;
+
+    /// Run the pass once, returning the number of changes it made
+    fn run(&mut self, program: &mut AotProgram) -> usize;
+}
+
+impl AotPass for AotConstantFolder {
+    fn name(&self) -> &str {
+        "constant_folding"
+    }
+
+    fn run(&mut self, program: &mut AotProgram) -> usize {
+        self.optimize_program(program)
+    }
+}
+
+impl AotPass for AotCSE {
+    fn name(&self) -> &str {
+        "cse"
+    }
+
+    fn run(&mut self, program: &mut AotProgram) -> usize {
+        self.optimize_program(program)
+    }
+}
+
+impl AotPass for AotDeadBindingEliminator {
+    fn name(&self) -> &str {
+        "dead_binding_elimination"
+    }
+
+    fn run(&mut self, program: &mut AotProgram) -> usize {
+        self.optimize_program(program)
+    }
+}
+
+/// Runs constant folding, then CSE, then dead binding elimination,
+/// repeating until a full round makes no further changes (or
+/// `max_iterations` is hit).
+#[derive(Debug)]
+pub struct AotPassPipeline {
+    max_iterations: usize,
+}
+
+impl Default for AotPassPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AotPassPipeline {
+    /// Create a pipeline with the default iteration cap
+    pub fn new() -> Self {
+        Self { max_iterations: 10 }
+    }
+
+    /// Set the maximum number of fold/CSE/DCE rounds
+    pub fn set_max_iterations(&mut self, max: usize) {
+        self.max_iterations = max;
+    }
+
+    /// Run the pipeline to a fixpoint, returning the total number of
+    /// changes made across all rounds
+    pub fn run(&self, program: &mut AotProgram) -> usize {
+        let mut total = 0;
+
+        for _round in 0..self.max_iterations {
+            let mut passes: Vec<Box<dyn AotPass>> = vec![
+                Box::new(AotConstantFolder::new()),
+                Box::new(AotCSE::new()),
+                Box::new(AotDeadBindingEliminator::new()),
+            ];
+
+            let mut changes_this_round = 0;
+            for pass in &mut passes {
+                changes_this_round += pass.run(program);
+            }
+            total += changes_this_round;
+
+            if changes_this_round == 0 {
+                break;
+            }
+        }
+
+        total
+    }
+}
+
+/// Run the fold -> CSE -> dead-binding-elimination pipeline to a fixpoint
+pub fn optimize_aot_program_with_pass_pipeline(program: &mut AotProgram) -> usize {
+    AotPassPipeline::new().run(program)
+}
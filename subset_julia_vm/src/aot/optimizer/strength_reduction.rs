@@ -485,6 +485,27 @@ impl AotStrengthReducer {
                 (expr.clone(), 0)
             }
 
+            // Throw construction - recurse into fields, same as StructNew
+            AotExpr::ThrowTyped { kind, fields } => {
+                let mut new_fields = Vec::with_capacity(fields.len());
+                let mut total = 0;
+                for field in fields {
+                    let (reduced, red) = self.reduce_expr(field);
+                    new_fields.push(reduced);
+                    total += red;
+                }
+                if total > 0 {
+                    return (
+                        AotExpr::ThrowTyped {
+                            kind: *kind,
+                            fields: new_fields,
+                        },
+                        total,
+                    );
+                }
+                (expr.clone(), 0)
+            }
+
             AotExpr::FieldAccess {
                 object,
                 field,
@@ -527,13 +548,18 @@ impl AotStrengthReducer {
                 (expr.clone(), 0)
             }
 
-            AotExpr::Convert { value, target_ty } => {
+            AotExpr::Convert {
+                value,
+                target_ty,
+                checked,
+            } => {
                 let (reduced, red) = self.reduce_expr(value);
                 if red > 0 {
                     return (
                         AotExpr::Convert {
                             value: Box::new(reduced),
                             target_ty: target_ty.clone(),
+                            checked: *checked,
                         },
                         red,
                     );
@@ -155,6 +155,27 @@ impl JuliaType {
             _ => "Value".to_string(), // Fallback to dynamic Value
         }
     }
+
+    /// Get the WebAssembly value type this type maps to, or `None` if it
+    /// has no scalar wasm representation (strings, arrays, structs, `Any`,
+    /// ...). Narrow integers and `Bool` widen to `i32`, since wasm has no
+    /// sub-32-bit value types; `Int64`/`UInt64` map to `i64`.
+    pub fn to_wasm_type(&self) -> Option<&'static str> {
+        match self {
+            JuliaType::Bool
+            | JuliaType::Int8
+            | JuliaType::Int16
+            | JuliaType::Int32
+            | JuliaType::UInt8
+            | JuliaType::UInt16
+            | JuliaType::UInt32
+            | JuliaType::Char => Some("i32"),
+            JuliaType::Int64 | JuliaType::UInt64 => Some("i64"),
+            JuliaType::Float32 | JuliaType::Float16 => Some("f32"),
+            JuliaType::Float64 => Some("f64"),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for JuliaType {
@@ -233,6 +254,8 @@ impl fmt::Display for JuliaType {
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum StaticType {
     // ========== Primitive Types ==========
+    /// 128-bit signed integer (Julia Int128, Rust i128)
+    I128,
     /// 64-bit signed integer (Julia Int64, Rust i64)
     I64,
     /// 32-bit signed integer (Julia Int32, Rust i32)
@@ -241,6 +264,8 @@ pub enum StaticType {
     I16,
     /// 8-bit signed integer (Julia Int8, Rust i8)
     I8,
+    /// 128-bit unsigned integer (Julia UInt128, Rust u128)
+    U128,
     /// 64-bit unsigned integer (Julia UInt64, Rust u64)
     U64,
     /// 32-bit unsigned integer (Julia UInt32, Rust u32)
@@ -357,10 +382,12 @@ impl StaticType {
     pub fn is_primitive(&self) -> bool {
         matches!(
             self,
-            StaticType::I64
+            StaticType::I128
+                | StaticType::I64
                 | StaticType::I32
                 | StaticType::I16
                 | StaticType::I8
+                | StaticType::U128
                 | StaticType::U64
                 | StaticType::U32
                 | StaticType::U16
@@ -381,10 +408,12 @@ impl StaticType {
     pub fn is_numeric(&self) -> bool {
         matches!(
             self,
-            StaticType::I64
+            StaticType::I128
+                | StaticType::I64
                 | StaticType::I32
                 | StaticType::I16
                 | StaticType::I8
+                | StaticType::U128
                 | StaticType::U64
                 | StaticType::U32
                 | StaticType::U16
@@ -402,10 +431,12 @@ impl StaticType {
     pub fn is_integer(&self) -> bool {
         matches!(
             self,
-            StaticType::I64
+            StaticType::I128
+                | StaticType::I64
                 | StaticType::I32
                 | StaticType::I16
                 | StaticType::I8
+                | StaticType::U128
                 | StaticType::U64
                 | StaticType::U32
                 | StaticType::U16
@@ -418,7 +449,11 @@ impl StaticType {
     pub fn is_signed(&self) -> bool {
         matches!(
             self,
-            StaticType::I64 | StaticType::I32 | StaticType::I16 | StaticType::I8
+            StaticType::I128
+                | StaticType::I64
+                | StaticType::I32
+                | StaticType::I16
+                | StaticType::I8
         )
     }
 
@@ -426,10 +461,95 @@ impl StaticType {
     pub fn is_unsigned(&self) -> bool {
         matches!(
             self,
-            StaticType::U64 | StaticType::U32 | StaticType::U16 | StaticType::U8
+            StaticType::U128
+                | StaticType::U64
+                | StaticType::U32
+                | StaticType::U16
+                | StaticType::U8
         )
     }
 
+    /// Numeric rank used for integer/float promotion (wider magnitude wins).
+    /// Mirrors Julia's default integer promotion: sub-`Int64`-width integer
+    /// types widen to `Int64`, `Int64`/`UInt64` and wider keep their own
+    /// representation. Returns `None` for non-numeric types.
+    fn numeric_rank(&self) -> Option<i32> {
+        match self {
+            StaticType::Bool => Some(0),
+            StaticType::I8 => Some(1),
+            StaticType::U8 => Some(2),
+            StaticType::I16 => Some(3),
+            StaticType::U16 => Some(4),
+            StaticType::I32 => Some(5),
+            StaticType::U32 => Some(6),
+            StaticType::I64 => Some(7),
+            StaticType::U64 => Some(8),
+            StaticType::I128 => Some(9),
+            StaticType::U128 => Some(10),
+            StaticType::F32 => Some(100),
+            StaticType::F64 => Some(101),
+            _ => None,
+        }
+    }
+
+    /// Promote a pair of numeric types following Julia's default
+    /// `promote_type` rules: float beats integer, and among integers,
+    /// sub-`Int64`-width types widen to `Int64` while `Int64`/`UInt64` and
+    /// wider keep the wider of the two operands. Non-numeric operands
+    /// promote to `Any`.
+    pub fn promote_with(&self, other: &StaticType) -> StaticType {
+        if self == other {
+            return self.clone();
+        }
+
+        let left_rank = self.numeric_rank();
+        let right_rank = other.numeric_rank();
+
+        match (left_rank, right_rank) {
+            (Some(l), Some(r)) => {
+                if self.is_float() && other.is_float() {
+                    if l >= r {
+                        self.clone()
+                    } else {
+                        other.clone()
+                    }
+                } else if self.is_float() {
+                    self.clone()
+                } else if other.is_float() {
+                    other.clone()
+                } else {
+                    let max_rank = l.max(r);
+                    if max_rank <= 0 {
+                        StaticType::I64
+                    } else if max_rank >= 7 {
+                        if l >= r {
+                            self.clone()
+                        } else {
+                            other.clone()
+                        }
+                    } else {
+                        StaticType::I64
+                    }
+                }
+            }
+            (Some(_), None) => {
+                if self.is_numeric() {
+                    self.clone()
+                } else {
+                    StaticType::Any
+                }
+            }
+            (None, Some(_)) => {
+                if other.is_numeric() {
+                    other.clone()
+                } else {
+                    StaticType::Any
+                }
+            }
+            _ => StaticType::Any,
+        }
+    }
+
     /// Check if this is a floating point type
     pub fn is_float(&self) -> bool {
         matches!(self, StaticType::F64 | StaticType::F32)
@@ -463,10 +583,12 @@ impl StaticType {
     /// ```
     pub fn to_rust_type(&self) -> String {
         match self {
+            StaticType::I128 => "i128".to_string(),
             StaticType::I64 => "i64".to_string(),
             StaticType::I32 => "i32".to_string(),
             StaticType::I16 => "i16".to_string(),
             StaticType::I8 => "i8".to_string(),
+            StaticType::U128 => "u128".to_string(),
             StaticType::U64 => "u64".to_string(),
             StaticType::U32 => "u32".to_string(),
             StaticType::U16 => "u16".to_string(),
@@ -545,10 +667,12 @@ impl StaticType {
     /// Get the Julia type name
     pub fn julia_type_name(&self) -> String {
         match self {
+            StaticType::I128 => "Int128".to_string(),
             StaticType::I64 => "Int64".to_string(),
             StaticType::I32 => "Int32".to_string(),
             StaticType::I16 => "Int16".to_string(),
             StaticType::I8 => "Int8".to_string(),
+            StaticType::U128 => "UInt128".to_string(),
             StaticType::U64 => "UInt64".to_string(),
             StaticType::U32 => "UInt32".to_string(),
             StaticType::U16 => "UInt16".to_string(),
@@ -612,10 +736,12 @@ impl StaticType {
     /// ```
     pub fn mangle_suffix(&self) -> String {
         match self {
+            StaticType::I128 => "i128".to_string(),
             StaticType::I64 => "i64".to_string(),
             StaticType::I32 => "i32".to_string(),
             StaticType::I16 => "i16".to_string(),
             StaticType::I8 => "i8".to_string(),
+            StaticType::U128 => "u128".to_string(),
             StaticType::U64 => "u64".to_string(),
             StaticType::U32 => "u32".to_string(),
             StaticType::U16 => "u16".to_string(),
@@ -890,6 +1016,17 @@ mod tests {
         assert_eq!(JuliaType::Bool.to_rust_type(), "bool");
     }
 
+    #[test]
+    fn test_julia_type_to_wasm_type() {
+        assert_eq!(JuliaType::Bool.to_wasm_type(), Some("i32"));
+        assert_eq!(JuliaType::Int32.to_wasm_type(), Some("i32"));
+        assert_eq!(JuliaType::Int64.to_wasm_type(), Some("i64"));
+        assert_eq!(JuliaType::Float32.to_wasm_type(), Some("f32"));
+        assert_eq!(JuliaType::Float64.to_wasm_type(), Some("f64"));
+        assert_eq!(JuliaType::String.to_wasm_type(), None);
+        assert_eq!(JuliaType::Any.to_wasm_type(), None);
+    }
+
     #[test]
     fn test_julia_type_display() {
         assert_eq!(format!("{}", JuliaType::Int64), "Int64");
@@ -4,6 +4,7 @@
 //! Also provides a global registry for Base macros that can be used by user code.
 
 use crate::ir::core::Program;
+use crate::load_timing::{timed, LoadPhase};
 use crate::lowering::{Lowering, MacroParamType, StoredMacroDef};
 use crate::parser::Parser;
 use once_cell::sync::Lazy;
@@ -37,11 +38,11 @@ pub fn get_base_program() -> Option<&'static Program> {
 
         // Parse using pure Rust parser
         let mut parser = Parser::new().ok()?;
-        let parse_outcome = parser.parse(&source).ok()?;
+        let parse_outcome = timed("Base", LoadPhase::Parse, || parser.parse(&source)).ok()?;
 
         // Lower using unified Lowering
         let mut lowering = Lowering::new(&source);
-        let program = lowering.lower(parse_outcome).ok()?;
+        let program = timed("Base", LoadPhase::Lower, || lowering.lower(parse_outcome)).ok()?;
 
         // Extract and store macros in the global registry
         register_base_macros(&program);
@@ -22,7 +22,7 @@ use subset_julia_vm::aot::codegen::CodegenConfig;
 use subset_julia_vm::aot::inference::TypeInferenceEngine;
 use subset_julia_vm::aot::ir::AotProgram;
 use subset_julia_vm::aot::optimizer::optimize_aot_program_full;
-use subset_julia_vm::aot::{AotError, AotOutput, AotStats};
+use subset_julia_vm::aot::{Artifact, AotError, AotOutput, AotStats};
 use subset_julia_vm::base;
 use subset_julia_vm::bytecode;
 use subset_julia_vm::ir::core::Program;
@@ -250,8 +250,17 @@ fn main() {
 
     match result {
         Ok(output) => {
+            // This CLI only ever drives the Rust-source pipeline today.
+            let code = match &output.artifact {
+                Artifact::RustSource(code) => code,
+                Artifact::Wat(_) => {
+                    eprintln!("Error: expected Rust source output from this pipeline");
+                    process::exit(1);
+                }
+            };
+
             // Write output file
-            if let Err(e) = fs::write(&output_file, &output.rust_code) {
+            if let Err(e) = fs::write(&output_file, code) {
                 eprintln!("Error writing output file '{}': {}", output_file, e);
                 process::exit(1);
             }
@@ -444,7 +453,7 @@ fn compile_julia_to_rust(
     let mut codegen = AotCodeGenerator::new(config);
     let rust_code = codegen.generate_program(&aot_program)?;
 
-    let mut output = AotOutput::new(rust_code, stats);
+    let mut output = AotOutput::new(Artifact::RustSource(rust_code), stats);
 
     // Add source information as a comment
     if emit_comments {
@@ -452,7 +461,9 @@ fn compile_julia_to_rust(
             "// Source: {}\n// Generated by SubsetJuliaVM AoT Compiler v{}\n\n",
             source_name, VERSION
         );
-        output.rust_code = header + &output.rust_code;
+        if let Artifact::RustSource(code) = &mut output.artifact {
+            *code = header + code;
+        }
     }
 
     // Count dynamic fallbacks
@@ -541,7 +552,7 @@ fn compile_program_to_rust(
     let mut codegen = AotCodeGenerator::new(config);
     let rust_code = codegen.generate_program(&aot_program)?;
 
-    let mut output = AotOutput::new(rust_code, stats.clone());
+    let mut output = AotOutput::new(Artifact::RustSource(rust_code), stats.clone());
 
     // Add source information as a comment
     if emit_comments {
@@ -549,7 +560,9 @@ fn compile_program_to_rust(
             "// Source: {}\n// Generated by SubsetJuliaVM AoT Compiler v{}\n\n",
             source_name, VERSION
         );
-        output.rust_code = header + &output.rust_code;
+        if let Artifact::RustSource(code) = &mut output.artifact {
+            *code = header + code;
+        }
     }
 
     // Count dynamic fallbacks
@@ -1369,8 +1369,11 @@ fn format_value_with_vm(
             }
         }
         Value::Rng(_) => "Random.default_rng()".to_string(),
+        Value::Task(_) => "Task(...)".to_string(),
+        Value::VaList(_) => "(...)".to_string(),
         Value::SliceAll => ":".to_string(),
         Value::Ref(inner) => format!("Ref({})", format_value_with_vm(inner, struct_heap)),
+        Value::Boxed(cell) => format_value_with_vm(&cell.borrow(), struct_heap),
         Value::Char(c) => format!("'{}'", c),
         Value::Generator(_) => "<generator>".to_string(),
         Value::DataType(jt) => jt.to_string(), // DataType displays as type name
@@ -158,6 +158,8 @@ pub(super) fn run_file(file_path: &str) {
         Ok(Value::StructRef(_)) => println!("result struct_ref"),
         Ok(Value::SliceAll) => println!("result slice_all"),
         Ok(Value::Rng(_)) => println!("result rng"),
+        Ok(Value::Task(_)) => println!("result task"),
+        Ok(Value::VaList(_)) => println!("result va_list"),
         Ok(Value::Tuple(t)) => println!("result tuple = {:?}", t.elements),
         Ok(Value::NamedTuple(nt)) => println!("result named_tuple = {:?}", nt.names),
         Ok(Value::Dict(d)) => println!("result dict = {} pairs", d.len()),
@@ -64,6 +64,8 @@ pub enum BuiltinId {
     Exponent,    // exponent(x) - get exponent part of float
     Significand, // significand(x) - get significand (mantissa) part
     Frexp,       // frexp(x) - returns (mantissa, exponent) tuple
+    Ldexp,       // ldexp(m, e) / scalbn(m, e) - compute m * 2^e, inverse of frexp
+    Ilogb,       // ilogb(x) - unbiased base-2 exponent of abs(x)
 
     // Float inspection
     Issubnormal, // issubnormal(x) - check if subnormal number
@@ -72,6 +74,18 @@ pub enum BuiltinId {
     // Fused multiply-add
     Fma,    // fma(x, y, z) = x*y + z (fused, single rounding)
     Muladd, // muladd(x, y, z) = x*y + z (may or may not be fused)
+    FmaF128,    // fma(x, y, z) over software Float128
+    MuladdF128, // muladd(x, y, z) over software Float128
+    RoundF128,  // round(x) over software Float128
+    TruncF128,  // trunc(x) over software Float128
+
+    // Sign manipulation
+    SignI64,   // sign(x::Int) - 1/0/-1, preserving the integer type
+    SignF64,   // sign(x::Float64) - +-1.0/0.0/NaN, preserving the float type
+    Signbit,   // signbit(x) - true if the sign bit is set
+    Copysign,  // copysign(x, y) - magnitude of x with the sign of y
+    FlipsignI64, // flipsign(x::Int, y) - x, negated if y < 0
+    FlipsignF64, // flipsign(x::Float64, y) - x, negated if y < 0
 
     // Note: Abs is now Pure Julia (number.jl, int.jl, float.jl, bool.jl, complex.jl)
 
@@ -282,8 +296,9 @@ pub enum BuiltinId {
     // =========================================================================
     // Time Operations
     // =========================================================================
-    TimeNs, // time_ns()
-    Sleep,  // sleep(seconds)
+    TimeNs,      // time_ns()
+    Sleep,       // sleep(seconds)
+    _AtexitPush, // _atexit_push!(f) - register a zero-arg callback to run at VM shutdown (internal)
 
     // =========================================================================
     // Type Operations
@@ -345,9 +360,15 @@ pub enum BuiltinId {
     _Fieldnames,     // _fieldnames(T) - tuple of field names (internal)
     _Fieldtypes,     // _fieldtypes(T) - tuple of field types (internal)
     _Getfield,       // _getfield(x, i) - get field value by index (internal)
+    _Setfield,       // _setfield!(x, i, v) - set field value by index (internal)
     _Isabstracttype, // _isabstracttype(T) - check abstract type (internal)
     _Isconcretetype, // _isconcretetype(T) - check concrete type (internal)
     _Ismutabletype,  // _ismutabletype(T) - check mutable type (internal)
+    _Isstructtype,   // _isstructtype(T) - check user-defined struct type (internal)
+    _Isprimitivetype, // _isprimitivetype(T) - check builtin primitive numeric/bool/char type (internal)
+    _Fieldoffset,    // _fieldoffset(T, i) - approximate byte offset of field i (internal)
+    _Structequals,   // _structequals(a, b) - reflection-driven generic == for structs (internal)
+    _Structhash,     // _structhash(x) - reflection-driven generic hash for structs (internal)
     _Hash,           // _hash(x) - compute hash value (internal, Issue #2582)
     _Eltype,         // _eltype(x) - get element type (internal, Issue #2570)
     _DictGet,        // _dict_get(d, key) - HashMap lookup (internal, Issue #2572)
@@ -366,9 +387,15 @@ pub enum BuiltinId {
     _SetLength,      // _set_length(s) - HashSet len (internal, Issue #2574)
     Getfield,        // getfield(x, name) or getfield(x, i) - get field by name or index
     Setfield,        // setfield!(x, name, v) or setfield!(x, i, v) - set field by name or index
+    Getproperty,     // getproperty(x, sym) - overloadable property read, falls back to getfield
+    Setproperty,     // setproperty!(x, sym, v) - overloadable property write, falls back to setfield!
+    Propertynames,   // propertynames(x) - tuple of property names, falls back to fieldnames
     Methods,         // methods(f) or methods(f, types) - list of methods
     HasMethod,       // hasmethod(f, types) - check if method exists
     Which,           // which(f, types) - get specific method
+    CodeLowered,     // code_lowered(f, types) - disassembly of the dispatched method's bytecode
+    CodeNative,      // code_native(f, types) - disassembly annotated as the native-codegen view
+    _MethodsWith,    // _methodswith(T, supertypes) - methods with an argument of type T (internal)
     IsExported,      // isexported(m::Module, s::Symbol) - check if symbol is exported
     IsPublic,        // ispublic(m::Module, s::Symbol) - check if symbol is public (Julia 1.11+)
 
@@ -465,9 +492,10 @@ pub enum BuiltinId {
     UInt64,  // UInt64(x) - convert to UInt64
     UInt128, // UInt128(x) - convert to UInt128
     // Floating point
-    Float16, // Float16(x) - convert to Float16
-    Float32, // Float32(x) - convert to Float32
-    Float64, // Float64(x) - convert to Float64
+    Float16,  // Float16(x) - convert to Float16
+    Float32,  // Float32(x) - convert to Float32
+    Float64,  // Float64(x) - convert to Float64
+    Float128, // Float128(x) - convert to software quad-precision Float128
 
     // =========================================================================
     // BigInt Operations
@@ -525,6 +553,7 @@ pub enum BuiltinId {
     MetaLower,             // _meta_lower(expr) - lower expression to Core IR
     MacroExpand,           // macroexpand(m, x) - return expanded form of macro call
     MacroExpandBang, // macroexpand!(m, x) - destructively expand macro call (same behavior in SubsetJuliaVM)
+    MacroExpand1,    // macroexpand1(m, x) - expand only the outermost macro call, one step
     IncludeString,   // include_string(m, code) - parse and evaluate code string
     EvalFile,        // evalfile(path) - evaluate all expressions in a file
 
@@ -535,6 +564,8 @@ pub enum BuiltinId {
     TestRecordBroken, // _test_record_broken!(passed, msg) - record broken test result
     TestSetBegin,     // _testset_begin!(name) - begin test set
     TestSetEnd,       // _testset_end!() - end test set and print summary
+    TestSetSetFilter, // _testset_set_filter!(pattern) - restrict testsets/tests to a name/message pattern
+    TestThrowsRecord, // _test_throws_record!(thrown_type, expected_type, msg) - record @test_throws result
 
     // =========================================================================
     // Regex Operations
@@ -545,6 +576,24 @@ pub enum BuiltinId {
     RegexReplace,   // replace(string, regex => replacement) - replace matches
     RegexSplit,     // split(string, regex) - split string by regex
     RegexEachmatch, // eachmatch(regex, string) - return iterator of all matches (collected as Vector)
+
+    // =========================================================================
+    // Native Host Bridge (ccall-style)
+    // =========================================================================
+    CallNative, // ccall_native(name, args...) - dispatch to a host fn registered via ffi::register_native
+
+    // =========================================================================
+    // Task Subsystem (Issue chunk426-4)
+    // =========================================================================
+    TaskNew,    // Task(f) - wrap a zero-argument callable as a not-yet-started Task handle
+    TaskResume, // resume(t) - drive a Task to its next yield/produce, return, or error
+    IsTaskDone, // istaskdone(t) - check whether a Task has finished (normally or by error)
+
+    // =========================================================================
+    // Lazy VaList (Issue chunk427-2)
+    // =========================================================================
+    VaArg,   // va_arg(va) - pop and return the next argument from a VaList
+    VaCount, // va_count(va) - number of arguments left in a VaList
 }
 
 impl BuiltinId {
@@ -591,12 +640,17 @@ impl BuiltinId {
             "exponent" => Some(Self::Exponent),
             "significand" => Some(Self::Significand),
             "frexp" => Some(Self::Frexp),
+            "ldexp" | "scalbn" => Some(Self::Ldexp),
+            "ilogb" => Some(Self::Ilogb),
             // Float inspection
             "issubnormal" => Some(Self::Issubnormal),
             "maxintfloat" => Some(Self::Maxintfloat),
             // Fused multiply-add
             "fma" => Some(Self::Fma),
             "muladd" => Some(Self::Muladd),
+            // Note: sign/signbit/copysign/flipsign are dispatched from compile_builtin_math
+            // (they pick SignI64/SignF64/FlipsignI64/FlipsignF64 based on inferred arg type),
+            // so they are not looked up by name here.
             // Number theory - now Pure Julia (base/intfuncs.jl)
             // gcd, lcm, factorial removed
 
@@ -724,6 +778,7 @@ impl BuiltinId {
 
             // Time
             "time_ns" => Some(Self::TimeNs),
+            "_atexit_push!" => Some(Self::_AtexitPush),
             "sleep" => Some(Self::Sleep),
 
             // Type
@@ -769,11 +824,17 @@ impl BuiltinId {
             "_fieldnames" => Some(Self::_Fieldnames),
             "_fieldtypes" => Some(Self::_Fieldtypes),
             "_getfield" => Some(Self::_Getfield),
+            "_setfield!" => Some(Self::_Setfield),
             "_hash" => Some(Self::_Hash),
             "_eltype" => Some(Self::_Eltype),
             "_isabstracttype" => Some(Self::_Isabstracttype),
             "_isconcretetype" => Some(Self::_Isconcretetype),
             "_ismutabletype" => Some(Self::_Ismutabletype),
+            "_isstructtype" => Some(Self::_Isstructtype),
+            "_isprimitivetype" => Some(Self::_Isprimitivetype),
+            "_fieldoffset" => Some(Self::_Fieldoffset),
+            "_structequals" => Some(Self::_Structequals),
+            "_structhash" => Some(Self::_Structhash),
 
             // Dict internal intrinsics (Issue #2572)
             "_dict_get" => Some(Self::_DictGet),
@@ -793,9 +854,15 @@ impl BuiltinId {
             "_set_length" => Some(Self::_SetLength),
             "getfield" => Some(Self::Getfield),
             "setfield!" => Some(Self::Setfield),
+            "getproperty" => Some(Self::Getproperty),
+            "setproperty!" => Some(Self::Setproperty),
+            "propertynames" => Some(Self::Propertynames),
             "methods" => Some(Self::Methods),
             "hasmethod" => Some(Self::HasMethod),
             "which" => Some(Self::Which),
+            "code_lowered" => Some(Self::CodeLowered),
+            "code_native" => Some(Self::CodeNative),
+            "_methodswith" => Some(Self::_MethodsWith),
             "isexported" => Some(Self::IsExported),
             "ispublic" => Some(Self::IsPublic),
 
@@ -868,6 +935,7 @@ impl BuiltinId {
             "Float16" => Some(Self::Float16),
             "Float32" => Some(Self::Float32),
             "Float64" => Some(Self::Float64),
+            "Float128" => Some(Self::Float128),
 
             // BigInt
             "BigInt" => Some(Self::BigInt),
@@ -912,6 +980,7 @@ impl BuiltinId {
             "_meta_lower" => Some(Self::MetaLower),
             "macroexpand" => Some(Self::MacroExpand),
             "macroexpand!" => Some(Self::MacroExpandBang),
+            "macroexpand1" => Some(Self::MacroExpand1),
             "include_string" => Some(Self::IncludeString),
             "evalfile" => Some(Self::EvalFile),
 
@@ -920,6 +989,8 @@ impl BuiltinId {
             "_test_record_broken!" => Some(Self::TestRecordBroken),
             "_testset_begin!" => Some(Self::TestSetBegin),
             "_testset_end!" => Some(Self::TestSetEnd),
+            "_testset_set_filter!" => Some(Self::TestSetSetFilter),
+            "_test_throws_record!" => Some(Self::TestThrowsRecord),
 
             // Regex operations
             "Regex" => Some(Self::RegexNew),
@@ -927,6 +998,18 @@ impl BuiltinId {
             "eachmatch" => Some(Self::RegexEachmatch),
             "_regex_replace" => Some(Self::RegexReplace),
 
+            // Native host bridge
+            "ccall_native" => Some(Self::CallNative),
+
+            // Task subsystem
+            "Task" => Some(Self::TaskNew),
+            "resume" => Some(Self::TaskResume),
+            "istaskdone" => Some(Self::IsTaskDone),
+
+            // Lazy VaList
+            "va_arg" => Some(Self::VaArg),
+            "va_count" => Some(Self::VaCount),
+
             _ => None,
         }
     }
@@ -967,12 +1050,25 @@ impl BuiltinId {
             Self::Exponent => "exponent",
             Self::Significand => "significand",
             Self::Frexp => "frexp",
+            Self::Ldexp => "ldexp",
+            Self::Ilogb => "ilogb",
             // Float inspection
             Self::Issubnormal => "issubnormal",
             Self::Maxintfloat => "maxintfloat",
             // Fused multiply-add
             Self::Fma => "fma",
             Self::Muladd => "muladd",
+            Self::FmaF128 => "fma",
+            Self::MuladdF128 => "muladd",
+            Self::RoundF128 => "round",
+            Self::TruncF128 => "trunc",
+            // Sign manipulation
+            Self::SignI64 => "sign",
+            Self::SignF64 => "sign",
+            Self::Signbit => "signbit",
+            Self::Copysign => "copysign",
+            Self::FlipsignI64 => "flipsign",
+            Self::FlipsignF64 => "flipsign",
 
             // Note: Abs is now Pure Julia
 
@@ -1124,6 +1220,7 @@ impl BuiltinId {
 
             // Time
             Self::TimeNs => "time_ns",
+            Self::_AtexitPush => "_atexit_push!",
             Self::Sleep => "sleep",
 
             // Type
@@ -1175,11 +1272,17 @@ impl BuiltinId {
             Self::_Fieldnames => "_fieldnames",
             Self::_Fieldtypes => "_fieldtypes",
             Self::_Getfield => "_getfield",
+            Self::_Setfield => "_setfield!",
             Self::_Hash => "_hash",
             Self::_Eltype => "_eltype",
             Self::_Isabstracttype => "_isabstracttype",
             Self::_Isconcretetype => "_isconcretetype",
             Self::_Ismutabletype => "_ismutabletype",
+            Self::_Isstructtype => "_isstructtype",
+            Self::_Isprimitivetype => "_isprimitivetype",
+            Self::_Fieldoffset => "_fieldoffset",
+            Self::_Structequals => "_structequals",
+            Self::_Structhash => "_structhash",
             Self::_DictGet => "_dict_get",
             Self::_DictSet => "_dict_set!",
             Self::_DictDelete => "_dict_delete!",
@@ -1196,9 +1299,15 @@ impl BuiltinId {
             Self::_SetLength => "_set_length",
             Self::Getfield => "getfield",
             Self::Setfield => "setfield!",
+            Self::Getproperty => "getproperty",
+            Self::Setproperty => "setproperty!",
+            Self::Propertynames => "propertynames",
             Self::Methods => "methods",
             Self::HasMethod => "hasmethod",
             Self::Which => "which",
+            Self::CodeLowered => "code_lowered",
+            Self::CodeNative => "code_native",
+            Self::_MethodsWith => "_methodswith",
             Self::IsExported => "isexported",
             Self::IsPublic => "ispublic",
 
@@ -1280,6 +1389,7 @@ impl BuiltinId {
             Self::Float16 => "Float16",
             Self::Float32 => "Float32",
             Self::Float64 => "Float64",
+            Self::Float128 => "Float128",
 
             // BigInt
             Self::BigInt => "BigInt",
@@ -1325,6 +1435,7 @@ impl BuiltinId {
             Self::MetaLower => "_meta_lower",
             Self::MacroExpand => "macroexpand",
             Self::MacroExpandBang => "macroexpand!",
+            Self::MacroExpand1 => "macroexpand1",
             Self::IncludeString => "include_string",
             Self::EvalFile => "evalfile",
 
@@ -1336,6 +1447,8 @@ impl BuiltinId {
             Self::TestRecordBroken => "_test_record_broken!",
             Self::TestSetBegin => "_testset_begin!",
             Self::TestSetEnd => "_testset_end!",
+            Self::TestSetSetFilter => "_testset_set_filter!",
+            Self::TestThrowsRecord => "_test_throws_record!",
 
             // Regex Operations
             Self::RegexNew => "Regex",
@@ -1344,6 +1457,18 @@ impl BuiltinId {
             Self::RegexReplace => "_regex_replace",
             Self::RegexSplit => "split",
             Self::RegexEachmatch => "eachmatch",
+
+            // Native Host Bridge
+            Self::CallNative => "ccall_native",
+
+            // Task Subsystem
+            Self::TaskNew => "Task",
+            Self::TaskResume => "resume",
+            Self::IsTaskDone => "istaskdone",
+
+            // Lazy VaList
+            Self::VaArg => "va_arg",
+            Self::VaCount => "va_count",
         }
     }
 
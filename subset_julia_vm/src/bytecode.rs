@@ -41,9 +41,11 @@
 //! See the `sjulia` CLI for a complete example of bytecode compilation and loading.
 
 use crate::ir::core::Program;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::fs::File;
 use std::io::{Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Magic bytes identifying a SubsetJuliaVM bytecode file
 pub const MAGIC: &[u8; 4] = b"SJBC";
@@ -64,6 +66,8 @@ pub enum BytecodeError {
     DeserializeError(String),
     /// Serialization error
     SerializeError(String),
+    /// Parsing/lowering the source failed (on a precompilation cache miss)
+    CompileError(String),
 }
 
 impl std::fmt::Display for BytecodeError {
@@ -82,6 +86,7 @@ impl std::fmt::Display for BytecodeError {
             }
             BytecodeError::DeserializeError(e) => write!(f, "Failed to deserialize: {}", e),
             BytecodeError::SerializeError(e) => write!(f, "Failed to serialize: {}", e),
+            BytecodeError::CompileError(e) => write!(f, "Failed to compile source: {}", e),
         }
     }
 }
@@ -304,6 +309,188 @@ pub fn save_to_bytes(program: &Program) -> Result<Vec<u8>, BytecodeError> {
     Ok(result)
 }
 
+// ── Precompilation cache ────────────────────────────────────────────────
+//
+// A persistent, on-disk cache analogous to Julia's package images: keyed
+// by a SHA-256 hash of the source text, validated against both that hash
+// and the current bytecode `VERSION` so a stale or foreign-format entry is
+// never loaded. Concurrency safety against two processes racing to build
+// the same entry is handled with a pid-stamped lock file (see
+// `acquire_lock`), and entries are written to a temp file and renamed into
+// place so a reader never observes a partial write.
+
+/// A cached precompilation entry: the compiled program plus the metadata
+/// needed to validate it before use.
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    version: u32,
+    source_hash: String,
+    program: Program,
+}
+
+/// How long an unclaimed lock file is tolerated before a competing process
+/// treats it as abandoned (e.g. the holder crashed) and steals it.
+const LOCK_STALE_SECS: u64 = 30;
+
+/// Load a compiled [`Program`] for the Julia source at `path`, using the
+/// on-disk precompilation cache when possible.
+///
+/// On a cache hit (matching source hash and format version) this skips
+/// parsing and lowering entirely. On a miss, the source is compiled via
+/// [`crate::pipeline::parse_and_lower`] and the cache is populated
+/// best-effort for next time — a failure to read or write the cache never
+/// fails the call, it just falls back to compiling from scratch.
+pub fn load_or_compile<P: AsRef<Path>>(path: P) -> Result<Program, BytecodeError> {
+    let source = std::fs::read_to_string(path.as_ref())?;
+    load_or_compile_source(&source)
+}
+
+/// Same as [`load_or_compile`], but takes source text directly rather than
+/// a path (for callers, such as REPL sessions, that already have it in
+/// memory).
+pub fn load_or_compile_source(source: &str) -> Result<Program, BytecodeError> {
+    let hash = source_hash(source);
+
+    let dir = match cache_dir() {
+        Some(dir) => dir,
+        None => return compile_source(source),
+    };
+
+    let entry_path = cache_entry_path(&dir, &hash);
+    if let Some(program) = read_cache_entry(&entry_path, &hash) {
+        return Ok(program);
+    }
+
+    let program = compile_source(source)?;
+
+    if let Err(e) = write_cache_entry(&dir, &entry_path, &hash, &program) {
+        let _ = writeln!(std::io::stderr(), "[bytecode] cache write failed: {}", e);
+    }
+
+    Ok(program)
+}
+
+fn compile_source(source: &str) -> Result<Program, BytecodeError> {
+    crate::pipeline::parse_and_lower(source).map_err(|e| BytecodeError::CompileError(e.to_string()))
+}
+
+/// SHA-256 hex digest of `source`, used as the cache key.
+fn source_hash(source: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(source.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+/// Directory backing the precompilation cache, or `None` if caching is
+/// disabled (no writable temp dir on iOS/WASM). A non-empty
+/// `SUBSETJULIA_BYTECODE_CACHE_DIR` overrides the location; an empty one is
+/// ignored, so there is no env var that turns caching off.
+fn cache_dir() -> Option<PathBuf> {
+    if let Ok(val) = std::env::var("SUBSETJULIA_BYTECODE_CACHE_DIR") {
+        if !val.trim().is_empty() {
+            return Some(PathBuf::from(val));
+        }
+    }
+
+    if cfg!(any(target_os = "ios", target_arch = "wasm32")) {
+        return None;
+    }
+
+    Some(std::env::temp_dir().join("subset_julia_vm_cache").join("bytecode"))
+}
+
+fn cache_entry_path(dir: &Path, hash: &str) -> PathBuf {
+    dir.join(format!("{}.sjcache", hash))
+}
+
+fn lock_path(dir: &Path, hash: &str) -> PathBuf {
+    dir.join(format!("{}.lock", hash))
+}
+
+fn read_cache_entry(path: &Path, expected_hash: &str) -> Option<Program> {
+    let bytes = std::fs::read(path).ok()?;
+    let entry: CacheEntry = bincode::deserialize(&bytes).ok()?;
+    if entry.version != VERSION || entry.source_hash != expected_hash {
+        return None;
+    }
+    Some(entry.program)
+}
+
+fn write_cache_entry(
+    dir: &Path,
+    entry_path: &Path,
+    hash: &str,
+    program: &Program,
+) -> Result<(), BytecodeError> {
+    std::fs::create_dir_all(dir)?;
+
+    let lock_path = lock_path(dir, hash);
+    if !acquire_lock(&lock_path)? {
+        // Another live process is already building this entry; don't race
+        // it for the write, just skip caching this time around.
+        return Ok(());
+    }
+
+    let entry = CacheEntry {
+        version: VERSION,
+        source_hash: hash.to_string(),
+        program: program.clone(),
+    };
+    let bytes =
+        bincode::serialize(&entry).map_err(|e| BytecodeError::SerializeError(e.to_string()))?;
+
+    // Write to a sibling temp file and rename into place, so a concurrent
+    // reader never observes a partially-written entry.
+    let tmp_path = entry_path.with_extension("sjcache.tmp");
+    let result = std::fs::write(&tmp_path, &bytes)
+        .and_then(|()| std::fs::rename(&tmp_path, entry_path))
+        .map_err(BytecodeError::from);
+
+    release_lock(&lock_path);
+    result
+}
+
+/// Try to claim `lock_path` for this process, stamping it with our pid.
+/// Returns `Ok(true)` if the lock was acquired, `Ok(false)` if another
+/// live process already holds it.
+fn acquire_lock(lock_path: &Path) -> Result<bool, BytecodeError> {
+    match File::options()
+        .write(true)
+        .create_new(true)
+        .open(lock_path)
+    {
+        Ok(mut file) => {
+            let _ = write!(file, "{}", std::process::id());
+            Ok(true)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+            if lock_is_stale(lock_path) {
+                // The previous holder likely crashed without cleaning up;
+                // steal the lock rather than caching forever.
+                let _ = std::fs::remove_file(lock_path);
+                acquire_lock(lock_path)
+            } else {
+                Ok(false)
+            }
+        }
+        Err(e) => Err(BytecodeError::from(e)),
+    }
+}
+
+fn release_lock(lock_path: &Path) {
+    let _ = std::fs::remove_file(lock_path);
+}
+
+fn lock_is_stale(lock_path: &Path) -> bool {
+    match std::fs::metadata(lock_path).and_then(|m| m.modified()) {
+        Ok(modified) => modified
+            .elapsed()
+            .map(|age| age.as_secs() > LOCK_STALE_SECS)
+            .unwrap_or(false),
+        Err(_) => true,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -373,4 +560,83 @@ mod tests {
             Err(BytecodeError::UnsupportedVersion(999))
         ));
     }
+
+    // ── Precompilation cache ───────────────────────────────────────────
+
+    fn unique_test_dir(label: &str) -> PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        std::env::temp_dir().join(format!("sjvm_bytecode_cache_test_{}_{}", label, nanos))
+    }
+
+    #[test]
+    fn test_source_hash_is_deterministic() {
+        assert_eq!(source_hash("x = 1"), source_hash("x = 1"));
+    }
+
+    #[test]
+    fn test_source_hash_differs_for_different_source() {
+        assert_ne!(source_hash("x = 1"), source_hash("x = 2"));
+    }
+
+    #[test]
+    fn test_cache_entry_roundtrip() {
+        let dir = unique_test_dir("roundtrip");
+        let program = empty_program();
+        let hash = source_hash("x = 1");
+        let entry_path = cache_entry_path(&dir, &hash);
+
+        write_cache_entry(&dir, &entry_path, &hash, &program).unwrap();
+        let loaded = read_cache_entry(&entry_path, &hash);
+
+        assert_eq!(loaded, Some(program));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_cache_entry_rejects_hash_mismatch() {
+        let dir = unique_test_dir("hash_mismatch");
+        let program = empty_program();
+        let hash = source_hash("x = 1");
+        let entry_path = cache_entry_path(&dir, &hash);
+
+        write_cache_entry(&dir, &entry_path, &hash, &program).unwrap();
+        let loaded = read_cache_entry(&entry_path, &source_hash("x = 2"));
+
+        assert_eq!(loaded, None);
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_cache_entry_missing_file_is_none() {
+        let dir = unique_test_dir("missing");
+        let hash = source_hash("x = 1");
+        let entry_path = cache_entry_path(&dir, &hash);
+
+        assert_eq!(read_cache_entry(&entry_path, &hash), None);
+    }
+
+    #[test]
+    fn test_acquire_lock_blocks_second_caller_until_released() {
+        let dir = unique_test_dir("lock");
+        std::fs::create_dir_all(&dir).unwrap();
+        let lock = lock_path(&dir, "deadbeef");
+
+        assert!(acquire_lock(&lock).unwrap(), "first caller should win");
+        assert!(
+            !acquire_lock(&lock).unwrap(),
+            "second caller should see the lock held"
+        );
+
+        release_lock(&lock);
+        assert!(
+            acquire_lock(&lock).unwrap(),
+            "lock should be re-acquirable after release"
+        );
+
+        release_lock(&lock);
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
@@ -1,7 +1,17 @@
+use std::cell::RefCell;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 static CANCEL_REQUESTED: AtomicBool = AtomicBool::new(false);
 
+thread_local! {
+    /// Per-thread override consulted by `is_requested` alongside the
+    /// process-wide flag above. Set by `crate::runtime`'s worker threads so
+    /// cancelling one in-flight task doesn't touch the global flag other
+    /// workers (and the synchronous FFI entry points) share.
+    static TASK_FLAG: RefCell<Option<Arc<AtomicBool>>> = const { RefCell::new(None) };
+}
+
 pub fn request() {
     CANCEL_REQUESTED.store(true, Ordering::SeqCst);
 }
@@ -10,6 +20,21 @@ pub fn reset() {
     CANCEL_REQUESTED.store(false, Ordering::SeqCst);
 }
 
+/// Register (or, with `None`, clear) the cancellation flag `is_requested`
+/// consults for code running on the *current* thread. Intended to be
+/// called by `crate::runtime` workers around each job, not by VM-execution
+/// code itself.
+pub fn set_task_flag(flag: Option<Arc<AtomicBool>>) {
+    TASK_FLAG.with(|cell| *cell.borrow_mut() = flag);
+}
+
 pub fn is_requested() -> bool {
-    CANCEL_REQUESTED.load(Ordering::SeqCst)
+    if CANCEL_REQUESTED.load(Ordering::SeqCst) {
+        return true;
+    }
+    TASK_FLAG.with(|cell| {
+        cell.borrow()
+            .as_ref()
+            .is_some_and(|flag| flag.load(Ordering::SeqCst))
+    })
 }
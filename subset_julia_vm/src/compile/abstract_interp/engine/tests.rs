@@ -64,6 +64,7 @@ fn test_infer_function_with_addition() {
                 type_annotation: Some(JuliaType::Int64),
                 is_varargs: false,
                 vararg_count: None,
+                nospecialize: false,
                 span: dummy_span(),
             },
             TypedParam {
@@ -71,6 +72,7 @@ fn test_infer_function_with_addition() {
                 type_annotation: Some(JuliaType::Int64),
                 is_varargs: false,
                 vararg_count: None,
+                nospecialize: false,
                 span: dummy_span(),
             },
         ],
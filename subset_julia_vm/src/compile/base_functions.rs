@@ -137,7 +137,7 @@ pub(super) fn is_base_function(name: &str) -> bool {
         // Note: isunordered is now Pure Julia (base/operators.jl, Issue #2715)
         "objectid" |
         // Reflection (method introspection)
-        "methods" | "hasmethod" | "which" |
+        "methods" | "hasmethod" | "which" | "code_lowered" | "code_native" |
         // Module introspection (Julia 1.11+)
         "isexported" | "ispublic" |
         // Set operations (builtin - works for both Sets and Arrays)
@@ -153,7 +153,9 @@ pub(super) fn is_base_function(name: &str) -> bool {
         // Regex internal builtins
         "_regex_replace" |
         // Internal intrinsics for Pure Julia migration (Issue #2570, #2582)
-        "_hash" | "_eltype"
+        "_hash" | "_eltype" |
+        // Native host bridge (ccall-style, chunk425-5)
+        "ccall_native"
     )
 }
 
@@ -235,6 +237,8 @@ pub(super) fn base_function_to_builtin_op(name: &str) -> Option<BuiltinOp> {
         "methods" => Some(BuiltinOp::Methods),
         "hasmethod" => Some(BuiltinOp::HasMethod),
         "which" => Some(BuiltinOp::Which),
+        "code_lowered" => Some(BuiltinOp::CodeLowered),
+        "code_native" => Some(BuiltinOp::CodeNative),
         "in" => Some(BuiltinOp::In),
         "iterate" => Some(BuiltinOp::Iterate),
         "collect" => Some(BuiltinOp::Collect),
@@ -316,6 +320,7 @@ mod tests {
             BuiltinOp::Eval,
             BuiltinOp::MacroExpand,
             BuiltinOp::MacroExpandBang,
+            BuiltinOp::MacroExpand1,
             BuiltinOp::IncludeString,
             BuiltinOp::EvalFile,
             BuiltinOp::SymbolNew,
@@ -328,6 +333,8 @@ mod tests {
             BuiltinOp::TestRecordBroken,
             BuiltinOp::TestSetBegin,
             BuiltinOp::TestSetEnd,
+            BuiltinOp::TestSetSetFilter,
+            BuiltinOp::TestThrowsRecord,
         ];
         for op in &map_builtin_variants {
             reachable.insert(*op);
@@ -385,6 +392,8 @@ mod tests {
             "methods",
             "hasmethod",
             "which",
+            "code_lowered",
+            "code_native",
             "in",
             "iterate",
             "collect",
@@ -500,6 +509,7 @@ mod tests {
             BuiltinOp::Eval,
             BuiltinOp::MacroExpand,
             BuiltinOp::MacroExpandBang,
+            BuiltinOp::MacroExpand1,
             BuiltinOp::IncludeString,
             BuiltinOp::EvalFile,
             BuiltinOp::SplatInterpolation,
@@ -507,6 +517,10 @@ mod tests {
             BuiltinOp::TestRecordBroken,
             BuiltinOp::TestSetBegin,
             BuiltinOp::TestSetEnd,
+            BuiltinOp::TestSetSetFilter,
+            BuiltinOp::TestThrowsRecord,
+            BuiltinOp::CodeLowered,
+            BuiltinOp::CodeNative,
             BuiltinOp::IsDefined,
         ];
 
@@ -528,7 +542,7 @@ mod tests {
         // Also verify the all_variants list is complete (catches missing entries)
         assert_eq!(
             all_variants.len(),
-            78, // Must match the actual enum variant count
+            83, // Must match the actual enum variant count
             "all_variants list count mismatch — update this test when adding/removing BuiltinOp variants"
         );
     }
@@ -665,6 +679,7 @@ mod tests {
             // Time
             "time_ns",
             "sleep",
+            "_atexit_push!",
             // Type
             "typeof",
             "isa",
@@ -713,14 +728,26 @@ mod tests {
             "_isabstracttype",
             "_isconcretetype",
             "_ismutabletype",
+            "_isstructtype",
+            "_isprimitivetype",
+            "_setfield!",
+            "_fieldoffset",
+            "_structequals",
+            "_structhash",
             // Hash/Eltype internal intrinsics (Issue #2570, #2582)
             "_hash",
             "_eltype",
             "getfield",
             "setfield!",
+            "getproperty",
+            "setproperty!",
+            "propertynames",
             "methods",
             "hasmethod",
             "which",
+            "code_lowered",
+            "code_native",
+            "_methodswith",
             "isexported",
             "ispublic",
             // Dict internal intrinsics (Issue #2572, #2669)
@@ -815,6 +842,7 @@ mod tests {
             "_meta_lower",
             "macroexpand",
             "macroexpand!",
+            "macroexpand1",
             "include_string",
             "evalfile",
             // Test
@@ -822,11 +850,22 @@ mod tests {
             "_test_record_broken!",
             "_testset_begin!",
             "_testset_end!",
+            "_testset_set_filter!",
+            "_test_throws_record!",
             // Regex
             "Regex",
             "match",
             "eachmatch",
             "_regex_replace",
+            // Native host bridge
+            "ccall_native",
+            // Task subsystem
+            "Task",
+            "resume",
+            "istaskdone",
+            // Lazy VaList
+            "va_arg",
+            "va_count",
         ];
 
         // Verify each name actually resolves via from_name
@@ -871,6 +910,14 @@ mod tests {
             "_isabstracttype",
             "_isconcretetype",
             "_ismutabletype",
+            "_isstructtype",
+            "_isprimitivetype",
+            "_setfield!",
+            "_fieldoffset",
+            "_structequals",
+            "_structhash",
+            "_methodswith",
+            "_atexit_push!",
             // _hash, _eltype: now in is_base_function (Issue #2570, #2582)
             "_dict_get",
             "_dict_set!",
@@ -903,6 +950,8 @@ mod tests {
             "_test_record_broken!",
             "_testset_begin!",
             "_testset_end!",
+            "_testset_set_filter!",
+            "_test_throws_record!",
             // _regex_replace is in is_base_function() — not exempted
             // Compile-time intercepted — handled by explicit routing in call.rs
             // before is_base_function() is checked
@@ -983,6 +1032,9 @@ mod tests {
             // These are in is_base_function but under different names or paths
             "getfield",
             "setfield!",
+            "getproperty",
+            "setproperty!",
+            "propertynames",
             // Equality — compile-time routed
             "isequal",
             "isless",
@@ -990,6 +1042,13 @@ mod tests {
             "nonmissingtype",
             "get_zero_subnormals",
             "set_zero_subnormals",
+            // Task subsystem — compile-time routed (chunk426-4)
+            "Task",
+            "resume",
+            "istaskdone",
+            // Lazy VaList — compile-time routed (chunk427-2)
+            "va_arg",
+            "va_count",
             // Set mutation variants — now in is_base_function(), removed from exemptions
             // String — compile-time routed
             "occursin",
@@ -1004,6 +1063,7 @@ mod tests {
             "eval",
             "macroexpand",
             "macroexpand!",
+            "macroexpand1",
             "include_string",
             "evalfile",
             "match",
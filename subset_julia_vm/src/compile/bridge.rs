@@ -43,6 +43,7 @@ impl From<&ValueType> for LatticeType {
             ValueType::F32 => LatticeType::Concrete(ConcreteType::Float32),
             ValueType::F64 => LatticeType::Concrete(ConcreteType::Float64),
             ValueType::BigFloat => LatticeType::Concrete(ConcreteType::BigFloat),
+            ValueType::Float128 => LatticeType::Concrete(ConcreteType::Float128),
 
             // Array types
             ValueType::Array => LatticeType::Concrete(ConcreteType::Array {
@@ -240,6 +241,7 @@ impl From<&LatticeType> for ValueType {
                 ConcreteType::Float32 => ValueType::F32,
                 ConcreteType::Float64 => ValueType::F64,
                 ConcreteType::BigFloat => ValueType::BigFloat,
+                ConcreteType::Float128 => ValueType::Float128,
 
                 // String types
                 ConcreteType::String => ValueType::Str,
@@ -3,138 +3,587 @@
 //! This module implements compile-time evaluation of pure operations on constant values.
 
 use crate::compile::lattice::types::ConstValue;
+use half::f16;
+use std::cmp::Ordering;
 
-/// Evaluate a binary operation on two constant values.
+/// Why constant folding of an operation did not produce a value.
 ///
-/// Returns Some(result) if the operation can be evaluated at compile time,
-/// or None if the operation is not supported or would cause an error.
-pub fn eval_const_binary(op: &str, lhs: &ConstValue, rhs: &ConstValue) -> Option<ConstValue> {
-    match (op, lhs, rhs) {
-        // Integer arithmetic
-        ("+", ConstValue::Int64(a), ConstValue::Int64(b)) => {
-            a.checked_add(*b).map(ConstValue::Int64)
-        }
-        ("-", ConstValue::Int64(a), ConstValue::Int64(b)) => {
-            a.checked_sub(*b).map(ConstValue::Int64)
-        }
-        ("*", ConstValue::Int64(a), ConstValue::Int64(b)) => {
-            a.checked_mul(*b).map(ConstValue::Int64)
-        }
-        ("/", ConstValue::Int64(a), ConstValue::Int64(b)) => {
-            if *b != 0 {
-                // Julia's / always returns Float64 for integers
-                Some(ConstValue::Float64(*a as f64 / *b as f64))
-            } else {
-                None // Division by zero
-            }
-        }
-        ("÷", ConstValue::Int64(a), ConstValue::Int64(b)) => {
-            if *b != 0 {
-                a.checked_div(*b).map(ConstValue::Int64)
-            } else {
-                None // Division by zero
-            }
-        }
-        ("%", ConstValue::Int64(a), ConstValue::Int64(b)) => {
-            if *b != 0 {
-                // Julia's % is rem (truncated remainder), same as Rust's %
-                Some(ConstValue::Int64(a % b))
-            } else {
-                None // Division by zero
-            }
-        }
+/// This distinguishes "not a constant expression" from "a constant
+/// expression that is a guaranteed runtime error", so callers can surface a
+/// compile-time diagnostic for the latter instead of silently falling back
+/// to the runtime the way they do for the former.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConstEvalError {
+    /// Integer division or remainder by a literal zero.
+    DivideByZero,
+    /// The operation is defined but this particular combination of constant
+    /// values is a guaranteed runtime error (e.g. `typemin(Int) ÷ -1`).
+    DomainError(String),
+    /// The operator has no compile-time semantics defined here at all;
+    /// leave it for the runtime, same as today's plain `None`.
+    Unsupported,
+    /// The operator is recognized, but these operand types have no defined
+    /// compile-time meaning for it (e.g. `true & 1.0`).
+    TypeMismatch,
+}
 
-        // Float arithmetic
-        ("+", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Float64(a + b)),
-        ("-", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Float64(a - b)),
-        ("*", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Float64(a * b)),
-        ("/", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Float64(a / b)),
-        ("%", ConstValue::Float64(a), ConstValue::Float64(b)) => {
-            // Julia's % is rem (truncated remainder), same as Rust's % for f64
-            Some(ConstValue::Float64(a % b))
+impl std::fmt::Display for ConstEvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConstEvalError::DivideByZero => write!(f, "division by zero"),
+            ConstEvalError::DomainError(reason) => write!(f, "{}", reason),
+            ConstEvalError::Unsupported => write!(f, "operator not supported in constant folding"),
+            ConstEvalError::TypeMismatch => write!(f, "operand types don't match this operator"),
         }
+    }
+}
 
-        // Mixed int/float arithmetic (promote to float)
-        ("+", ConstValue::Int64(a), ConstValue::Float64(b)) => {
-            Some(ConstValue::Float64(*a as f64 + b))
-        }
-        ("+", ConstValue::Float64(a), ConstValue::Int64(b)) => {
-            Some(ConstValue::Float64(a + *b as f64))
-        }
-        ("-", ConstValue::Int64(a), ConstValue::Float64(b)) => {
-            Some(ConstValue::Float64(*a as f64 - b))
-        }
-        ("-", ConstValue::Float64(a), ConstValue::Int64(b)) => {
-            Some(ConstValue::Float64(a - *b as f64))
+/// Binary operator tokens this module assigns compile-time semantics to, for
+/// any operand types. Used to decide `TypeMismatch` vs `Unsupported` when an
+/// operator/operand-type combination isn't matched in `eval_const_binary`.
+fn is_known_binary_operator(op: &str) -> bool {
+    matches!(
+        op,
+        "+" | "-"
+            | "*"
+            | "/"
+            | "÷"
+            | "%"
+            | "<"
+            | "<="
+            | ">"
+            | ">="
+            | "=="
+            | "!="
+            | "&&"
+            | "||"
+            | "&"
+            | "|"
+            | "xor"
+            | "⊻"
+            | "<<"
+            | ">>"
+            | ">>>"
+    )
+}
+
+/// Unary operator tokens this module assigns compile-time semantics to, for
+/// any operand type. Used to decide `TypeMismatch` vs `Unsupported`.
+fn is_known_unary_operator(op: &str) -> bool {
+    matches!(op, "-" | "+" | "~" | "!")
+}
+
+/// Integer width/signedness tag, used to drive Julia's integer promotion
+/// rules during constant folding: the wider operand wins, and a tie between
+/// a signed and an unsigned type of the same width promotes to unsigned.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum IntKind {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+impl IntKind {
+    fn bits(self) -> u32 {
+        match self {
+            IntKind::I8 | IntKind::U8 => 8,
+            IntKind::I16 | IntKind::U16 => 16,
+            IntKind::I32 | IntKind::U32 => 32,
+            IntKind::I64 | IntKind::U64 => 64,
+            IntKind::I128 | IntKind::U128 => 128,
         }
-        ("*", ConstValue::Int64(a), ConstValue::Float64(b)) => {
-            Some(ConstValue::Float64(*a as f64 * b))
+    }
+
+    fn is_unsigned(self) -> bool {
+        matches!(
+            self,
+            IntKind::U8 | IntKind::U16 | IntKind::U32 | IntKind::U64 | IntKind::U128
+        )
+    }
+}
+
+/// Extract an integer constant's width/signedness tag, along with its value
+/// sign/zero-extended to a 128-bit pattern (`as u128` sign-extends signed
+/// sources, matching Rust's normal integer cast rules). Since the `IntKind`
+/// a value is ever reinterpreted at is never narrower than its own width
+/// (see `promote_int`), truncating this pattern back down to any target
+/// kind's native type always recovers exactly the value that kind should
+/// see, letting arithmetic below wrap at the *target* width instead of at
+/// 128 bits.
+fn as_int(v: &ConstValue) -> Option<(IntKind, u128)> {
+    match v {
+        ConstValue::Int8(x) => Some((IntKind::I8, *x as u128)),
+        ConstValue::Int16(x) => Some((IntKind::I16, *x as u128)),
+        ConstValue::Int32(x) => Some((IntKind::I32, *x as u128)),
+        ConstValue::Int64(x) => Some((IntKind::I64, *x as u128)),
+        ConstValue::Int128(x) => Some((IntKind::I128, *x as u128)),
+        ConstValue::UInt8(x) => Some((IntKind::U8, *x as u128)),
+        ConstValue::UInt16(x) => Some((IntKind::U16, *x as u128)),
+        ConstValue::UInt32(x) => Some((IntKind::U32, *x as u128)),
+        ConstValue::UInt64(x) => Some((IntKind::U64, *x as u128)),
+        ConstValue::UInt128(x) => Some((IntKind::U128, *x)),
+        _ => None,
+    }
+}
+
+/// Julia integer promotion: the wider bit width wins; a tie between a
+/// signed and an unsigned type of the same width promotes to unsigned.
+fn promote_int(a: IntKind, b: IntKind) -> IntKind {
+    if a.bits() != b.bits() {
+        if a.bits() > b.bits() { a } else { b }
+    } else if a.is_unsigned() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Dispatch a wrapping binary arithmetic op to the concrete native integer
+/// type for `kind`. Two's-complement wrapping is identical bit-for-bit
+/// whether or not the type is signed, so this mirrors exactly what the VM's
+/// own `wrapping_*` instructions produce at runtime (Issue: Julia integer
+/// arithmetic never traps on overflow, it wraps modulo 2^width).
+macro_rules! int_wrapping_op {
+    ($kind:expr, $a:expr, $b:expr, $op:ident) => {
+        match $kind {
+            IntKind::I8 => ConstValue::Int8(($a as i8).$op($b as i8)),
+            IntKind::I16 => ConstValue::Int16(($a as i16).$op($b as i16)),
+            IntKind::I32 => ConstValue::Int32(($a as i32).$op($b as i32)),
+            IntKind::I64 => ConstValue::Int64(($a as i64).$op($b as i64)),
+            IntKind::I128 => ConstValue::Int128(($a as i128).$op($b as i128)),
+            IntKind::U8 => ConstValue::UInt8(($a as u8).$op($b as u8)),
+            IntKind::U16 => ConstValue::UInt16(($a as u16).$op($b as u16)),
+            IntKind::U32 => ConstValue::UInt32(($a as u32).$op($b as u32)),
+            IntKind::U64 => ConstValue::UInt64(($a as u64).$op($b as u64)),
+            IntKind::U128 => ConstValue::UInt128(($a as u128).$op($b as u128)),
         }
-        ("*", ConstValue::Float64(a), ConstValue::Int64(b)) => {
-            Some(ConstValue::Float64(a * *b as f64))
+    };
+}
+
+/// Like `int_wrapping_op!`, but for the checked division/remainder ops that
+/// must report `None` on division-by-zero, or on the one genuine overflow
+/// trap `typemin ÷ -1` (Julia raises `DivideError` for both; folding gives
+/// up and lets the runtime instruction raise it instead).
+macro_rules! int_checked_op {
+    ($kind:expr, $a:expr, $b:expr, $op:ident) => {
+        match $kind {
+            IntKind::I8 => ($a as i8).$op($b as i8).map(ConstValue::Int8),
+            IntKind::I16 => ($a as i16).$op($b as i16).map(ConstValue::Int16),
+            IntKind::I32 => ($a as i32).$op($b as i32).map(ConstValue::Int32),
+            IntKind::I64 => ($a as i64).$op($b as i64).map(ConstValue::Int64),
+            IntKind::I128 => ($a as i128).$op($b as i128).map(ConstValue::Int128),
+            IntKind::U8 => ($a as u8).$op($b as u8).map(ConstValue::UInt8),
+            IntKind::U16 => ($a as u16).$op($b as u16).map(ConstValue::UInt16),
+            IntKind::U32 => ($a as u32).$op($b as u32).map(ConstValue::UInt32),
+            IntKind::U64 => ($a as u64).$op($b as u64).map(ConstValue::UInt64),
+            IntKind::U128 => ($a as u128).$op($b as u128).map(ConstValue::UInt128),
         }
-        ("/", ConstValue::Int64(a), ConstValue::Float64(b)) => {
-            Some(ConstValue::Float64(*a as f64 / b))
+    };
+}
+
+/// Turn the `None` from `int_checked_op!` into the right `ConstEvalError`:
+/// division/remainder by zero, or the one genuine overflow trap
+/// `typemin ÷ -1` (the only case where the divisor isn't zero but the
+/// checked op still fails).
+fn int_checked_op_error(kind: IntKind, b: u128, op_name: &str) -> ConstEvalError {
+    if int_cmp(kind, b, 0) == Ordering::Equal {
+        ConstEvalError::DivideByZero
+    } else {
+        ConstEvalError::DomainError(format!(
+            "integer overflow in `{}`: typemin(T) {} -1",
+            op_name, op_name
+        ))
+    }
+}
+
+/// Wrapping negation at `kind`'s own width (two's-complement, so this also
+/// applies to unsigned kinds: `-UInt8(1) == 0xff`, same as Julia).
+fn int_wrapping_neg(kind: IntKind, v: u128) -> ConstValue {
+    match kind {
+        IntKind::I8 => ConstValue::Int8((v as i8).wrapping_neg()),
+        IntKind::I16 => ConstValue::Int16((v as i16).wrapping_neg()),
+        IntKind::I32 => ConstValue::Int32((v as i32).wrapping_neg()),
+        IntKind::I64 => ConstValue::Int64((v as i64).wrapping_neg()),
+        IntKind::I128 => ConstValue::Int128((v as i128).wrapping_neg()),
+        IntKind::U8 => ConstValue::UInt8((v as u8).wrapping_neg()),
+        IntKind::U16 => ConstValue::UInt16((v as u16).wrapping_neg()),
+        IntKind::U32 => ConstValue::UInt32((v as u32).wrapping_neg()),
+        IntKind::U64 => ConstValue::UInt64((v as u64).wrapping_neg()),
+        IntKind::U128 => ConstValue::UInt128((v as u128).wrapping_neg()),
+    }
+}
+
+/// Compare two raw integer patterns as `kind`'s own (signed or unsigned)
+/// native type, so e.g. `Int8(-1) < Int8(1)` compares correctly instead of
+/// comparing the raw zero/sign-extended 128-bit patterns.
+fn int_cmp(kind: IntKind, a: u128, b: u128) -> Ordering {
+    match kind {
+        IntKind::I8 => (a as i8).cmp(&(b as i8)),
+        IntKind::I16 => (a as i16).cmp(&(b as i16)),
+        IntKind::I32 => (a as i32).cmp(&(b as i32)),
+        IntKind::I64 => (a as i64).cmp(&(b as i64)),
+        IntKind::I128 => (a as i128).cmp(&(b as i128)),
+        IntKind::U8 => (a as u8).cmp(&(b as u8)),
+        IntKind::U16 => (a as u16).cmp(&(b as u16)),
+        IntKind::U32 => (a as u32).cmp(&(b as u32)),
+        IntKind::U64 => (a as u64).cmp(&(b as u64)),
+        IntKind::U128 => a.cmp(&b),
+    }
+}
+
+/// Interpret a raw integer pattern as `kind`'s own native type and widen it
+/// to `f64`, for Julia's "int / int always yields Float64" rule.
+fn int_value_as_f64(kind: IntKind, v: u128) -> f64 {
+    match kind {
+        IntKind::I8 => (v as i8) as f64,
+        IntKind::I16 => (v as i16) as f64,
+        IntKind::I32 => (v as i32) as f64,
+        IntKind::I64 => (v as i64) as f64,
+        IntKind::I128 => (v as i128) as f64,
+        IntKind::U8 => (v as u8) as f64,
+        IntKind::U16 => (v as u16) as f64,
+        IntKind::U32 => (v as u32) as f64,
+        IntKind::U64 => (v as u64) as f64,
+        IntKind::U128 => (v as u128) as f64,
+    }
+}
+
+/// Interpret a raw integer pattern as `kind`'s own native type and widen it
+/// to `i128`, used to read a shift count (which can be negative).
+fn int_value_as_i128(kind: IntKind, v: u128) -> i128 {
+    match kind {
+        IntKind::I8 => (v as i8) as i128,
+        IntKind::I16 => (v as i16) as i128,
+        IntKind::I32 => (v as i32) as i128,
+        IntKind::I64 => (v as i64) as i128,
+        IntKind::I128 => v as i128,
+        IntKind::U8 => (v as u8) as i128,
+        IntKind::U16 => (v as u16) as i128,
+        IntKind::U32 => (v as u32) as i128,
+        IntKind::U64 => (v as u64) as i128,
+        IntKind::U128 => v as i128,
+    }
+}
+
+/// Reconstruct a `ConstValue` of `kind` from a full 128-bit pattern,
+/// truncating down to `kind`'s own width the same way `as_int` widened it.
+fn int_from_pattern(kind: IntKind, pattern: u128) -> ConstValue {
+    match kind {
+        IntKind::I8 => ConstValue::Int8(pattern as i8),
+        IntKind::I16 => ConstValue::Int16(pattern as i16),
+        IntKind::I32 => ConstValue::Int32(pattern as i32),
+        IntKind::I64 => ConstValue::Int64(pattern as i64),
+        IntKind::I128 => ConstValue::Int128(pattern as i128),
+        IntKind::U8 => ConstValue::UInt8(pattern as u8),
+        IntKind::U16 => ConstValue::UInt16(pattern as u16),
+        IntKind::U32 => ConstValue::UInt32(pattern as u32),
+        IntKind::U64 => ConstValue::UInt64(pattern as u64),
+        IntKind::U128 => ConstValue::UInt128(pattern),
+    }
+}
+
+/// Mask a 128-bit pattern down to its low `bits` bits.
+fn mask_to_width(pattern: u128, bits: u32) -> u128 {
+    if bits >= 128 {
+        pattern
+    } else {
+        pattern & ((1u128 << bits) - 1)
+    }
+}
+
+/// Whether `kind`'s own sign bit is set in `pattern` (used to sign-fill an
+/// arithmetic right shift).
+fn sign_bit_set(kind: IntKind, pattern: u128) -> bool {
+    let bits = kind.bits();
+    (mask_to_width(pattern, bits) >> (bits - 1)) & 1 == 1
+}
+
+/// Logical (zero-filling) left shift at `kind`'s own width; shifting by at
+/// least the width yields zero, same as Julia.
+fn shl(kind: IntKind, pattern: u128, count: u32) -> u128 {
+    if count >= kind.bits() {
+        0
+    } else {
+        mask_to_width(pattern << count, kind.bits())
+    }
+}
+
+/// Logical (zero-filling) right shift at `kind`'s own width.
+fn lshr(kind: IntKind, pattern: u128, count: u32) -> u128 {
+    if count >= kind.bits() {
+        0
+    } else {
+        mask_to_width(pattern, kind.bits()) >> count
+    }
+}
+
+/// Negate a shift count without panicking on overflow (`i128::MIN`); any
+/// count this large already shifts out every bit, so clamping to `i128::MAX`
+/// changes nothing observable.
+fn negate_shift(count: i128) -> i128 {
+    count.checked_neg().unwrap_or(i128::MAX)
+}
+
+/// Clamp a (non-negative) shift count down to `kind`'s own bit width, since
+/// `shl`/`lshr`/`ashr` only need to know "at least the width or not".
+fn clamp_shift(count: i128, kind: IntKind) -> u32 {
+    count.min(kind.bits() as i128) as u32
+}
+
+/// Arithmetic (sign-filling) right shift at `kind`'s own width: negative
+/// values shift in ones instead of zeros, same as Julia's `>>` on signed
+/// (and unsigned, per two's-complement) integers.
+fn ashr(kind: IntKind, pattern: u128, count: u32) -> u128 {
+    let bits = kind.bits();
+    let negative = sign_bit_set(kind, pattern);
+    if count == 0 {
+        return mask_to_width(pattern, bits);
+    }
+    if count >= bits {
+        return if negative { mask_to_width(!0u128, bits) } else { 0 };
+    }
+    let shifted = mask_to_width(pattern, bits) >> count;
+    if negative {
+        mask_to_width(shifted | (!0u128 << (bits - count)), bits)
+    } else {
+        shifted
+    }
+}
+
+/// Float width tag, used to promote to the widest float type present.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum FloatKind {
+    F16,
+    F32,
+    F64,
+}
+
+impl FloatKind {
+    fn rank(self) -> u32 {
+        match self {
+            FloatKind::F16 => 16,
+            FloatKind::F32 => 32,
+            FloatKind::F64 => 64,
         }
-        ("/", ConstValue::Float64(a), ConstValue::Int64(b)) => {
-            Some(ConstValue::Float64(a / *b as f64))
+    }
+
+    fn make(self, v: f64) -> ConstValue {
+        match self {
+            FloatKind::F16 => ConstValue::Float16(f16::from_f64(v)),
+            FloatKind::F32 => ConstValue::Float32(v as f32),
+            FloatKind::F64 => ConstValue::Float64(v),
         }
+    }
+}
+
+fn as_float(v: &ConstValue) -> Option<(FloatKind, f64)> {
+    match v {
+        ConstValue::Float16(x) => Some((FloatKind::F16, x.to_f64())),
+        ConstValue::Float32(x) => Some((FloatKind::F32, *x as f64)),
+        ConstValue::Float64(x) => Some((FloatKind::F64, *x)),
+        _ => None,
+    }
+}
 
-        // Integer comparisons
-        ("<", ConstValue::Int64(a), ConstValue::Int64(b)) => Some(ConstValue::Bool(a < b)),
-        ("<=", ConstValue::Int64(a), ConstValue::Int64(b)) => Some(ConstValue::Bool(a <= b)),
-        (">", ConstValue::Int64(a), ConstValue::Int64(b)) => Some(ConstValue::Bool(a > b)),
-        (">=", ConstValue::Int64(a), ConstValue::Int64(b)) => Some(ConstValue::Bool(a >= b)),
-        ("==", ConstValue::Int64(a), ConstValue::Int64(b)) => Some(ConstValue::Bool(a == b)),
-        ("!=", ConstValue::Int64(a), ConstValue::Int64(b)) => Some(ConstValue::Bool(a != b)),
-
-        // Float comparisons
-        ("<", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Bool(a < b)),
-        ("<=", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Bool(a <= b)),
-        (">", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Bool(a > b)),
-        (">=", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Bool(a >= b)),
-        ("==", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Bool(a == b)),
-        ("!=", ConstValue::Float64(a), ConstValue::Float64(b)) => Some(ConstValue::Bool(a != b)),
+fn promote_float(a: FloatKind, b: FloatKind) -> FloatKind {
+    if a.rank() >= b.rank() { a } else { b }
+}
 
+/// Evaluate a binary operation on two constant values.
+///
+/// Returns `Ok(result)` if the operation can be evaluated at compile time.
+/// Returns `Err(ConstEvalError::Unsupported)` or `Err(TypeMismatch)` for
+/// operations that simply aren't constant-foldable here (callers should
+/// treat these like the old plain `None`: fall back to the runtime without
+/// comment). Returns `Err(DivideByZero)` or `Err(DomainError)` when the
+/// constant expression is a *guaranteed* runtime error, so callers can
+/// surface a compile-time diagnostic instead of folding silently.
+pub fn eval_const_binary(
+    op: &str,
+    lhs: &ConstValue,
+    rhs: &ConstValue,
+) -> Result<ConstValue, ConstEvalError> {
+    // Integer/integer arithmetic and comparisons, width- and sign-aware.
+    // Native arithmetic (+, -, *, unary -) wraps modulo 2^width exactly like
+    // Julia and the VM's runtime instructions, so folding never bails out
+    // just because the result overflows the promoted type.
+    if let (Some((lk, a)), Some((rk, b))) = (as_int(lhs), as_int(rhs)) {
+        let kind = promote_int(lk, rk);
+        return match op {
+            "+" => Ok(int_wrapping_op!(kind, a, b, wrapping_add)),
+            "-" => Ok(int_wrapping_op!(kind, a, b, wrapping_sub)),
+            "*" => Ok(int_wrapping_op!(kind, a, b, wrapping_mul)),
+            "/" => {
+                // Julia's / always returns Float64 for integer operands.
+                let bf = int_value_as_f64(kind, b);
+                if bf != 0.0 {
+                    Ok(ConstValue::Float64(int_value_as_f64(kind, a) / bf))
+                } else {
+                    Err(ConstEvalError::DivideByZero)
+                }
+            }
+            "÷" => int_checked_op!(kind, a, b, checked_div)
+                .ok_or_else(|| int_checked_op_error(kind, b, "÷")),
+            "%" => int_checked_op!(kind, a, b, checked_rem)
+                .ok_or_else(|| int_checked_op_error(kind, b, "%")),
+            "<" => Ok(ConstValue::Bool(int_cmp(kind, a, b) == Ordering::Less)),
+            "<=" => Ok(ConstValue::Bool(int_cmp(kind, a, b) != Ordering::Greater)),
+            ">" => Ok(ConstValue::Bool(int_cmp(kind, a, b) == Ordering::Greater)),
+            ">=" => Ok(ConstValue::Bool(int_cmp(kind, a, b) != Ordering::Less)),
+            "==" => Ok(ConstValue::Bool(int_cmp(kind, a, b) == Ordering::Equal)),
+            "!=" => Ok(ConstValue::Bool(int_cmp(kind, a, b) != Ordering::Equal)),
+            "&" => Ok(int_from_pattern(kind, a & b)),
+            "|" => Ok(int_from_pattern(kind, a | b)),
+            "xor" | "⊻" => Ok(int_from_pattern(kind, a ^ b)),
+            // Shifts take their result type from the left operand alone (the
+            // right operand is just a count), and flip direction on a
+            // negative count, same as Julia's Base `<<`/`>>`/`>>>`.
+            "<<" => {
+                let count = int_value_as_i128(rk, b);
+                Ok(int_from_pattern(
+                    lk,
+                    if count >= 0 {
+                        shl(lk, a, clamp_shift(count, lk))
+                    } else {
+                        lshr(lk, a, clamp_shift(negate_shift(count), lk))
+                    },
+                ))
+            }
+            ">>" => {
+                let count = int_value_as_i128(rk, b);
+                Ok(int_from_pattern(
+                    lk,
+                    if count >= 0 {
+                        ashr(lk, a, clamp_shift(count, lk))
+                    } else {
+                        shl(lk, a, clamp_shift(negate_shift(count), lk))
+                    },
+                ))
+            }
+            ">>>" => {
+                let count = int_value_as_i128(rk, b);
+                Ok(int_from_pattern(
+                    lk,
+                    if count >= 0 {
+                        lshr(lk, a, clamp_shift(count, lk))
+                    } else {
+                        shl(lk, a, clamp_shift(negate_shift(count), lk))
+                    },
+                ))
+            }
+            _ if is_known_binary_operator(op) => Err(ConstEvalError::TypeMismatch),
+            _ => Err(ConstEvalError::Unsupported),
+        };
+    }
+
+    // Float/float and mixed int/float arithmetic and comparisons. Integers
+    // promote to whichever float type is present; when both sides are
+    // float, the result promotes to the widest of the two.
+    let lhs_float = as_float(lhs);
+    let rhs_float = as_float(rhs);
+    if lhs_float.is_some() || rhs_float.is_some() {
+        let a = lhs_float
+            .map(|(_, v)| v)
+            .or_else(|| as_int(lhs).map(|(k, v)| int_value_as_f64(k, v)));
+        let b = rhs_float
+            .map(|(_, v)| v)
+            .or_else(|| as_int(rhs).map(|(k, v)| int_value_as_f64(k, v)));
+        if let (Some(a), Some(b)) = (a, b) {
+            let kind = match (lhs_float, rhs_float) {
+                (Some((lk, _)), Some((rk, _))) => promote_float(lk, rk),
+                (Some((k, _)), None) | (None, Some((k, _))) => k,
+                (None, None) => unreachable!("guarded by the outer is_some() check"),
+            };
+            return match op {
+                "+" => Ok(kind.make(a + b)),
+                "-" => Ok(kind.make(a - b)),
+                "*" => Ok(kind.make(a * b)),
+                "/" => Ok(kind.make(a / b)),
+                "%" => {
+                    // Julia's % is rem (truncated remainder), same as Rust's % for f64.
+                    Ok(kind.make(a % b))
+                }
+                "<" => Ok(ConstValue::Bool(a < b)),
+                "<=" => Ok(ConstValue::Bool(a <= b)),
+                ">" => Ok(ConstValue::Bool(a > b)),
+                ">=" => Ok(ConstValue::Bool(a >= b)),
+                "==" => Ok(ConstValue::Bool(a == b)),
+                "!=" => Ok(ConstValue::Bool(a != b)),
+                _ if is_known_binary_operator(op) => Err(ConstEvalError::TypeMismatch),
+                _ => Err(ConstEvalError::Unsupported),
+            };
+        }
+    }
+
+    match (op, lhs, rhs) {
         // Boolean operations
-        ("&&", ConstValue::Bool(a), ConstValue::Bool(b)) => Some(ConstValue::Bool(*a && *b)),
-        ("||", ConstValue::Bool(a), ConstValue::Bool(b)) => Some(ConstValue::Bool(*a || *b)),
-        ("==", ConstValue::Bool(a), ConstValue::Bool(b)) => Some(ConstValue::Bool(a == b)),
-        ("!=", ConstValue::Bool(a), ConstValue::Bool(b)) => Some(ConstValue::Bool(a != b)),
+        ("&&", ConstValue::Bool(a), ConstValue::Bool(b)) => Ok(ConstValue::Bool(*a && *b)),
+        ("||", ConstValue::Bool(a), ConstValue::Bool(b)) => Ok(ConstValue::Bool(*a || *b)),
+        ("==", ConstValue::Bool(a), ConstValue::Bool(b)) => Ok(ConstValue::Bool(a == b)),
+        ("!=", ConstValue::Bool(a), ConstValue::Bool(b)) => Ok(ConstValue::Bool(a != b)),
 
         // String operations
         ("*", ConstValue::String(a), ConstValue::String(b)) => {
-            Some(ConstValue::String(format!("{}{}", a, b)))
+            Ok(ConstValue::String(format!("{}{}", a, b)))
         }
-        ("==", ConstValue::String(a), ConstValue::String(b)) => Some(ConstValue::Bool(a == b)),
-        ("!=", ConstValue::String(a), ConstValue::String(b)) => Some(ConstValue::Bool(a != b)),
+        ("==", ConstValue::String(a), ConstValue::String(b)) => Ok(ConstValue::Bool(a == b)),
+        ("!=", ConstValue::String(a), ConstValue::String(b)) => Ok(ConstValue::Bool(a != b)),
 
         // Nothing comparisons
-        ("==", ConstValue::Nothing, ConstValue::Nothing) => Some(ConstValue::Bool(true)),
-        ("!=", ConstValue::Nothing, ConstValue::Nothing) => Some(ConstValue::Bool(false)),
+        ("==", ConstValue::Nothing, ConstValue::Nothing) => Ok(ConstValue::Bool(true)),
+        ("!=", ConstValue::Nothing, ConstValue::Nothing) => Ok(ConstValue::Bool(false)),
 
-        _ => None, // Unsupported operation
+        _ => {
+            if is_known_binary_operator(op) {
+                Err(ConstEvalError::TypeMismatch)
+            } else {
+                Err(ConstEvalError::Unsupported)
+            }
+        }
     }
 }
 
 /// Evaluate a unary operation on a constant value.
 ///
-/// Returns Some(result) if the operation can be evaluated at compile time,
-/// or None if the operation is not supported.
-pub fn eval_const_unary(op: &str, operand: &ConstValue) -> Option<ConstValue> {
-    match (op, operand) {
-        // Numeric negation
-        ("-", ConstValue::Int64(v)) => v.checked_neg().map(ConstValue::Int64),
-        ("-", ConstValue::Float64(v)) => Some(ConstValue::Float64(-v)),
+/// See `eval_const_binary` for what each `Err` variant means.
+pub fn eval_const_unary(op: &str, operand: &ConstValue) -> Result<ConstValue, ConstEvalError> {
+    if let Some((kind, v)) = as_int(operand) {
+        return match op {
+            "-" => Ok(int_wrapping_neg(kind, v)),
+            "+" => Ok(operand.clone()),
+            "~" => Ok(int_from_pattern(kind, !v)),
+            _ if is_known_unary_operator(op) => Err(ConstEvalError::TypeMismatch),
+            _ => Err(ConstEvalError::Unsupported),
+        };
+    }
 
-        // Numeric positive (identity)
-        ("+", ConstValue::Int64(v)) => Some(ConstValue::Int64(*v)),
-        ("+", ConstValue::Float64(v)) => Some(ConstValue::Float64(*v)),
+    if let Some((kind, v)) = as_float(operand) {
+        return match op {
+            "-" => Ok(kind.make(-v)),
+            "+" => Ok(operand.clone()),
+            _ if is_known_unary_operator(op) => Err(ConstEvalError::TypeMismatch),
+            _ => Err(ConstEvalError::Unsupported),
+        };
+    }
 
+    match (op, operand) {
         // Boolean negation
-        ("!", ConstValue::Bool(v)) => Some(ConstValue::Bool(!v)),
+        ("!", ConstValue::Bool(v)) => Ok(ConstValue::Bool(!v)),
 
-        _ => None, // Unsupported operation
+        _ => {
+            if is_known_unary_operator(op) {
+                Err(ConstEvalError::TypeMismatch)
+            } else {
+                Err(ConstEvalError::Unsupported)
+            }
+        }
     }
 }
 
@@ -146,15 +595,15 @@ mod tests {
     fn test_int_arithmetic() {
         assert_eq!(
             eval_const_binary("+", &ConstValue::Int64(2), &ConstValue::Int64(3)),
-            Some(ConstValue::Int64(5))
+            Ok(ConstValue::Int64(5))
         );
         assert_eq!(
             eval_const_binary("-", &ConstValue::Int64(5), &ConstValue::Int64(3)),
-            Some(ConstValue::Int64(2))
+            Ok(ConstValue::Int64(2))
         );
         assert_eq!(
             eval_const_binary("*", &ConstValue::Int64(2), &ConstValue::Int64(3)),
-            Some(ConstValue::Int64(6))
+            Ok(ConstValue::Int64(6))
         );
     }
 
@@ -163,12 +612,12 @@ mod tests {
         // Julia's / always returns Float64
         assert_eq!(
             eval_const_binary("/", &ConstValue::Int64(6), &ConstValue::Int64(2)),
-            Some(ConstValue::Float64(3.0))
+            Ok(ConstValue::Float64(3.0))
         );
         // Integer division
         assert_eq!(
             eval_const_binary("÷", &ConstValue::Int64(7), &ConstValue::Int64(2)),
-            Some(ConstValue::Int64(3))
+            Ok(ConstValue::Int64(3))
         );
     }
 
@@ -176,11 +625,11 @@ mod tests {
     fn test_bool_ops() {
         assert_eq!(
             eval_const_binary("&&", &ConstValue::Bool(true), &ConstValue::Bool(false)),
-            Some(ConstValue::Bool(false))
+            Ok(ConstValue::Bool(false))
         );
         assert_eq!(
             eval_const_binary("||", &ConstValue::Bool(true), &ConstValue::Bool(false)),
-            Some(ConstValue::Bool(true))
+            Ok(ConstValue::Bool(true))
         );
     }
 
@@ -188,11 +637,11 @@ mod tests {
     fn test_comparisons() {
         assert_eq!(
             eval_const_binary("<", &ConstValue::Int64(2), &ConstValue::Int64(3)),
-            Some(ConstValue::Bool(true))
+            Ok(ConstValue::Bool(true))
         );
         assert_eq!(
             eval_const_binary("==", &ConstValue::Int64(2), &ConstValue::Int64(2)),
-            Some(ConstValue::Bool(true))
+            Ok(ConstValue::Bool(true))
         );
     }
 
@@ -201,7 +650,7 @@ mod tests {
         // Julia: 7 % 3 == 1
         assert_eq!(
             eval_const_binary("%", &ConstValue::Int64(7), &ConstValue::Int64(3)),
-            Some(ConstValue::Int64(1))
+            Ok(ConstValue::Int64(1))
         );
     }
 
@@ -210,15 +659,15 @@ mod tests {
         // Julia: -7 % 3 == -1  (truncated remainder, NOT rem_euclid)
         assert_eq!(
             eval_const_binary("%", &ConstValue::Int64(-7), &ConstValue::Int64(3)),
-            Some(ConstValue::Int64(-1))
+            Ok(ConstValue::Int64(-1))
         );
     }
 
     #[test]
-    fn test_int_remainder_by_zero_returns_none() {
+    fn test_int_remainder_by_zero_is_divide_by_zero_error() {
         assert_eq!(
             eval_const_binary("%", &ConstValue::Int64(7), &ConstValue::Int64(0)),
-            None
+            Err(ConstEvalError::DivideByZero)
         );
     }
 
@@ -226,7 +675,7 @@ mod tests {
     fn test_float_remainder_positive() {
         // Julia: 7.0 % 3.0 == 1.0
         let result = eval_const_binary("%", &ConstValue::Float64(7.0), &ConstValue::Float64(3.0));
-        assert_eq!(result, Some(ConstValue::Float64(1.0)));
+        assert_eq!(result, Ok(ConstValue::Float64(1.0)));
     }
 
     #[test]
@@ -234,18 +683,212 @@ mod tests {
         // Julia: -7.0 % 3.0 == -1.0  (truncated remainder, NOT floor-division mod)
         let result =
             eval_const_binary("%", &ConstValue::Float64(-7.0), &ConstValue::Float64(3.0));
-        assert_eq!(result, Some(ConstValue::Float64(-1.0)));
+        assert_eq!(result, Ok(ConstValue::Float64(-1.0)));
     }
 
     #[test]
     fn test_unary_ops() {
         assert_eq!(
             eval_const_unary("-", &ConstValue::Int64(5)),
-            Some(ConstValue::Int64(-5))
+            Ok(ConstValue::Int64(-5))
         );
         assert_eq!(
             eval_const_unary("!", &ConstValue::Bool(true)),
-            Some(ConstValue::Bool(false))
+            Ok(ConstValue::Bool(false))
+        );
+    }
+
+    #[test]
+    fn test_int32_arithmetic() {
+        assert_eq!(
+            eval_const_binary("+", &ConstValue::Int32(2), &ConstValue::Int32(3)),
+            Ok(ConstValue::Int32(5))
+        );
+    }
+
+    #[test]
+    fn test_int8_wraps_on_overflow() {
+        // Julia: Int8(100) + Int8(100) == -56 (wraps, does not trap)
+        assert_eq!(
+            eval_const_binary("+", &ConstValue::Int8(100), &ConstValue::Int8(100)),
+            Ok(ConstValue::Int8(-56))
+        );
+    }
+
+    #[test]
+    fn test_int64_wraps_at_typemax() {
+        // Julia: typemax(Int64) + 1 == typemin(Int64)
+        assert_eq!(
+            eval_const_binary("+", &ConstValue::Int64(i64::MAX), &ConstValue::Int64(1)),
+            Ok(ConstValue::Int64(i64::MIN))
+        );
+    }
+
+    #[test]
+    fn test_unsigned_wraps_on_negation() {
+        // Julia: -UInt8(1) == 0xff
+        assert_eq!(
+            eval_const_unary("-", &ConstValue::UInt8(1)),
+            Ok(ConstValue::UInt8(255))
+        );
+    }
+
+    #[test]
+    fn test_signed_division_overflow_trap_is_domain_error() {
+        // Julia: div(typemin(Int64), -1) raises DivideError, but it's not a
+        // *division by zero* - distinguish it as a domain error instead.
+        assert!(matches!(
+            eval_const_binary("÷", &ConstValue::Int64(i64::MIN), &ConstValue::Int64(-1)),
+            Err(ConstEvalError::DomainError(_))
+        ));
+        assert!(matches!(
+            eval_const_binary("%", &ConstValue::Int64(i64::MIN), &ConstValue::Int64(-1)),
+            Err(ConstEvalError::DomainError(_))
+        ));
+    }
+
+    #[test]
+    fn test_mixed_width_promotes_to_wider() {
+        // Julia: Int8(2) + Int32(3) promotes to Int32
+        assert_eq!(
+            eval_const_binary("+", &ConstValue::Int8(2), &ConstValue::Int32(3)),
+            Ok(ConstValue::Int32(5))
+        );
+    }
+
+    #[test]
+    fn test_same_width_signed_unsigned_promotes_to_unsigned() {
+        // Julia: same-width signed/unsigned pairs promote to the unsigned type
+        assert_eq!(
+            eval_const_binary("+", &ConstValue::Int32(2), &ConstValue::UInt32(3)),
+            Ok(ConstValue::UInt32(5))
+        );
+    }
+
+    #[test]
+    fn test_float32_arithmetic_stays_float32() {
+        // Julia: Float32/Float32 division stays Float32, unlike integer division
+        assert_eq!(
+            eval_const_binary("/", &ConstValue::Float32(6.0), &ConstValue::Float32(2.0)),
+            Ok(ConstValue::Float32(3.0))
+        );
+    }
+
+    #[test]
+    fn test_int_float32_promotes_to_float32() {
+        // Julia: Int64(2) + Float32(1.5) promotes to Float32, not Float64
+        assert_eq!(
+            eval_const_binary("+", &ConstValue::Int64(2), &ConstValue::Float32(1.5)),
+            Ok(ConstValue::Float32(3.5))
+        );
+    }
+
+    #[test]
+    fn test_bitwise_and_or_xor() {
+        assert_eq!(
+            eval_const_binary("&", &ConstValue::Int64(0b1100), &ConstValue::Int64(0b1010)),
+            Ok(ConstValue::Int64(0b1000))
+        );
+        assert_eq!(
+            eval_const_binary("|", &ConstValue::Int64(0b1100), &ConstValue::Int64(0b1010)),
+            Ok(ConstValue::Int64(0b1110))
+        );
+        assert_eq!(
+            eval_const_binary("xor", &ConstValue::Int64(0b1100), &ConstValue::Int64(0b1010)),
+            Ok(ConstValue::Int64(0b0110))
+        );
+    }
+
+    #[test]
+    fn test_bitwise_ops_reject_non_integers() {
+        assert_eq!(
+            eval_const_binary("&", &ConstValue::Bool(true), &ConstValue::Bool(false)),
+            Err(ConstEvalError::TypeMismatch)
+        );
+        assert_eq!(
+            eval_const_binary("&", &ConstValue::Float64(1.0), &ConstValue::Int64(1)),
+            Err(ConstEvalError::TypeMismatch)
+        );
+    }
+
+    #[test]
+    fn test_shift_left() {
+        assert_eq!(
+            eval_const_binary("<<", &ConstValue::Int8(1), &ConstValue::Int64(3)),
+            Ok(ConstValue::Int8(8))
+        );
+        // Shifting out past the width yields zero, same as Julia.
+        assert_eq!(
+            eval_const_binary("<<", &ConstValue::Int8(1), &ConstValue::Int64(8)),
+            Ok(ConstValue::Int8(0))
+        );
+    }
+
+    #[test]
+    fn test_shift_right_arithmetic_sign_fills_negative() {
+        assert_eq!(
+            eval_const_binary(">>", &ConstValue::Int8(-8), &ConstValue::Int64(2)),
+            Ok(ConstValue::Int8(-2))
+        );
+        // Shifting a negative value out past the width yields all-ones (-1).
+        assert_eq!(
+            eval_const_binary(">>", &ConstValue::Int8(-8), &ConstValue::Int64(8)),
+            Ok(ConstValue::Int8(-1))
+        );
+    }
+
+    #[test]
+    fn test_shift_right_logical_zero_fills_negative() {
+        // >>> is the unsigned/logical shift: it zero-fills even for a
+        // negative (i.e. high-bit-set) signed value.
+        assert_eq!(
+            eval_const_binary(">>>", &ConstValue::Int8(-1), &ConstValue::Int64(4)),
+            Ok(ConstValue::Int8(0x0f))
+        );
+    }
+
+    #[test]
+    fn test_negative_shift_count_reverses_direction() {
+        assert_eq!(
+            eval_const_binary("<<", &ConstValue::Int8(8), &ConstValue::Int64(-3)),
+            eval_const_binary(">>>", &ConstValue::Int8(8), &ConstValue::Int64(3))
+        );
+        assert_eq!(
+            eval_const_binary(">>", &ConstValue::Int8(1), &ConstValue::Int64(-3)),
+            eval_const_binary("<<", &ConstValue::Int8(1), &ConstValue::Int64(3))
+        );
+    }
+
+    #[test]
+    fn test_shift_result_type_follows_left_operand() {
+        // The shift count's own type doesn't affect the result type.
+        assert_eq!(
+            eval_const_binary("<<", &ConstValue::Int16(1), &ConstValue::Int8(4)),
+            Ok(ConstValue::Int16(16))
+        );
+    }
+
+    #[test]
+    fn test_bitwise_not() {
+        assert_eq!(
+            eval_const_unary("~", &ConstValue::Int8(5)),
+            Ok(ConstValue::Int8(-6))
+        );
+        assert_eq!(
+            eval_const_unary("~", &ConstValue::UInt8(0)),
+            Ok(ConstValue::UInt8(255))
+        );
+    }
+
+    #[test]
+    fn test_unknown_operator_is_unsupported_not_type_mismatch() {
+        assert_eq!(
+            eval_const_binary("frobnicate", &ConstValue::Int64(1), &ConstValue::Int64(2)),
+            Err(ConstEvalError::Unsupported)
+        );
+        assert_eq!(
+            eval_const_unary("frobnicate", &ConstValue::Int64(1)),
+            Err(ConstEvalError::Unsupported)
         );
     }
 }
@@ -11,18 +11,33 @@
 
 mod eval;
 
-pub use eval::{eval_const_binary, eval_const_unary};
+pub use eval::{eval_const_binary, eval_const_unary, ConstEvalError};
 
+use crate::compile::diagnostics::emit_const_eval_trap;
 use crate::compile::lattice::types::LatticeType;
 
 /// Try to evaluate a binary operation on two lattice types.
 ///
-/// If both operands are constants, evaluate the operation and return Const(result).
-/// Otherwise, return None.
+/// If both operands are constants and the operation folds, returns
+/// `Some(Const(result))`. Otherwise returns `None`, the same way it does for
+/// an unsupported operator or non-constant operands - but if the constant
+/// expression is a guaranteed runtime error (e.g. division by zero), this
+/// also emits a compile-time diagnostic before falling back.
 pub fn try_eval_binary(op: &str, left: &LatticeType, right: &LatticeType) -> Option<LatticeType> {
     match (left, right) {
         (LatticeType::Const(lv), LatticeType::Const(rv)) => {
-            eval_const_binary(op, lv, rv).map(LatticeType::Const)
+            match eval_const_binary(op, lv, rv) {
+                Ok(v) => Some(LatticeType::Const(v)),
+                Err(ConstEvalError::DivideByZero) => {
+                    emit_const_eval_trap(op, &ConstEvalError::DivideByZero.to_string());
+                    None
+                }
+                Err(err @ ConstEvalError::DomainError(_)) => {
+                    emit_const_eval_trap(op, &err.to_string());
+                    None
+                }
+                Err(ConstEvalError::Unsupported) | Err(ConstEvalError::TypeMismatch) => None,
+            }
         }
         _ => None,
     }
@@ -30,11 +45,21 @@ pub fn try_eval_binary(op: &str, left: &LatticeType, right: &LatticeType) -> Opt
 
 /// Try to evaluate a unary operation on a lattice type.
 ///
-/// If the operand is a constant, evaluate the operation and return Const(result).
-/// Otherwise, return None.
+/// See `try_eval_binary` for how constant-expression traps are diagnosed.
 pub fn try_eval_unary(op: &str, operand: &LatticeType) -> Option<LatticeType> {
     match operand {
-        LatticeType::Const(v) => eval_const_unary(op, v).map(LatticeType::Const),
+        LatticeType::Const(v) => match eval_const_unary(op, v) {
+            Ok(v) => Some(LatticeType::Const(v)),
+            Err(ConstEvalError::DivideByZero) => {
+                emit_const_eval_trap(op, &ConstEvalError::DivideByZero.to_string());
+                None
+            }
+            Err(err @ ConstEvalError::DomainError(_)) => {
+                emit_const_eval_trap(op, &err.to_string());
+                None
+            }
+            Err(ConstEvalError::Unsupported) | Err(ConstEvalError::TypeMismatch) => None,
+        },
         _ => None,
     }
 }
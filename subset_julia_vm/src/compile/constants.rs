@@ -321,6 +321,7 @@ mod tests {
                 type_annotation: Some(crate::types::JuliaType::Int64),
                 is_varargs: false,
                 vararg_count: None,
+                nospecialize: false,
                 span: span(),
             }],
             vec![Stmt::Return {
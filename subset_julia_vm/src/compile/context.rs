@@ -7,7 +7,7 @@ use std::collections::HashMap;
 
 use crate::ir::core::{Block, Function, MacroDef};
 use crate::types::{JuliaType, TypeExpr};
-use crate::vm::{AbstractTypeDefInfo, StructDefInfo, ValueType};
+use crate::vm::{AbstractTypeDefInfo, EnumMembershipCheck, StructDefInfo, ValueType};
 
 use super::types::{err, CResult, CompileError, InstantiationKey, ParametricStructDef};
 use super::{
@@ -81,6 +81,14 @@ pub struct SharedCompileContext {
     /// Closure captured variables: maps function name -> set of captured variable names.
     /// Used when compiling closures to know which variables to load via LoadCaptured.
     pub closure_captures: HashMap<String, std::collections::HashSet<String>>,
+    /// Subset of each closure's captures (keyed the same way as `closure_captures`)
+    /// that are shared by reference via a boxed cell rather than copied by value,
+    /// because the closure body reassigns them (Issue chunk421-1).
+    pub boxed_closure_captures: HashMap<String, std::collections::HashSet<String>>,
+    /// `@enum` type name -> membership check for its declared values.
+    /// Populated when compiling `Stmt::EnumDef`; consulted when compiling a call
+    /// `EnumName(x)` to emit `Instr::EnumConvert` instead of a normal function call.
+    pub enum_checks: HashMap<String, EnumMembershipCheck>,
 }
 
 impl SharedCompileContext {
@@ -154,6 +162,8 @@ impl SharedCompileContext {
             function_ir_by_global_index: HashMap::new(),
             type_aliases: HashMap::new(),
             closure_captures: HashMap::new(),
+            boxed_closure_captures: HashMap::new(),
+            enum_checks: HashMap::new(),
         }
     }
 
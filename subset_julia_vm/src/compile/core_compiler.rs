@@ -32,6 +32,12 @@ pub(super) struct LoopContext {
 
 /// Finally block context for tracking pending finally blocks.
 /// Used to ensure finally blocks execute even with return/break/continue.
+///
+/// `Stmt::Break`/`Stmt::Continue` compare `loop_depth` against
+/// `self.loop_stack.len()` at the jump site to find which pending finally
+/// blocks belong to loops being unwound past (`loop_depth >= current_loop_depth`)
+/// versus the loop actually being broken out of or continued, so `for ... try
+/// ... finally ... break end` runs cleanup exactly like Julia does.
 #[derive(Debug)]
 pub(super) struct FinallyContext {
     /// The finally block IR to execute
@@ -109,17 +115,43 @@ pub(super) struct CoreCompiler<'a> {
     pub(super) current_module_path: Option<String>,
     /// Module name -> Set of constant names defined in that module's body
     pub(super) module_constants: &'a HashMap<String, HashSet<String>>,
-    /// Label positions: label_name -> instruction index (for @label)
-    pub(super) label_positions: HashMap<String, usize>,
-    /// Goto patches: (instruction_index, target_label_name) (for @goto)
-    pub(super) goto_patches: Vec<(usize, String)>,
+    /// Label positions: label_name -> (instruction index, try_depth) (for @label).
+    /// `try_depth` is `self.try_depth` as it was when the label was recorded,
+    /// used to reject a `@goto` that would jump across a try/catch handler
+    /// boundary (Issue chunk420-5).
+    pub(super) label_positions: HashMap<String, (usize, usize)>,
+    /// Goto patches: (instruction_index, target_label_name, try_depth at the goto site)
+    /// (for @goto)
+    pub(super) goto_patches: Vec<(usize, String, usize)>,
+    /// Nesting depth of `try`/`catch` handler regions currently being compiled.
+    /// Incremented around `try_block`/`catch_block`/`else_block` compilation in
+    /// `compile_try_stmt`, used to detect `@goto`s that would jump into or out
+    /// of an active exception handler (Issue chunk420-5).
+    pub(super) try_depth: usize,
     /// Captured variables from outer scope (for closures).
     /// When compiling a closure body, this contains the names of variables
     /// that were captured from the enclosing function scope.
     pub(super) captured_vars: HashSet<String>,
+    /// Local variables in the CURRENT scope that have been promoted to a shared
+    /// boxed cell (`Value::Boxed`) because some closure defined in this scope
+    /// reassigns them. Consulted by `load_local`/`store_local` before the normal
+    /// `locals` dispatch so reads/writes go through the box (Issue chunk421-1).
+    pub(super) boxed_locals: HashSet<String>,
+    /// Captured variables (subset of `captured_vars`) that the enclosing scope
+    /// shares by reference rather than by value, because this closure body
+    /// reassigns them. Consulted by `load_local`/`store_local` to emit
+    /// `LoadCapturedBoxed`/`StoreCapturedBoxed` instead of the by-value captured
+    /// forms (Issue chunk421-1).
+    pub(super) boxed_captures: HashSet<String>,
     /// Current enclosing function name (for creating qualified nested function names).
     /// Used to disambiguate nested functions with the same name in different parent functions.
     pub(super) current_function_name: Option<String>,
+    /// Every name assigned anywhere in the function body currently being compiled (outside
+    /// any closure), scanned once at function entry. A name in here is reassigned by the
+    /// outer scope itself, not just by some closure's own body, so it needs the same boxing
+    /// as a closure-mutated capture for sibling closures to observe later writes (Issue
+    /// chunk421-1).
+    pub(super) enclosing_assigned_vars: HashSet<String>,
 }
 
 /// Check if a ValueType is an integer type (signed or unsigned)
@@ -204,8 +236,12 @@ impl<'a> CoreCompiler<'a> {
             module_constants,
             label_positions: HashMap::new(),
             goto_patches: Vec::new(),
+            try_depth: 0,
             captured_vars: HashSet::new(),
+            boxed_locals: HashSet::new(),
+            boxed_captures: HashSet::new(),
             current_function_name: None,
+            enclosing_assigned_vars: HashSet::new(),
         }
     }
 
@@ -249,8 +285,12 @@ impl<'a> CoreCompiler<'a> {
             module_constants,
             label_positions: HashMap::new(),
             goto_patches: Vec::new(),
+            try_depth: 0,
             captured_vars: HashSet::new(), // Will be populated for closures
-            current_function_name: None,   // Will be set when compiling functions
+            boxed_locals: HashSet::new(),
+            boxed_captures: HashSet::new(), // Will be populated for closures that mutate a capture
+            current_function_name: None,    // Will be set when compiling functions
+            enclosing_assigned_vars: HashSet::new(), // Will be populated at function-body entry
         }
     }
 
@@ -430,10 +470,17 @@ impl<'a> CoreCompiler<'a> {
 
     /// Patch all @goto jumps with the corresponding @label positions.
     /// This must be called after all statements have been compiled.
-    /// Returns an error if any @goto references an undefined label.
+    /// Returns an error if any @goto references an undefined label, or if a
+    /// @goto would jump into or out of an active try/catch handler region.
     pub(super) fn patch_goto_jumps(&mut self) -> CResult<()> {
-        for (patch_pos, label_name) in &self.goto_patches {
-            if let Some(&label_pos) = self.label_positions.get(label_name) {
+        for (patch_pos, label_name, goto_try_depth) in &self.goto_patches {
+            if let Some(&(label_pos, label_try_depth)) = self.label_positions.get(label_name) {
+                if label_try_depth != *goto_try_depth {
+                    return types::err(format!(
+                        "@goto {} cannot jump into or out of a try/catch block",
+                        label_name
+                    ));
+                }
                 self.code[*patch_pos] = Instr::Jump(label_pos);
             } else {
                 return types::err(format!(
@@ -69,6 +69,12 @@ pub enum DiagnosticReason {
     /// Generic fallback for other widening reasons.
     /// Contains a description.
     Other(String),
+
+    /// A constant expression is a guaranteed runtime error (e.g. division
+    /// by a literal zero). Folding gives up so the runtime instruction can
+    /// raise the error itself, but this is worth flagging at compile time.
+    /// Contains a description of the trap.
+    ConstantTrap(String),
 }
 
 impl std::fmt::Display for DiagnosticReason {
@@ -125,6 +131,9 @@ impl std::fmt::Display for DiagnosticReason {
                 }
             }
             DiagnosticReason::Other(desc) => write!(f, "{}", desc),
+            DiagnosticReason::ConstantTrap(desc) => {
+                write!(f, "constant expression would trap at runtime: {}", desc)
+            }
         }
     }
 }
@@ -300,6 +309,14 @@ pub fn emit_unknown_array_element() {
     ));
 }
 
+/// Helper function to emit a constant-expression-traps-at-runtime diagnostic.
+pub fn emit_const_eval_trap(op: &str, reason: &str) {
+    DiagnosticsCollector::emit(
+        TypeInferenceDiagnostic::new(DiagnosticReason::ConstantTrap(reason.to_string()))
+            .with_context(format!("constant `{}` expression", op)),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -392,4 +409,22 @@ mod tests {
 
         DiagnosticsCollector::disable();
     }
+
+    #[test]
+    fn test_const_eval_trap_diagnostic() {
+        DiagnosticsCollector::enable();
+        DiagnosticsCollector::clear();
+
+        emit_const_eval_trap("%", "division by zero");
+
+        let diags = DiagnosticsCollector::take();
+        assert_eq!(diags.len(), 1);
+        assert!(matches!(
+            &diags[0].reason,
+            DiagnosticReason::ConstantTrap(desc) if desc == "division by zero"
+        ));
+        assert!(diags[0].to_string().contains("would trap at runtime"));
+
+        DiagnosticsCollector::disable();
+    }
 }
@@ -129,7 +129,9 @@ fn infer_builtin_op_effects(op: &BuiltinOp, arg_effects: &[Effects]) -> Effects
         | BuiltinOp::TestRecord
         | BuiltinOp::TestRecordBroken
         | BuiltinOp::TestSetBegin
-        | BuiltinOp::TestSetEnd => Effects::with_side_effects(),
+        | BuiltinOp::TestSetEnd
+        | BuiltinOp::TestSetSetFilter
+        | BuiltinOp::TestThrowsRecord => Effects::with_side_effects(),
 
         // Mutating collection operations.
         BuiltinOp::Push
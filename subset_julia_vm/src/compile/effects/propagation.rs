@@ -94,6 +94,15 @@ fn extract_callees_from_stmt(stmt: &Stmt) -> HashSet<FuncId> {
     match stmt {
         Stmt::Assign { value, .. }
         | Stmt::AddAssign { value, .. }
+        | Stmt::SubAssign { value, .. }
+        | Stmt::MulAssign { value, .. }
+        | Stmt::DivAssign { value, .. }
+        | Stmt::FldAssign { value, .. }
+        | Stmt::PowAssign { value, .. }
+        | Stmt::BitAndAssign { value, .. }
+        | Stmt::BitOrAssign { value, .. }
+        | Stmt::BitXorAssign { value, .. }
+        | Stmt::BroadcastAssign { value, .. }
         | Stmt::Expr { expr: value, .. } => extract_callees_from_expr(value),
         Stmt::For {
             body,
@@ -341,6 +350,15 @@ fn compute_stmt_effects(stmt: &Stmt, effects_map: &HashMap<FuncId, Effects>) ->
     match stmt {
         Stmt::Assign { value, .. }
         | Stmt::AddAssign { value, .. }
+        | Stmt::SubAssign { value, .. }
+        | Stmt::MulAssign { value, .. }
+        | Stmt::DivAssign { value, .. }
+        | Stmt::FldAssign { value, .. }
+        | Stmt::PowAssign { value, .. }
+        | Stmt::BitAndAssign { value, .. }
+        | Stmt::BitOrAssign { value, .. }
+        | Stmt::BitXorAssign { value, .. }
+        | Stmt::BroadcastAssign { value, .. }
         | Stmt::Expr { expr: value, .. } => compute_expr_effects(value, effects_map),
         Stmt::For {
             body,
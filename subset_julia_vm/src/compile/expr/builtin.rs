@@ -200,7 +200,73 @@ impl CoreCompiler<'_> {
                 self.compile_expr(&args[3])?;
                 self.emit(Instr::CallBuiltin(BuiltinId::RegexReplace, 4));
                 Ok(ValueType::Str)
-            }            _ => {
+            }
+            "ccall_native" => {
+                // ccall_native(name, args...) - dispatch to a host Rust fn
+                // registered via ffi::register_native. Returns Any since the
+                // host function's result type isn't known at compile time.
+                if args.is_empty() {
+                    return err(
+                        "ccall_native requires at least 1 argument: ccall_native(name, args...)",
+                    );
+                }
+                for arg in args.iter() {
+                    self.compile_expr(arg)?;
+                }
+                self.emit(Instr::CallBuiltin(BuiltinId::CallNative, args.len()));
+                Ok(ValueType::Any)
+            }
+            "Task" => {
+                // Task(f) - wrap a zero-argument callable as a not-yet-started
+                // Task handle (Issue chunk426-4). Returns Any since the
+                // handle's eventual value type isn't known at compile time.
+                if args.len() != 1 {
+                    return err("Task requires exactly 1 argument: Task(f)");
+                }
+                self.compile_expr(&args[0])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::TaskNew, 1));
+                Ok(ValueType::Any)
+            }
+            "resume" => {
+                // resume(t) - drive a Task to its next yield/produce, return,
+                // or error (Issue chunk426-4).
+                if args.len() != 1 {
+                    return err("resume requires exactly 1 argument: resume(t)");
+                }
+                self.compile_expr(&args[0])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::TaskResume, 1));
+                Ok(ValueType::Any)
+            }
+            "istaskdone" => {
+                // istaskdone(t) - check whether a Task has finished, without
+                // resuming it (Issue chunk426-4).
+                if args.len() != 1 {
+                    return err("istaskdone requires exactly 1 argument: istaskdone(t)");
+                }
+                self.compile_expr(&args[0])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::IsTaskDone, 1));
+                Ok(ValueType::Bool)
+            }
+            "va_arg" => {
+                // va_arg(va) - pop and return the next argument from a
+                // VaList (Issue chunk427-2).
+                if args.len() != 1 {
+                    return err("va_arg requires exactly 1 argument: va_arg(va)");
+                }
+                self.compile_expr(&args[0])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::VaArg, 1));
+                Ok(ValueType::Any)
+            }
+            "va_count" => {
+                // va_count(va) - arguments left in a VaList (Issue chunk427-2).
+                if args.len() != 1 {
+                    return err("va_count requires exactly 1 argument: va_count(va)");
+                }
+                self.compile_expr(&args[0])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::VaCount, 1));
+                Ok(ValueType::I64)
+            }
+            _ => {
                 // Phase 7-1 (Issue #2549): User-defined function broadcast (f.(arr)) is now
                 // handled by lowering (Phase 6) which generates materialize(Broadcasted(f, (args...)))
                 // IR. The ".f" compiler pattern is dead code.
@@ -1186,6 +1252,28 @@ impl CoreCompiler<'_> {
                 self.emit(Instr::CallBuiltin(BuiltinId::Which, 2));
                 Ok(ValueType::Any) // Returns Method struct
             }
+            BuiltinOp::CodeLowered => {
+                // code_lowered(f, types) - disassemble the dispatched method's Instr stream
+                if args.len() != 2 {
+                    return err("code_lowered requires exactly 2 arguments: code_lowered(f, types)");
+                }
+                self.compile_expr(&args[0])?;
+                self.compile_expr(&args[1])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::CodeLowered, 2));
+                Ok(ValueType::Str)
+            }
+            BuiltinOp::CodeNative => {
+                // code_native(f, types) - same disassembly as code_lowered, annotated as
+                // the native-codegen view (this VM has no separate JIT/native backend,
+                // so the bytecode stream IS the lowest level there is to show).
+                if args.len() != 2 {
+                    return err("code_native requires exactly 2 arguments: code_native(f, types)");
+                }
+                self.compile_expr(&args[0])?;
+                self.compile_expr(&args[1])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::CodeNative, 2));
+                Ok(ValueType::Str)
+            }
             BuiltinOp::Seed => {
                 // seed!(n) - reseed global RNG (only via Random.seed!())
                 if args.len() != 1 {
@@ -1570,6 +1658,18 @@ impl CoreCompiler<'_> {
                 self.emit(Instr::CallBuiltin(BuiltinId::MacroExpandBang, 2));
                 Ok(ValueType::Any) // Can return any type (Expr, literal, Symbol, etc.)
             }
+            BuiltinOp::MacroExpand1 => {
+                // macroexpand1(m, x) - expand only the outermost macro call, one step
+                if args.len() != 2 {
+                    return err("macroexpand1 requires exactly 2 arguments: macroexpand1(m, x)");
+                }
+                // Compile the module (ignored at runtime)
+                self.compile_expr(&args[0])?;
+                // Compile the expression
+                self.compile_expr(&args[1])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::MacroExpand1, 2));
+                Ok(ValueType::Any) // Can return any type (Expr, literal, Symbol, etc.)
+            }
             BuiltinOp::IncludeString => {
                 // include_string(m, code) or include_string(m, code, filename)
                 // Parse and evaluate all expressions in the code string.
@@ -1640,11 +1740,38 @@ impl CoreCompiler<'_> {
                 Ok(ValueType::Nothing)
             }
             BuiltinOp::TestSetEnd => {
-                // _testset_end!() - end test set and print summary
+                // _testset_end!() - end test set, print a summary, and return a
+                // structured (name, pass, fail, broken, errored, messages) result.
                 if !args.is_empty() {
                     return err("_testset_end! takes no arguments");
                 }
                 self.emit(Instr::CallBuiltin(BuiltinId::TestSetEnd, 0));
+                Ok(ValueType::NamedTuple)
+            }
+            BuiltinOp::TestSetSetFilter => {
+                // _testset_set_filter!(pattern) - restrict testsets/tests to a name/message pattern
+                if args.len() != 1 {
+                    return err(
+                        "_testset_set_filter! requires exactly 1 argument: _testset_set_filter!(pattern)",
+                    );
+                }
+                self.compile_expr(&args[0])?; // pattern: Regex or Str
+                self.emit(Instr::CallBuiltin(BuiltinId::TestSetSetFilter, 1));
+                Ok(ValueType::Nothing)
+            }
+            BuiltinOp::TestThrowsRecord => {
+                // _test_throws_record!(thrown_type, expected_type, msg) - record a
+                // @test_throws result, passing when the thrown exception's type
+                // matches the expected type (or type+message, per the msg passed in).
+                if args.len() != 3 {
+                    return err(
+                        "_test_throws_record! requires exactly 3 arguments: _test_throws_record!(thrown_type, expected_type, msg)",
+                    );
+                }
+                self.compile_expr(&args[0])?; // thrown_type: String or nothing
+                self.compile_expr(&args[1])?; // expected_type: String
+                self.compile_expr(&args[2])?; // msg: String
+                self.emit(Instr::CallBuiltin(BuiltinId::TestThrowsRecord, 3));
                 Ok(ValueType::Nothing)
             }
             BuiltinOp::IsDefined => {
@@ -66,6 +66,13 @@ impl CoreCompiler<'_> {
                         }
                     }
                 }
+                // Quad-precision path: dispatch to the software Float128 sqrt when the
+                // argument is already inferred as Float128.
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.emit(Instr::SqrtF128);
+                    return Ok(Some(ValueType::Float128));
+                }
                 // Fall back to builtin F64 sqrt
                 self.compile_expr_as(&args[0], ValueType::F64)?;
                 self.emit(Instr::SqrtF64);
@@ -98,6 +105,11 @@ impl CoreCompiler<'_> {
                         }
                     }
                 }
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.emit(Instr::FloorF128);
+                    return Ok(Some(ValueType::Float128));
+                }
                 // Check for user-defined floor method (e.g., floor(::Rational))
                 let arg_ty = self.infer_julia_type(&args[0]);
                 if matches!(arg_ty, JuliaType::Struct(_)) {
@@ -150,6 +162,11 @@ impl CoreCompiler<'_> {
                         }
                     }
                 }
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.emit(Instr::CeilF128);
+                    return Ok(Some(ValueType::Float128));
+                }
                 // Check for user-defined ceil method (e.g., ceil(::Rational))
                 let arg_ty = self.infer_julia_type(&args[0]);
                 if matches!(arg_ty, JuliaType::Struct(_)) {
@@ -202,6 +219,11 @@ impl CoreCompiler<'_> {
                         }
                     }
                 }
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.emit(Instr::CallBuiltin(BuiltinId::RoundF128, 1));
+                    return Ok(Some(ValueType::Float128));
+                }
                 self.compile_expr_as(&args[0], ValueType::F64)?;
                 self.emit(Instr::CallBuiltin(BuiltinId::Round, 1));
                 Ok(Some(ValueType::F64))
@@ -218,10 +240,67 @@ impl CoreCompiler<'_> {
                         }
                     }
                 }
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.emit(Instr::CallBuiltin(BuiltinId::TruncF128, 1));
+                    return Ok(Some(ValueType::Float128));
+                }
                 self.compile_expr_as(&args[0], ValueType::F64)?;
                 self.emit(Instr::CallBuiltin(BuiltinId::Trunc, 1));
                 Ok(Some(ValueType::F64))
             }
+            // Sign manipulation
+            "sign" => {
+                // sign(x) preserves the numeric type of its argument: 1/0/-1 for integers,
+                // +-1.0/0.0/NaN for floats. Branch on the inferred type like the `sleep` dispatch.
+                let arg_ty = self.infer_expr_type(&args[0]);
+                match arg_ty {
+                    ValueType::I64 => {
+                        self.compile_expr_as(&args[0], ValueType::I64)?;
+                        self.emit(Instr::CallBuiltin(BuiltinId::SignI64, 1));
+                        Ok(Some(ValueType::I64))
+                    }
+                    _ => {
+                        self.compile_expr_as(&args[0], ValueType::F64)?;
+                        self.emit(Instr::CallBuiltin(BuiltinId::SignF64, 1));
+                        Ok(Some(ValueType::F64))
+                    }
+                }
+            }
+            "signbit" => {
+                self.compile_expr_as(&args[0], ValueType::F64)?;
+                self.emit(Instr::CallBuiltin(BuiltinId::Signbit, 1));
+                Ok(Some(ValueType::Bool))
+            }
+            "copysign" => {
+                if args.len() != 2 {
+                    return err(format!("copysign requires 2 arguments, got {}", args.len()));
+                }
+                self.compile_expr_as(&args[0], ValueType::F64)?;
+                self.compile_expr_as(&args[1], ValueType::F64)?;
+                self.emit(Instr::CallBuiltin(BuiltinId::Copysign, 2));
+                Ok(Some(ValueType::F64))
+            }
+            "flipsign" => {
+                if args.len() != 2 {
+                    return err(format!("flipsign requires 2 arguments, got {}", args.len()));
+                }
+                let arg_ty = self.infer_expr_type(&args[0]);
+                match arg_ty {
+                    ValueType::I64 => {
+                        self.compile_expr_as(&args[0], ValueType::I64)?;
+                        self.compile_expr_as(&args[1], ValueType::I64)?;
+                        self.emit(Instr::CallBuiltin(BuiltinId::FlipsignI64, 2));
+                        Ok(Some(ValueType::I64))
+                    }
+                    _ => {
+                        self.compile_expr_as(&args[0], ValueType::F64)?;
+                        self.compile_expr_as(&args[1], ValueType::F64)?;
+                        self.emit(Instr::CallBuiltin(BuiltinId::FlipsignF64, 2));
+                        Ok(Some(ValueType::F64))
+                    }
+                }
+            }
             "nextfloat" => {
                 self.compile_expr_as(&args[0], ValueType::F64)?;
                 self.emit(Instr::CallBuiltin(BuiltinId::NextFloat, 1));
@@ -302,6 +381,21 @@ impl CoreCompiler<'_> {
                 self.emit(Instr::CallBuiltin(BuiltinId::Frexp, 1));
                 Ok(Some(ValueType::Tuple))
             }
+            "ldexp" | "scalbn" => {
+                // ldexp(m, e) = m * 2^e, the inverse of frexp. `scalbn` is the intrinsic alias.
+                if args.len() != 2 {
+                    return err(format!("{} requires 2 arguments, got {}", name, args.len()));
+                }
+                self.compile_expr_as(&args[0], ValueType::F64)?;
+                self.compile_expr_as(&args[1], ValueType::I64)?;
+                self.emit(Instr::CallBuiltin(BuiltinId::Ldexp, 2));
+                Ok(Some(ValueType::F64))
+            }
+            "ilogb" => {
+                self.compile_expr_as(&args[0], ValueType::F64)?;
+                self.emit(Instr::CallBuiltin(BuiltinId::Ilogb, 1));
+                Ok(Some(ValueType::I64))
+            }
             // Float inspection
             "issubnormal" => {
                 self.compile_expr_as(&args[0], ValueType::F64)?;
@@ -318,6 +412,13 @@ impl CoreCompiler<'_> {
                 if args.len() != 3 {
                     return err(format!("fma requires 3 arguments, got {}", args.len()));
                 }
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.compile_expr(&args[1])?;
+                    self.compile_expr(&args[2])?;
+                    self.emit(Instr::CallBuiltin(BuiltinId::FmaF128, 3));
+                    return Ok(Some(ValueType::Float128));
+                }
                 self.compile_expr_as(&args[0], ValueType::F64)?;
                 self.compile_expr_as(&args[1], ValueType::F64)?;
                 self.compile_expr_as(&args[2], ValueType::F64)?;
@@ -328,6 +429,13 @@ impl CoreCompiler<'_> {
                 if args.len() != 3 {
                     return err(format!("muladd requires 3 arguments, got {}", args.len()));
                 }
+                if matches!(self.infer_expr_type(&args[0]), ValueType::Float128) {
+                    self.compile_expr(&args[0])?;
+                    self.compile_expr(&args[1])?;
+                    self.compile_expr(&args[2])?;
+                    self.emit(Instr::CallBuiltin(BuiltinId::MuladdF128, 3));
+                    return Ok(Some(ValueType::Float128));
+                }
                 self.compile_expr_as(&args[0], ValueType::F64)?;
                 self.compile_expr_as(&args[1], ValueType::F64)?;
                 self.compile_expr_as(&args[2], ValueType::F64)?;
@@ -365,12 +473,51 @@ impl CoreCompiler<'_> {
         }
     }
 
-    /// Emit conversion instruction after a rounding operation to convert F64 to target type (Issue #2028).
+    /// Emit conversion instruction after a rounding operation to convert F64 to target type
+    /// (Issue #2028). Narrow integer targets go through the range-checked `CheckedToInt`
+    /// instruction so e.g. `round(Int8, 300.0)` raises `InexactError` instead of wrapping.
     fn emit_rounding_conversion(&mut self, target: &ValueType) {
         match target {
-            ValueType::I64 => {
-                self.emit(Instr::DynamicToI64);
-            }
+            ValueType::I8 => self.emit(Instr::CheckedToInt {
+                bits: 8,
+                signed: true,
+            }),
+            ValueType::I16 => self.emit(Instr::CheckedToInt {
+                bits: 16,
+                signed: true,
+            }),
+            ValueType::I32 => self.emit(Instr::CheckedToInt {
+                bits: 32,
+                signed: true,
+            }),
+            ValueType::I64 => self.emit(Instr::CheckedToInt {
+                bits: 64,
+                signed: true,
+            }),
+            ValueType::I128 => self.emit(Instr::CheckedToInt {
+                bits: 128,
+                signed: true,
+            }),
+            ValueType::U8 => self.emit(Instr::CheckedToInt {
+                bits: 8,
+                signed: false,
+            }),
+            ValueType::U16 => self.emit(Instr::CheckedToInt {
+                bits: 16,
+                signed: false,
+            }),
+            ValueType::U32 => self.emit(Instr::CheckedToInt {
+                bits: 32,
+                signed: false,
+            }),
+            ValueType::U64 => self.emit(Instr::CheckedToInt {
+                bits: 64,
+                signed: false,
+            }),
+            ValueType::U128 => self.emit(Instr::CheckedToInt {
+                bits: 128,
+                signed: false,
+            }),
             ValueType::F32 => {
                 self.emit(Instr::DynamicToF32);
             }
@@ -387,11 +534,21 @@ impl CoreCompiler<'_> {
 }
 
 /// Map a type name to the ValueType for rounding target type conversion (Issue #2028).
-/// Returns None if the name is not a recognized numeric type.
+/// Returns None if the name is not a recognized numeric type. Each concrete integer width
+/// is preserved (rather than collapsed to `I64`) so `emit_rounding_conversion` can
+/// range-check the narrowing cast and raise `InexactError` on overflow.
 pub(super) fn rounding_target_type(type_name: &str) -> Option<ValueType> {
     match type_name {
-        "Int" | "Int64" | "Int32" | "Int16" | "Int8" | "Int128" | "UInt64" | "UInt32"
-        | "UInt16" | "UInt8" | "UInt128" => Some(ValueType::I64),
+        "Int" | "Int64" => Some(ValueType::I64),
+        "Int8" => Some(ValueType::I8),
+        "Int16" => Some(ValueType::I16),
+        "Int32" => Some(ValueType::I32),
+        "Int128" => Some(ValueType::I128),
+        "UInt8" => Some(ValueType::U8),
+        "UInt16" => Some(ValueType::U16),
+        "UInt32" => Some(ValueType::U32),
+        "UInt64" => Some(ValueType::U64),
+        "UInt128" => Some(ValueType::U128),
         "Float64" => Some(ValueType::F64),
         "Float32" => Some(ValueType::F32),
         "Float16" => Some(ValueType::F16),
@@ -408,7 +565,12 @@ mod tests {
     fn test_rounding_target_type_integer_names() {
         assert!(matches!(rounding_target_type("Int64"), Some(ValueType::I64)));
         assert!(matches!(rounding_target_type("Int"), Some(ValueType::I64)));
-        assert!(matches!(rounding_target_type("UInt8"), Some(ValueType::I64)));
+        assert!(matches!(rounding_target_type("UInt8"), Some(ValueType::U8)));
+        assert!(matches!(rounding_target_type("Int8"), Some(ValueType::I8)));
+        assert!(matches!(
+            rounding_target_type("UInt128"),
+            Some(ValueType::U128)
+        ));
     }
 
     #[test]
@@ -167,6 +167,11 @@ impl CoreCompiler<'_> {
                 self.emit(Instr::CallBuiltin(BuiltinId::Float64, 1));
                 Ok(Some(ValueType::F64))
             }
+            "Float128" => {
+                self.compile_expr(&args[0])?;
+                self.emit(Instr::CallBuiltin(BuiltinId::Float128, 1));
+                Ok(Some(ValueType::Float128))
+            }
             // Module introspection (Julia 1.11+)
             "isexported" => {
                 // isexported(m::Module, s::Symbol) -> Bool
@@ -168,6 +168,7 @@ impl CoreCompiler<'_> {
                     Some(ValueType::Any) => JuliaType::Any,
                     Some(ValueType::BigInt) => JuliaType::BigInt,
                     Some(ValueType::BigFloat) => JuliaType::BigFloat,
+                    Some(ValueType::Float128) => JuliaType::Float128,
                     Some(ValueType::IO) => JuliaType::IO,
                     // New numeric types
                     Some(ValueType::I8) => JuliaType::Int8,
@@ -993,6 +994,7 @@ impl CoreCompiler<'_> {
             ValueType::Rng | ValueType::Any => JuliaType::Any,
             ValueType::BigInt => JuliaType::BigInt,
             ValueType::BigFloat => JuliaType::BigFloat,
+            ValueType::Float128 => JuliaType::Float128,
             ValueType::IO => JuliaType::IO,
             // New numeric types
             ValueType::I8 => JuliaType::Int8,
@@ -346,7 +346,7 @@ impl CoreCompiler<'_> {
                     BuiltinOp::Gensym => ValueType::Symbol,          // gensym() or gensym("base")
                     BuiltinOp::Esc => ValueType::Expr,               // esc(expr)
                     BuiltinOp::Eval => ValueType::Any, // eval(expr) - result type is dynamic
-                    BuiltinOp::MacroExpand | BuiltinOp::MacroExpandBang => ValueType::Any, // macroexpand returns any type
+                    BuiltinOp::MacroExpand | BuiltinOp::MacroExpandBang | BuiltinOp::MacroExpand1 => ValueType::Any, // macroexpand returns any type
                     BuiltinOp::IncludeString | BuiltinOp::EvalFile => ValueType::Any, // dynamic code evaluation
                     // Note: BuiltinOp::Zero is already handled above
                     BuiltinOp::IfElse => {
@@ -478,6 +478,7 @@ impl CoreCompiler<'_> {
                     "Float64" => ValueType::F64,
                     "BigInt" => ValueType::BigInt,
                     "BigFloat" => ValueType::BigFloat,
+                    "Float128" => ValueType::Float128,
                     // big() function - converts to BigInt or BigFloat depending on argument
                     "big" => {
                         if let Some(arg) = args.first() {
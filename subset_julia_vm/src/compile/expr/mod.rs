@@ -446,7 +446,94 @@ impl CoreCompiler<'_> {
                 splat_mask,
                 kwargs_splat_mask,
                 ..
-            } => self.compile_call(function, args, kwargs, splat_mask, kwargs_splat_mask),
+            } => {
+                // `EnumName(x)`: convert an integer to the enum, validated against the
+                // density-adapted membership check built when the `@enum` was declared.
+                if let Some(check) = self.shared_ctx.enum_checks.get(function).cloned() {
+                    if args.len() == 1 && kwargs.is_empty() {
+                        self.compile_expr_as(&args[0], ValueType::I64)?;
+                        self.emit(Instr::EnumConvert {
+                            type_name: function.clone(),
+                            check,
+                        });
+                        return Ok(ValueType::Enum);
+                    }
+                }
+                // `put!(x)`/`produce(x)`: suspend the current producer and hand
+                // `x` to whatever is driving execution via
+                // `Vm::run_resumable`/`Vm::resume` (Issue chunk421-4). Extra
+                // leading arguments (e.g. the channel in `put!(chan, x)`) are
+                // compiled for side effects and discarded; only the last
+                // argument is the yielded value.
+                if (function == "put!" || function == "produce") && kwargs.is_empty() {
+                    let Some((last, rest)) = args.split_last() else {
+                        return Err(CompileError::Msg(format!(
+                            "{} requires at least one argument",
+                            function
+                        )));
+                    };
+                    for arg in rest {
+                        self.compile_expr(arg)?;
+                        self.emit(Instr::Pop);
+                    }
+                    self.compile_expr(last)?;
+                    self.emit(Instr::Yield);
+                    self.emit(Instr::PushNothing);
+                    return Ok(ValueType::Any);
+                }
+                // `apply_iterate(iterate_fn, f, args...)`: the internal form
+                // behind the splat lowering below, with an explicit
+                // (overridable) iteration function instead of the compiler's
+                // own `iterate` dispatch, so instrumentation/interception
+                // contexts can substitute a different method and track
+                // recursion through iteration (Issue chunk422-1). Every
+                // argument after `iterate_fn`/`f` is spread via the
+                // iteration protocol - unlike plain splat lowering below,
+                // which only spreads `...`-marked arguments. Scope: both
+                // `iterate_fn` and `f` must be plain function-name
+                // references, and `f` must resolve to exactly one method.
+                if function == "apply_iterate" && kwargs.is_empty() {
+                    return self.compile_apply_iterate_builtin(args);
+                }
+                // `f(a, xs..., b)`: splat a single argument via the
+                // iteration protocol so a custom struct iterator (not just
+                // Array/Tuple/Range) splats correctly (Issue chunk422-1).
+                // Scope: only one argument may be splatted, and `function`
+                // must resolve to exactly one method (no dispatch
+                // ambiguity) - any other shape falls through to the general
+                // call path below exactly as before.
+                if kwargs.is_empty() && splat_mask.iter().filter(|&&s| s).count() == 1 {
+                    if let Some(splat_pos) = splat_mask.iter().position(|&s| s) {
+                        if let Some(table) = self.method_tables.get(function) {
+                            if table.methods.len() == 1 {
+                                let func_index = table.methods[0].global_index;
+                                let splat_ty = self.infer_julia_type(&args[splat_pos]);
+                                let (iterate_1, iterate_2) =
+                                    if self.should_use_pure_julia_iterate(&splat_ty) {
+                                        (
+                                            self.resolve_iterate_method_1(&splat_ty),
+                                            self.resolve_iterate_method_2(&splat_ty),
+                                        )
+                                    } else {
+                                        (None, None)
+                                    };
+                                for arg in args {
+                                    self.compile_expr(arg)?;
+                                }
+                                self.emit(Instr::ApplyIterate {
+                                    func_index,
+                                    arg_count: args.len(),
+                                    splat_mask: splat_mask.clone(),
+                                    iterate_1,
+                                    iterate_2,
+                                });
+                                return Ok(ValueType::Any);
+                            }
+                        }
+                    }
+                }
+                self.compile_call(function, args, kwargs, splat_mask, kwargs_splat_mask)
+            }
             Expr::Builtin { name, args, .. } => {
                 // Base functions are never implicitly shadowed.
                 // To extend Base functions, use Base.func(x::T) = ... syntax.
@@ -794,11 +881,16 @@ impl CoreCompiler<'_> {
                 // have their captured variables pre-analyzed during main block setup.
                 if let Some(captures) = self.shared_ctx.closure_captures.get(name) {
                     if !captures.is_empty() {
-                        // This is a closure - emit CreateClosure instead of PushFunction
+                        // This is a closure - emit CreateClosure instead of PushFunction.
+                        // Bare function-name references are always captured by value here
+                        // (no boxed/mutable captures): the mutation pre-pass that drives
+                        // boxing runs where the closure literal is compiled
+                        // (`Stmt::FunctionDef`), which this reference path bypasses.
                         let capture_names: Vec<String> = captures.iter().cloned().collect();
                         self.emit(Instr::CreateClosure {
                             func_name: name.clone(),
                             capture_names,
+                            boxed_capture_names: Vec::new(),
                         });
                         return Ok(ValueType::Any);
                     }
@@ -1099,11 +1191,23 @@ impl CoreCompiler<'_> {
     }
 
     pub(super) fn load_local(&mut self, name: &str) -> CResult<()> {
+        // Check if this is a captured variable shared by reference via a boxed
+        // cell, because the closure body reassigns it (Issue chunk421-1).
+        if self.boxed_captures.contains(name) {
+            self.emit(Instr::LoadCapturedBoxed(name.to_string()));
+            return Ok(());
+        }
         // Check if this is a captured variable from a closure's outer scope
         if self.captured_vars.contains(name) {
             self.emit(Instr::LoadCaptured(name.to_string()));
             return Ok(());
         }
+        // Check if this local has been promoted to a boxed cell in the current
+        // scope, because a closure defined here reassigns it (Issue chunk421-1).
+        if self.boxed_locals.contains(name) {
+            self.emit(Instr::LoadBoxed(name.to_string()));
+            return Ok(());
+        }
 
         // Resolve module constants to qualified names (both in module body and function context)
         // This matches store_local behavior which stores module constants with qualified names
@@ -1154,6 +1258,48 @@ impl CoreCompiler<'_> {
             ValueType::Tuple => Instr::LoadTuple(load_name.clone()),
             ValueType::NamedTuple => Instr::LoadNamedTuple(load_name.clone()),
             ValueType::Dict => Instr::LoadDict(load_name.clone()),
+            // Narrow integer/Bool types: mirror store_local's packed slot
+            // path (Issue chunk421-5); I128/U128 fall through to LoadAny.
+            ValueType::I8 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 8,
+                signed: true,
+            },
+            ValueType::I16 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 16,
+                signed: true,
+            },
+            ValueType::I32 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 32,
+                signed: true,
+            },
+            ValueType::U8 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 8,
+                signed: false,
+            },
+            ValueType::U16 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 16,
+                signed: false,
+            },
+            ValueType::U32 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 32,
+                signed: false,
+            },
+            ValueType::U64 => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 64,
+                signed: false,
+            },
+            ValueType::Bool => Instr::LoadNarrow {
+                name: load_name.clone(),
+                width: 1,
+                signed: false,
+            },
             // All other types use LoadAny
             _ => Instr::LoadAny(load_name),
         });
@@ -1161,6 +1307,22 @@ impl CoreCompiler<'_> {
     }
 
     pub(super) fn store_local(&mut self, name: &str, ty: ValueType) {
+        // Reassigning a captured variable shared by reference: write through the
+        // boxed cell in place rather than shadowing it with a new local
+        // (Issue chunk421-1).
+        if self.boxed_captures.contains(name) {
+            self.emit(Instr::StoreCapturedBoxed(name.to_string()));
+            return;
+        }
+        // Reassigning a local already promoted to a boxed cell in this scope:
+        // write through the cell so closures sharing it observe the update.
+        // The local's declared type stays Any (set at promotion time), since
+        // the box can hold values of any type (Issue chunk421-1).
+        if self.boxed_locals.contains(name) {
+            self.emit(Instr::StoreBoxed(name.to_string()));
+            return;
+        }
+
         // In module body context (not function), store constants with qualified names
         // so they can be accessed from module functions
         let (store_name, is_module_constant) = if !self.strict_undefined_check {
@@ -1214,6 +1376,50 @@ impl CoreCompiler<'_> {
                     ValueType::Tuple => Instr::StoreTuple(store_name.clone()),
                     ValueType::NamedTuple => Instr::StoreNamedTuple(store_name.clone()),
                     ValueType::Dict => Instr::StoreDict(store_name.clone()),
+                    // Narrow integer/Bool types get a packed, allocation-free
+                    // slot instead of boxing into StoreAny's dynamic Value map
+                    // (Issue chunk421-5). I128/U128 don't fit a u64 word, so
+                    // they keep using StoreAny/locals_narrow_int below.
+                    ValueType::I8 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 8,
+                        signed: true,
+                    },
+                    ValueType::I16 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 16,
+                        signed: true,
+                    },
+                    ValueType::I32 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 32,
+                        signed: true,
+                    },
+                    ValueType::U8 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 8,
+                        signed: false,
+                    },
+                    ValueType::U16 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 16,
+                        signed: false,
+                    },
+                    ValueType::U32 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 32,
+                        signed: false,
+                    },
+                    ValueType::U64 => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 64,
+                        signed: false,
+                    },
+                    ValueType::Bool => Instr::StoreNarrow {
+                        name: store_name.clone(),
+                        width: 1,
+                        signed: false,
+                    },
                     // All other types use StoreAny
                     _ => Instr::StoreAny(store_name),
                 };
@@ -1221,4 +1427,76 @@ impl CoreCompiler<'_> {
             }
         }
     }
+
+    /// Compile the `apply_iterate(iterate_fn, f, args...)` internal form
+    /// (Issue chunk422-1). See the call site in `Expr::Call` for the
+    /// rationale; this just does the name resolution and emits the same
+    /// `Instr::ApplyIterate` the `f(a, xs..., b)` splat lowering does, with
+    /// every argument treated as spread.
+    fn compile_apply_iterate_builtin(&mut self, args: &[Expr]) -> CResult<ValueType> {
+        if args.len() < 2 {
+            return Err(CompileError::Msg(
+                "apply_iterate requires at least (iterate_fn, f)".to_string(),
+            ));
+        }
+        let iterate_name = match &args[0] {
+            Expr::Var(name, _) => name.clone(),
+            _ => {
+                return Err(CompileError::Msg(
+                    "apply_iterate: iterate_fn must be a function name".to_string(),
+                ))
+            }
+        };
+        let target_name = match &args[1] {
+            Expr::Var(name, _) => name.clone(),
+            _ => {
+                return Err(CompileError::Msg(
+                    "apply_iterate: f must be a function name".to_string(),
+                ))
+            }
+        };
+        let iterate_table = self.method_tables.get(&iterate_name).ok_or_else(|| {
+            CompileError::Msg(format!("apply_iterate: unknown function '{}'", iterate_name))
+        })?;
+        let iterate_1 = iterate_table
+            .methods
+            .iter()
+            .find(|m| m.params.len() == 1)
+            .map(|m| m.global_index);
+        if iterate_1.is_none() {
+            return Err(CompileError::Msg(format!(
+                "apply_iterate: '{}' has no 1-argument method",
+                iterate_name
+            )));
+        }
+        let iterate_2 = iterate_table
+            .methods
+            .iter()
+            .find(|m| m.params.len() == 2)
+            .map(|m| m.global_index);
+
+        let target_table = self.method_tables.get(&target_name).ok_or_else(|| {
+            CompileError::Msg(format!("apply_iterate: unknown function '{}'", target_name))
+        })?;
+        if target_table.methods.len() != 1 {
+            return Err(CompileError::Msg(format!(
+                "apply_iterate: '{}' must have exactly one method",
+                target_name
+            )));
+        }
+        let func_index = target_table.methods[0].global_index;
+
+        let call_args = &args[2..];
+        for arg in call_args {
+            self.compile_expr(arg)?;
+        }
+        self.emit(Instr::ApplyIterate {
+            func_index,
+            arg_count: call_args.len(),
+            splat_mask: vec![true; call_args.len()],
+            iterate_1,
+            iterate_2,
+        });
+        Ok(ValueType::Any)
+    }
 }
@@ -1,11 +1,12 @@
 //! Struct compilation (constructors and field access).
 
 use crate::ir::core::Expr;
+use crate::types::JuliaType;
 use crate::vm::{Instr, ValueType};
 
 use super::super::{
     err, extract_module_path_from_expr, get_base_exported_constant_value, get_math_constant_value,
-    is_stdlib_module, CResult, CoreCompiler, StructInfo,
+    is_stdlib_module, julia_type_to_value_type, CResult, CoreCompiler, StructInfo,
 };
 
 const EXPR_FIELD_HEAD_INDEX: usize = 0;
@@ -134,6 +135,26 @@ impl CoreCompiler<'_> {
                     }
                 }
 
+                // User-defined getproperty(::T, ::Symbol) overrides take priority over
+                // direct field access, mirroring how operator overloads are dispatched
+                // at compile time in compile/expr/binary/mod.rs.
+                if !struct_name.is_empty() {
+                    if let Some(table) = self.method_tables.get("getproperty") {
+                        let arg_types =
+                            vec![JuliaType::Struct(struct_name.clone()), JuliaType::Symbol];
+                        if let Ok(method) = table.dispatch(&arg_types) {
+                            self.emit(Instr::PushSymbol(field.to_string()));
+                            self.emit(Instr::Call(method.global_index, 2));
+                            let ret_ty = method
+                                .return_julia_type
+                                .as_ref()
+                                .map(julia_type_to_value_type)
+                                .unwrap_or(ValueType::Any);
+                            return Ok(ret_ty);
+                        }
+                    }
+                }
+
                 match result {
                     Some((idx, field_ty)) => {
                         self.emit(Instr::GetField(idx));
@@ -76,7 +76,16 @@ fn analyze_stmt_free_vars(
             // Then mark var as local (simple assignments create local bindings)
             local_vars.insert(var.clone());
         }
-        Stmt::AddAssign { var, value, .. } => {
+        Stmt::AddAssign { var, value, .. }
+        | Stmt::SubAssign { var, value, .. }
+        | Stmt::MulAssign { var, value, .. }
+        | Stmt::DivAssign { var, value, .. }
+        | Stmt::FldAssign { var, value, .. }
+        | Stmt::PowAssign { var, value, .. }
+        | Stmt::BitAndAssign { var, value, .. }
+        | Stmt::BitOrAssign { var, value, .. }
+        | Stmt::BitXorAssign { var, value, .. }
+        | Stmt::BroadcastAssign { var, value, .. } => {
             // var must already exist - check if it's from outer scope
             if !local_vars.contains(var) && outer_scope_vars.contains(var) {
                 free_vars.insert(var.clone());
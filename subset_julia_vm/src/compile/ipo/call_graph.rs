@@ -193,7 +193,17 @@ fn extract_called_functions(block: &Block) -> HashSet<String> {
 /// Recursively extract function calls from a statement.
 fn extract_calls_from_stmt(stmt: &Stmt, called: &mut HashSet<String>) {
     match stmt {
-        Stmt::Assign { value, .. } | Stmt::AddAssign { value, .. } => {
+        Stmt::Assign { value, .. }
+        | Stmt::AddAssign { value, .. }
+        | Stmt::SubAssign { value, .. }
+        | Stmt::MulAssign { value, .. }
+        | Stmt::DivAssign { value, .. }
+        | Stmt::FldAssign { value, .. }
+        | Stmt::PowAssign { value, .. }
+        | Stmt::BitAndAssign { value, .. }
+        | Stmt::BitOrAssign { value, .. }
+        | Stmt::BitXorAssign { value, .. }
+        | Stmt::BroadcastAssign { value, .. } => {
             extract_calls_from_expr(value, called);
         }
         Stmt::Return { value, .. } => {
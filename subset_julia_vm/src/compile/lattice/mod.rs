@@ -15,6 +15,8 @@
 //! - `widening`: Constants controlling type widening behavior
 
 pub mod ops;
+#[cfg(test)]
+mod proptests;
 pub mod types;
 pub mod widening;
 
@@ -9,7 +9,9 @@
 //! These operations follow Julia's type lattice semantics.
 
 use super::types::{ConcreteType, LatticeType};
-use super::widening::{MAX_UNION_COMPLEXITY, MAX_UNION_LENGTH};
+#[cfg(test)]
+use super::types::ConstValue;
+use super::widening::{MAX_TUPLE_DEPTH, MAX_TUPLE_LEN, MAX_UNION_COMPLEXITY, MAX_UNION_LENGTH};
 use crate::compile::diagnostics::{emit_conditional_join, emit_union_widened, DiagnosticReason};
 use std::collections::BTreeSet;
 
@@ -30,7 +32,7 @@ impl LatticeType {
     /// T.join(Top) = Top
     /// ```
     pub fn join(&self, other: &LatticeType) -> LatticeType {
-        match (self, other) {
+        let result = match (self, other) {
             // Bottom is the identity element for join
             (LatticeType::Bottom, t) | (t, LatticeType::Bottom) => t.clone(),
 
@@ -74,13 +76,18 @@ impl LatticeType {
                 LatticeType::Concrete(a.clone())
             }
 
-            // Different concrete types → Union
-            (LatticeType::Concrete(a), LatticeType::Concrete(b)) => {
-                let mut set = BTreeSet::new();
-                set.insert(a.clone());
-                set.insert(b.clone());
-                Self::simplify_union(set)
-            }
+            // Different concrete types: collapse to their closest common
+            // abstract supertype (e.g. Int64/Int32 → Integer) when the
+            // numeric hierarchy relates them, otherwise form a Union.
+            (LatticeType::Concrete(a), LatticeType::Concrete(b)) => match a.common_supertype(b) {
+                Some(common) => LatticeType::Concrete(common),
+                None => {
+                    let mut set = BTreeSet::new();
+                    set.insert(a.clone());
+                    set.insert(b.clone());
+                    Self::simplify_union(set)
+                }
+            },
 
             // Union + Concrete
             (LatticeType::Union(us), LatticeType::Concrete(c))
@@ -96,12 +103,63 @@ impl LatticeType {
                 Self::simplify_union(combined)
             }
 
+            // Idempotence: a type is always its own join, even when neither
+            // side is otherwise handled above (e.g. two identical
+            // Conditionals) — checked before the conservative Conditional
+            // fallback below so it doesn't spuriously widen to Top.
+            (a, b) if a == b => a.clone(),
+
             // Conditional types are conservatively handled as Top
             (LatticeType::Conditional { .. }, _) | (_, LatticeType::Conditional { .. }) => {
                 emit_conditional_join();
                 LatticeType::Top
             }
+        };
+        result.normalize()
+    }
+
+    /// Join (⊔) a sequence of types in a single pass, instead of folding
+    /// pairwise with [`Self::join`], which would re-run `simplify_union`
+    /// after merging in every single element. Used to merge N predecessor
+    /// types at a control-flow join point (phi nodes, block entries).
+    ///
+    /// Short-circuits to `Top` as soon as an absorbing element (`Top`, or a
+    /// `Conditional`, which `join` also treats as `Top`) is seen. An empty
+    /// iterator returns `Bottom`, the identity element for join.
+    pub fn join_all<I: IntoIterator<Item = LatticeType>>(iter: I) -> LatticeType {
+        let mut members = Vec::new();
+        for item in iter {
+            match item {
+                LatticeType::Top => return LatticeType::Top,
+                LatticeType::Bottom => {}
+                LatticeType::Conditional { .. } => {
+                    emit_conditional_join();
+                    return LatticeType::Top;
+                }
+                other => members.push(other),
+            }
+        }
+
+        if members.len() <= 1 {
+            return members.pop().unwrap_or(LatticeType::Bottom);
+        }
+
+        let mut set = BTreeSet::new();
+        for member in members {
+            match member {
+                LatticeType::Const(cv) => {
+                    set.insert(cv.to_concrete_type());
+                }
+                LatticeType::Concrete(c) => {
+                    set.insert(c);
+                }
+                LatticeType::Union(us) => set.extend(us),
+                LatticeType::Top | LatticeType::Bottom | LatticeType::Conditional { .. } => {
+                    unreachable!("Top/Bottom/Conditional were filtered out above")
+                }
+            }
         }
+        Self::simplify_union(set)
     }
 
     /// Meet operation (⊓): compute the greatest lower bound of two types.
@@ -120,7 +178,7 @@ impl LatticeType {
     /// Top.meet(T) = T
     /// ```
     pub fn meet(&self, other: &LatticeType) -> LatticeType {
-        match (self, other) {
+        let result = match (self, other) {
             // Top is the identity element for meet
             (LatticeType::Top, t) | (t, LatticeType::Top) => t.clone(),
 
@@ -145,14 +203,18 @@ impl LatticeType {
                 }
             }
 
-            // Same concrete type
-            (LatticeType::Concrete(a), LatticeType::Concrete(b)) if a == b => {
-                LatticeType::Concrete(a.clone())
+            // One is an abstract supertype of the other (e.g. Int64/Integer)
+            // → the more specific type; unrelated concrete types → Bottom.
+            (LatticeType::Concrete(a), LatticeType::Concrete(b)) => {
+                if a.is_subtype_of(b) {
+                    LatticeType::Concrete(a.clone())
+                } else if b.is_subtype_of(a) {
+                    LatticeType::Concrete(b.clone())
+                } else {
+                    LatticeType::Bottom
+                }
             }
 
-            // Different concrete types → Bottom (empty intersection)
-            (LatticeType::Concrete(_), LatticeType::Concrete(_)) => LatticeType::Bottom,
-
             // Union and Concrete intersection
             (LatticeType::Union(us), LatticeType::Concrete(c))
             | (LatticeType::Concrete(c), LatticeType::Union(us)) => {
@@ -179,9 +241,46 @@ impl LatticeType {
                 }
             }
 
+            // Idempotence: a type is always its own meet (e.g. two
+            // identical Conditionals), checked before the conservative
+            // Conditional fallback below so it doesn't spuriously narrow
+            // to Bottom.
+            (a, b) if a == b => a.clone(),
+
             // Conditional types are conservatively handled
             _ => LatticeType::Bottom,
+        };
+        result.normalize()
+    }
+
+    /// Meet (⊓) a sequence of types in a single pass. Short-circuits to
+    /// `Bottom`, the absorbing element, as soon as one is seen; an empty
+    /// iterator returns `Top`, the identity element for meet.
+    ///
+    /// Unlike [`Self::join_all`], this doesn't collect into a `BTreeSet`
+    /// and go through `simplify_union`: meet only ever narrows types, so
+    /// there's no union-size widening to amortize across N elements, and
+    /// folding pairwise via [`Self::meet`] is already O(n).
+    pub fn meet_all<I: IntoIterator<Item = LatticeType>>(iter: I) -> LatticeType {
+        let mut members = iter.into_iter();
+        let Some(first) = members.next() else {
+            return LatticeType::Top;
+        };
+        if matches!(first, LatticeType::Bottom) {
+            return LatticeType::Bottom;
+        }
+
+        let mut acc = first;
+        for next in members {
+            if matches!(next, LatticeType::Bottom) {
+                return LatticeType::Bottom;
+            }
+            acc = acc.meet(&next);
+            if matches!(acc, LatticeType::Bottom) {
+                return LatticeType::Bottom;
+            }
         }
+        acc
     }
 
     /// Subtype relation (⊑): check if self is a subtype of other.
@@ -206,11 +305,15 @@ impl LatticeType {
             // Top is not a subtype of anything except itself
             (LatticeType::Top, _) => false,
 
-            // Concrete types must be equal
-            (LatticeType::Concrete(a), LatticeType::Concrete(b)) => a == b,
+            // Concrete types: equal, or related through the abstract
+            // numeric hierarchy (Int64 ⊑ Integer ⊑ Number).
+            (LatticeType::Concrete(a), LatticeType::Concrete(b)) => a.is_subtype_of(b),
 
-            // Concrete is a subtype of Union if it's an element
-            (LatticeType::Concrete(c), LatticeType::Union(us)) => us.contains(c),
+            // Concrete is a subtype of Union if it's an element, or a
+            // subtype of one (Int64 ⊑ Union{Integer, String}).
+            (LatticeType::Concrete(c), LatticeType::Union(us)) => {
+                us.iter().any(|u| c.is_subtype_of(u))
+            }
 
             // Union is a subtype of Union if all elements are contained
             (LatticeType::Union(a), LatticeType::Union(b)) => a.is_subset(b),
@@ -218,6 +321,11 @@ impl LatticeType {
             // Union is never a subtype of a single Concrete
             (LatticeType::Union(_), LatticeType::Concrete(_)) => false,
 
+            // Reflexivity: a type is always a subtype of itself (e.g. two
+            // identical Conditionals), checked before the conservative
+            // Conditional fallback below.
+            (a, b) if a == b => true,
+
             // Conservative handling for Conditional
             _ => false,
         }
@@ -236,7 +344,7 @@ impl LatticeType {
     /// Int64.subtract(Float64) = Int64
     /// ```
     pub fn subtract(&self, other: &LatticeType) -> LatticeType {
-        match (self, other) {
+        let result = match (self, other) {
             // Subtracting from Bottom or Top
             (LatticeType::Bottom, _) => LatticeType::Bottom,
             (LatticeType::Top, _) => LatticeType::Top, // Conservative
@@ -245,9 +353,13 @@ impl LatticeType {
             (t, LatticeType::Bottom) => t.clone(),
             (_, LatticeType::Top) => LatticeType::Bottom, // Everything is removed
 
-            // Concrete - Concrete
+            // Concrete - Concrete: nothing is left once `a` is fully
+            // covered by `b` (equal, or `b` an abstract supertype of `a`,
+            // e.g. Int64 - Integer). Otherwise `a` is unaffected — there's
+            // no complement type to express "Integer but not Int64" in
+            // this lattice, so the conservative answer is to keep `a`.
             (LatticeType::Concrete(a), LatticeType::Concrete(b)) => {
-                if a == b {
+                if a.is_subtype_of(b) {
                     LatticeType::Bottom
                 } else {
                     LatticeType::Concrete(a.clone())
@@ -256,7 +368,7 @@ impl LatticeType {
 
             // Concrete - Union
             (LatticeType::Concrete(c), LatticeType::Union(us)) => {
-                if us.contains(c) {
+                if us.iter().any(|u| c.is_subtype_of(u)) {
                     LatticeType::Bottom
                 } else {
                     LatticeType::Concrete(c.clone())
@@ -265,30 +377,208 @@ impl LatticeType {
 
             // Union - Concrete
             (LatticeType::Union(us), LatticeType::Concrete(c)) => {
-                let remaining: BTreeSet<_> = us.iter().filter(|t| *t != c).cloned().collect();
+                let remaining: BTreeSet<_> =
+                    us.iter().filter(|t| !t.is_subtype_of(c)).cloned().collect();
                 Self::simplify_union(remaining)
             }
 
             // Union - Union
             (LatticeType::Union(a), LatticeType::Union(b)) => {
-                let remaining: BTreeSet<_> = a.difference(b).cloned().collect();
+                let remaining: BTreeSet<_> = a
+                    .iter()
+                    .filter(|t| !b.iter().any(|u| t.is_subtype_of(u)))
+                    .cloned()
+                    .collect();
                 Self::simplify_union(remaining)
             }
 
+            // Subtracting a type from an identical copy of itself always
+            // leaves nothing, even for combinations (like two identical
+            // Conditionals) the conservative fallback below doesn't
+            // otherwise understand how to narrow.
+            (a, b) if a == b => LatticeType::Bottom,
+
             // Conservative for Conditional
             _ => self.clone(),
+        };
+        result.normalize()
+    }
+
+    /// Project out the lattice type of the `index`-th field of a tuple or
+    /// named-tuple-like composite, 0-indexed to match `Vec` storage.
+    ///
+    /// For a `Union` of composites, returns the join over every member's
+    /// element at `index` — but `Bottom` if any member doesn't have that
+    /// many fields, since there's no value that could flow through such a
+    /// branch. Anything else that isn't indexable (scalars, `Array`, `Top`,
+    /// `Conditional`, ...) is `Bottom`.
+    pub fn element_at(&self, index: usize) -> LatticeType {
+        match self {
+            LatticeType::Concrete(c) => Self::concrete_element_at(c, index),
+            LatticeType::Union(us) => {
+                let mut elements = Vec::with_capacity(us.len());
+                for c in us {
+                    match Self::concrete_element_at(c, index) {
+                        LatticeType::Bottom => return LatticeType::Bottom,
+                        elem => elements.push(elem),
+                    }
+                }
+                Self::join_all(elements)
+            }
+            _ => LatticeType::Bottom,
+        }
+    }
+
+    fn concrete_element_at(c: &ConcreteType, index: usize) -> LatticeType {
+        match c {
+            ConcreteType::Tuple { elements } => elements
+                .get(index)
+                .cloned()
+                .map(LatticeType::Concrete)
+                .unwrap_or(LatticeType::Bottom),
+            ConcreteType::NamedTuple { fields } => fields
+                .get(index)
+                .map(|(_, ty)| LatticeType::Concrete(ty.clone())),
+            _ => None,
+        }
+        .unwrap_or(LatticeType::Bottom)
+    }
+
+    /// Return a refined copy of `self` with the `index`-th tuple/named-tuple
+    /// field narrowed to `new.meet(&old_field)`, so a store or `setindex!`
+    /// can only narrow the field's type, never widen it past what's already
+    /// known. Types this can't project into (see [`Self::element_at`])
+    /// are returned unchanged.
+    pub fn with_element(&self, index: usize, new: &LatticeType) -> LatticeType {
+        match self {
+            LatticeType::Concrete(ConcreteType::Tuple { elements }) => {
+                let Some(old) = elements.get(index) else {
+                    return self.clone();
+                };
+                let narrowed = new.meet(&LatticeType::Concrete(old.clone()));
+                let mut elements = elements.clone();
+                match narrowed {
+                    LatticeType::Concrete(c) => elements[index] = c,
+                    LatticeType::Bottom => return LatticeType::Bottom,
+                    _ => return self.clone(),
+                }
+                LatticeType::Concrete(ConcreteType::Tuple { elements })
+            }
+            LatticeType::Concrete(ConcreteType::NamedTuple { fields }) => {
+                let Some((_, old)) = fields.get(index) else {
+                    return self.clone();
+                };
+                let narrowed = new.meet(&LatticeType::Concrete(old.clone()));
+                let mut fields = fields.clone();
+                match narrowed {
+                    LatticeType::Concrete(c) => fields[index].1 = c,
+                    LatticeType::Bottom => return LatticeType::Bottom,
+                    _ => return self.clone(),
+                }
+                LatticeType::Concrete(ConcreteType::NamedTuple { fields })
+            }
+            _ => self.clone(),
+        }
+    }
+
+    /// Lattice type of an `Array`/`Range`/`Set`/`Generator`'s element, or
+    /// `Bottom` if `self` isn't one of those container types.
+    pub fn element_type(&self) -> LatticeType {
+        match self {
+            LatticeType::Concrete(
+                ConcreteType::Array { element }
+                | ConcreteType::Range { element }
+                | ConcreteType::Set { element }
+                | ConcreteType::Generator { element },
+            ) => LatticeType::Concrete((**element).clone()),
+            _ => LatticeType::Bottom,
+        }
+    }
+
+    /// Lattice type of a `Dict`'s key type, or `Bottom` if `self` isn't a
+    /// `Dict`.
+    pub fn key_type(&self) -> LatticeType {
+        match self {
+            LatticeType::Concrete(ConcreteType::Dict { key, .. }) => {
+                LatticeType::Concrete((**key).clone())
+            }
+            _ => LatticeType::Bottom,
+        }
+    }
+
+    /// Lattice type of a `Dict`'s value type, or `Bottom` if `self` isn't a
+    /// `Dict`.
+    pub fn value_type(&self) -> LatticeType {
+        match self {
+            LatticeType::Concrete(ConcreteType::Dict { value, .. }) => {
+                LatticeType::Concrete((**value).clone())
+            }
+            _ => LatticeType::Bottom,
+        }
+    }
+
+    /// Put a lattice value into canonical form, so that two values built
+    /// through different paths but denoting the same set of runtime values
+    /// compare equal (`Union{Int64}` and `Concrete(Int64)` are the same
+    /// type, and fixed-point detection during inference relies on `==`
+    /// actually seeing that).
+    ///
+    /// Rules:
+    /// - An empty `Union` becomes `Bottom`.
+    /// - A `Union` containing `Any` becomes `Top`, since `Any` already
+    ///   makes every other member redundant.
+    /// - A single-element `Union` becomes `Concrete`, and a `Union` with a
+    ///   member implied by a broader sibling (e.g. `Int64` alongside
+    ///   `Integer`) has that member dropped first — both via
+    ///   [`Self::simplify_union`], which `join`/`meet`'s own Union-producing
+    ///   arms already route through.
+    /// - `Conditional` branches are normalized recursively.
+    /// - Everything else (`Bottom`, `Top`, `Const`, `Concrete`) is already
+    ///   canonical.
+    ///
+    /// Idempotent: `t.normalize().normalize() == t.normalize()`. `join`,
+    /// `meet`, and `subtract` all funnel their result through this before
+    /// returning, so callers never need to normalize by hand.
+    pub fn normalize(self) -> LatticeType {
+        match self {
+            LatticeType::Union(types) => {
+                if types.contains(&ConcreteType::Any) {
+                    LatticeType::Top
+                } else {
+                    Self::simplify_union(types)
+                }
+            }
+            LatticeType::Conditional {
+                slot,
+                then_type,
+                else_type,
+            } => LatticeType::Conditional {
+                slot,
+                then_type: Box::new(then_type.normalize()),
+                else_type: Box::new(else_type.normalize()),
+            },
+            other => other,
         }
     }
 
     /// Simplify a Union type, applying widening if necessary.
     ///
     /// Rules:
+    /// - Contains `Any` → Top
     /// - Empty set → Bottom
     /// - Single element → Concrete
-    /// - Too many elements (> MAX_UNION_LENGTH) → widen
-    /// - Too complex (> MAX_UNION_COMPLEXITY) → widen
+    /// - Too many elements (> MAX_UNION_LENGTH) → limit
+    /// - Too complex (> MAX_UNION_COMPLEXITY) → limit
     /// - Otherwise → Union
     fn simplify_union(types: BTreeSet<ConcreteType>) -> LatticeType {
+        let types = Self::drop_redundant_subtypes(types);
+
+        // `Any` covers every other member, so such a union is `Top`, the same
+        // canonical form `normalize` gives a `Union` containing `Any`.
+        if types.contains(&ConcreteType::Any) {
+            return LatticeType::Top;
+        }
+
         if types.is_empty() {
             return LatticeType::Bottom;
         }
@@ -303,19 +593,251 @@ impl LatticeType {
         // Check if widening is needed based on length
         if types.len() > MAX_UNION_LENGTH {
             emit_union_widened(DiagnosticReason::UnionTooLarge(types.len()));
-            return Self::widen_union(&types);
+            return Self::limit_union_size(&types);
         }
 
         // Check complexity (maximum depth of nested types)
         let complexity = Self::compute_complexity(&types);
         if complexity > MAX_UNION_COMPLEXITY {
             emit_union_widened(DiagnosticReason::UnionTooComplex(complexity));
-            return Self::widen_union(&types);
+            return Self::limit_union_size(&types);
         }
 
         LatticeType::Union(types)
     }
 
+    /// Limit every member of an over-sized or over-complex union via
+    /// [`LatticeType::limit_type_size`], using the union itself as the
+    /// `compare` reference so widening preserves as much tuple/array/dict
+    /// structure as the depth budget allows, instead of discarding it the
+    /// way a pure length/depth cutoff would. If limiting still leaves the
+    /// union over `MAX_UNION_LENGTH` (there is no nested structure left to
+    /// trade off against — e.g. a flat union of many scalar types), falls
+    /// back to [`Self::widen_union`].
+    fn limit_union_size(types: &BTreeSet<ConcreteType>) -> LatticeType {
+        let compare = LatticeType::Union(types.clone());
+        let source: Vec<ConcreteType> = types.iter().cloned().collect();
+
+        let mut limited = BTreeSet::new();
+        for t in types {
+            match LatticeType::Concrete(t.clone()).limit_type_size(
+                &compare,
+                &source,
+                MAX_TUPLE_DEPTH,
+                MAX_TUPLE_LEN,
+            ) {
+                LatticeType::Concrete(c) => {
+                    limited.insert(c);
+                }
+                _ => return LatticeType::Top,
+            }
+        }
+
+        let limited = Self::drop_redundant_subtypes(limited);
+        if limited.len() > MAX_UNION_LENGTH {
+            return Self::widen_union(&limited);
+        }
+
+        match limited.len() {
+            0 => LatticeType::Bottom,
+            1 => LatticeType::Concrete(limited.into_iter().next().unwrap()),
+            _ => LatticeType::Union(limited),
+        }
+    }
+
+    /// Limit `self` to be no more complex than `compare`, the reference
+    /// type observed at the previous iteration of abstract interpretation.
+    /// This is what prevents infinite growth in fixed-point inference
+    /// without flattening everything to `Top`: a subcomponent nested
+    /// deeper than `compare` allows is replaced either by the matching
+    /// component of `compare`, or by a type already present in `source` if
+    /// one already covers it — never by a brand-new type.
+    ///
+    /// Maintains the invariant `self ⊑ result`: if the rebuilt type fails
+    /// that check, the limiter is re-run against `Top` as the reference,
+    /// and `Top` itself is the final escape hatch.
+    pub(crate) fn limit_type_size(
+        &self,
+        compare: &LatticeType,
+        source: &[ConcreteType],
+        allowed_tupledepth: usize,
+        allowed_tuplelen: usize,
+    ) -> LatticeType {
+        let LatticeType::Concrete(t) = self else {
+            return self.clone();
+        };
+
+        if !Self::type_more_complex(t, compare, allowed_tupledepth, allowed_tuplelen) {
+            return self.clone();
+        }
+
+        let rebuilt = LatticeType::Concrete(Self::rebuild_component(
+            t,
+            compare,
+            source,
+            allowed_tupledepth,
+            allowed_tuplelen,
+        ));
+        if self.is_subtype_of(&rebuilt) {
+            return rebuilt;
+        }
+
+        let rebuilt_top = LatticeType::Concrete(Self::rebuild_component(
+            t,
+            &LatticeType::Top,
+            source,
+            allowed_tupledepth,
+            allowed_tuplelen,
+        ));
+        if self.is_subtype_of(&rebuilt_top) {
+            return rebuilt_top;
+        }
+
+        LatticeType::Top
+    }
+
+    /// True when `t` nests deeper than `compare` beyond `allowed_tupledepth`,
+    /// or `t` is an outer tuple longer than `allowed_tuplelen`.
+    fn type_more_complex(
+        t: &ConcreteType,
+        compare: &LatticeType,
+        allowed_tupledepth: usize,
+        allowed_tuplelen: usize,
+    ) -> bool {
+        if let ConcreteType::Tuple { elements } = t {
+            if elements.len() > allowed_tuplelen {
+                return true;
+            }
+        }
+
+        match Self::reference_concrete(compare) {
+            Some(reference) => {
+                Self::type_depth(t) > Self::type_depth(reference) + allowed_tupledepth
+            }
+            // Nothing structural to compare against (Top/Bottom/Const/
+            // Conditional compare) — only the outer-tuple-length check above
+            // applies.
+            None => false,
+        }
+    }
+
+    /// Pick a single concrete type out of `compare` to compare structure
+    /// against: the type itself if `compare` is already Concrete, or the
+    /// deepest member if it's a Union (the most permissive choice, since
+    /// recursing against a shallower sibling would over-limit).
+    fn reference_concrete(compare: &LatticeType) -> Option<&ConcreteType> {
+        match compare {
+            LatticeType::Concrete(c) => Some(c),
+            LatticeType::Union(us) => us.iter().max_by_key(|t| Self::type_depth(t)),
+            _ => None,
+        }
+    }
+
+    /// Recursively rebuild `t`, replacing any subcomponent that is too
+    /// complex relative to `compare` with the matching component of
+    /// `compare`, or with a type from `source` that already covers it.
+    fn rebuild_component(
+        t: &ConcreteType,
+        compare: &LatticeType,
+        source: &[ConcreteType],
+        allowed_tupledepth: usize,
+        allowed_tuplelen: usize,
+    ) -> ConcreteType {
+        if !Self::type_more_complex(t, compare, allowed_tupledepth, allowed_tuplelen) {
+            return t.clone();
+        }
+
+        if let Some(existing) = source.iter().find(|s| {
+            *s != t && LatticeType::Concrete(t.clone()).is_subtype_of(&LatticeType::Concrete((*s).clone()))
+        }) {
+            return existing.clone();
+        }
+
+        // An outer tuple that is itself too long can't be fixed by
+        // recursing into its elements — replace it wholesale.
+        if let ConcreteType::Tuple { elements } = t {
+            if elements.len() > allowed_tuplelen {
+                return Self::reference_concrete(compare)
+                    .cloned()
+                    .unwrap_or(ConcreteType::Any);
+            }
+        }
+
+        match (t, Self::reference_concrete(compare)) {
+            (ConcreteType::Tuple { elements }, Some(ConcreteType::Tuple { elements: refs })) => {
+                ConcreteType::Tuple {
+                    elements: elements
+                        .iter()
+                        .enumerate()
+                        .map(|(i, el)| {
+                            let el_compare = refs
+                                .get(i)
+                                .map(|r| LatticeType::Concrete(r.clone()))
+                                .unwrap_or_else(|| compare.clone());
+                            Self::rebuild_component(
+                                el,
+                                &el_compare,
+                                source,
+                                allowed_tupledepth,
+                                allowed_tuplelen,
+                            )
+                        })
+                        .collect(),
+                }
+            }
+            (ConcreteType::Array { element }, Some(ConcreteType::Array { element: reference })) => {
+                ConcreteType::Array {
+                    element: Box::new(Self::rebuild_component(
+                        element,
+                        &LatticeType::Concrete((**reference).clone()),
+                        source,
+                        allowed_tupledepth,
+                        allowed_tuplelen,
+                    )),
+                }
+            }
+            (
+                ConcreteType::Dict { key, value },
+                Some(ConcreteType::Dict {
+                    key: ref_key,
+                    value: ref_value,
+                }),
+            ) => ConcreteType::Dict {
+                key: Box::new(Self::rebuild_component(
+                    key,
+                    &LatticeType::Concrete((**ref_key).clone()),
+                    source,
+                    allowed_tupledepth,
+                    allowed_tuplelen,
+                )),
+                value: Box::new(Self::rebuild_component(
+                    value,
+                    &LatticeType::Concrete((**ref_value).clone()),
+                    source,
+                    allowed_tupledepth,
+                    allowed_tuplelen,
+                )),
+            },
+            // No structurally-compatible component in `compare` — fall back
+            // to whatever `compare` offers as a whole. The caller verifies
+            // `self ⊑ result` and falls back further (eventually to `Top`)
+            // if this isn't actually a valid widening.
+            (_, Some(reference)) => reference.clone(),
+            (_, None) => ConcreteType::Any,
+        }
+    }
+
+    /// Drop any element that is already a subtype of another element in the
+    /// set (e.g. `Union{Int64, Number}` simplifies to `{Number}`), so the
+    /// abstract numeric hierarchy is reflected in how unions are stored.
+    fn drop_redundant_subtypes(types: BTreeSet<ConcreteType>) -> BTreeSet<ConcreteType> {
+        types
+            .iter()
+            .filter(|t| !types.iter().any(|u| *t != u && t.is_subtype_of(u)))
+            .cloned()
+            .collect()
+    }
+
     /// Widen a Union type to prevent infinite growth.
     ///
     /// Strategy:
@@ -465,6 +987,86 @@ mod tests {
         assert_eq!(top.join(&int), LatticeType::Top);
     }
 
+    #[test]
+    fn test_join_all_empty_is_bottom() {
+        assert_eq!(LatticeType::join_all(vec![]), LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_join_all_single_element_is_identity() {
+        let int = LatticeType::Concrete(ConcreteType::Int64);
+        assert_eq!(LatticeType::join_all(vec![int.clone()]), int);
+    }
+
+    #[test]
+    fn test_join_all_short_circuits_on_top() {
+        let int = LatticeType::Concrete(ConcreteType::Int64);
+        let result = LatticeType::join_all(vec![int, LatticeType::Top, LatticeType::Bottom]);
+        assert_eq!(result, LatticeType::Top);
+    }
+
+    #[test]
+    fn test_join_all_merges_into_one_union() {
+        let members = vec![
+            LatticeType::Concrete(ConcreteType::Int64),
+            LatticeType::Concrete(ConcreteType::String),
+            LatticeType::Const(ConstValue::Float64(1.5)),
+            LatticeType::Bottom,
+        ];
+
+        let result = LatticeType::join_all(members);
+        let mut expected = BTreeSet::new();
+        expected.insert(ConcreteType::Int64);
+        expected.insert(ConcreteType::String);
+        expected.insert(ConcreteType::Float64);
+        assert_eq!(result, LatticeType::Union(expected));
+    }
+
+    #[test]
+    fn test_join_all_drops_subtypes_of_sibling_members() {
+        let members = vec![
+            LatticeType::Concrete(ConcreteType::Int64),
+            LatticeType::Concrete(ConcreteType::Integer),
+            LatticeType::Concrete(ConcreteType::String),
+        ];
+
+        let result = LatticeType::join_all(members);
+        let mut expected = BTreeSet::new();
+        expected.insert(ConcreteType::Integer);
+        expected.insert(ConcreteType::String);
+        assert_eq!(result, LatticeType::Union(expected));
+    }
+
+    #[test]
+    fn test_meet_all_empty_is_top() {
+        assert_eq!(LatticeType::meet_all(vec![]), LatticeType::Top);
+    }
+
+    #[test]
+    fn test_meet_all_single_element_is_identity() {
+        let int = LatticeType::Concrete(ConcreteType::Int64);
+        assert_eq!(LatticeType::meet_all(vec![int.clone()]), int);
+    }
+
+    #[test]
+    fn test_meet_all_short_circuits_on_bottom() {
+        let int = LatticeType::Concrete(ConcreteType::Int64);
+        let result = LatticeType::meet_all(vec![int, LatticeType::Bottom, LatticeType::Top]);
+        assert_eq!(result, LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_meet_all_narrows_to_most_specific() {
+        let members = vec![
+            LatticeType::Concrete(ConcreteType::Number),
+            LatticeType::Concrete(ConcreteType::Integer),
+            LatticeType::Concrete(ConcreteType::Int64),
+            LatticeType::Top,
+        ];
+        let result = LatticeType::meet_all(members);
+        assert_eq!(result, LatticeType::Concrete(ConcreteType::Int64));
+    }
+
     #[test]
     fn test_meet_concrete_same() {
         let int = LatticeType::Concrete(ConcreteType::Int64);
@@ -611,6 +1213,303 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_join_related_concrete_types_collapses_to_abstract_supertype() {
+        let int64 = LatticeType::Concrete(ConcreteType::Int64);
+        let int32 = LatticeType::Concrete(ConcreteType::Int32);
+        assert_eq!(
+            int64.join(&int32),
+            LatticeType::Concrete(ConcreteType::Integer)
+        );
+
+        let float64 = LatticeType::Concrete(ConcreteType::Float64);
+        assert_eq!(
+            int64.join(&float64),
+            LatticeType::Concrete(ConcreteType::Number)
+        );
+    }
+
+    #[test]
+    fn test_join_unrelated_concrete_types_still_unions() {
+        let int64 = LatticeType::Concrete(ConcreteType::Int64);
+        let string = LatticeType::Concrete(ConcreteType::String);
+        assert!(matches!(int64.join(&string), LatticeType::Union(_)));
+    }
+
+    #[test]
+    fn test_meet_concrete_with_abstract_supertype() {
+        let int64 = LatticeType::Concrete(ConcreteType::Int64);
+        let integer = LatticeType::Concrete(ConcreteType::Integer);
+        assert_eq!(int64.meet(&integer), int64);
+        assert_eq!(integer.meet(&int64), int64);
+
+        let float64 = LatticeType::Concrete(ConcreteType::Float64);
+        assert_eq!(int64.meet(&float64), LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_is_subtype_of_abstract_numeric_hierarchy() {
+        let int64 = LatticeType::Concrete(ConcreteType::Int64);
+        let integer = LatticeType::Concrete(ConcreteType::Integer);
+        let number = LatticeType::Concrete(ConcreteType::Number);
+
+        assert!(int64.is_subtype_of(&integer));
+        assert!(int64.is_subtype_of(&number));
+        assert!(integer.is_subtype_of(&number));
+        assert!(!integer.is_subtype_of(&int64));
+    }
+
+    #[test]
+    fn test_is_subtype_of_union_via_abstract_supertype() {
+        let int64 = LatticeType::Concrete(ConcreteType::Int64);
+        let mut union_types = BTreeSet::new();
+        union_types.insert(ConcreteType::Integer);
+        union_types.insert(ConcreteType::String);
+        let union = LatticeType::Union(union_types);
+
+        assert!(int64.is_subtype_of(&union));
+    }
+
+    #[test]
+    fn test_element_at_tuple() {
+        let t = LatticeType::Concrete(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int64, ConcreteType::String],
+        });
+        assert_eq!(t.element_at(0), LatticeType::Concrete(ConcreteType::Int64));
+        assert_eq!(t.element_at(1), LatticeType::Concrete(ConcreteType::String));
+        assert_eq!(t.element_at(2), LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_element_at_named_tuple() {
+        let t = LatticeType::Concrete(ConcreteType::NamedTuple {
+            fields: vec![
+                ("x".to_string(), ConcreteType::Int64),
+                ("y".to_string(), ConcreteType::Float64),
+            ],
+        });
+        assert_eq!(t.element_at(1), LatticeType::Concrete(ConcreteType::Float64));
+    }
+
+    #[test]
+    fn test_element_at_union_joins_members() {
+        let mut members = BTreeSet::new();
+        members.insert(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int64],
+        });
+        members.insert(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Integer],
+        });
+        let t = LatticeType::Union(members);
+        // `Int64` is a literal subtype of the sibling member's `Integer`
+        // field, so the per-member join collapses to the single abstract
+        // supertype rather than staying a Union.
+        assert_eq!(t.element_at(0), LatticeType::Concrete(ConcreteType::Integer));
+    }
+
+    #[test]
+    fn test_element_at_union_bottom_when_any_member_lacks_index() {
+        let mut members = BTreeSet::new();
+        members.insert(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int64, ConcreteType::String],
+        });
+        members.insert(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int32],
+        });
+        let t = LatticeType::Union(members);
+        assert_eq!(t.element_at(1), LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_with_element_narrows_tuple_field() {
+        let t = LatticeType::Concrete(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Integer, ConcreteType::String],
+        });
+        let narrowed = t.with_element(0, &LatticeType::Concrete(ConcreteType::Int64));
+        assert_eq!(
+            narrowed,
+            LatticeType::Concrete(ConcreteType::Tuple {
+                elements: vec![ConcreteType::Int64, ConcreteType::String],
+            })
+        );
+    }
+
+    #[test]
+    fn test_with_element_rejects_widening() {
+        // Meeting a field already known to be Int64 with a wider Integer
+        // store leaves the field at Int64, since meet never widens.
+        let t = LatticeType::Concrete(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int64],
+        });
+        let narrowed = t.with_element(0, &LatticeType::Concrete(ConcreteType::Integer));
+        assert_eq!(
+            narrowed,
+            LatticeType::Concrete(ConcreteType::Tuple {
+                elements: vec![ConcreteType::Int64],
+            })
+        );
+    }
+
+    #[test]
+    fn test_element_type_array_and_range() {
+        let array = LatticeType::Concrete(ConcreteType::Array {
+            element: Box::new(ConcreteType::Int64),
+        });
+        assert_eq!(array.element_type(), LatticeType::Concrete(ConcreteType::Int64));
+
+        let not_a_container = LatticeType::Concrete(ConcreteType::Int64);
+        assert_eq!(not_a_container.element_type(), LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_key_type_and_value_type_dict() {
+        let dict = LatticeType::Concrete(ConcreteType::Dict {
+            key: Box::new(ConcreteType::String),
+            value: Box::new(ConcreteType::Int64),
+        });
+        assert_eq!(dict.key_type(), LatticeType::Concrete(ConcreteType::String));
+        assert_eq!(dict.value_type(), LatticeType::Concrete(ConcreteType::Int64));
+    }
+
+    #[test]
+    fn test_normalize_single_element_union_becomes_concrete() {
+        let mut types = BTreeSet::new();
+        types.insert(ConcreteType::Int64);
+        let union = LatticeType::Union(types);
+        assert_eq!(union.normalize(), LatticeType::Concrete(ConcreteType::Int64));
+    }
+
+    #[test]
+    fn test_normalize_empty_union_becomes_bottom() {
+        let union = LatticeType::Union(BTreeSet::new());
+        assert_eq!(union.normalize(), LatticeType::Bottom);
+    }
+
+    #[test]
+    fn test_normalize_union_containing_any_becomes_top() {
+        let mut types = BTreeSet::new();
+        types.insert(ConcreteType::Int64);
+        types.insert(ConcreteType::Any);
+        let union = LatticeType::Union(types);
+        assert_eq!(union.normalize(), LatticeType::Top);
+    }
+
+    #[test]
+    fn test_normalize_is_idempotent() {
+        let mut types = BTreeSet::new();
+        types.insert(ConcreteType::Int64);
+        types.insert(ConcreteType::String);
+        let union = LatticeType::Union(types);
+        let once = union.clone().normalize();
+        assert_eq!(once.clone().normalize(), once);
+    }
+
+    #[test]
+    fn test_normalize_recurses_into_conditional_branches() {
+        let mut types = BTreeSet::new();
+        types.insert(ConcreteType::Int64);
+        let cond = LatticeType::Conditional {
+            slot: "x".to_string(),
+            then_type: Box::new(LatticeType::Union(types)),
+            else_type: Box::new(LatticeType::Bottom),
+        };
+        assert_eq!(
+            cond.normalize(),
+            LatticeType::Conditional {
+                slot: "x".to_string(),
+                then_type: Box::new(LatticeType::Concrete(ConcreteType::Int64)),
+                else_type: Box::new(LatticeType::Bottom),
+            }
+        );
+    }
+
+    #[test]
+    fn test_join_result_is_normalized_union_of_one() {
+        // Joining a Union{Int64} (constructed directly, bypassing
+        // simplify_union) with Bottom should still come back as Concrete,
+        // since join funnels its result through normalize.
+        let mut types = BTreeSet::new();
+        types.insert(ConcreteType::Int64);
+        let union = LatticeType::Union(types);
+        let result = union.join(&LatticeType::Bottom);
+        assert_eq!(result, LatticeType::Concrete(ConcreteType::Int64));
+    }
+
+    #[test]
+    fn test_singleton_value_returns_underlying_const() {
+        let t = LatticeType::Const(ConstValue::Int64(42));
+        assert_eq!(t.singleton_value(), Some(&ConstValue::Int64(42)));
+
+        let not_const = LatticeType::Concrete(ConcreteType::Int64);
+        assert_eq!(not_const.singleton_value(), None);
+    }
+
+    #[test]
+    fn test_simplify_union_drops_redundant_subtype() {
+        let mut types = BTreeSet::new();
+        types.insert(ConcreteType::Int64);
+        types.insert(ConcreteType::Number);
+
+        let result = LatticeType::simplify_union(types);
+        assert_eq!(result, LatticeType::Concrete(ConcreteType::Number));
+    }
+
+    #[test]
+    fn test_limit_type_size_within_budget_is_unchanged() {
+        let array_int = ConcreteType::Array {
+            element: Box::new(ConcreteType::Int64),
+        };
+        let t = LatticeType::Concrete(array_int.clone());
+        let compare = t.clone();
+        let limited = t.limit_type_size(&compare, &[], 4, 8);
+        assert_eq!(limited, LatticeType::Concrete(array_int));
+    }
+
+    #[test]
+    fn test_limit_type_size_reuses_existing_source_tuple() {
+        // A too-long tuple that already has a same-arity, element-wise
+        // abstract supertype sitting in `source` is replaced by that
+        // existing type rather than discarded to `Any` — this is the case
+        // the old widen_union-by-length heuristic couldn't express.
+        let t = LatticeType::Concrete(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int32; 3],
+        });
+        let source = vec![ConcreteType::Tuple {
+            elements: vec![ConcreteType::Integer; 3],
+        }];
+        let compare = LatticeType::Concrete(ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int32; 3],
+        });
+
+        let limited = t.limit_type_size(&compare, &source, 4, 1);
+        assert_eq!(
+            limited,
+            LatticeType::Concrete(ConcreteType::Tuple {
+                elements: vec![ConcreteType::Integer; 3],
+            })
+        );
+    }
+
+    #[test]
+    fn test_limit_type_size_falls_back_to_any_when_unreconcilable() {
+        // `compare` has an incompatible shape (a bare Int64) for the
+        // deeply-nested array in `t`, and there's no `source` type that
+        // covers it (arrays are invariant in their element type), so the
+        // naive rebuild isn't actually a supertype of `t` and the limiter
+        // falls all the way back to its final escape hatch.
+        let mut nested = ConcreteType::Int64;
+        for _ in 0..6 {
+            nested = ConcreteType::Array {
+                element: Box::new(nested),
+            };
+        }
+        let t = LatticeType::Concrete(nested);
+        let compare = LatticeType::Concrete(ConcreteType::Int64);
+
+        let limited = t.limit_type_size(&compare, &[], 0, 8);
+        assert_eq!(limited, LatticeType::Concrete(ConcreteType::Any));
+    }
+
     #[test]
     fn test_complexity_computation() {
         // Simple types have depth 1
@@ -0,0 +1,162 @@
+//! Property-based tests that check the type lattice obeys the algebraic
+//! laws a sound lattice must satisfy: idempotence, commutativity,
+//! absorption, order/operation consistency, and the subtraction invariant.
+//!
+//! These generate random [`LatticeType`] values (including nested
+//! arrays/tuples/unions and `Const`) rather than hand-picking fixtures, so
+//! a regression in `join`/`meet`/`subtract`/`is_subtype_of` that only
+//! shows up on some combination nobody thought to write a unit test for
+//! still gets caught. Union generation is bounded below
+//! `MAX_UNION_LENGTH`/`MAX_UNION_COMPLEXITY` so the laws hold exactly in
+//! the common case; where `simplify_union` widening is legitimately
+//! unavoidable, the weaker monotonic bound `a.is_subtype_of(&result)` is
+//! checked instead (see `assert_join_absorbs`/`assert_meet_absorbs`).
+
+use super::types::{ConcreteType, ConstValue, LatticeType};
+use super::widening::{MAX_UNION_COMPLEXITY, MAX_UNION_LENGTH};
+use proptest::prelude::*;
+use std::collections::BTreeSet;
+
+/// A handful of scalar types, deliberately including siblings in the
+/// abstract numeric hierarchy (`Int64`/`Int32`/`Float64`) so the
+/// hierarchy-aware paths of `join`/`meet`/`is_subtype_of` get exercised.
+fn arb_concrete_scalar() -> impl Strategy<Value = ConcreteType> {
+    prop_oneof![
+        Just(ConcreteType::Int64),
+        Just(ConcreteType::Int32),
+        Just(ConcreteType::Float64),
+        Just(ConcreteType::Bool),
+        Just(ConcreteType::String),
+        Just(ConcreteType::Char),
+        Just(ConcreteType::Integer),
+        Just(ConcreteType::AbstractFloat),
+        Just(ConcreteType::Number),
+    ]
+}
+
+/// Concrete types, including nested arrays/tuples a couple of levels
+/// deep, bounded well under `MAX_UNION_COMPLEXITY` so widening isn't
+/// triggered just by generating a single value.
+fn arb_concrete() -> impl Strategy<Value = ConcreteType> {
+    arb_concrete_scalar().prop_recursive(3, 16, 3, |inner| {
+        prop_oneof![
+            inner
+                .clone()
+                .prop_map(|element| ConcreteType::Array {
+                    element: Box::new(element)
+                }),
+            prop::collection::vec(inner, 1..3)
+                .prop_map(|elements| ConcreteType::Tuple { elements }),
+        ]
+    })
+}
+
+fn arb_const_value() -> impl Strategy<Value = ConstValue> {
+    prop_oneof![
+        any::<i64>().prop_map(ConstValue::Int64),
+        any::<bool>().prop_map(ConstValue::Bool),
+        "[a-z]{0,4}".prop_map(ConstValue::String),
+    ]
+}
+
+/// A union small enough to stay under both widening thresholds.
+fn arb_union() -> impl Strategy<Value = BTreeSet<ConcreteType>> {
+    prop::collection::btree_set(arb_concrete_scalar(), 2..(MAX_UNION_LENGTH - 1).max(2))
+        .prop_filter("stay under the complexity bound too", |types| {
+            types.len() <= MAX_UNION_COMPLEXITY
+        })
+}
+
+/// Any lattice type, including a shallow `Conditional` (whose `then`/`else`
+/// branches are themselves non-recursive, since `Conditional` nesting
+/// isn't the thing under test here).
+fn arb_lattice_type() -> impl Strategy<Value = LatticeType> {
+    let leaf = prop_oneof![
+        Just(LatticeType::Bottom),
+        Just(LatticeType::Top),
+        arb_const_value().prop_map(LatticeType::Const),
+        arb_concrete().prop_map(LatticeType::Concrete),
+        arb_union().prop_map(LatticeType::Union),
+    ];
+
+    leaf.clone().prop_recursive(1, 4, 2, move |_| {
+        ("[a-z]{1,4}", leaf.clone(), leaf.clone()).prop_map(|(slot, then_type, else_type)| {
+            LatticeType::Conditional {
+                slot,
+                then_type: Box::new(then_type),
+                else_type: Box::new(else_type),
+            }
+        })
+    })
+}
+
+/// `a.join(&a.meet(&b)) == a`, falling back to the weaker `a ⊑ result`
+/// bound if `a` was already large enough that re-merging it couldn't
+/// reproduce it byte-for-byte.
+fn assert_join_absorbs(a: &LatticeType, b: &LatticeType) {
+    let result = a.join(&a.meet(b));
+    if &result != a {
+        assert!(
+            a.is_subtype_of(&result),
+            "join-absorption violated outright: {a:?}.join({a:?}.meet({b:?})) = {result:?}"
+        );
+    }
+}
+
+/// `a.meet(&a.join(&b)) == a`, with the same widening fallback as
+/// [`assert_join_absorbs`].
+fn assert_meet_absorbs(a: &LatticeType, b: &LatticeType) {
+    let result = a.meet(&a.join(b));
+    if &result != a {
+        assert!(
+            a.is_subtype_of(&result),
+            "meet-absorption violated outright: {a:?}.meet({a:?}.join({b:?})) = {result:?}"
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn join_is_idempotent(a in arb_lattice_type()) {
+        prop_assert_eq!(a.join(&a), a);
+    }
+
+    #[test]
+    fn meet_is_idempotent(a in arb_lattice_type()) {
+        prop_assert_eq!(a.meet(&a), a);
+    }
+
+    #[test]
+    fn join_is_commutative(a in arb_lattice_type(), b in arb_lattice_type()) {
+        prop_assert_eq!(a.join(&b), b.join(&a));
+    }
+
+    #[test]
+    fn meet_is_commutative(a in arb_lattice_type(), b in arb_lattice_type()) {
+        prop_assert_eq!(a.meet(&b), b.meet(&a));
+    }
+
+    #[test]
+    fn join_absorbs_meet(a in arb_lattice_type(), b in arb_lattice_type()) {
+        assert_join_absorbs(&a, &b);
+    }
+
+    #[test]
+    fn meet_absorbs_join(a in arb_lattice_type(), b in arb_lattice_type()) {
+        assert_meet_absorbs(&a, &b);
+    }
+
+    #[test]
+    fn order_is_consistent_with_join_and_meet(a in arb_lattice_type(), b in arb_lattice_type()) {
+        let subtype = a.is_subtype_of(&b);
+        prop_assert_eq!(subtype, a.join(&b) == b);
+        prop_assert_eq!(subtype, a.meet(&b) == a);
+    }
+
+    #[test]
+    fn subtract_narrows_and_disjoints(a in arb_lattice_type(), b in arb_lattice_type()) {
+        let diff = a.subtract(&b);
+        prop_assert!(diff.is_subtype_of(&a));
+        prop_assert_eq!(diff.meet(&b), LatticeType::Bottom);
+    }
+}
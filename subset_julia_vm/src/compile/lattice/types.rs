@@ -17,16 +17,42 @@
 //! Bottom (unreachable/empty set - most specific)
 //! ```
 
+use half::f16;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
 
 /// A constant value known at compile time.
 ///
-/// Used for constant propagation during type inference.
+/// Used for constant propagation during type inference. One variant per
+/// concrete numeric width so folding stays consistent with the runtime
+/// `Value` tower (see `Instr::Zero`, which enumerates the same widths)
+/// instead of silently collapsing everything to `Int64`/`Float64`.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum ConstValue {
+    /// Integer constant (8-bit signed)
+    Int8(i8),
+    /// Integer constant (16-bit signed)
+    Int16(i16),
+    /// Integer constant (32-bit signed)
+    Int32(i32),
     /// Integer constant (64-bit signed)
     Int64(i64),
+    /// Integer constant (128-bit signed)
+    Int128(i128),
+    /// Integer constant (8-bit unsigned)
+    UInt8(u8),
+    /// Integer constant (16-bit unsigned)
+    UInt16(u16),
+    /// Integer constant (32-bit unsigned)
+    UInt32(u32),
+    /// Integer constant (64-bit unsigned)
+    UInt64(u64),
+    /// Integer constant (128-bit unsigned)
+    UInt128(u128),
+    /// Float constant (16-bit)
+    Float16(f16),
+    /// Float constant (32-bit)
+    Float32(f32),
     /// Float constant (64-bit)
     Float64(f64),
     /// Boolean constant
@@ -45,7 +71,18 @@ impl std::hash::Hash for ConstValue {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         std::mem::discriminant(self).hash(state);
         match self {
+            ConstValue::Int8(v) => v.hash(state),
+            ConstValue::Int16(v) => v.hash(state),
+            ConstValue::Int32(v) => v.hash(state),
             ConstValue::Int64(v) => v.hash(state),
+            ConstValue::Int128(v) => v.hash(state),
+            ConstValue::UInt8(v) => v.hash(state),
+            ConstValue::UInt16(v) => v.hash(state),
+            ConstValue::UInt32(v) => v.hash(state),
+            ConstValue::UInt64(v) => v.hash(state),
+            ConstValue::UInt128(v) => v.hash(state),
+            ConstValue::Float16(v) => v.to_bits().hash(state),
+            ConstValue::Float32(v) => v.to_bits().hash(state),
             ConstValue::Float64(v) => v.to_bits().hash(state),
             ConstValue::Bool(v) => v.hash(state),
             ConstValue::String(v) => v.hash(state),
@@ -59,7 +96,18 @@ impl ConstValue {
     /// Get the concrete type of this constant value.
     pub fn to_concrete_type(&self) -> ConcreteType {
         match self {
+            ConstValue::Int8(_) => ConcreteType::Int8,
+            ConstValue::Int16(_) => ConcreteType::Int16,
+            ConstValue::Int32(_) => ConcreteType::Int32,
             ConstValue::Int64(_) => ConcreteType::Int64,
+            ConstValue::Int128(_) => ConcreteType::Int128,
+            ConstValue::UInt8(_) => ConcreteType::UInt8,
+            ConstValue::UInt16(_) => ConcreteType::UInt16,
+            ConstValue::UInt32(_) => ConcreteType::UInt32,
+            ConstValue::UInt64(_) => ConcreteType::UInt64,
+            ConstValue::UInt128(_) => ConcreteType::UInt128,
+            ConstValue::Float16(_) => ConcreteType::Float16,
+            ConstValue::Float32(_) => ConcreteType::Float32,
             ConstValue::Float64(_) => ConcreteType::Float64,
             ConstValue::Bool(_) => ConcreteType::Bool,
             ConstValue::String(_) => ConcreteType::String,
@@ -179,6 +227,7 @@ pub enum ConcreteType {
     Float32,
     Float64,
     BigFloat,
+    Float128,
 
     // Boolean
     Bool,
@@ -294,7 +343,22 @@ impl LatticeType {
     /// Returns true if this is a numeric type (Int*, UInt*, Float*).
     pub fn is_numeric(&self) -> bool {
         match self {
-            LatticeType::Const(cv) => matches!(cv, ConstValue::Int64(_) | ConstValue::Float64(_)),
+            LatticeType::Const(cv) => matches!(
+                cv,
+                ConstValue::Int8(_)
+                    | ConstValue::Int16(_)
+                    | ConstValue::Int32(_)
+                    | ConstValue::Int64(_)
+                    | ConstValue::Int128(_)
+                    | ConstValue::UInt8(_)
+                    | ConstValue::UInt16(_)
+                    | ConstValue::UInt32(_)
+                    | ConstValue::UInt64(_)
+                    | ConstValue::UInt128(_)
+                    | ConstValue::Float16(_)
+                    | ConstValue::Float32(_)
+                    | ConstValue::Float64(_)
+            ),
             LatticeType::Concrete(ct) => ct.is_numeric(),
             LatticeType::Union(types) => types.iter().all(|t| t.is_numeric()),
             _ => false,
@@ -304,7 +368,19 @@ impl LatticeType {
     /// Returns true if this is an integer type (Int*, UInt*).
     pub fn is_integer(&self) -> bool {
         match self {
-            LatticeType::Const(cv) => matches!(cv, ConstValue::Int64(_)),
+            LatticeType::Const(cv) => matches!(
+                cv,
+                ConstValue::Int8(_)
+                    | ConstValue::Int16(_)
+                    | ConstValue::Int32(_)
+                    | ConstValue::Int64(_)
+                    | ConstValue::Int128(_)
+                    | ConstValue::UInt8(_)
+                    | ConstValue::UInt16(_)
+                    | ConstValue::UInt32(_)
+                    | ConstValue::UInt64(_)
+                    | ConstValue::UInt128(_)
+            ),
             LatticeType::Concrete(ct) => ct.is_integer(),
             LatticeType::Union(types) => types.iter().all(|t| t.is_integer()),
             _ => false,
@@ -314,12 +390,25 @@ impl LatticeType {
     /// Returns true if this is a floating-point type (Float*).
     pub fn is_float(&self) -> bool {
         match self {
-            LatticeType::Const(cv) => matches!(cv, ConstValue::Float64(_)),
+            LatticeType::Const(cv) => matches!(
+                cv,
+                ConstValue::Float16(_) | ConstValue::Float32(_) | ConstValue::Float64(_)
+            ),
             LatticeType::Concrete(ct) => ct.is_float(),
             LatticeType::Union(types) => types.iter().all(|t| t.is_float()),
             _ => false,
         }
     }
+
+    /// Returns the underlying constant value if `self` is `Const`, or
+    /// `None` otherwise. Lets inference read a compile-time-known value
+    /// back out of the lattice instead of only checking membership.
+    pub fn singleton_value(&self) -> Option<&ConstValue> {
+        match self {
+            LatticeType::Const(cv) => Some(cv),
+            _ => None,
+        }
+    }
 }
 
 impl ConcreteType {
@@ -350,7 +439,8 @@ impl ConcreteType {
             | ConcreteType::Float16
             | ConcreteType::Float32
             | ConcreteType::Float64
-            | ConcreteType::BigFloat => true,
+            | ConcreteType::BigFloat
+            | ConcreteType::Float128 => true,
             ConcreteType::UnionOf(types) => types.iter().all(|t| t.is_numeric()),
             _ => false,
         }
@@ -388,7 +478,8 @@ impl ConcreteType {
             | ConcreteType::Float16
             | ConcreteType::Float32
             | ConcreteType::Float64
-            | ConcreteType::BigFloat => true,
+            | ConcreteType::BigFloat
+            | ConcreteType::Float128 => true,
             ConcreteType::UnionOf(types) => types.iter().all(|t| t.is_float()),
             _ => false,
         }
@@ -438,6 +529,7 @@ impl ConcreteType {
             ConcreteType::Float32 => Some("Float32".to_string()),
             ConcreteType::Float64 => Some("Float64".to_string()),
             ConcreteType::BigFloat => Some("BigFloat".to_string()),
+            ConcreteType::Float128 => Some("Float128".to_string()),
             // Boolean
             ConcreteType::Bool => Some("Bool".to_string()),
             // Any
@@ -463,6 +555,73 @@ impl ConcreteType {
         }
     }
 
+    /// Direct abstract supertype of `self` in Julia's numeric hierarchy, if
+    /// any (`Int64 <: Integer <: Number`, `Float64 <: AbstractFloat <:
+    /// Number`). `Bool` is intentionally excluded, matching `is_integer`'s
+    /// treatment of it as its own type rather than an `Integer`.
+    fn direct_supertype(&self) -> Option<ConcreteType> {
+        match self {
+            ConcreteType::Int8
+            | ConcreteType::Int16
+            | ConcreteType::Int32
+            | ConcreteType::Int64
+            | ConcreteType::Int128
+            | ConcreteType::BigInt
+            | ConcreteType::UInt8
+            | ConcreteType::UInt16
+            | ConcreteType::UInt32
+            | ConcreteType::UInt64
+            | ConcreteType::UInt128 => Some(ConcreteType::Integer),
+            ConcreteType::Float16
+            | ConcreteType::Float32
+            | ConcreteType::Float64
+            | ConcreteType::BigFloat
+            | ConcreteType::Float128 => Some(ConcreteType::AbstractFloat),
+            ConcreteType::Integer | ConcreteType::AbstractFloat => Some(ConcreteType::Number),
+            _ => None,
+        }
+    }
+
+    /// `self` and all of its abstract supertypes, from most to least
+    /// specific (e.g. `[Int64, Integer, Number]`).
+    fn supertype_chain(&self) -> Vec<ConcreteType> {
+        let mut chain = vec![self.clone()];
+        let mut current = self.clone();
+        while let Some(next) = current.direct_supertype() {
+            chain.push(next.clone());
+            current = next;
+        }
+        chain
+    }
+
+    /// Subtype relation within the concrete-type hierarchy: true if `self`
+    /// equals `other` or `other` is one of `self`'s abstract supertypes
+    /// (`Int64.is_subtype_of(&Number)`, `Float64.is_subtype_of(&AbstractFloat)`).
+    pub fn is_subtype_of(&self, other: &ConcreteType) -> bool {
+        if matches!(other, ConcreteType::Any) {
+            return true;
+        }
+        // Tuples are covariant in their element types, same as in Julia:
+        // `Tuple{Int64, Int64} <: Tuple{Integer, Integer}`.
+        if let (ConcreteType::Tuple { elements: a }, ConcreteType::Tuple { elements: b }) =
+            (self, other)
+        {
+            return a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.is_subtype_of(y));
+        }
+        self.supertype_chain().contains(other)
+    }
+
+    /// Closest common ancestor of `self` and `other` in the abstract numeric
+    /// hierarchy, or `None` if they share none (e.g. `String` and `Int64`).
+    /// `Int64.common_supertype(&Int32) == Some(Integer)`;
+    /// `Int64.common_supertype(&Float64) == Some(Number)`.
+    pub(crate) fn common_supertype(&self, other: &ConcreteType) -> Option<ConcreteType> {
+        let other_chain = other.supertype_chain();
+        self.supertype_chain()
+            .into_iter()
+            .find(|t| other_chain.contains(t))
+    }
+
     /// Create a ConcreteType from a Julia type name string.
     /// Used for integration with the centralized promotion system.
     pub fn from_type_name(name: &str) -> Option<Self> {
@@ -485,6 +644,7 @@ impl ConcreteType {
             "Float32" => Some(ConcreteType::Float32),
             "Float64" => Some(ConcreteType::Float64),
             "BigFloat" => Some(ConcreteType::BigFloat),
+            "Float128" => Some(ConcreteType::Float128),
             // Boolean
             "Bool" => Some(ConcreteType::Bool),
             // Any
@@ -897,6 +1057,66 @@ mod tests {
         assert!(nested.is_numeric());
     }
 
+    #[test]
+    fn test_concrete_is_subtype_of_abstract_numeric() {
+        assert!(ConcreteType::Int64.is_subtype_of(&ConcreteType::Integer));
+        assert!(ConcreteType::Int64.is_subtype_of(&ConcreteType::Number));
+        assert!(ConcreteType::Float64.is_subtype_of(&ConcreteType::AbstractFloat));
+        assert!(ConcreteType::Float64.is_subtype_of(&ConcreteType::Number));
+        assert!(ConcreteType::Integer.is_subtype_of(&ConcreteType::Number));
+
+        // Reflexive
+        assert!(ConcreteType::Int64.is_subtype_of(&ConcreteType::Int64));
+
+        // Unrelated types
+        assert!(!ConcreteType::Int64.is_subtype_of(&ConcreteType::AbstractFloat));
+        assert!(!ConcreteType::Integer.is_subtype_of(&ConcreteType::Int64));
+        assert!(!ConcreteType::String.is_subtype_of(&ConcreteType::Number));
+    }
+
+    #[test]
+    fn test_concrete_common_supertype() {
+        assert_eq!(
+            ConcreteType::Int64.common_supertype(&ConcreteType::Int32),
+            Some(ConcreteType::Integer)
+        );
+        assert_eq!(
+            ConcreteType::Int64.common_supertype(&ConcreteType::Float64),
+            Some(ConcreteType::Number)
+        );
+        assert_eq!(
+            ConcreteType::Int64.common_supertype(&ConcreteType::Integer),
+            Some(ConcreteType::Integer)
+        );
+        assert_eq!(
+            ConcreteType::Int64.common_supertype(&ConcreteType::String),
+            None
+        );
+    }
+
+    #[test]
+    fn test_concrete_is_subtype_of_any() {
+        assert!(ConcreteType::Int64.is_subtype_of(&ConcreteType::Any));
+        assert!(ConcreteType::String.is_subtype_of(&ConcreteType::Any));
+    }
+
+    #[test]
+    fn test_concrete_tuple_is_covariant() {
+        let ints = ConcreteType::Tuple {
+            elements: vec![ConcreteType::Int64, ConcreteType::Int32],
+        };
+        let integers = ConcreteType::Tuple {
+            elements: vec![ConcreteType::Integer, ConcreteType::Integer],
+        };
+        assert!(ints.is_subtype_of(&integers));
+        assert!(!integers.is_subtype_of(&ints));
+
+        let wrong_arity = ConcreteType::Tuple {
+            elements: vec![ConcreteType::Integer],
+        };
+        assert!(!ints.is_subtype_of(&wrong_arity));
+    }
+
     /// Coverage test: all ConcreteType variants must be listed here (Issue #3187).
     ///
     /// When adding a new ConcreteType variant, update the list below AND review
@@ -15,3 +15,12 @@ pub const MAX_UNION_COMPLEXITY: usize = 5;
 
 /// Maximum iterations for fixed-point computation in abstract interpretation.
 pub const MAX_INFERENCE_ITERATIONS: usize = 100;
+
+/// Maximum tuple-element nesting depth `limit_type_size` allows beyond a
+/// `compare` reference type before limiting a subcomponent.
+/// Mirrors Julia's `MAX_TUPLE_DEPTH`.
+pub const MAX_TUPLE_DEPTH: usize = 4;
+
+/// Maximum number of elements an outer tuple type may have before
+/// `limit_type_size` limits it. Mirrors Julia's `MAX_TUPLETYPE_LEN`.
+pub const MAX_TUPLE_LEN: usize = 8;
@@ -49,6 +49,13 @@ pub(crate) struct MethodSig {
     pub global_index: usize,
     /// Parameter names and their declared types.
     pub params: Vec<(String, JuliaType)>,
+    /// Per-parameter `@nospecialize` flag, parallel to `params` (Issue chunk422-3).
+    /// `dispatch` treats a flagged parameter's declared type as `JuliaType::Any`
+    /// for both matching and specificity scoring, so the method stays the single
+    /// match regardless of the caller's concrete argument type there, instead of
+    /// losing to (or needing) a more specific overload for that position.
+    #[serde(default)]
+    pub nospecialize: Vec<bool>,
     /// Inferred return type.
     pub return_type: ValueType,
     /// Parametric return type that preserves element-level type info (Issue #2317).
@@ -181,12 +188,13 @@ impl MethodTable {
 
             // Track type variable bindings to ensure the same TypeVar binds to the same type
             // This is needed for methods like f(::Type{T}, ::Type{T}) where T - both args must be same type
+            //
+            // `@nospecialize` params (Issue chunk422-3) are matched/scored as `Any`
+            // regardless of their declared type, so a method stays the single match
+            // for that position no matter which concrete type the caller passes.
             let match_result = check_method_match_with_bindings(
-                &method
-                    .params
-                    .iter()
-                    .take(fixed_param_count)
-                    .map(|(_, ty)| ty.clone())
+                &(0..fixed_param_count)
+                    .map(|i| effective_param_type(method, i))
                     .collect::<Vec<_>>(),
                 &arg_types
                     .iter()
@@ -211,11 +219,8 @@ impl MethodTable {
                 // The key insight is that for concrete primitive types (like Bool and Int64),
                 // an exact match should be preferred over a subtype match. But for struct types
                 // and parametric types, we still want normal specificity-based dispatch.
-                let base_score: u32 = method
-                    .params
-                    .iter()
-                    .take(fixed_param_count)
-                    .map(|(_, ty)| ty.specificity() as u32)
+                let base_score: u32 = (0..fixed_param_count)
+                    .map(|i| effective_param_type(method, i).specificity() as u32)
                     .sum();
 
                 // Calculate match quality bonus/penalty for type matches.
@@ -223,12 +228,11 @@ impl MethodTable {
                 //   concrete primitive types (Bool, Int64, Float64, etc.) and they match exactly.
                 // - Gives penalty when argument type is Any but parameter is specific.
                 //   This avoids breaking dispatch for struct types like Rational.
-                let match_quality_bonus: i32 = method
-                    .params
-                    .iter()
-                    .take(fixed_param_count)
+                let match_quality_bonus: i32 = (0..fixed_param_count)
+                    .map(|i| effective_param_type(method, i))
                     .zip(arg_types.iter().take(fixed_param_count))
-                    .map(|((_, param_ty), arg_ty)| {
+                    .map(|(param_ty, arg_ty)| {
+                        let param_ty = &param_ty;
                         // Only give bonus for exact match of concrete primitive types
                         // This handles Bool vs Int64 dispatch correctly without affecting
                         // struct-based dispatch like Rational
@@ -398,6 +402,19 @@ impl MethodTable {
     }
 }
 
+/// Declared type of `method`'s parameter `index`, or `JuliaType::Any` if that
+/// parameter is flagged `@nospecialize` (Issue chunk422-3). Used everywhere
+/// `dispatch_inner` checks or scores a parameter, so a no-specialize parameter
+/// never narrows which argument types the method accepts, nor earns a
+/// specificity bonus for happening to be declared with a concrete type.
+fn effective_param_type(method: &MethodSig, index: usize) -> JuliaType {
+    if method.nospecialize.get(index).copied().unwrap_or(false) {
+        JuliaType::Any
+    } else {
+        method.params[index].1.clone()
+    }
+}
+
 /// Check if argument types match parameter types while tracking type variable bindings.
 ///
 /// When a type variable (like T in `f(::Type{T}, ::Type{T}) where T`) appears multiple times,
@@ -557,6 +574,7 @@ mod tests {
             _method_index: 0,
             global_index: 0,
             params: vec![("x".to_string(), JuliaType::Any)],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -570,6 +588,7 @@ mod tests {
             _method_index: 1,
             global_index: 1,
             params: vec![("x".to_string(), JuliaType::Int64)],
+            nospecialize: vec![],
             return_type: ValueType::I64,
             return_julia_type: None,
             is_base_extension: false,
@@ -600,6 +619,7 @@ mod tests {
             _method_index: 0,
             global_index: 0,
             params: vec![("x".to_string(), JuliaType::Any)],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -613,6 +633,7 @@ mod tests {
             _method_index: 1,
             global_index: 1,
             params: vec![("x".to_string(), JuliaType::Int64)],
+            nospecialize: vec![],
             return_type: ValueType::I64,
             return_julia_type: None,
             is_base_extension: false,
@@ -647,6 +668,7 @@ mod tests {
                 ("f".to_string(), JuliaType::Function),
                 ("A".to_string(), JuliaType::Any),
             ],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -663,6 +685,7 @@ mod tests {
                 ("f".to_string(), JuliaType::Function),
                 ("x".to_string(), JuliaType::Int64),
             ],
+            nospecialize: vec![],
             return_type: ValueType::I64,
             return_julia_type: None,
             is_base_extension: false,
@@ -705,6 +728,7 @@ mod tests {
                 "v".to_string(),
                 JuliaType::AbstractUser("MotorVehicle".to_string(), Some("Vehicle".to_string())),
             )],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -724,6 +748,7 @@ mod tests {
                     Some("Vehicle".to_string()),
                 ),
             )],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -787,6 +812,7 @@ mod tests {
             _method_index: 0,
             global_index: 0,
             params: vec![("x".to_string(), JuliaType::Int64)],
+            nospecialize: vec![],
             return_type: ValueType::I64,
             return_julia_type: None,
             is_base_extension: false,
@@ -798,6 +824,7 @@ mod tests {
             _method_index: 1,
             global_index: 1,
             params: vec![("x".to_string(), JuliaType::Any)],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -828,6 +855,7 @@ mod tests {
             _method_index: 0,
             global_index: 0,
             params: vec![("x".to_string(), JuliaType::Any)],
+            nospecialize: vec![],
             return_type: ValueType::Any,
             return_julia_type: None,
             is_base_extension: false,
@@ -845,6 +873,7 @@ mod tests {
             _method_index: 1,
             global_index: 1,
             params: vec![("x".to_string(), JuliaType::Int64)],
+            nospecialize: vec![],
             return_type: ValueType::I64,
             return_julia_type: None,
             is_base_extension: false,
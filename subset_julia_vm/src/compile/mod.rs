@@ -13,6 +13,7 @@
 //! - `core_compiler.rs`: CoreCompiler struct, LoopContext, FinallyContext, type predicates
 //! - `free_vars.rs`: Free variable analysis for closure capture detection
 //! - `inference.rs`: Type inference
+//! - `reflection.rs`: `code_typed`-style bytecode reflection for a compiled method
 //! - `types.rs`: Type definitions and error handling
 //! - `utils.rs`: Binary op conversion, literal evaluation, and other utilities
 //! - `stmt.rs`: Statement compilation
@@ -40,6 +41,7 @@ mod method_table;
 mod peephole;
 pub mod precompile;
 pub mod promotion;
+pub mod reflection;
 mod stmt;
 pub mod tfuncs;
 mod type_helpers;
@@ -942,6 +944,7 @@ pub(crate) fn compile_core_program_internal(
                 _method_index: method_index,
                 global_index,
                 params: params.clone(),
+                nospecialize: func.params.iter().map(|p| p.nospecialize).collect(),
                 return_type: return_type.clone(),
                 return_julia_type: return_julia_type.clone(),
                 is_base_extension: func.is_base_extension,
@@ -1010,6 +1013,8 @@ pub(crate) fn compile_core_program_internal(
                 param_slots: Vec::new(),
                 vararg_param_index,
                 vararg_fixed_count,
+                nothrow: false,
+                norecurse: false,
             });
 
             // Register function index for Stmt::FunctionDef lookups
@@ -1168,6 +1173,7 @@ pub(crate) fn compile_core_program_internal(
                 _method_index: method_index,
                 global_index,
                 params,
+                nospecialize: ctor.params.iter().map(|p| p.nospecialize).collect(),
                 return_type: return_type.clone(),
                 return_julia_type: None, // Inner constructors return structs, not parametric tuples
                 is_base_extension: false,
@@ -1194,6 +1200,8 @@ pub(crate) fn compile_core_program_internal(
                 param_slots: Vec::new(),
                 vararg_param_index: None, // Inner constructors don't have varargs
                 vararg_fixed_count: None,
+                nothrow: false,
+                norecurse: false,
             });
 
             inner_ctors.push(InnerCtorInfo {
@@ -1334,6 +1342,23 @@ pub(crate) fn compile_core_program_internal(
                 .unwrap_or_default()
         };
 
+        // Same lookup as closure_captures above, but for the subset of captures this
+        // closure shares by reference via a boxed cell (Issue chunk421-1).
+        let boxed_captures = if let Some(parent) = func_idx_to_parent.get(&idx) {
+            let qualified_name = format!("{}#{}", parent, func.name);
+            shared_ctx
+                .boxed_closure_captures
+                .get(&qualified_name)
+                .cloned()
+                .unwrap_or_default()
+        } else {
+            shared_ctx
+                .boxed_closure_captures
+                .get(&func.name)
+                .cloned()
+                .unwrap_or_default()
+        };
+
         let mut compiler = CoreCompiler::new_for_function(
             &method_tables,
             &module_functions,
@@ -1347,6 +1372,9 @@ pub(crate) fn compile_core_program_internal(
 
         // Set captured_vars so that load_local emits LoadCaptured for those variables
         compiler.captured_vars = closure_captures;
+        // Set boxed_captures so that load_local/store_local emit LoadCapturedBoxed/
+        // StoreCapturedBoxed for captures shared by reference (Issue chunk421-1)
+        compiler.boxed_captures = boxed_captures;
 
         // Set the current function name for nested function disambiguation
         // For nested functions, use the qualified name (parent#nested) so that
@@ -1391,6 +1419,16 @@ pub(crate) fn compile_core_program_internal(
 
         // Set up parameter types in locals
         for param in &func.params {
+            // `@nospecialize` params always compile as ValueType::Any, routed through
+            // the any_params dynamic path, regardless of their declared type. This
+            // intentionally skips the narrow-integer/TypeVar/parametric tracking below
+            // so the compiler never specializes a method body on the caller's argument
+            // type for these parameters.
+            if param.nospecialize {
+                compiler.locals.insert(param.name.clone(), ValueType::Any);
+                compiler.any_params.insert(param.name.clone());
+                continue;
+            }
             let param_ty = param.effective_type();
             // Ensure parametric struct instantiations exist (e.g., Complex{Float64})
             if let JuliaType::Struct(name) = &param_ty {
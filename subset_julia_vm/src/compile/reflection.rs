@@ -0,0 +1,122 @@
+//! Compiler reflection: dump the compiled instruction stream for a method.
+//!
+//! This is the VM analogue of Julia's `code_lowered`/`code_typed`: given a
+//! function name and an argument-type signature, it runs the normal
+//! compilation path and renders the resulting bytecode slice, annotated with
+//! the parameter/return `ValueType`s the compiler inferred for that method.
+//! It's meant for catching miscompilation and type-instability bugs by
+//! letting users see which `Return*` instruction fired and where coercions
+//! like `ToF64`/`DynamicToI64` were inserted.
+
+use std::fmt::Write as _;
+
+use crate::ir::core::Program;
+use crate::vm::{CompiledProgram, FunctionInfo, ValueType};
+
+use super::types::{err, CResult};
+use super::{compile_core_program, type_helpers::julia_type_to_value_type};
+
+/// Compile `program` and render the instruction stream for the method of
+/// `func_name` whose parameter types match `arg_types`.
+///
+/// When more than one method is named `func_name` (multiple dispatch), the
+/// method whose parameter `ValueType`s match `arg_types` exactly (a parameter
+/// declared `Any` matches every argument type) is preferred;
+/// if none match exactly, the first method with the same arity is used so
+/// that callers still get a result to inspect, with a note that the match
+/// was inexact.
+pub fn code_typed(program: &Program, func_name: &str, arg_types: &[ValueType]) -> CResult<String> {
+    let compiled = compile_core_program(program)?;
+
+    let candidates: Vec<&FunctionInfo> = compiled
+        .functions
+        .iter()
+        .filter(|f| f.name == func_name)
+        .collect();
+
+    if candidates.is_empty() {
+        return err(format!("code_typed: no method named '{}' found", func_name));
+    }
+
+    let exact = candidates
+        .iter()
+        .find(|f| signature_matches(f, arg_types))
+        .copied();
+    let (func_info, exact_match) = match exact {
+        Some(f) => (f, true),
+        None => {
+            let fallback = candidates
+                .iter()
+                .find(|f| f.params.len() == arg_types.len())
+                .copied()
+                .unwrap_or(candidates[0]);
+            (fallback, false)
+        }
+    };
+
+    Ok(render_method(&compiled, func_info, arg_types, exact_match))
+}
+
+/// Check whether a method's declared parameter types match the given
+/// `ValueType` signature positionally. Varargs methods match any extra
+/// trailing arguments.
+fn signature_matches(func_info: &FunctionInfo, arg_types: &[ValueType]) -> bool {
+    if func_info.vararg_param_index.is_none() && func_info.params.len() != arg_types.len() {
+        return false;
+    }
+    func_info
+        .params
+        .iter()
+        .zip(arg_types.iter())
+        .all(|((_, param_ty), arg_ty)| param_ty == arg_ty || *param_ty == ValueType::Any)
+}
+
+/// Render a single method's signature and bytecode slice to a string buffer.
+fn render_method(
+    compiled: &CompiledProgram,
+    func_info: &FunctionInfo,
+    arg_types: &[ValueType],
+    exact_match: bool,
+) -> String {
+    let mut out = String::new();
+
+    let params_rendered: Vec<String> = func_info
+        .params
+        .iter()
+        .map(|(name, ty)| format!("{}::{:?}", name, ty))
+        .collect();
+    let _ = writeln!(
+        out,
+        "{}({}) => {:?}",
+        func_info.name,
+        params_rendered.join(", "),
+        func_info.return_type
+    );
+
+    if !exact_match {
+        let _ = writeln!(
+            out,
+            "# note: no method of '{}' declares parameter types matching {:?} exactly; \
+             showing the first method with matching arity instead",
+            func_info.name, arg_types
+        );
+    }
+
+    let _ = writeln!(out, "CodeInfo(");
+    for (offset, instr) in compiled.code[func_info.code_start..func_info.code_end]
+        .iter()
+        .enumerate()
+    {
+        let _ = writeln!(out, "{:>4}: {:?}", func_info.code_start + offset, instr);
+    }
+    let _ = writeln!(out, ") => {:?}", func_info.return_type);
+
+    out
+}
+
+/// Convert a `JuliaType` argument-type tuple (as parsed from user-facing
+/// `code_typed(f, (Int64, Float64))` syntax) into the `ValueType`s used for
+/// signature matching.
+pub fn julia_types_to_value_types(types: &[crate::types::JuliaType]) -> Vec<ValueType> {
+    types.iter().map(julia_type_to_value_type).collect()
+}
@@ -3,11 +3,12 @@
 //! This module contains statement-level compilation methods including
 //! block, function body, and individual statement compilation.
 
-use crate::ir::core::{Block, Expr, Literal, Stmt};
+use crate::builtins::BuiltinId;
+use crate::ir::core::{BinaryOp, Block, Expr, Literal, Stmt};
 use crate::types::JuliaType;
 
 mod stmt_try_catch;
-use crate::vm::{Instr, ValueType};
+use crate::vm::{EnumMembershipCheck, Instr, ValueType};
 
 use super::types::{err, CResult, CompileError};
 use super::{analyze_free_variables, is_stdlib_module, CoreCompiler, LoopContext};
@@ -24,6 +25,28 @@ pub(super) fn can_convert_type(from: ValueType, to: ValueType) -> bool {
     )
 }
 
+/// Evaluate an `Expr` as a compile-time constant `i64`, if it is one.
+///
+/// Recognizes plain integer literals and a unary `-`/`+` applied to one
+/// (how the parser represents negative literal steps, e.g. `-1`). Returns
+/// `None` for anything that requires runtime evaluation.
+fn const_i64_literal(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Literal(Literal::Int(n), _) => Some(*n),
+        Expr::UnaryOp {
+            op: crate::ir::core::UnaryOp::Neg,
+            operand,
+            ..
+        } => const_i64_literal(operand).map(|n| -n),
+        Expr::UnaryOp {
+            op: crate::ir::core::UnaryOp::Pos,
+            operand,
+            ..
+        } => const_i64_literal(operand),
+        _ => None,
+    }
+}
+
 /// Determine the iteration strategy for a type known at compile time.
 ///
 /// Returns:
@@ -51,7 +74,386 @@ pub(super) fn static_iterate_strategy(ty: &JuliaType) -> Option<bool> {
     }
 }
 
+/// Element `ValueType` for a typed `ForEach` fast path, if the iterable's type
+/// is a known homogeneous collection of unboxed `i64`/`f64` elements.
+///
+/// Returns `None` for anything else (structs, `Any`, heterogeneous tuples,
+/// ...), which keeps using the generic `iterate()`-protocol path.
+fn typed_foreach_element_type(ty: &JuliaType) -> Option<ValueType> {
+    match ty {
+        JuliaType::VectorOf(elem) => match elem.as_ref() {
+            JuliaType::Int64 => Some(ValueType::I64),
+            JuliaType::Float64 => Some(ValueType::F64),
+            _ => None,
+        },
+        // Integer ranges always yield i64 elements.
+        JuliaType::UnitRange | JuliaType::StepRange => Some(ValueType::I64),
+        _ => None,
+    }
+}
+
+/// Collect every variable name assigned anywhere within `block`, including
+/// inside nested control flow and nested loops. Used by loop-invariant code
+/// motion to decide which `Expr::Var` leaves are safe to hoist out of a loop.
+fn collect_assigned_vars(block: &Block, out: &mut HashSet<String>) {
+    for stmt in &block.stmts {
+        collect_assigned_vars_stmt(stmt, out);
+    }
+}
+
+fn collect_assigned_vars_stmt(stmt: &Stmt, out: &mut HashSet<String>) {
+    match stmt {
+        Stmt::Block(b) => collect_assigned_vars(b, out),
+        Stmt::Assign { var, .. }
+        | Stmt::AddAssign { var, .. }
+        | Stmt::SubAssign { var, .. }
+        | Stmt::MulAssign { var, .. }
+        | Stmt::DivAssign { var, .. }
+        | Stmt::FldAssign { var, .. }
+        | Stmt::PowAssign { var, .. }
+        | Stmt::BitAndAssign { var, .. }
+        | Stmt::BitOrAssign { var, .. }
+        | Stmt::BitXorAssign { var, .. }
+        | Stmt::BroadcastAssign { var, .. }
+        | Stmt::IndexAssign { array: var, .. }
+        | Stmt::FieldAssign { object: var, .. }
+        | Stmt::DictAssign { dict: var, .. } => {
+            out.insert(var.clone());
+        }
+        Stmt::DestructuringAssign { targets, .. } => {
+            out.extend(targets.iter().cloned());
+        }
+        Stmt::For { var, body, .. } => {
+            out.insert(var.clone());
+            collect_assigned_vars(body, out);
+        }
+        Stmt::ForEach { var, body, .. } => {
+            out.insert(var.clone());
+            collect_assigned_vars(body, out);
+        }
+        Stmt::ForEachTuple { vars, body, .. } => {
+            out.extend(vars.iter().cloned());
+            collect_assigned_vars(body, out);
+        }
+        Stmt::While { body, .. } => collect_assigned_vars(body, out),
+        Stmt::If {
+            then_branch,
+            else_branch,
+            ..
+        } => {
+            collect_assigned_vars(then_branch, out);
+            if let Some(else_branch) = else_branch {
+                collect_assigned_vars(else_branch, out);
+            }
+        }
+        Stmt::Try {
+            try_block,
+            catch_var,
+            catch_block,
+            else_block,
+            finally_block,
+            ..
+        } => {
+            collect_assigned_vars(try_block, out);
+            if let Some(catch_var) = catch_var {
+                out.insert(catch_var.clone());
+            }
+            if let Some(catch_block) = catch_block {
+                collect_assigned_vars(catch_block, out);
+            }
+            if let Some(else_block) = else_block {
+                collect_assigned_vars(else_block, out);
+            }
+            if let Some(finally_block) = finally_block {
+                collect_assigned_vars(finally_block, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Rewrites pure arithmetic/comparison subexpressions that don't reference any
+/// loop-assigned variable into references to a hoisted preheader temp.
+///
+/// Scoped to `Expr::BinaryOp`/`Expr::UnaryOp` trees (the "purity whitelist" is
+/// implicit: these operators can't throw differently across iterations or have
+/// side effects). A node is hoisted whole, and not descended into, as soon as
+/// it is found invariant, which is what keeps hoisted subexpressions maximal.
+struct LoopInvariantHoister<'a> {
+    assigned: &'a HashSet<String>,
+    hoisted: Vec<(String, Expr)>,
+}
+
+impl<'a> LoopInvariantHoister<'a> {
+    fn new(assigned: &'a HashSet<String>) -> Self {
+        Self {
+            assigned,
+            hoisted: Vec::new(),
+        }
+    }
+
+    fn is_invariant(&self, expr: &Expr) -> bool {
+        match expr {
+            Expr::Literal(..) => true,
+            Expr::Var(name, _) => !self.assigned.contains(name),
+            Expr::BinaryOp { left, right, .. } => {
+                self.is_invariant(left) && self.is_invariant(right)
+            }
+            Expr::UnaryOp { operand, .. } => self.is_invariant(operand),
+            _ => false,
+        }
+    }
+
+    fn hoist(&mut self, expr: &Expr) -> Expr {
+        let span = expr.span();
+        let name = format!("licm{}", self.hoisted.len());
+        self.hoisted.push((name.clone(), expr.clone()));
+        Expr::Var(name, span)
+    }
+
+    fn rewrite(&mut self, expr: &Expr) -> Expr {
+        match expr {
+            Expr::BinaryOp { .. } | Expr::UnaryOp { .. } if self.is_invariant(expr) => {
+                self.hoist(expr)
+            }
+            Expr::BinaryOp {
+                op,
+                left,
+                right,
+                span,
+            } => Expr::BinaryOp {
+                op: *op,
+                left: Box::new(self.rewrite(left)),
+                right: Box::new(self.rewrite(right)),
+                span: *span,
+            },
+            Expr::UnaryOp { op, operand, span } => Expr::UnaryOp {
+                op: *op,
+                operand: Box::new(self.rewrite(operand)),
+                span: *span,
+            },
+            _ => expr.clone(),
+        }
+    }
+
+    fn rewrite_stmt(&mut self, stmt: &Stmt) -> Stmt {
+        match stmt {
+            Stmt::Block(b) => Stmt::Block(self.rewrite_block(b)),
+            Stmt::Assign { var, value, span } => Stmt::Assign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::AddAssign { var, value, span } => Stmt::AddAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::SubAssign { var, value, span } => Stmt::SubAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::MulAssign { var, value, span } => Stmt::MulAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::DivAssign { var, value, span } => Stmt::DivAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::FldAssign { var, value, span } => Stmt::FldAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::PowAssign { var, value, span } => Stmt::PowAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::BitAndAssign { var, value, span } => Stmt::BitAndAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::BitOrAssign { var, value, span } => Stmt::BitOrAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::BitXorAssign { var, value, span } => Stmt::BitXorAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::BroadcastAssign { var, value, span } => Stmt::BroadcastAssign {
+                var: var.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::IndexAssign {
+                array,
+                indices,
+                value,
+                span,
+            } => Stmt::IndexAssign {
+                array: array.clone(),
+                indices: indices.iter().map(|idx| self.rewrite(idx)).collect(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::FieldAssign {
+                object,
+                field,
+                value,
+                span,
+            } => Stmt::FieldAssign {
+                object: object.clone(),
+                field: field.clone(),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::DictAssign {
+                dict,
+                key,
+                value,
+                span,
+            } => Stmt::DictAssign {
+                dict: dict.clone(),
+                key: self.rewrite(key),
+                value: self.rewrite(value),
+                span: *span,
+            },
+            Stmt::Expr { expr, span } => Stmt::Expr {
+                expr: self.rewrite(expr),
+                span: *span,
+            },
+            Stmt::Return { value, span } => Stmt::Return {
+                value: value.as_ref().map(|v| self.rewrite(v)),
+                span: *span,
+            },
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+                span,
+            } => Stmt::If {
+                condition: self.rewrite(condition),
+                then_branch: self.rewrite_block(then_branch),
+                else_branch: else_branch.as_ref().map(|b| self.rewrite_block(b)),
+                span: *span,
+            },
+            // Nested loops get their own loop-invariant pass when they are
+            // compiled; leave them untouched here to avoid hoisting something
+            // past a boundary that has its own preheader.
+            Stmt::For { .. }
+            | Stmt::ForEach { .. }
+            | Stmt::ForEachTuple { .. }
+            | Stmt::While { .. } => stmt.clone(),
+            _ => stmt.clone(),
+        }
+    }
+
+    fn rewrite_block(&mut self, block: &Block) -> Block {
+        Block {
+            stmts: block.stmts.iter().map(|s| self.rewrite_stmt(s)).collect(),
+            span: block.span,
+        }
+    }
+}
+
 impl CoreCompiler<'_> {
+    /// Loop-invariant code motion: rewrite `body`, hoisting maximal pure
+    /// arithmetic/comparison subexpressions that don't reference `bound_vars`
+    /// or anything assigned within `body` itself, compiling each hoisted
+    /// value once into a preheader temp emitted by the caller *before* the
+    /// loop's back-edge target (Issue chunk420-3). Returns the rewritten body
+    /// to compile in place of the original.
+    pub(super) fn hoist_loop_invariants(
+        &mut self,
+        body: &Block,
+        bound_vars: &[&str],
+    ) -> CResult<Block> {
+        let mut assigned = HashSet::new();
+        assigned.extend(bound_vars.iter().map(|s| s.to_string()));
+        collect_assigned_vars(body, &mut assigned);
+
+        let mut hoister = LoopInvariantHoister::new(&assigned);
+        let rewritten = hoister.rewrite_block(body);
+
+        for (name, expr) in hoister.hoisted {
+            let ty = self.compile_expr(&expr)?;
+            self.store_local(&name, ty);
+        }
+
+        Ok(rewritten)
+    }
+
+    /// Whether `expr` is known, purely from its shape, to have no side effects
+    /// and to never raise an exception — so a discarded expression statement
+    /// built only from these forms can be skipped entirely instead of compiled
+    /// and then stored to a dummy local (Issue chunk421-2).
+    ///
+    /// Deliberately conservative: `Expr::Var` always stays impure, since reading
+    /// an undefined binding must still raise `UndefVarError`. Division-family
+    /// operators (`/`, `÷`, `%`, `^`) and `&&`/`||`/`<:` are excluded even
+    /// though they're "arithmetic" in the everyday sense, because they can
+    /// raise `DivisionByZero`/`DomainError`/`TypeError` for operand values this
+    /// shape-only check can't rule out; only the operators that provably never
+    /// throw for any operand type are whitelisted.
+    pub(super) fn expr_is_pure(&self, expr: &Expr) -> bool {
+        use crate::ir::core::UnaryOp;
+        match expr {
+            Expr::Literal(lit, _) => matches!(
+                lit,
+                Literal::Int(_)
+                    | Literal::Int128(_)
+                    | Literal::Float(_)
+                    | Literal::Float32(_)
+                    | Literal::Float16(_)
+                    | Literal::Bool(_)
+                    | Literal::Str(_)
+                    | Literal::Char(_)
+                    | Literal::Nothing
+                    | Literal::Missing
+            ),
+            // Reading a variable can raise UndefVarError, so it must stay impure
+            // even though the read itself has no other side effect.
+            Expr::Var(..) => false,
+            Expr::BinaryOp {
+                op, left, right, ..
+            } => {
+                matches!(
+                    op,
+                    BinaryOp::Add
+                        | BinaryOp::Sub
+                        | BinaryOp::Mul
+                        | BinaryOp::Lt
+                        | BinaryOp::Gt
+                        | BinaryOp::Le
+                        | BinaryOp::Ge
+                        | BinaryOp::Eq
+                        | BinaryOp::Ne
+                        | BinaryOp::Egal
+                        | BinaryOp::NotEgal
+                ) && self.expr_is_pure(left)
+                    && self.expr_is_pure(right)
+            }
+            Expr::UnaryOp { op, operand, .. } => {
+                matches!(op, UnaryOp::Neg | UnaryOp::Pos) && self.expr_is_pure(operand)
+            }
+            Expr::TupleLiteral { elements, .. } => elements.iter().all(|e| self.expr_is_pure(e)),
+            Expr::ArrayLiteral { elements, .. } => elements.iter().all(|e| self.expr_is_pure(e)),
+            Expr::NamedTupleLiteral { fields, .. } => {
+                fields.iter().all(|(_, e)| self.expr_is_pure(e))
+            }
+            _ => false,
+        }
+    }
+
     pub(super) fn compile_block(&mut self, block: &Block) -> CResult<()> {
         for stmt in &block.stmts {
             self.compile_stmt(stmt)?;
@@ -66,6 +468,16 @@ impl CoreCompiler<'_> {
         block: &Block,
         return_type: ValueType,
     ) -> CResult<()> {
+        // Cooperative safepoint at function entry, so recursive calls are bounded
+        // by the operation budget even without a loop in the function body
+        // (Issue chunk421-3).
+        self.emit(Instr::SafePoint);
+
+        // Record every name this function body assigns anywhere (outside any closure), so a
+        // closure captured further down can tell an outer-scope reassignment apart from a
+        // capture that's never written to outside its own body (Issue chunk421-1).
+        collect_assigned_vars(block, &mut self.enclosing_assigned_vars);
+
         let stmts = &block.stmts;
 
         if stmts.is_empty() {
@@ -177,6 +589,7 @@ impl CoreCompiler<'_> {
             ValueType::Module => self.emit(Instr::ReturnAny),
             ValueType::BigInt => self.emit(Instr::ReturnAny),
             ValueType::BigFloat => self.emit(Instr::ReturnAny),
+            ValueType::Float128 => self.emit(Instr::ReturnAny),
             ValueType::IO => self.emit(Instr::ReturnAny),
             ValueType::Function => self.emit(Instr::ReturnAny),
             // Narrow integer types: ReturnI64 handler already preserves the original Value type
@@ -339,6 +752,60 @@ impl CoreCompiler<'_> {
         Ok(())
     }
 
+    /// Compile a compound-assignment statement (`x += y`, `x &= y`, ...) by
+    /// desugaring it to `x = x op y` and compiling that as a regular
+    /// `Stmt::Assign`. This reuses `Stmt::Assign`'s full type-widening logic
+    /// (`ToF64`, `DynamicToI64`/`DynamicToF64` for mixed-type variables,
+    /// `convert()` fallback for `Any`, etc.) instead of duplicating it, so
+    /// e.g. `x -= 1.0` on an integer accumulator widens correctly rather than
+    /// erroring.
+    fn compile_compound_assign(
+        &mut self,
+        var: &str,
+        op: BinaryOp,
+        value: &Expr,
+        span: crate::span::Span,
+    ) -> CResult<()> {
+        let desugared = Stmt::Assign {
+            var: var.to_string(),
+            value: Expr::BinaryOp {
+                op,
+                left: Box::new(Expr::Var(var.to_string(), span)),
+                right: Box::new(value.clone()),
+                span,
+            },
+            span,
+        };
+        self.compile_stmt(&desugared)
+    }
+
+    /// Compile a bitwise compound-assignment statement (`x &= y`, `x |= y`,
+    /// `x ⊻= y`). Bitwise `&`/`|`/`⊻` have no `BinaryOp` variant of their own
+    /// (they lower to calls to the Pure Julia wrappers in `base/int.jl`, same
+    /// as ordinary `a & b`), so this desugars through `Expr::Call` instead of
+    /// `Expr::BinaryOp`.
+    fn compile_bitwise_compound_assign(
+        &mut self,
+        var: &str,
+        op: &str,
+        value: &Expr,
+        span: crate::span::Span,
+    ) -> CResult<()> {
+        let desugared = Stmt::Assign {
+            var: var.to_string(),
+            value: Expr::Call {
+                function: op.to_string(),
+                args: vec![Expr::Var(var.to_string(), span), value.clone()],
+                kwargs: Vec::new(),
+                splat_mask: vec![false, false],
+                kwargs_splat_mask: vec![],
+                span,
+            },
+            span,
+        };
+        self.compile_stmt(&desugared)
+    }
+
     pub(super) fn compile_stmt(&mut self, stmt: &Stmt) -> CResult<()> {
         if self.compile_try_stmt(stmt)?.is_some() {
             return Ok(());
@@ -473,16 +940,91 @@ impl CoreCompiler<'_> {
                 self.store_local(var, final_ty);
                 Ok(())
             }
-            Stmt::AddAssign { var, value, .. } => {
-                let var_ty = self.locals.get(var).cloned().unwrap_or(ValueType::I64);
+            Stmt::AddAssign { var, value, span } => {
+                self.compile_compound_assign(var, BinaryOp::Add, value, *span)
+            }
+            Stmt::SubAssign { var, value, span } => {
+                self.compile_compound_assign(var, BinaryOp::Sub, value, *span)
+            }
+            Stmt::MulAssign { var, value, span } => {
+                self.compile_compound_assign(var, BinaryOp::Mul, value, *span)
+            }
+            Stmt::DivAssign { var, value, span } => {
+                self.compile_compound_assign(var, BinaryOp::Div, value, *span)
+            }
+            Stmt::FldAssign { var, value, span } => {
+                self.compile_compound_assign(var, BinaryOp::IntDiv, value, *span)
+            }
+            Stmt::PowAssign { var, value, span } => {
+                self.compile_compound_assign(var, BinaryOp::Pow, value, *span)
+            }
+            Stmt::BitAndAssign { var, value, span } => {
+                self.compile_bitwise_compound_assign(var, "&", value, *span)
+            }
+            Stmt::BitOrAssign { var, value, span } => {
+                self.compile_bitwise_compound_assign(var, "|", value, *span)
+            }
+            Stmt::BitXorAssign { var, value, span } => {
+                self.compile_bitwise_compound_assign(var, "⊻", value, *span)
+            }
+            Stmt::BroadcastAssign { var, value, .. } => {
+                // `x .= y`: unlike `Stmt::Assign`, this must not rebind `var` to a
+                // freshly allocated array. When `var` is a known array-typed local,
+                // fuse the update into an elementwise in-place store over the
+                // existing buffer (mirrors the Stmt::IndexAssign in-place pattern).
+                let target_ty = self.locals.get(var).cloned();
+                if !matches!(target_ty, Some(ValueType::Array) | Some(ValueType::ArrayOf(_))) {
+                    // Dynamic/unknown-type target: fall back to a plain assignment,
+                    // same as the pre-existing `.=` behavior for non-array locals.
+                    self.compile_expr(value)?;
+                    self.store_local(var, ValueType::Array);
+                    return Ok(());
+                }
+
+                let src_var = self.new_temp("bcast_src");
+                self.compile_expr(value)?;
+                self.emit(Instr::StoreArray(src_var.clone()));
+
+                let len_var = self.new_temp("bcast_len");
+                self.emit(Instr::LoadArray(src_var.clone()));
+                self.emit(Instr::CallBuiltin(BuiltinId::Length, 1));
+                self.emit(Instr::StoreI64(len_var.clone()));
+
+                let idx_var = self.new_temp("bcast_i");
+                self.emit(Instr::PushI64(1));
+                self.emit(Instr::StoreI64(idx_var.clone()));
+
+                let loop_start = self.here();
+                self.emit(Instr::LoadI64(idx_var.clone()));
+                self.emit(Instr::LoadI64(len_var.clone()));
+                self.emit(Instr::GtI64);
+                let j_continue = self.here();
+                self.emit(Instr::JumpIfZero(usize::MAX));
+                let j_exit = self.here();
+                self.emit(Instr::Jump(usize::MAX));
+
+                let body_start = self.here();
+                self.patch_jump(j_continue, body_start);
+
+                // dest[i] = src[i]
                 self.load_local(var)?;
-                self.compile_expr_as(value, var_ty.clone())?;
-                self.emit(match var_ty {
-                    ValueType::I64 => Instr::AddI64,
-                    ValueType::F64 => Instr::AddF64,
-                    _ => return err("AddAssign not supported for this type"),
-                });
-                self.store_local(var, var_ty);
+                self.emit(Instr::LoadI64(idx_var.clone()));
+                self.emit(Instr::LoadArray(src_var.clone()));
+                self.emit(Instr::LoadI64(idx_var.clone()));
+                self.emit(Instr::IndexLoad(1));
+                self.emit(Instr::IndexStore(1));
+                self.emit(Instr::StoreArray(var.clone()));
+
+                self.emit(Instr::LoadI64(idx_var.clone()));
+                self.emit(Instr::PushI64(1));
+                self.emit(Instr::AddI64);
+                self.emit(Instr::StoreI64(idx_var.clone()));
+
+                self.emit(Instr::Jump(loop_start));
+
+                let exit_label = self.here();
+                self.patch_jump(j_exit, exit_label);
+
                 Ok(())
             }
             Stmt::For {
@@ -496,6 +1038,62 @@ impl CoreCompiler<'_> {
                 // For loop: for var in start:end or start:step:end
                 self.locals.insert(var.clone(), ValueType::I64);
 
+                // The step, if given, as a compile-time constant (defaults to 1).
+                let step_const = match step {
+                    Some(step_expr) => const_i64_literal(step_expr),
+                    None => Some(1),
+                };
+
+                // Fully unroll small literal-bound ranges (trip count <= 8) instead
+                // of emitting any loop control flow at all (Issue chunk420-1).
+                if let (Some(start_c), Some(end_c), Some(step_c)) =
+                    (const_i64_literal(start), const_i64_literal(end), step_const)
+                {
+                    if step_c != 0 {
+                        let trip_count = if step_c > 0 {
+                            if start_c > end_c {
+                                0
+                            } else {
+                                (end_c - start_c) / step_c + 1
+                            }
+                        } else if start_c < end_c {
+                            0
+                        } else {
+                            (start_c - end_c) / (-step_c) + 1
+                        };
+                        if (0..=8).contains(&trip_count) {
+                            let mut all_exit_patches = Vec::new();
+                            for i in 0..trip_count {
+                                self.emit(Instr::PushI64(start_c + i * step_c));
+                                self.emit(Instr::StoreI64(var.clone()));
+
+                                let loop_ctx = LoopContext {
+                                    exit_patches: Vec::new(),
+                                    continue_patches: Vec::new(),
+                                };
+                                self.loop_stack.push(loop_ctx);
+                                self.compile_block(body)?;
+                                let loop_ctx = self.loop_stack.pop().unwrap();
+                                all_exit_patches.extend(loop_ctx.exit_patches);
+
+                                let continue_target = self.here();
+                                for patch_pos in loop_ctx.continue_patches {
+                                    self.patch_jump(patch_pos, continue_target);
+                                }
+                            }
+                            let exit = self.here();
+                            for patch_pos in all_exit_patches {
+                                self.patch_jump(patch_pos, exit);
+                            }
+                            return Ok(());
+                        }
+                    }
+                }
+
+                if step_const == Some(0) {
+                    return err("range step cannot be zero");
+                }
+
                 let stop_var = self.new_temp("stop");
                 let step_var = self.new_temp("step");
 
@@ -515,7 +1113,15 @@ impl CoreCompiler<'_> {
                 self.compile_expr_as(start, ValueType::I64)?;
                 self.emit(Instr::StoreI64(var.clone()));
 
+                // Hoist loop-invariant subexpressions into a preheader before
+                // marking the loop's back-edge target.
+                let body = self.hoist_loop_invariants(body, &[var.as_str()])?;
+
                 let loop_start = self.here();
+                // Cooperative safepoint: every back-edge into the loop (including
+                // via `continue`) lands here before re-checking the condition
+                // (Issue chunk421-3).
+                self.emit(Instr::SafePoint);
 
                 // Push loop context for break/continue
                 let mut loop_ctx = LoopContext {
@@ -523,47 +1129,81 @@ impl CoreCompiler<'_> {
                     continue_patches: Vec::new(),
                 };
 
-                // Check loop condition based on step sign:
-                // If step > 0: continue while var <= stop (exit when var > stop)
-                // If step < 0: continue while var >= stop (exit when var < stop)
-                // We check: (step > 0 && var > stop) || (step < 0 && var < stop)
-
-                // Check if step > 0
-                self.emit(Instr::LoadI64(step_var.clone()));
-                self.emit(Instr::PushI64(0));
-                self.emit(Instr::GtI64);
-                let j_positive = self.here();
-                self.emit(Instr::JumpIfZero(usize::MAX)); // jump to negative check if step <= 0
-
-                // Step is positive: check var > stop
-                self.emit(Instr::LoadI64(var.clone()));
-                self.emit(Instr::LoadI64(stop_var.clone()));
-                self.emit(Instr::GtI64);
-                let j_exit_pos = self.here();
-                self.emit(Instr::JumpIfZero(usize::MAX)); // continue if var <= stop
-                let j_to_exit1 = self.here();
-                self.emit(Instr::Jump(usize::MAX)); // exit loop
-                loop_ctx.exit_patches.push(j_to_exit1);
-
-                // Step is negative: check var < stop
-                let negative_check = self.here();
-                self.patch_jump(j_positive, negative_check);
-                self.emit(Instr::LoadI64(var.clone()));
-                self.emit(Instr::LoadI64(stop_var.clone()));
-                self.emit(Instr::LtI64);
-                let j_exit_neg = self.here();
-                self.emit(Instr::JumpIfZero(usize::MAX)); // continue if var >= stop
-                let j_to_exit2 = self.here();
-                self.emit(Instr::Jump(usize::MAX)); // exit loop
-                loop_ctx.exit_patches.push(j_to_exit2);
-
-                let body_start = self.here();
-                self.patch_jump(j_exit_pos, body_start);
-                self.patch_jump(j_exit_neg, body_start);
+                match step_const {
+                    Some(step_c) if step_c > 0 => {
+                        // Literal positive step: only the `var > stop` exit test is
+                        // reachable, so skip the runtime sign test and the dead
+                        // negative-step branch entirely.
+                        self.emit(Instr::LoadI64(var.clone()));
+                        self.emit(Instr::LoadI64(stop_var.clone()));
+                        self.emit(Instr::GtI64);
+                        let j_exit = self.here();
+                        self.emit(Instr::JumpIfZero(usize::MAX)); // continue if var <= stop
+                        let j_to_exit = self.here();
+                        self.emit(Instr::Jump(usize::MAX)); // exit loop
+                        loop_ctx.exit_patches.push(j_to_exit);
+                        let body_start = self.here();
+                        self.patch_jump(j_exit, body_start);
+                    }
+                    Some(step_c) if step_c < 0 => {
+                        // Literal negative step: only the `var < stop` exit test is
+                        // reachable.
+                        self.emit(Instr::LoadI64(var.clone()));
+                        self.emit(Instr::LoadI64(stop_var.clone()));
+                        self.emit(Instr::LtI64);
+                        let j_exit = self.here();
+                        self.emit(Instr::JumpIfZero(usize::MAX)); // continue if var >= stop
+                        let j_to_exit = self.here();
+                        self.emit(Instr::Jump(usize::MAX)); // exit loop
+                        loop_ctx.exit_patches.push(j_to_exit);
+                        let body_start = self.here();
+                        self.patch_jump(j_exit, body_start);
+                    }
+                    _ => {
+                        // Step sign unknown at compile time: check loop condition
+                        // based on step sign.
+                        // If step > 0: continue while var <= stop (exit when var > stop)
+                        // If step < 0: continue while var >= stop (exit when var < stop)
+                        // We check: (step > 0 && var > stop) || (step < 0 && var < stop)
+
+                        // Check if step > 0
+                        self.emit(Instr::LoadI64(step_var.clone()));
+                        self.emit(Instr::PushI64(0));
+                        self.emit(Instr::GtI64);
+                        let j_positive = self.here();
+                        self.emit(Instr::JumpIfZero(usize::MAX)); // jump to negative check if step <= 0
+
+                        // Step is positive: check var > stop
+                        self.emit(Instr::LoadI64(var.clone()));
+                        self.emit(Instr::LoadI64(stop_var.clone()));
+                        self.emit(Instr::GtI64);
+                        let j_exit_pos = self.here();
+                        self.emit(Instr::JumpIfZero(usize::MAX)); // continue if var <= stop
+                        let j_to_exit1 = self.here();
+                        self.emit(Instr::Jump(usize::MAX)); // exit loop
+                        loop_ctx.exit_patches.push(j_to_exit1);
+
+                        // Step is negative: check var < stop
+                        let negative_check = self.here();
+                        self.patch_jump(j_positive, negative_check);
+                        self.emit(Instr::LoadI64(var.clone()));
+                        self.emit(Instr::LoadI64(stop_var.clone()));
+                        self.emit(Instr::LtI64);
+                        let j_exit_neg = self.here();
+                        self.emit(Instr::JumpIfZero(usize::MAX)); // continue if var >= stop
+                        let j_to_exit2 = self.here();
+                        self.emit(Instr::Jump(usize::MAX)); // exit loop
+                        loop_ctx.exit_patches.push(j_to_exit2);
+
+                        let body_start = self.here();
+                        self.patch_jump(j_exit_pos, body_start);
+                        self.patch_jump(j_exit_neg, body_start);
+                    }
+                }
 
                 // Compile body with loop context
                 self.loop_stack.push(loop_ctx);
-                self.compile_block(body)?;
+                self.compile_block(&body)?;
                 let loop_ctx = self.loop_stack.pop().unwrap();
 
                 let continue_target = self.here();
@@ -607,6 +1247,82 @@ impl CoreCompiler<'_> {
 
                 // Check if we should use Pure Julia iterate (for struct types)
                 let iterable_ty = self.infer_julia_type(iterable);
+
+                // Typed fast path: known homogeneous i64/f64 collections (ArrayOf,
+                // UnitRange, StepRange) are iterated by index instead of going
+                // through the generic tuple-unpacking iterate() protocol, so the
+                // loop variable is bound with a typed Store and the body's
+                // arithmetic gets the unboxed AddI64/LtI64 instructions (Issue
+                // chunk420-2).
+                if let Some(elem_ty) = typed_foreach_element_type(&iterable_ty) {
+                    let iterable_var = self.new_temp("iterable");
+                    let len_var = self.new_temp("len");
+                    let idx_var = self.new_temp("idx");
+                    self.compile_expr(iterable)?;
+                    self.emit(Instr::StoreAny(iterable_var.clone()));
+
+                    self.emit(Instr::LoadAny(iterable_var.clone()));
+                    self.emit(Instr::CallBuiltin(BuiltinId::Length, 1));
+                    self.emit(Instr::StoreI64(len_var.clone()));
+
+                    self.emit(Instr::PushI64(1));
+                    self.emit(Instr::StoreI64(idx_var.clone()));
+
+                    // Hoist loop-invariant subexpressions into a preheader before
+                    // marking the loop's back-edge target.
+                    let body = self.hoist_loop_invariants(body, &[var.as_str()])?;
+
+                    let loop_start = self.here();
+                    // Cooperative safepoint: every back-edge into the loop (including
+                    // via `continue`) lands here before re-checking the condition
+                    // (Issue chunk421-3).
+                    self.emit(Instr::SafePoint);
+                    self.emit(Instr::LoadI64(idx_var.clone()));
+                    self.emit(Instr::LoadI64(len_var.clone()));
+                    self.emit(Instr::GtI64);
+                    let j_continue = self.here();
+                    self.emit(Instr::JumpIfZero(usize::MAX));
+                    let j_exit = self.here();
+                    self.emit(Instr::Jump(usize::MAX));
+
+                    let body_start = self.here();
+                    self.patch_jump(j_continue, body_start);
+
+                    self.emit(Instr::LoadAny(iterable_var.clone()));
+                    self.emit(Instr::LoadI64(idx_var.clone()));
+                    self.emit(Instr::IndexLoad(1));
+                    self.emit(match elem_ty {
+                        ValueType::F64 => Instr::StoreF64(var.clone()),
+                        _ => Instr::StoreI64(var.clone()),
+                    });
+                    self.locals.insert(var.clone(), elem_ty);
+
+                    let loop_ctx = LoopContext {
+                        exit_patches: vec![j_exit],
+                        continue_patches: Vec::new(),
+                    };
+                    self.loop_stack.push(loop_ctx);
+                    self.compile_block(&body)?;
+                    let loop_ctx = self.loop_stack.pop().unwrap();
+
+                    let continue_target = self.here();
+                    self.emit(Instr::LoadI64(idx_var.clone()));
+                    self.emit(Instr::PushI64(1));
+                    self.emit(Instr::AddI64);
+                    self.emit(Instr::StoreI64(idx_var.clone()));
+                    self.emit(Instr::Jump(loop_start));
+
+                    let exit = self.here();
+                    for patch_pos in loop_ctx.exit_patches {
+                        self.patch_jump(patch_pos, exit);
+                    }
+                    for patch_pos in loop_ctx.continue_patches {
+                        self.patch_jump(patch_pos, continue_target);
+                    }
+
+                    return Ok(());
+                }
+
                 let use_pure_julia_iterate = self.should_use_pure_julia_iterate(&iterable_ty);
 
                 // Store the iterable
@@ -645,6 +1361,10 @@ impl CoreCompiler<'_> {
                 self.emit(Instr::TupleFirst); // Get element
 
                 let loop_start = self.here();
+                // Cooperative safepoint: every back-edge into the loop (including
+                // via `continue`) lands here before re-binding the loop variable
+                // (Issue chunk421-3).
+                self.emit(Instr::SafePoint);
 
                 // Store element in loop variable
                 self.emit(Instr::StoreAny(var.clone()));
@@ -762,6 +1482,10 @@ impl CoreCompiler<'_> {
                 self.emit(Instr::StoreAny(elem_var.clone()));
 
                 let loop_start = self.here();
+                // Cooperative safepoint: every back-edge into the loop (including
+                // via `continue`) lands here before re-destructuring the element
+                // (Issue chunk421-3).
+                self.emit(Instr::SafePoint);
 
                 // Destructure element tuple into individual variables
                 // Element is already a tuple like (1, 10), extract each component
@@ -836,7 +1560,15 @@ impl CoreCompiler<'_> {
             Stmt::While {
                 condition, body, ..
             } => {
+                // Hoist loop-invariant subexpressions into a preheader before
+                // marking the loop's back-edge target.
+                let body = self.hoist_loop_invariants(body, &[])?;
+
                 let loop_start = self.here();
+                // Cooperative safepoint: every back-edge into the loop (including
+                // via `continue`, which jumps straight back to `loop_start`) lands
+                // here before re-checking the condition (Issue chunk421-3).
+                self.emit(Instr::SafePoint);
 
                 // Push loop context for break/continue
                 let mut loop_ctx = LoopContext {
@@ -852,7 +1584,7 @@ impl CoreCompiler<'_> {
 
                 // Compile body with loop context
                 self.loop_stack.push(loop_ctx);
-                self.compile_block(body)?;
+                self.compile_block(&body)?;
                 let loop_ctx = self.loop_stack.pop().unwrap();
 
                 self.emit(Instr::Jump(loop_start));
@@ -932,6 +1664,7 @@ impl CoreCompiler<'_> {
                             ValueType::Module => Instr::ReturnAny,
                             ValueType::BigInt => Instr::ReturnAny,
                             ValueType::BigFloat => Instr::ReturnAny,
+                            ValueType::Float128 => Instr::ReturnAny,
                             ValueType::IO => Instr::ReturnAny,
                             ValueType::Function => Instr::ReturnAny,
                             ValueType::I8 | ValueType::I16 | ValueType::I32 | ValueType::I128 => {
@@ -1043,6 +1776,12 @@ impl CoreCompiler<'_> {
                 Ok(())
             }
             Stmt::Expr { expr, .. } => {
+                // The result is discarded, so a statically pure expression
+                // (Issue chunk421-2) contributes nothing observable - skip
+                // compiling it entirely instead of computing and discarding it.
+                if self.expr_is_pure(expr) {
+                    return Ok(());
+                }
                 let ty = self.compile_expr(expr)?;
                 // Pop unused value by storing to dummy variable
                 let dummy = self.new_temp("discard");
@@ -1066,21 +1805,58 @@ impl CoreCompiler<'_> {
                     ValueType::Any => self.emit(Instr::StoreAny(dummy)),
                     ValueType::BigInt => self.emit(Instr::StoreAny(dummy)),
                     ValueType::BigFloat => self.emit(Instr::StoreAny(dummy)),
+                    ValueType::Float128 => self.emit(Instr::StoreAny(dummy)),
                     ValueType::IO => self.emit(Instr::StoreAny(dummy)),
                     ValueType::Function => self.emit(Instr::StoreAny(dummy)),
-                    // Narrow integer types use StoreAny which dispatches to locals_narrow_int
-                    // at runtime, preserving the exact Value type (e.g. I8(42), U32(99)).
-                    ValueType::I8 | ValueType::I16 | ValueType::I32 | ValueType::I128 => {
-                        self.emit(Instr::StoreAny(dummy))
-                    }
-                    ValueType::U8
-                    | ValueType::U16
-                    | ValueType::U32
-                    | ValueType::U64
-                    | ValueType::U128 => self.emit(Instr::StoreAny(dummy)),
+                    // I128/U128 don't fit a u64 packed slot, so they still go
+                    // through StoreAny, which dispatches to locals_narrow_int
+                    // at runtime, preserving the exact Value type.
+                    ValueType::I128 | ValueType::U128 => self.emit(Instr::StoreAny(dummy)),
+                    // Other narrow integer types use the packed slot path
+                    // (Issue chunk421-5), preserving the exact Value type
+                    // (e.g. I8(42), U32(99)) without a Value allocation.
+                    ValueType::I8 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 8,
+                        signed: true,
+                    }),
+                    ValueType::I16 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 16,
+                        signed: true,
+                    }),
+                    ValueType::I32 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 32,
+                        signed: true,
+                    }),
+                    ValueType::U8 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 8,
+                        signed: false,
+                    }),
+                    ValueType::U16 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 16,
+                        signed: false,
+                    }),
+                    ValueType::U32 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 32,
+                        signed: false,
+                    }),
+                    ValueType::U64 => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 64,
+                        signed: false,
+                    }),
                     ValueType::F32 => self.emit(Instr::StoreF32(dummy)),
                     ValueType::F16 => self.emit(Instr::StoreF16(dummy)),
-                    ValueType::Bool => self.emit(Instr::StoreAny(dummy)),
+                    ValueType::Bool => self.emit(Instr::StoreNarrow {
+                        name: dummy,
+                        width: 1,
+                        signed: false,
+                    }),
                     // Macro system types
                     ValueType::Symbol
                     | ValueType::Expr
@@ -1308,9 +2084,11 @@ impl CoreCompiler<'_> {
                         let mut field_idx = None;
                         let mut field_ty = ValueType::F64;
                         let mut is_mutable = false;
+                        let mut struct_name = String::new();
 
-                        for (_, struct_info) in self.shared_ctx.struct_table.iter() {
+                        for (name, struct_info) in self.shared_ctx.struct_table.iter() {
                             if struct_info.type_id == type_id {
+                                struct_name = name.clone();
                                 is_mutable = struct_info.is_mutable;
                                 for (idx, (field_name, fty)) in
                                     struct_info.fields.iter().enumerate()
@@ -1325,6 +2103,27 @@ impl CoreCompiler<'_> {
                             }
                         }
 
+                        // User-defined setproperty!(::T, ::Symbol, ::Any) overrides take
+                        // priority over direct field mutation, mirroring the getproperty
+                        // dispatch added to compile_field_access.
+                        if !struct_name.is_empty() {
+                            if let Some(table) = self.method_tables.get("setproperty!") {
+                                let arg_types = vec![
+                                    JuliaType::Struct(struct_name.clone()),
+                                    JuliaType::Symbol,
+                                    JuliaType::Any,
+                                ];
+                                if let Ok(method) = table.dispatch(&arg_types) {
+                                    self.emit(Instr::LoadStruct(object.clone()));
+                                    self.emit(Instr::PushSymbol(field.to_string()));
+                                    self.compile_expr(value)?;
+                                    self.emit(Instr::Call(method.global_index, 3));
+                                    self.emit(Instr::Pop);
+                                    return Ok(());
+                                }
+                            }
+                        }
+
                         if !is_mutable {
                             return err("Cannot assign to field of immutable struct".to_string());
                         }
@@ -1469,13 +2268,47 @@ impl CoreCompiler<'_> {
                         .closure_captures
                         .insert(qualified_name.clone(), free_vars.clone());
 
+                    // A captured variable that the closure body itself reassigns needs to be
+                    // shared by reference, not copied by value, so the write is visible to the
+                    // enclosing scope and any sibling closures over the same variable (Issue
+                    // chunk421-1). Promote such names to a boxed cell here, at the point the
+                    // closure is created, so assignments to them from here on (in this scope
+                    // or the closure body) go through the box; earlier reads/writes in this
+                    // scope are unaffected. A capture also needs boxing if it's already boxed
+                    // from an earlier sibling closure (so this closure observes that closure's
+                    // writes too) or if the enclosing function body reassigns it outside any
+                    // closure (so the enclosing scope's writes are observed here).
+                    let mut mutated_in_closure = HashSet::new();
+                    collect_assigned_vars(&func.body, &mut mutated_in_closure);
+                    let mut capture_names = Vec::new();
+                    let mut boxed_capture_names = Vec::new();
+                    for name in free_vars {
+                        if mutated_in_closure.contains(&name)
+                            || self.boxed_locals.contains(&name)
+                            || self.enclosing_assigned_vars.contains(&name)
+                        {
+                            if !self.boxed_locals.contains(&name) {
+                                self.emit(Instr::PromoteToBoxed(name.clone()));
+                                self.boxed_locals.insert(name.clone());
+                            }
+                            boxed_capture_names.push(name);
+                        } else {
+                            capture_names.push(name);
+                        }
+                    }
+                    if !boxed_capture_names.is_empty() {
+                        self.shared_ctx
+                            .boxed_closure_captures
+                            .insert(qualified_name.clone(), boxed_capture_names.iter().cloned().collect());
+                    }
+
                     // Emit CreateClosure with the QUALIFIED function name
                     // FunctionInfo.name also uses the qualified name for nested functions,
                     // so the runtime lookup will find the correct function (Issue #1743)
-                    let capture_names: Vec<String> = free_vars.into_iter().collect();
                     self.emit(Instr::CreateClosure {
                         func_name: qualified_name,
                         capture_names,
+                        boxed_capture_names,
                     });
                     // Store the closure in the local scope using the ORIGINAL name
                     // (so the local variable `inner` can be accessed normally in user code)
@@ -1507,8 +2340,12 @@ impl CoreCompiler<'_> {
             Stmt::Label { name, .. } => {
                 // Record the label position for @goto to jump to.
                 // The label marks the current instruction position.
+                if self.label_positions.contains_key(name) {
+                    return err(format!("label '{}' defined multiple times", name));
+                }
                 let position = self.here();
-                self.label_positions.insert(name.clone(), position);
+                self.label_positions
+                    .insert(name.clone(), (position, self.try_depth));
                 Ok(())
             }
             Stmt::Goto { name, span } => {
@@ -1517,7 +2354,8 @@ impl CoreCompiler<'_> {
                 // after all labels are collected.
                 let patch_position = self.here();
                 self.emit(Instr::Jump(usize::MAX));
-                self.goto_patches.push((patch_position, name.clone()));
+                self.goto_patches
+                    .push((patch_position, name.clone(), self.try_depth));
                 // Note: The patch will be applied after compilation by patch_goto_jumps()
                 let _ = span; // Span is kept for potential future error reporting
                 Ok(())
@@ -1531,6 +2369,13 @@ impl CoreCompiler<'_> {
                         .global_types
                         .insert(member.name.clone(), ValueType::Enum);
                 }
+                // Pick a membership-check strategy for EnumName(x) integer conversion,
+                // consulted when compiling a call to the enum's type name.
+                let values: Vec<i64> = enum_def.members.iter().map(|m| m.value).collect();
+                self.shared_ctx.enum_checks.insert(
+                    enum_def.name.clone(),
+                    EnumMembershipCheck::from_values(&values),
+                );
                 Ok(())
             }
         }
@@ -1539,10 +2384,19 @@ impl CoreCompiler<'_> {
     // ==========================================================================
     // Iteration Protocol Helpers
     // ==========================================================================
+    //
+    // `emit_iterate_call_1/2` below go through `MethodTable::dispatch`, so an
+    // `iterate` method with an `@nospecialize`d parameter (e.g. the state
+    // argument) is matched the same way regardless of the collection's static
+    // type at a given loop, rather than needing a matching overload per
+    // concrete type (Issue chunk422-3).
 
     /// Check if we should use Pure Julia iterate for this type.
     /// Returns true for struct types (custom iterators), false for builtin types.
-    fn should_use_pure_julia_iterate(&self, ty: &JuliaType) -> bool {
+    ///
+    /// `pub(super)` so `compile/expr`'s `ApplyIterate` splat lowering can
+    /// reuse the same builtin-vs-Pure-Julia decision (Issue chunk422-1).
+    pub(super) fn should_use_pure_julia_iterate(&self, ty: &JuliaType) -> bool {
         if let Some(result) = static_iterate_strategy(ty) {
             return result;
         }
@@ -1556,6 +2410,13 @@ impl CoreCompiler<'_> {
 
     /// Emit a call to iterate(collection) - 1 argument version.
     /// Looks up the iterate method from method tables and emits a Call instruction.
+    ///
+    /// When `ty` is `Any`, falls back to scanning the registered struct-typed
+    /// `iterate` methods; if exactly one candidate exists the dispatch was
+    /// never ambiguous, so a monomorphic `Call` is emitted instead of
+    /// `IterateDynamic` (Issue chunk422-2). `IterateDynamic` is kept only for
+    /// the genuinely-ambiguous multi-candidate case (e.g. `zip`'s Any-typed
+    /// result, which could be any of several `ZipN` structs at runtime).
     fn emit_iterate_call_1(&mut self, ty: &JuliaType) -> CResult<()> {
         if let Some(table) = self.method_tables.get("iterate") {
             let arg_types = vec![ty.clone()];
@@ -1585,6 +2446,13 @@ impl CoreCompiler<'_> {
                         }
                     })
                     .collect();
+                // Issue chunk422-2: a single candidate is not actually ambiguous —
+                // there is exactly one concrete struct type `iterate` could dispatch
+                // to, so skip the runtime candidate scan and call it directly.
+                if let [(global_index, _)] = candidates.as_slice() {
+                    self.emit(Instr::Call(*global_index, 1));
+                    return Ok(());
+                }
                 if !candidates.is_empty() {
                     self.emit(Instr::IterateDynamic(1, candidates));
                     return Ok(());
@@ -1598,6 +2466,9 @@ impl CoreCompiler<'_> {
 
     /// Emit a call to iterate(collection, state) - 2 argument version.
     /// Looks up the iterate method from method tables and emits a Call instruction.
+    ///
+    /// See `emit_iterate_call_1`'s doc comment for the single-candidate
+    /// monomorphic-`Call` narrowing (Issue chunk422-2).
     fn emit_iterate_call_2(&mut self, ty: &JuliaType) -> CResult<()> {
         if let Some(table) = self.method_tables.get("iterate") {
             // Try to find method with (collection_type, Int64) signature
@@ -1632,6 +2503,12 @@ impl CoreCompiler<'_> {
                         }
                     })
                     .collect();
+                // Issue chunk422-2: same reasoning as `emit_iterate_call_1` — one
+                // candidate means the dispatch was never actually ambiguous.
+                if let [(global_index, _)] = candidates.as_slice() {
+                    self.emit(Instr::Call(*global_index, 2));
+                    return Ok(());
+                }
                 if !candidates.is_empty() {
                     self.emit(Instr::IterateDynamic(2, candidates));
                     return Ok(());
@@ -1642,6 +2519,33 @@ impl CoreCompiler<'_> {
         self.emit(Instr::IterateNext);
         Ok(())
     }
+
+    /// Resolve the global method index for the 1-argument `iterate(collection)`
+    /// form without emitting anything (Issue chunk422-1), for `Instr::ApplyIterate`
+    /// to call directly at runtime. Mirrors `emit_iterate_call_1`'s dispatch
+    /// order, minus the `IterateDynamic` runtime-struct-dispatch fallback:
+    /// `ApplyIterate` resolves once at compile time per splatted argument, so
+    /// `None` here means "fall back to the builtin `IterateFirst` protocol".
+    pub(super) fn resolve_iterate_method_1(&self, ty: &JuliaType) -> Option<usize> {
+        let table = self.method_tables.get("iterate")?;
+        table
+            .dispatch(&[ty.clone()])
+            .or_else(|_| table.dispatch(&[JuliaType::Any]))
+            .ok()
+            .map(|method| method.global_index)
+    }
+
+    /// Resolve the global method index for the 2-argument `iterate(collection, state)`
+    /// form; see `resolve_iterate_method_1` (Issue chunk422-1).
+    pub(super) fn resolve_iterate_method_2(&self, ty: &JuliaType) -> Option<usize> {
+        let table = self.method_tables.get("iterate")?;
+        table
+            .dispatch(&[ty.clone(), JuliaType::Int64])
+            .or_else(|_| table.dispatch(&[ty.clone(), JuliaType::Any]))
+            .or_else(|_| table.dispatch(&[JuliaType::Any, JuliaType::Any]))
+            .ok()
+            .map(|method| method.global_index)
+    }
 }
 
 #[cfg(test)]
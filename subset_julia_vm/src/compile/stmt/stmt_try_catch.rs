@@ -3,8 +3,8 @@ use crate::vm::instr::Instr;
 use crate::vm::ValueType;
 
 use super::super::type_helpers::join_type;
-use super::super::CoreCompiler;
 use super::super::CResult;
+use super::super::CoreCompiler;
 
 impl CoreCompiler<'_> {
     pub(super) fn compile_try_stmt(&mut self, stmt: &Stmt) -> CResult<Option<()>> {
@@ -36,6 +36,10 @@ impl CoreCompiler<'_> {
         let handler_pos = self.here();
         self.emit(Instr::PushHandler(None, None));
 
+        // A @goto inside the try/catch/else handler region cannot jump to a
+        // @label outside it (or vice versa), since the handler stack would be
+        // left unbalanced. try_depth lets patch_goto_jumps() reject that.
+        self.try_depth += 1;
         self.compile_block(try_block)?;
         self.emit(Instr::PopHandler);
 
@@ -89,10 +93,13 @@ impl CoreCompiler<'_> {
                 // commit to either type alone â€” use join_type() to widen to Any when
                 // the two paths disagree. (Issue #3044)
                 let catch_ty = self.locals.get(name).cloned().unwrap_or(ValueType::Any);
-                self.locals.insert(name.clone(), join_type(try_ty, &catch_ty));
+                self.locals
+                    .insert(name.clone(), join_type(try_ty, &catch_ty));
             }
         }
 
+        self.try_depth -= 1;
+
         let finally_start = self.here();
         if let Some(finally_block) = finally_block {
             self.compile_block(finally_block)?;
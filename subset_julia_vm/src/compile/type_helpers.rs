@@ -199,6 +199,7 @@ pub(super) fn julia_type_to_value_type(jt: &JuliaType) -> ValueType {
         JuliaType::Float32 => ValueType::F32,
         JuliaType::Float64 => ValueType::F64,
         JuliaType::BigFloat => ValueType::BigFloat,
+        JuliaType::Float128 => ValueType::Float128,
         // Complex is now a Pure Julia struct - falls through to Struct case
         // String/Char
         JuliaType::String | JuliaType::AbstractString => ValueType::Str,
@@ -256,6 +257,7 @@ pub(super) fn julia_type_to_value_type(jt: &JuliaType) -> ValueType {
                     "Float32",
                     "Float64",
                     "BigFloat",
+                    "Float128",
                     "Bool",
                     "String",
                     "Char",
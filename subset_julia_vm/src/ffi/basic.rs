@@ -212,6 +212,8 @@ pub extern "C" fn compile_and_run_auto(src_ptr: *const c_char, seed: u64) -> f64
         Ok(Value::StructRef(_)) => f64::NAN, // StructRef can't be returned as f64
         Ok(Value::SliceAll) => f64::NAN,
         Ok(Value::Rng(_)) => f64::NAN, // RNG can't be returned as f64
+        Ok(Value::Task(_)) => f64::NAN, // Task can't be returned as f64
+        Ok(Value::VaList(_)) => f64::NAN, // VaList can't be returned as f64
         Ok(Value::Tuple(_)) => f64::NAN, // Tuple can't be returned as f64
         Ok(Value::NamedTuple(_)) => f64::NAN, // NamedTuple can't be returned as f64
         Ok(Value::Dict(_)) => f64::NAN, // Dict can't be returned as f64
@@ -225,6 +227,14 @@ pub extern "C" fn compile_and_run_auto(src_ptr: *const c_char, seed: u64) -> f64
                 _ => f64::NAN,
             }
         }
+        Ok(Value::Boxed(cell)) => {
+            // Unwrap the boxed cell and return its numeric value
+            match &*cell.borrow() {
+                Value::I64(x) => *x as f64,
+                Value::F64(x) => *x,
+                _ => f64::NAN,
+            }
+        }
         Ok(Value::Generator(_)) => f64::NAN, // Generator can't be returned as f64
         Ok(Value::Char(_)) => f64::NAN,      // Char cannot be returned as f64
         Ok(Value::DataType(_)) => f64::NAN,  // DataType cannot be returned as f64
@@ -318,6 +328,8 @@ pub extern "C" fn compile_and_run_with_output(src_ptr: *const c_char, seed: u64)
         Ok(Value::StructRef(_)) => output.push_str("[result] <struct ref>\n"),
         Ok(Value::SliceAll) => {}
         Ok(Value::Rng(_)) => output.push_str("[result] <RNG>\n"),
+        Ok(Value::Task(_)) => output.push_str("[result] <Task>\n"),
+        Ok(Value::VaList(_)) => output.push_str("[result] <VaList>\n"),
         Ok(Value::Tuple(t)) => output.push_str(&format!("[result] ({:?})\n", t.elements)),
         Ok(Value::NamedTuple(nt)) => {
             output.push_str(&format!("[result] <NamedTuple {:?}>\n", nt.names))
@@ -339,6 +351,7 @@ pub extern "C" fn compile_and_run_with_output(src_ptr: *const c_char, seed: u64)
             }
         }
         Ok(Value::Ref(inner)) => output.push_str(&format!("[result] Ref({:?})\n", inner)),
+        Ok(Value::Boxed(cell)) => output.push_str(&format!("[result] {:?}\n", cell.borrow())),
         Ok(Value::Generator(_)) => output.push_str("[result] <Generator>\n"),
         Ok(Value::Char(c)) => output.push_str(&format!("[result] '{}'\n", c)),
         Ok(Value::DataType(jt)) => output.push_str(&format!("[result] {}\n", jt)),
@@ -0,0 +1,45 @@
+//! Precompilation cache FFI functions.
+//!
+//! These let embedders (CLI, Swift, etc.) load a Julia source file through
+//! the on-disk bytecode cache instead of always re-parsing and re-lowering.
+
+// FFI functions intentionally take raw pointers and are called from C/Swift code.
+// The caller is responsible for ensuring pointer validity.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+use crate::bytecode;
+
+/// Load the Core IR for the Julia source file at `path_ptr`, using the
+/// on-disk precompilation cache when possible.
+/// Returns a JSON-serialized Core IR `Program`, or null on failure (missing
+/// file, parse/lowering error, or invalid UTF-8 path).
+/// The result must be freed with `free_string`.
+#[no_mangle]
+pub extern "C" fn bytecode_load_or_compile(path_ptr: *const c_char) -> *mut c_char {
+    if path_ptr.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let path = match unsafe { CStr::from_ptr(path_ptr) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let program = match bytecode::load_or_compile(path) {
+        Ok(p) => p,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let json = match serde_json::to_string(&program) {
+        Ok(j) => j,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    match CString::new(json) {
+        Ok(cstr) => cstr.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
@@ -153,10 +153,13 @@ pub fn format_value(value: &Value) -> String {
         Value::Nothing => "nothing".to_string(),
         Value::Missing => "missing".to_string(),
         Value::Rng(_) => "Random.MersenneTwister(...)".to_string(),
+        Value::Task(_) => "Task(...)".to_string(),
+        Value::VaList(_) => "(...)".to_string(),
         Value::Struct(s) => format_struct_instance(s),
         Value::StructRef(_) => "<struct ref>".to_string(), // Should be resolved by VM before formatting
         Value::SliceAll => ":".to_string(),
         Value::Ref(inner) => format!("Ref({})", format_value(inner)),
+        Value::Boxed(cell) => format_value(&cell.borrow()),
         Value::Generator(_) => "Generator(...)".to_string(),
         Value::Char(c) => format!("'{}'", c),
         Value::DataType(jt) => jt.to_string(), // DataType displays as type name
@@ -0,0 +1,65 @@
+//! Load timing instrumentation FFI functions.
+//!
+//! Lets embedders toggle the opt-in module load timing breakdown (see
+//! [`crate::load_timing`]) and pull a JSON snapshot to render or log.
+
+// FFI functions intentionally take raw pointers and are called from C/Swift code.
+// The caller is responsible for ensuring pointer validity.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+
+use serde::Serialize;
+
+use crate::load_timing;
+
+#[derive(Serialize)]
+struct ModuleTimingJson {
+    module: String,
+    parse_ms: f64,
+    lower_ms: f64,
+    execute_ms: f64,
+    total_ms: f64,
+}
+
+/// Enable (`1`) or disable (`0`) load timing instrumentation.
+#[no_mangle]
+pub extern "C" fn load_timing_set_enabled(enabled: i32) {
+    load_timing::set_enabled(enabled != 0);
+}
+
+/// Clear all accumulated load timings.
+#[no_mangle]
+pub extern "C" fn load_timing_reset() {
+    load_timing::reset();
+}
+
+/// Snapshot the accumulated load timings as a JSON array of
+/// `{"module", "parse_ms", "lower_ms", "execute_ms", "total_ms"}` objects,
+/// in the order each module was first timed. Returns null on failure.
+/// The result must be freed with `free_string`.
+#[no_mangle]
+pub extern "C" fn load_timing_report() -> *mut c_char {
+    let entries: Vec<ModuleTimingJson> = load_timing::report()
+        .entries()
+        .iter()
+        .map(|(module, timings)| ModuleTimingJson {
+            module: module.clone(),
+            parse_ms: timings.parse.as_secs_f64() * 1000.0,
+            lower_ms: timings.lower.as_secs_f64() * 1000.0,
+            execute_ms: timings.execute.as_secs_f64() * 1000.0,
+            total_ms: timings.total().as_secs_f64() * 1000.0,
+        })
+        .collect();
+
+    let json = match serde_json::to_string(&entries) {
+        Ok(j) => j,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    match CString::new(json) {
+        Ok(cstr) => cstr.into_raw(),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
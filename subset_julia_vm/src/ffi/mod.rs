@@ -4,11 +4,15 @@
 //! These functions are exposed as `extern "C"` and can be called from Swift, C, etc.
 
 mod basic;
+mod bytecode_ffi;
 mod demo;
 mod detailed;
 mod error;
 mod format;
+mod load_timing_ffi;
+mod native_ffi;
 mod repl_ffi;
+mod runtime_ffi;
 #[cfg(not(target_arch = "wasm32"))]
 mod unicode_ffi;
 
@@ -19,6 +23,8 @@ pub use basic::{
     vm_reset_cancel,
 };
 
+pub use bytecode_ffi::bytecode_load_or_compile;
+
 pub use demo::subset_julia_vm_demo;
 
 pub use detailed::{compile_and_run_detailed, compile_and_run_streaming, OutputCallback};
@@ -27,9 +33,19 @@ pub use error::{free_execution_result, CError, CErrorKind, CExecutionResult, CSp
 
 pub use format::{format_struct_instance, format_value};
 
+pub use load_timing_ffi::{load_timing_report, load_timing_reset, load_timing_set_enabled};
+
+pub use native_ffi::{register_native, NativeFn};
+
+pub(crate) use native_ffi::call_native;
+
 pub use repl_ffi::{
-    free_repl_result, is_expression_complete, repl_session_eval, repl_session_free,
-    repl_session_new, repl_session_reset, split_expressions, CREPLResult,
+    free_repl_result, is_expression_complete, repl_complete, repl_session_eval,
+    repl_session_free, repl_session_new, repl_session_reset, split_expressions, CREPLResult,
+};
+
+pub use runtime_ffi::{
+    vm_runtime_cancel, vm_runtime_free, vm_runtime_new, vm_runtime_poll, vm_runtime_submit,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
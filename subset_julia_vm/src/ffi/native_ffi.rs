@@ -0,0 +1,83 @@
+//! Native host-function bridge (ccall-style).
+//!
+//! Unlike the rest of `ffi`, this is not a C ABI surface: `Value` carries
+//! `Rc`/`RefCell` internals that aren't FFI-safe, so there is no sensible
+//! `extern "C"` signature for it. This module is the Rust-embedder
+//! equivalent instead — a host binary linking this crate directly can
+//! register a Rust function under a name, then call it from Julia source
+//! via `ccall_native("name", args...)`.
+
+use std::collections::HashMap;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use once_cell::sync::Lazy;
+use std::sync::RwLock;
+
+use crate::vm::{Value, VmError};
+
+/// A Rust function registered as a native host callback.
+///
+/// Takes the marshalled argument `Value`s and returns either a result
+/// `Value` or an error message to surface as a `VmError::NativeCallError`.
+pub type NativeFn = fn(&[Value]) -> Result<Value, String>;
+
+struct NativeEntry {
+    func: NativeFn,
+    arity: usize,
+}
+
+static NATIVE_FUNCTIONS: Lazy<RwLock<HashMap<String, NativeEntry>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Register a Rust function so it can be called from Julia source as
+/// `ccall_native("name", args...)`.
+///
+/// `arity` is the exact number of arguments the function expects; a call
+/// site with a different argument count fails with `NativeCallError`
+/// rather than invoking `func`. Registering the same `name` again replaces
+/// the previous entry.
+pub fn register_native(name: &str, func: NativeFn, arity: usize) {
+    NATIVE_FUNCTIONS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .insert(name.to_string(), NativeEntry { func, arity });
+}
+
+/// Look up and invoke a registered native function, translating arity
+/// mismatches, missing registrations, and panics inside `func` into
+/// `VmError::NativeCallError`.
+pub(crate) fn call_native(name: &str, args: &[Value]) -> Result<Value, VmError> {
+    let (func, arity) = {
+        let registry = NATIVE_FUNCTIONS
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let entry = registry.get(name).ok_or_else(|| {
+            VmError::NativeCallError(format!("no native function registered as '{}'", name))
+        })?;
+        (entry.func, entry.arity)
+    };
+
+    if args.len() != arity {
+        return Err(VmError::NativeCallError(format!(
+            "native function '{}' expects {} argument(s), got {}",
+            name,
+            arity,
+            args.len()
+        )));
+    }
+
+    // `func` is a plain fn pointer and `args` is only ever read, so the
+    // interior mutability reachable through `Value` (e.g. `Value::Boxed`)
+    // can't leave the callback in an observably inconsistent state here.
+    match catch_unwind(AssertUnwindSafe(|| func(args))) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(msg)) => Err(VmError::NativeCallError(format!(
+            "native function '{}' failed: {}",
+            name, msg
+        ))),
+        Err(_) => Err(VmError::NativeCallError(format!(
+            "native function '{}' panicked",
+            name
+        ))),
+    }
+}
@@ -10,7 +10,7 @@ use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
 
 use super::format::format_value;
-use crate::repl::REPLSession;
+use crate::repl::{self, REPLSession};
 
 /// C-compatible REPL evaluation result
 #[repr(C)]
@@ -136,6 +136,35 @@ pub extern "C" fn free_repl_result(result: *mut CREPLResult) {
     }
 }
 
+/// Compute completions for `line` at byte offset `cursor`.
+/// Returns a JSON array of `{"text", "start", "end"}` objects (the
+/// replacement span as byte offsets into `line`), or null on error.
+/// The result must be freed with `free_string`.
+#[no_mangle]
+pub extern "C" fn repl_complete(
+    session: *mut REPLSession,
+    line: *const c_char,
+    cursor: usize,
+) -> *mut c_char {
+    if session.is_null() || line.is_null() {
+        return std::ptr::null_mut();
+    }
+    let line = match unsafe { CStr::from_ptr(line) }.to_str() {
+        Ok(s) => s,
+        Err(_) => return std::ptr::null_mut(),
+    };
+
+    let session = unsafe { &mut *session };
+    let completions = repl::complete(session, line, cursor);
+
+    match serde_json::to_string(&completions) {
+        Ok(json) => CString::new(json)
+            .map(|s| s.into_raw())
+            .unwrap_or(std::ptr::null_mut()),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
 /// Check if a Julia expression is complete or needs more input.
 /// Returns 1 if the expression is complete (can be evaluated),
 /// 0 if it appears incomplete (e.g., unclosed brackets, unfinished blocks).
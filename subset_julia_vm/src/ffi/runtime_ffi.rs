@@ -0,0 +1,88 @@
+//! Async runtime FFI functions.
+//!
+//! C ABI for submitting compile-and-run jobs to a [`VmRuntime`] worker pool
+//! and collecting results out of order, instead of blocking the caller for
+//! the duration of each job the way `compile_and_run`/`repl_session_eval` do.
+
+// FFI functions intentionally take raw pointers and are called from C/Swift code.
+// The caller is responsible for ensuring pointer validity.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use std::ffi::{CStr, CString};
+use std::os::raw::c_char;
+
+use super::repl_ffi::CREPLResult;
+use crate::runtime::VmRuntime;
+
+/// Create a new runtime with `worker_count` worker threads (clamped to at
+/// least 1), each with an independent REPL session seeded from `seed`.
+/// Returns an opaque pointer that must be freed with `vm_runtime_free`.
+#[no_mangle]
+pub extern "C" fn vm_runtime_new(worker_count: u32, seed: u64) -> *mut VmRuntime {
+    Box::into_raw(Box::new(VmRuntime::new(worker_count as usize, seed)))
+}
+
+/// Submit Julia source for evaluation, returning its task id immediately.
+/// Returns `u64::MAX` if `runtime` or `src` is null or not valid UTF-8.
+#[no_mangle]
+pub extern "C" fn vm_runtime_submit(runtime: *mut VmRuntime, src: *const c_char) -> u64 {
+    if runtime.is_null() || src.is_null() {
+        return u64::MAX;
+    }
+    let src = match unsafe { CStr::from_ptr(src) }.to_str() {
+        Ok(s) => s.to_string(),
+        Err(_) => return u64::MAX,
+    };
+
+    unsafe { &*runtime }.submit(src)
+}
+
+/// Non-blocking poll for a submitted task's result.
+/// Returns null if `runtime` is null or the task hasn't completed yet.
+/// The result must be freed with `free_repl_result`.
+#[no_mangle]
+pub extern "C" fn vm_runtime_poll(runtime: *mut VmRuntime, task_id: u64) -> *mut CREPLResult {
+    if runtime.is_null() {
+        return std::ptr::null_mut();
+    }
+    let outcome = match unsafe { &*runtime }.poll(task_id) {
+        Some(outcome) => outcome,
+        None => return std::ptr::null_mut(),
+    };
+
+    let result = CREPLResult {
+        success: outcome.success,
+        output: CString::new(outcome.output)
+            .map(|s| s.into_raw())
+            .unwrap_or(std::ptr::null_mut()),
+        value: outcome
+            .value
+            .and_then(|v| CString::new(v).ok())
+            .map(|s| s.into_raw())
+            .unwrap_or(std::ptr::null_mut()),
+        error: outcome
+            .error
+            .and_then(|e| CString::new(e).ok())
+            .map(|s| s.into_raw())
+            .unwrap_or(std::ptr::null_mut()),
+    };
+    Box::into_raw(Box::new(result))
+}
+
+/// Request cancellation of a single in-flight task, without tearing down
+/// the runtime or affecting any other task. A no-op if `runtime` is null,
+/// `task_id` is unknown, or the task already completed.
+#[no_mangle]
+pub extern "C" fn vm_runtime_cancel(runtime: *mut VmRuntime, task_id: u64) {
+    if !runtime.is_null() {
+        unsafe { &*runtime }.cancel(task_id);
+    }
+}
+
+/// Shut down the runtime, waiting for its workers to finish, and free it.
+#[no_mangle]
+pub extern "C" fn vm_runtime_free(runtime: *mut VmRuntime) {
+    if !runtime.is_null() {
+        unsafe { Box::from_raw(runtime) }.shutdown();
+    }
+}
@@ -231,6 +231,12 @@ pub struct TypedParam {
     /// For Vararg{T, N}: fixed argument count N. None = any count. (Issue #2525)
     #[serde(default)]
     pub vararg_count: Option<usize>,
+    /// True if this parameter was declared `@nospecialize(name)` inline in the
+    /// function signature. Such parameters are always treated as `ValueType::Any`,
+    /// skipping per-type specialization to reduce code growth on arguments that
+    /// are only forwarded, not computed on.
+    #[serde(default)]
+    pub nospecialize: bool,
     pub span: Span,
 }
 
@@ -242,6 +248,7 @@ impl TypedParam {
             type_annotation,
             is_varargs: false,
             vararg_count: None,
+            nospecialize: false,
             span,
         }
     }
@@ -253,6 +260,7 @@ impl TypedParam {
             type_annotation: None,
             is_varargs: false,
             vararg_count: None,
+            nospecialize: false,
             span,
         }
     }
@@ -265,6 +273,7 @@ impl TypedParam {
             type_annotation,
             is_varargs: true,
             vararg_count: None,
+            nospecialize: false,
             span,
         }
     }
@@ -281,6 +290,7 @@ impl TypedParam {
             type_annotation,
             is_varargs: true,
             vararg_count: Some(count),
+            nospecialize: false,
             span,
         }
     }
@@ -385,6 +395,57 @@ pub enum Stmt {
         value: Expr,
         span: Span,
     },
+    SubAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    MulAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    DivAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    /// ÷= (integer/floor division compound assignment)
+    FldAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    PowAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    BitAndAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    BitOrAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    /// ⊻= (bitwise xor compound assignment)
+    BitXorAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
+    /// .= (in-place broadcast assignment): `x .= y`
+    /// Unlike `Assign`, this does not rebind `var` to a freshly allocated array —
+    /// when `var` is an array-typed local, `compile_stmt` fuses this into an
+    /// element-wise store into the existing buffer.
+    BroadcastAssign {
+        var: String,
+        value: Expr,
+        span: Span,
+    },
     For {
         var: String,
         start: Expr,
@@ -900,6 +961,8 @@ pub enum BuiltinOp {
     Methods,   // methods(f) - get all methods for function
     HasMethod, // hasmethod(f, types) - check if method exists
     Which,     // which(f, types) - get specific method
+    CodeLowered, // code_lowered(f, types) - disassembly of the dispatched method's bytecode
+    CodeNative,  // code_native(f, types) - disassembly annotated as the native-codegen view
     // Set operations
     In, // in(x, collection) - check if element is in collection
     // RNG seeding
@@ -919,6 +982,7 @@ pub enum BuiltinOp {
     Eval,               // eval(expr) - evaluate an Expr at runtime
     MacroExpand,        // macroexpand(m, x) - return expanded form of macro call
     MacroExpandBang,    // macroexpand!(m, x) - destructively expand macro call
+    MacroExpand1,       // macroexpand1(m, x) - expand only the outermost macro call, one step
     IncludeString,      // include_string(m, code) - parse and evaluate code string
     EvalFile,           // evalfile(path) - evaluate all expressions in a file
     SplatInterpolation, // Marker for $(expr...) splat interpolation in quotes (compile-time)
@@ -928,6 +992,8 @@ pub enum BuiltinOp {
     TestRecordBroken, // _test_record_broken!(passed, msg) - record broken test result
     TestSetBegin,     // _testset_begin!(name) - begin test set
     TestSetEnd,       // _testset_end!() - end test set and print summary
+    TestSetSetFilter, // _testset_set_filter!(pattern) - restrict testsets/tests to a name/message pattern
+    TestThrowsRecord, // _test_throws_record!(thrown_type, expected_type, msg) - record @test_throws result
     // Variable reflection
     IsDefined, // @isdefined(x) - check if variable is defined
 }
@@ -976,6 +1042,14 @@ impl Stmt {
             Self::Block(block) => block.span,
             Self::Assign { span, .. } => *span,
             Self::AddAssign { span, .. } => *span,
+            Self::SubAssign { span, .. } => *span,
+            Self::MulAssign { span, .. } => *span,
+            Self::DivAssign { span, .. } => *span,
+            Self::FldAssign { span, .. } => *span,
+            Self::PowAssign { span, .. } => *span,
+            Self::BitAndAssign { span, .. } => *span,
+            Self::BitOrAssign { span, .. } => *span,
+            Self::BitXorAssign { span, .. } => *span,
             Self::For { span, .. } => *span,
             Self::ForEach { span, .. } => *span,
             Self::ForEachTuple { span, .. } => *span,
@@ -27,7 +27,7 @@
 //! - combinatorics.jl: Combinatorial functions (binomial)
 //! - sort.jl: Sorting algorithms (sort, sortperm, searchsorted, etc.)
 //! - strings/: String/character utilities
-//! - tuple.jl: Tuple utilities (empty - use standard functions)
+//! - tuple.jl: Tuple utilities (ntuple, front, tail, setindex)
 //! - set.jl: Set operations (unique, union, intersect, setdiff, etc.)
 
 /// Core.Intrinsics wrappers (add_int, sub_int, sdiv_int, etc.)
@@ -93,6 +93,10 @@ pub const ARRAY_JL: &str = include_str!("array.jl");
 /// Based on Julia's base/subarray.jl and base/views.jl
 pub const SUBARRAY_JL: &str = include_str!("subarray.jl");
 
+/// ReinterpretArray type presenting an array's underlying bytes as a
+/// different bitstype. Based on Julia's base/reinterpretarray.jl.
+pub const REINTERPRETARRAY_JL: &str = include_str!("reinterpretarray.jl");
+
 /// Memory{T} typed memory buffer
 /// Based on Julia's base/genericmemory.jl
 /// Low-level fixed-size typed buffer used internally by Vector, Dict, etc.
@@ -142,7 +146,11 @@ pub const STRINGS_UTIL_JL: &str = include_str!("strings/util.jl");
 /// Based on Julia's base/strings/unicode.jl
 pub const STRINGS_UNICODE_JL: &str = include_str!("strings/unicode.jl");
 
-/// Tuple utilities (empty - use standard functions like sum, prod)
+/// Generic `ntuple` tuple generator, split out into its own file
+/// (Based on Julia's base/ntuple.jl)
+pub const NTUPLE_JL: &str = include_str!("ntuple.jl");
+
+/// Tuple utilities (front, tail, setindex, fill_to_length)
 pub const TUPLE_JL: &str = include_str!("tuple.jl");
 
 /// Set operations (unique, union, intersect, setdiff, etc.)
@@ -268,7 +276,7 @@ pub const BROADCAST_JL: &str = include_str!("broadcast.jl");
 /// Order matters: abstract type hierarchy first, then basic types, math, arrays, and higher-order functions.
 pub fn get_base() -> String {
     format!(
-        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+        "{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
         BOOT_JL,              // 1. Intrinsics + abstract types + Val
         ERROR_JL,             // 2. Exceptions
         PROMOTION_JL,         // 3. Type promotion
@@ -294,6 +302,7 @@ pub fn get_base() -> String {
         COMPLEX_JL,           // 20. Complex
         ARRAY_JL,             // 21. Array
         SUBARRAY_JL,          // 22. SubArray
+        REINTERPRETARRAY_JL,  // 22b. ReinterpretArray
         GENERICMEMORY_JL,     // 22a. Memory{T} buffer
         RANGE_JL,             // 23. Range
         GENERATOR_JL,         // 24. Generator + traits
@@ -307,6 +316,7 @@ pub fn get_base() -> String {
         STRINGS_SEARCH_JL,    // 32. String search
         STRINGS_UTIL_JL,      // 33. String utils
         STRINGS_UNICODE_JL,   // 33a. Unicode (uppercase, lowercase)
+        NTUPLE_JL,            // 33a. ntuple generator
         TUPLE_JL,             // 34. Tuple
         SET_JL,               // 35. Set
         DICT_JL,              // 36. Dict
@@ -416,6 +426,12 @@ mod tests {
         assert!(!INTFUNCS_JL.contains("function ctz"));
     }
 
+    #[test]
+    fn test_intfuncs_invmod() {
+        assert!(INTFUNCS_JL.contains("function invmod(n::Integer, m::Integer)"));
+        assert!(INTFUNCS_JL.contains("function invmod(n::T, ::Type{T}) where {T<:Integer}"));
+    }
+
     #[test]
     fn test_floatfuncs_functions() {
         assert!(FLOATFUNCS_JL.contains("function isinteger"));
@@ -622,6 +638,21 @@ mod tests {
         assert!(STRINGS_UNICODE_JL.contains("function lowercase(c::Char)"));
         assert!(STRINGS_UNICODE_JL.contains("function uppercase(s::String)"));
         assert!(STRINGS_UNICODE_JL.contains("function lowercase(s::String)"));
+        // Normalization in strings/unicode.jl
+        assert!(STRINGS_UNICODE_JL.contains("function normalize(s::String"));
+        assert!(STRINGS_UNICODE_JL.contains("function isnormalized(s::String"));
+    }
+
+    #[test]
+    fn test_util_functions() {
+        assert!(UTIL_JL.contains("function atexit(f)"));
+        assert!(UTIL_JL.contains("_atexit_push!"));
+    }
+
+    #[test]
+    fn test_parse_bool() {
+        assert!(PARSE_JL.contains("function parse(::Type{Bool}, s::AbstractString)"));
+        assert!(PARSE_JL.contains("function tryparse(::Type{Bool}, s::AbstractString)"));
     }
 
     #[test]
@@ -634,6 +665,14 @@ mod tests {
         assert!(!TUPLE_JL.contains("function tuple_contains"));
     }
 
+    #[test]
+    fn test_ntuple_functions() {
+        assert!(NTUPLE_JL.contains("function ntuple(f, n::Integer)"));
+        assert!(NTUPLE_JL.contains("function ntuple(f, ::Val{0})"));
+        assert!(NTUPLE_JL.contains("function ntuple(f, ::Val{10})"));
+        assert!(NTUPLE_JL.contains("function ntuple(f, ::Val{N}) where {N}"));
+    }
+
     #[test]
     fn test_set_functions() {
         // Array utility functions (unique, allunique, allequal)
@@ -657,6 +696,10 @@ mod tests {
         // These should call the internal VM builtins
         assert!(REFLECTION_JL.contains("_fieldnames"));
         assert!(REFLECTION_JL.contains("_fieldtypes"));
+        // Method introspection (methodswith)
+        assert!(REFLECTION_JL.contains("struct Method"));
+        assert!(REFLECTION_JL.contains("function methodswith(T::Type"));
+        assert!(REFLECTION_JL.contains("_methodswith"));
     }
 
     #[test]
@@ -704,6 +747,18 @@ mod tests {
         assert!(SUBARRAY_JL.contains("function parent(v::SubArray"));
     }
 
+    #[test]
+    fn test_reinterpret_functions() {
+        // ReinterpretArray type presenting an array's bytes as a different bitstype
+        assert!(REINTERPRETARRAY_JL.contains("struct ReinterpretArray"));
+        assert!(REINTERPRETARRAY_JL.contains("function reinterpret(::Type{T}, A::AbstractArray)"));
+        assert!(REINTERPRETARRAY_JL.contains("function getindex(r::ReinterpretArray"));
+        assert!(REINTERPRETARRAY_JL.contains("function setindex!(r::ReinterpretArray"));
+        assert!(REINTERPRETARRAY_JL.contains("function size(r::ReinterpretArray)"));
+        assert!(REINTERPRETARRAY_JL.contains("function length(r::ReinterpretArray)"));
+        assert!(REINTERPRETARRAY_JL.contains("function parent(r::ReinterpretArray)"));
+    }
+
     #[test]
     fn test_multimedia_display_stack() {
         // Display stack functionality (Issue #376)
@@ -868,6 +923,7 @@ mod tests {
             "meta.jl",
             "missing.jl",
             "multimedia.jl",
+            "ntuple.jl",
             "number.jl",
             "operators.jl",
             "pair.jl",
@@ -879,6 +935,7 @@ mod tests {
             "rational.jl",
             "reduce.jl",
             "reflection.jl",
+            "reinterpretarray.jl",
             "rounding.jl",
             "runtime_internals.jl",
             "set.jl",
@@ -947,7 +1004,7 @@ mod tests {
         // This catches typos in the loaded_files list
         assert_eq!(
             loaded_files.len(),
-            62,
+            64,
             "loaded_files count mismatch - update test when adding new files"
         );
     }
@@ -13,6 +13,7 @@ pub mod ir;
 pub mod julia;
 pub use julia::{base, stdlib}; // Re-export for backwards compatibility
 pub mod base_loader;
+pub mod load_timing;
 pub mod loader;
 pub mod rng;
 pub mod span;
@@ -35,6 +36,9 @@ pub mod bytecode;
 // REPL session management
 pub mod repl;
 
+// Async multi-session execution runtime (worker thread pool)
+pub mod runtime;
+
 // AoT (Ahead-of-Time) compiler module
 #[cfg(feature = "aot")]
 pub mod aot;
@@ -63,12 +67,21 @@ pub use ffi::{
     compile_and_run_streaming,
     compile_and_run_with_output,
     compile_to_ir,
+    // Bytecode cache FFI
+    bytecode_load_or_compile,
     // Error types
     free_execution_result,
     // REPL FFI
     free_repl_result,
     free_string,
     is_expression_complete,
+    // Load timing FFI
+    load_timing_report,
+    load_timing_reset,
+    load_timing_set_enabled,
+    // Native host bridge FFI
+    register_native,
+    repl_complete,
     repl_session_eval,
     repl_session_free,
     repl_session_new,
@@ -81,11 +94,18 @@ pub use ffi::{
     subset_julia_vm_demo,
     vm_request_cancel,
     vm_reset_cancel,
+    // Async runtime FFI
+    vm_runtime_cancel,
+    vm_runtime_free,
+    vm_runtime_new,
+    vm_runtime_poll,
+    vm_runtime_submit,
     CError,
     CErrorKind,
     CExecutionResult,
     CREPLResult,
     CSpan,
+    NativeFn,
     OutputCallback,
 };
 
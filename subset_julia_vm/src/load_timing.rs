@@ -0,0 +1,188 @@
+//! Opt-in timing instrumentation for module loading (Base prelude, stdlib
+//! modules, and user packages), mirroring Julia's `@time_imports` breakdown.
+//!
+//! Disabled by default: [`timed`] checks two cached flags (the API toggle
+//! and the env var, read once) before doing anything else, so the normal
+//! (disabled) load path pays only those two checks — no `Instant::now()`,
+//! no locking, no allocation.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable load timing instrumentation via the API toggle.
+/// Instrumentation is also active when `SUBSETJULIA_LOAD_TIMING=1` is set
+/// in the environment (checked once and cached — see [`is_enabled`]).
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether load timing is currently active, via the API toggle or the
+/// `SUBSETJULIA_LOAD_TIMING` env var.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed) || env_enabled()
+}
+
+fn env_enabled() -> bool {
+    static ENV_ENABLED: Lazy<bool> = Lazy::new(|| {
+        std::env::var("SUBSETJULIA_LOAD_TIMING")
+            .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false)
+    });
+    *ENV_ENABLED
+}
+
+/// A load phase that can be timed independently per module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoadPhase {
+    /// Source text -> CST.
+    Parse,
+    /// CST -> Core IR.
+    Lower,
+    /// Running module-level statements.
+    Execute,
+}
+
+/// Accumulated per-phase timings for a single module.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ModuleTimings {
+    pub parse: Duration,
+    pub lower: Duration,
+    pub execute: Duration,
+}
+
+impl ModuleTimings {
+    /// Total time spent across all phases for this module.
+    pub fn total(&self) -> Duration {
+        self.parse + self.lower + self.execute
+    }
+
+    fn add(&mut self, phase: LoadPhase, duration: Duration) {
+        match phase {
+            LoadPhase::Parse => self.parse += duration,
+            LoadPhase::Lower => self.lower += duration,
+            LoadPhase::Execute => self.execute += duration,
+        }
+    }
+}
+
+/// A snapshot of accumulated load timings, keyed by module name (e.g.
+/// `"Prelude"`, `"Statistics"`) in the order each module was first timed —
+/// so a dump reads like Julia's `@time_imports` output.
+#[derive(Debug, Clone, Default)]
+pub struct LoadReport {
+    entries: Vec<(String, ModuleTimings)>,
+}
+
+impl LoadReport {
+    /// The recorded entries, in first-seen order.
+    pub fn entries(&self) -> &[(String, ModuleTimings)] {
+        &self.entries
+    }
+
+    /// Total time spent across every module and phase.
+    pub fn total(&self) -> Duration {
+        self.entries.iter().map(|(_, t)| t.total()).sum()
+    }
+}
+
+static TIMINGS: Lazy<RwLock<Vec<(String, ModuleTimings)>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Record `duration` spent in `phase` while loading `module`. A no-op when
+/// timing is disabled.
+pub fn record(module: &str, phase: LoadPhase, duration: Duration) {
+    if !is_enabled() {
+        return;
+    }
+    let mut timings = TIMINGS.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+    match timings.iter_mut().find(|(name, _)| name == module) {
+        Some((_, entry)) => entry.add(phase, duration),
+        None => {
+            let mut entry = ModuleTimings::default();
+            entry.add(phase, duration);
+            timings.push((module.to_string(), entry));
+        }
+    }
+}
+
+/// Run `f`, recording its wall-clock duration under `module`/`phase` when
+/// timing is enabled, and returning `f`'s result either way. Checks
+/// [`is_enabled`] before touching the clock so the disabled path is just
+/// the toggle load, the cached env-var lookup, and the call to `f`.
+pub fn timed<T>(module: &str, phase: LoadPhase, f: impl FnOnce() -> T) -> T {
+    if !is_enabled() {
+        return f();
+    }
+    let start = std::time::Instant::now();
+    let result = f();
+    record(module, phase, start.elapsed());
+    result
+}
+
+/// Snapshot the timings accumulated so far.
+pub fn report() -> LoadReport {
+    let timings = TIMINGS.read().unwrap_or_else(|poisoned| poisoned.into_inner());
+    LoadReport {
+        entries: timings.clone(),
+    }
+}
+
+/// Clear all accumulated timings (e.g. between REPL sessions).
+pub fn reset() {
+    TIMINGS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // Tests below mutate the shared ENABLED/TIMINGS globals, so they must
+    // not run concurrently with each other.
+    static TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn test_disabled_by_default_records_nothing() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+        reset();
+        set_enabled(false);
+        record("Foo", LoadPhase::Parse, Duration::from_millis(5));
+        assert!(report().entries().is_empty());
+    }
+
+    #[test]
+    fn test_enabled_accumulates_across_phases() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+        reset();
+        set_enabled(true);
+        record("Foo", LoadPhase::Parse, Duration::from_millis(5));
+        record("Foo", LoadPhase::Lower, Duration::from_millis(3));
+
+        let report = report();
+        let (name, timings) = &report.entries()[0];
+        assert_eq!(name, "Foo");
+        assert_eq!(timings.parse, Duration::from_millis(5));
+        assert_eq!(timings.lower, Duration::from_millis(3));
+
+        set_enabled(false);
+        reset();
+    }
+
+    #[test]
+    fn test_timed_skips_clock_when_disabled() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|p| p.into_inner());
+        reset();
+        set_enabled(false);
+        let value = timed("Bar", LoadPhase::Execute, || 42);
+        assert_eq!(value, 42);
+        assert!(report().entries().is_empty());
+    }
+}
@@ -9,10 +9,16 @@ use sha2::{Digest, Sha256};
 
 use crate::error::{SyntaxError, UnsupportedFeature};
 use crate::ir::core::{Module, Program, Stmt, UsingImport};
+use crate::load_timing::{timed, LoadPhase};
 use crate::lowering::LoweringWithInclude;
 use crate::parser::Parser;
 use crate::stdlib;
 
+// Re-exported so callers can do `loader::LoadReport` for the opt-in load
+// timing breakdown (mirrors Julia's `@time_imports`) without reaching into
+// `load_timing` directly.
+pub use crate::load_timing::LoadReport;
+
 const CACHE_VERSION: u32 = 1;
 
 #[derive(Debug, Clone)]
@@ -264,13 +270,14 @@ fn parse_module_source(
         message: e.to_string(),
     })?;
 
-    let outcome = parser.parse(source).map_err(|e| LoadError::ParseError {
-        module: module.to_string(),
-        error: format_syntax_error(&e),
-    })?;
+    let outcome = timed(module, LoadPhase::Parse, || parser.parse(source))
+        .map_err(|e| LoadError::ParseError {
+            module: module.to_string(),
+            error: format_syntax_error(&e),
+        })?;
 
     let mut lowering = LoweringWithInclude::with_base_dir(source, base_dir.cloned());
-    lowering.lower(outcome).map_err(|e| LoadError::LowerError {
+    timed(module, LoadPhase::Lower, || lowering.lower(outcome)).map_err(|e| LoadError::LowerError {
         module: module.to_string(),
         error: format_lower_error(&e),
     })
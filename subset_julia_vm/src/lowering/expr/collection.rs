@@ -4,11 +4,11 @@
 //! index expressions, and comprehensions.
 
 use crate::error::{UnsupportedFeature, UnsupportedFeatureKind};
-use crate::ir::core::Expr;
-use crate::lowering::LowerResult;
+use crate::ir::core::{Block, Expr, Function, Stmt, TypedParam};
+use crate::lowering::{LambdaContext, LowerResult};
 use crate::parser::cst::{CstWalker, Node, NodeKind};
 
-use super::lower_expr;
+use super::{lower_expr, lower_expr_with_ctx};
 
 /// Lower vector expression: [1, 2, 3] or []
 pub fn lower_vector_expr<'a>(walker: &CstWalker<'a>, node: Node<'a>) -> LowerResult<Expr> {
@@ -661,6 +661,275 @@ pub fn lower_generator_expr<'a>(walker: &CstWalker<'a>, node: Node<'a>) -> Lower
     })
 }
 
+/// Lower generator expression with lambda-lifting support: (x^2 for x in 1:10)
+///
+/// Unlike `lower_generator_expr`, this variant lifts non-trivial bodies (anything
+/// beyond a plain call like `f(x)`) into a synthetic top-level function, the same
+/// way arrow functions are lifted (see `lower_arrow_function`). This lets
+/// `compile_generator_expr`'s lazy `MakeGenerator` fast path — which only
+/// recognizes a plain `f(x)` body — kick in for arbitrary generator bodies such as
+/// `x^2`, so expressions like `sum(x^2 for x in 1:n)` can stream without
+/// allocating an intermediate array.
+pub fn lower_generator_expr_with_ctx<'a>(
+    walker: &CstWalker<'a>,
+    node: Node<'a>,
+    lambda_ctx: &LambdaContext,
+) -> LowerResult<Expr> {
+    let span = walker.span(&node);
+    let named = walker.named_children(&node);
+
+    if named.is_empty() {
+        return Err(
+            UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+                .with_hint("empty generator"),
+        );
+    }
+
+    let mut body_expr = None;
+    let mut for_clause = None;
+    let mut if_clause = None;
+
+    for child in &named {
+        match walker.kind(child) {
+            NodeKind::ForClause => {
+                if for_clause.is_some() {
+                    return Err(UnsupportedFeature::new(
+                        UnsupportedFeatureKind::Comprehension,
+                        span,
+                    )
+                    .with_hint("nested generators not supported"));
+                }
+                for_clause = Some(*child);
+            }
+            NodeKind::IfClause => {
+                if_clause = Some(*child);
+            }
+            _ => {
+                if body_expr.is_none() {
+                    body_expr = Some(*child);
+                }
+            }
+        }
+    }
+
+    let body_node = body_expr.ok_or_else(|| {
+        UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+            .with_hint("missing body expression")
+    })?;
+
+    let for_node = for_clause.ok_or_else(|| {
+        UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+            .with_hint("missing for clause")
+    })?;
+
+    let (var_name, iter_expr) = parse_for_clause(walker, for_node)?;
+    let body = lower_expr_with_ctx(walker, body_node, lambda_ctx)?;
+    let filter = if let Some(if_node) = if_clause {
+        Some(Box::new(parse_if_clause(walker, if_node)?))
+    } else {
+        None
+    };
+
+    // Only lift when there's no filter: `compile_generator_expr`'s lazy fast path
+    // requires `filter.is_none()`, so lifting a filtered body would just be dead
+    // weight (the eager fallback path already handles filters correctly).
+    let body = if filter.is_none() {
+        lift_generator_body(lambda_ctx, &var_name, body, span)
+    } else {
+        body
+    };
+
+    Ok(Expr::Generator {
+        body: Box::new(body),
+        var: var_name,
+        iter: Box::new(iter_expr),
+        filter,
+        span,
+    })
+}
+
+/// If `body` isn't already a plain single-argument call on `var` (the shape
+/// `compile_generator_expr` recognizes for its lazy `MakeGenerator` path), lift it
+/// into a synthetic one-parameter function and rewrite `body` as a call to it.
+fn lift_generator_body(
+    lambda_ctx: &LambdaContext,
+    var: &str,
+    body: Expr,
+    span: crate::span::Span,
+) -> Expr {
+    if is_plain_call_on_var(&body, var) {
+        return body;
+    }
+
+    let lambda_name = lambda_ctx.next_lambda_name();
+    let func = Function {
+        name: lambda_name.clone(),
+        params: vec![TypedParam::untyped(var.to_string(), span)],
+        kwparams: vec![],
+        type_params: Vec::new(),
+        return_type: None,
+        body: Block {
+            stmts: vec![Stmt::Return {
+                value: Some(body),
+                span,
+            }],
+            span,
+        },
+        is_base_extension: false,
+        span,
+    };
+    lambda_ctx.add_lifted_function(func);
+
+    Expr::Call {
+        function: lambda_name,
+        args: vec![Expr::Var(var.to_string(), span)],
+        kwargs: Vec::new(),
+        splat_mask: vec![false],
+        kwargs_splat_mask: vec![],
+        span,
+    }
+}
+
+/// True if `expr` is already `f(var)`: a single-argument call on `var` with no
+/// kwargs or splats, matching the shape `extract_simple_function_call` detects.
+fn is_plain_call_on_var(expr: &Expr, var: &str) -> bool {
+    matches!(
+        expr,
+        Expr::Call { args, kwargs, splat_mask, .. }
+            if args.len() == 1
+                && kwargs.is_empty()
+                && splat_mask.iter().all(|&s| !s)
+                && matches!(&args[0], Expr::Var(name, _) if name == var)
+    )
+}
+
+/// Lower comprehension expression with lambda-lifting support: [f(x) for x in 1:10]
+///
+/// Desugars a single-variable comprehension into `collect(Generator(body, var, iter, filter))`,
+/// the same expansion Julia itself uses for `[... for ...]` syntax. Reusing `Expr::Generator`
+/// here means comprehensions inherit the same lazy `MakeGenerator` fast path (via
+/// `lift_generator_body`) as a hand-written generator expression, instead of maintaining a
+/// second, parallel eager-array compilation strategy.
+///
+/// Multi-variable comprehensions (`for i in r, j in r`) have no single `var`/`iter` pair to
+/// hand to `Generator`, so they keep going through `MultiComprehension` unchanged.
+pub fn lower_comprehension_expr_with_ctx<'a>(
+    walker: &CstWalker<'a>,
+    node: Node<'a>,
+    lambda_ctx: &LambdaContext,
+) -> LowerResult<Expr> {
+    let span = walker.span(&node);
+    let named = walker.named_children(&node);
+
+    if named.is_empty() {
+        return Err(
+            UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+                .with_hint("empty comprehension"),
+        );
+    }
+
+    let mut body_expr = None;
+    let mut for_clauses = Vec::new();
+    let mut if_clause = None;
+
+    for child in &named {
+        match walker.kind(child) {
+            NodeKind::ForClause => {
+                for_clauses.push(*child);
+            }
+            NodeKind::IfClause => {
+                if_clause = Some(*child);
+            }
+            _ => {
+                if body_expr.is_none() {
+                    body_expr = Some(*child);
+                }
+            }
+        }
+    }
+
+    let body_node = body_expr.ok_or_else(|| {
+        UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+            .with_hint("missing body expression")
+    })?;
+
+    if for_clauses.is_empty() {
+        return Err(
+            UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+                .with_hint("missing for clause"),
+        );
+    }
+
+    let body = lower_expr_with_ctx(walker, body_node, lambda_ctx)?;
+
+    let filter = if let Some(if_node) = if_clause {
+        Some(Box::new(parse_if_clause(walker, if_node)?))
+    } else {
+        None
+    };
+
+    let mut all_bindings = Vec::new();
+    for fc in &for_clauses {
+        let bindings = parse_for_clause_bindings(walker, *fc)?;
+        all_bindings.extend(bindings);
+    }
+
+    if all_bindings.len() == 1 {
+        let Some((var_name, iter_expr)) = all_bindings.pop() else {
+            return Err(
+                UnsupportedFeature::new(UnsupportedFeatureKind::Comprehension, span)
+                    .with_hint("missing for clause binding"),
+            );
+        };
+
+        // `Dict(k => v for ...)` is recognized by matching a `Pair`-bodied `Comprehension`
+        // or `Generator` directly (see `compile_dict_constructor`); keep producing the
+        // plain `Comprehension` IR for that shape instead of desugaring it away.
+        if matches!(body, Expr::Pair { .. }) {
+            return Ok(Expr::Comprehension {
+                body: Box::new(body),
+                var: var_name,
+                iter: Box::new(iter_expr),
+                filter,
+                span,
+            });
+        }
+
+        // Same restriction as `lower_generator_expr_with_ctx`: the lazy `MakeGenerator`
+        // fast path only applies when there's no filter.
+        let body = if filter.is_none() {
+            lift_generator_body(lambda_ctx, &var_name, body, span)
+        } else {
+            body
+        };
+
+        let generator = Expr::Generator {
+            body: Box::new(body),
+            var: var_name,
+            iter: Box::new(iter_expr),
+            filter,
+            span,
+        };
+
+        return Ok(Expr::Call {
+            function: "collect".to_string(),
+            args: vec![generator],
+            kwargs: Vec::new(),
+            splat_mask: vec![false],
+            kwargs_splat_mask: vec![],
+            span,
+        });
+    }
+
+    // Multi-variable comprehension: use MultiComprehension IR (Issue #2143)
+    Ok(Expr::MultiComprehension {
+        body: Box::new(body),
+        iterations: all_bindings,
+        filter,
+        span,
+    })
+}
+
 /// Parse ALL bindings from a for clause.
 /// A single ForClause may contain multiple ForBindings when comma-separated:
 ///   `for i in 1:3, j in 1:3` produces one ForClause with two ForBinding children.
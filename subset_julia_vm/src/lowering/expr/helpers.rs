@@ -276,6 +276,7 @@ pub(crate) fn map_builtin_name(name: &str) -> Option<BuiltinOp> {
         "eval" => BuiltinOp::Eval,
         "macroexpand" => BuiltinOp::MacroExpand,
         "macroexpand!" => BuiltinOp::MacroExpandBang,
+        "macroexpand1" => BuiltinOp::MacroExpand1,
         "include_string" => BuiltinOp::IncludeString,
         "evalfile" => BuiltinOp::EvalFile,
         "Symbol" => BuiltinOp::SymbolNew,
@@ -290,6 +291,8 @@ pub(crate) fn map_builtin_name(name: &str) -> Option<BuiltinOp> {
         "_test_record_broken!" => BuiltinOp::TestRecordBroken,
         "_testset_begin!" => BuiltinOp::TestSetBegin,
         "_testset_end!" => BuiltinOp::TestSetEnd,
+        "_testset_set_filter!" => BuiltinOp::TestSetSetFilter,
+        "_test_throws_record!" => BuiltinOp::TestThrowsRecord,
         // Note: seed! is only available via Random.seed!() (not exported by default)
         _ => return None,
     })
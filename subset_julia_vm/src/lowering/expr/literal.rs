@@ -178,7 +178,7 @@ fn parse_hex_float(text: &str) -> Option<f64> {
 }
 
 /// Parse a string literal, handling quotes and escape sequences.
-fn parse_string_literal(text: &str) -> String {
+pub(crate) fn parse_string_literal(text: &str) -> String {
     let content = if let Some(stripped) = text
         .strip_prefix("\"\"\"")
         .and_then(|s| s.strip_suffix("\"\"\""))
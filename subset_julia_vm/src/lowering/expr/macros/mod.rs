@@ -230,6 +230,55 @@ pub(crate) fn lower_macro_expr_with_ctx<'a>(
                 )
             }
         }
+        // @code_lowered f(args...) / @code_native f(args...) - disassemble the method
+        // that dispatch would select for this call.
+        "code_lowered" | "code_native" => {
+            if args.len() != 1 || walker.kind(&args[0]) != NodeKind::CallExpression {
+                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                    .with_hint(format!(
+                        "@{macro_name} requires a call expression: @{macro_name} f(args...)"
+                    )));
+            }
+
+            let call_children: Vec<Node<'a>> = walker.named_children(&args[0]);
+            if call_children.is_empty() {
+                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                    .with_hint(format!(
+                        "@{macro_name} requires a call expression: @{macro_name} f(args...)"
+                    )));
+            }
+
+            let func_expr = super::lower_expr_with_ctx(walker, call_children[0], lambda_ctx)?;
+            let type_tuple_elements: Vec<Expr> = call_children[1..]
+                .iter()
+                .map(|arg_node| {
+                    let arg_expr = super::lower_expr_with_ctx(walker, *arg_node, lambda_ctx)?;
+                    Ok(Expr::Call {
+                        function: "typeof".to_string(),
+                        args: vec![arg_expr],
+                        kwargs: vec![],
+                        splat_mask: vec![],
+                        kwargs_splat_mask: vec![],
+                        span,
+                    })
+                })
+                .collect::<LowerResult<Vec<_>>>()?;
+
+            Ok(Expr::Call {
+                function: macro_name.clone(),
+                args: vec![
+                    func_expr,
+                    Expr::TupleLiteral {
+                        elements: type_tuple_elements,
+                        span,
+                    },
+                ],
+                kwargs: vec![],
+                splat_mask: vec![],
+                kwargs_splat_mask: vec![],
+                span,
+            })
+        }
         // @views expression - convert all array slicing to views within expression
         "views" => {
             if args.is_empty() {
@@ -47,10 +47,13 @@ pub(super) use call::{
     lower_argument_list, lower_arrow_function, lower_call_expr, lower_call_expr_with_ctx,
 };
 pub(super) use collection::{
-    lower_comprehension_expr, lower_generator_expr, lower_index_expr, lower_matrix_expr,
-    lower_range_expr, lower_vector_expr,
+    lower_comprehension_expr, lower_comprehension_expr_with_ctx, lower_generator_expr,
+    lower_generator_expr_with_ctx, lower_index_expr, lower_matrix_expr, lower_range_expr,
+    lower_vector_expr,
+};
+pub(super) use literal::{
+    lower_char_literal, lower_string_literal, parse_float, parse_int, parse_string_literal,
 };
-pub(super) use literal::{lower_char_literal, lower_string_literal, parse_float, parse_int};
 pub(super) use misc::{
     lower_adjoint_expr, lower_broadcast_call_expr, lower_field_expr, lower_if_expr, lower_let_expr,
     lower_pair_expr, lower_parenthesized_expr, lower_parenthesized_expr_with_ctx,
@@ -474,8 +477,12 @@ pub fn lower_expr_with_ctx<'a>(
         NodeKind::VectorExpression => lower_vector_expr(walker, node),
         NodeKind::MatrixExpression => lower_matrix_expr(walker, node),
         NodeKind::IndexExpression => lower_index_expr(walker, node),
-        NodeKind::ComprehensionExpression => lower_comprehension_expr(walker, node),
-        NodeKind::GeneratorExpression => lower_generator_expr(walker, node),
+        NodeKind::ComprehensionExpression => {
+            lower_comprehension_expr_with_ctx(walker, node, lambda_ctx)
+        }
+        NodeKind::GeneratorExpression => {
+            lower_generator_expr_with_ctx(walker, node, lambda_ctx)
+        }
         NodeKind::FieldExpression => lower_field_expr(walker, node),
         NodeKind::AdjointExpression => lower_adjoint_expr(walker, node),
         NodeKind::TupleExpression => lower_tuple_expr(walker, node),
@@ -430,8 +430,12 @@ pub(super) fn handle_macrocall_expr<'a>(
         );
     }
 
-    // Expand the nested macro
-    expand_nested_macro_from_expr_args(&macro_def, &converted_args, span, lambda_ctx)
+    // Expand the nested macro, guarded against self- or mutually-recursive
+    // macros looping forever (e.g. `macro a(n) :(@a($n)) end`).
+    lambda_ctx.enter_macro_expansion(span)?;
+    let result = expand_nested_macro_from_expr_args(&macro_def, &converted_args, span, lambda_ctx);
+    lambda_ctx.exit_macro_expansion();
+    result
 }
 
 pub(super) fn handle_tuple_expr<'a>(
@@ -998,6 +1002,15 @@ pub(in crate::lowering::expr) fn collect_introduced_vars(
                                 collect_introduced_vars(stmt, hygiene, in_esc);
                             }
                         }
+                        "->" => {
+                            // Arrow function: Expr(:(->), params, body) - the
+                            // parameters are local to the lambda, same as any
+                            // other locally-bound identifier.
+                            if builtin_args.len() >= 3 {
+                                collect_lambda_param_names(&builtin_args[1], hygiene, in_esc);
+                                collect_introduced_vars(&builtin_args[2], hygiene, in_esc);
+                            }
+                        }
                         _ => {
                             // Other expression heads - recurse into arguments
                             for arg in &builtin_args[1..] {
@@ -1019,6 +1032,42 @@ pub(in crate::lowering::expr) fn collect_introduced_vars(
     }
 }
 
+/// Helper to collect parameter names from an arrow function's parameter
+/// constructor, which is either a single `SymbolNew` (`x -> ...`) or a
+/// `tuple` of them (`(x, y) -> ...`).
+fn collect_lambda_param_names(params: &Expr, hygiene: &mut HygieneContext, in_esc: bool) {
+    if in_esc {
+        return;
+    }
+    match params {
+        Expr::Builtin {
+            name: BuiltinOp::SymbolNew,
+            args,
+            ..
+        } => {
+            if let Some(Expr::Literal(Literal::Str(name), _)) = args.first() {
+                hygiene.register_local(name);
+            }
+        }
+        Expr::Builtin {
+            name: BuiltinOp::ExprNew,
+            args,
+            ..
+        } => {
+            if !args.is_empty() {
+                if let Ok(head) = extract_symbol_from_constructor(&args[0]) {
+                    if head == "tuple" {
+                        for param in &args[1..] {
+                            collect_lambda_param_names(param, hygiene, in_esc);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
 /// Helper to extract variable name from local declaration inner expression.
 fn collect_local_var_name(inner: &Expr, hygiene: &mut HygieneContext) {
     match inner {
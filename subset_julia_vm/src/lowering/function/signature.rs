@@ -661,6 +661,7 @@ pub(super) fn parse_parameter<'a>(
             // Used in promote_rule, convert signatures
             parse_unary_typed_parameter(walker, node)
         }
+        NodeKind::MacroCall => parse_nospecialize_parameter(walker, node),
         _ => Err(UnsupportedFeature::new(
             UnsupportedFeatureKind::Other(format!(
                 "unsupported function parameter: {:?}",
@@ -671,6 +672,65 @@ pub(super) fn parse_parameter<'a>(
     }
 }
 
+/// Parse `@nospecialize(x)` (or `@nospecialize(x::Int64)`) used inline as a
+/// function parameter, e.g. `function f(@nospecialize(x)) ... end`.
+///
+/// Unwraps the macro and parses the wrapped node as an ordinary parameter,
+/// then marks it `nospecialize` so the compiler always treats it as
+/// `ValueType::Any` instead of specializing on the caller's argument type.
+fn parse_nospecialize_parameter<'a>(
+    walker: &CstWalker<'a>,
+    node: Node<'a>,
+) -> LowerResult<TypedParam> {
+    let span = walker.span(&node);
+
+    let macro_ident = walker
+        .find_child(&node, NodeKind::MacroIdentifier)
+        .ok_or_else(|| {
+            UnsupportedFeature::new(
+                UnsupportedFeatureKind::Other(
+                    "unsupported function parameter: MacroCall without a macro name".to_string(),
+                ),
+                span,
+            )
+        })?;
+    let macro_name = walker
+        .text(&macro_ident)
+        .trim_start_matches('@')
+        .to_string();
+    if macro_name != "nospecialize" {
+        return Err(UnsupportedFeature::new(
+            UnsupportedFeatureKind::Other(format!(
+                "unsupported function parameter macro: @{}",
+                macro_name
+            )),
+            span,
+        ));
+    }
+
+    let args_node = walker.find_child(&node, NodeKind::MacroArgumentList);
+    let args: Vec<Node<'a>> = match args_node {
+        Some(args_node) => walker.named_children(&args_node),
+        None => walker
+            .named_children(&node)
+            .into_iter()
+            .filter(|child| walker.kind(child) != NodeKind::MacroIdentifier)
+            .collect(),
+    };
+    let Some(inner) = args.into_iter().next() else {
+        return Err(UnsupportedFeature::new(
+            UnsupportedFeatureKind::Other(
+                "@nospecialize requires a parameter argument".to_string(),
+            ),
+            span,
+        ));
+    };
+
+    let mut param = parse_parameter(walker, inner)?;
+    param.nospecialize = true;
+    Ok(param)
+}
+
 /// Parse a typed parameter (x::Int64).
 /// Also handles varargs typed parameters (x::Int64...) when the parser emits them as Parameter nodes.
 pub(super) fn parse_typed_parameter<'a>(
@@ -44,8 +44,16 @@ pub struct LambdaContext {
     /// Current file path (for @__FILE__ and @__DIR__ macros)
     /// None means REPL or unknown source
     current_file: Option<String>,
+    /// Nesting depth of macro-within-macro expansion, guarded against
+    /// `MAX_MACRO_EXPANSION_DEPTH` to catch self- and mutually-recursive
+    /// macros before they blow the stack.
+    macro_expansion_depth: RefCell<usize>,
 }
 
+/// Crate-level recursion limit for macro-calling-macro expansion, mirroring
+/// the `Limit`-guarded expansion loops in rustc's macro expander.
+pub const MAX_MACRO_EXPANSION_DEPTH: usize = 256;
+
 impl LambdaContext {
     pub fn new() -> Self {
         Self {
@@ -54,6 +62,7 @@ impl LambdaContext {
             usings: RefCell::new(HashSet::new()),
             macros: RefCell::new(HashMap::new()),
             current_file: None,
+            macro_expansion_depth: RefCell::new(0),
         }
     }
 
@@ -66,6 +75,7 @@ impl LambdaContext {
             usings: RefCell::new(HashSet::new()),
             macros: RefCell::new(HashMap::new()),
             current_file: file_path,
+            macro_expansion_depth: RefCell::new(0),
         }
     }
 
@@ -229,6 +239,26 @@ impl LambdaContext {
     pub fn get_usings(&self) -> Vec<String> {
         self.usings.borrow().iter().cloned().collect()
     }
+
+    /// Enter a nested macro expansion (a macro body invoking another
+    /// macro, e.g. via `:(@bar($x))`), bumping the depth counter.
+    /// Errors once `MAX_MACRO_EXPANSION_DEPTH` is exceeded, which is how
+    /// self- and mutually-recursive macros are caught instead of
+    /// overflowing the stack.
+    pub fn enter_macro_expansion(&self, span: Span) -> LowerResult<()> {
+        let mut depth = self.macro_expansion_depth.borrow_mut();
+        if *depth >= MAX_MACRO_EXPANSION_DEPTH {
+            return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                .with_hint("macro expansion exceeded recursion limit (possible infinite macro)"));
+        }
+        *depth += 1;
+        Ok(())
+    }
+
+    /// Leave a nested macro expansion entered via `enter_macro_expansion`.
+    pub fn exit_macro_expansion(&self) {
+        *self.macro_expansion_depth.borrow_mut() -= 1;
+    }
 }
 
 impl Default for LambdaContext {
@@ -118,20 +118,15 @@ fn lower_assignment_parts<'a>(
         NodeKind::Identifier => {
             let var = walker.text(&lhs).to_string();
             let rhs_expr = expr::lower_expr(walker, rhs)?;
-            let value = if op_text == ".=" {
-                let rhs_expr = strip_outer_materialize_broadcast(rhs_expr);
-                Expr::Call {
-                    function: "materialize!".to_string(),
-                    args: vec![Expr::Var(var.clone(), span), rhs_expr],
-                    kwargs: Vec::new(),
-                    splat_mask: vec![false, false],
-                    kwargs_splat_mask: vec![],
-                    span,
-                }
-            } else {
-                rhs_expr
-            };
-            Ok(Stmt::Assign { var, value, span })
+            if op_text == ".=" {
+                let value = strip_outer_materialize_broadcast(rhs_expr);
+                return Ok(Stmt::BroadcastAssign { var, value, span });
+            }
+            Ok(Stmt::Assign {
+                var,
+                value: rhs_expr,
+                span,
+            })
         }
         NodeKind::TypedExpression | NodeKind::TypedParameter => {
             // Typed local variable declaration: x::Type = value
@@ -594,24 +589,12 @@ pub fn lower_compound_assignment<'a>(walker: &CstWalker<'a>, node: Node<'a>) ->
         return Ok(Stmt::Assign { var, value, span });
     }
 
-    // Handle broadcast assignment (.=)
-    // Z .= expr lowers to Z = materialize!(Z, expr) so alias-observable in-place semantics
-    // are preserved.
+    // Handle broadcast assignment (.=): `Z .= expr` becomes a dedicated
+    // BroadcastAssign statement so `compile_stmt` can fuse it into an in-place
+    // element-wise store instead of rebinding `Z` to a freshly allocated array.
     if op_text == ".=" {
-        let var_expr = Expr::Var(var.clone(), span);
-        let value = Expr::Call {
-            function: "materialize!".to_string(),
-            args: vec![var_expr, strip_outer_materialize_broadcast(rhs_expr)],
-            kwargs: Vec::new(),
-            splat_mask: vec![false, false],
-            kwargs_splat_mask: vec![],
-            span,
-        };
-        return Ok(Stmt::Assign {
-            var,
-            value,
-            span,
-        });
+        let value = strip_outer_materialize_broadcast(rhs_expr);
+        return Ok(Stmt::BroadcastAssign { var, value, span });
     }
 
     // Handle broadcast compound assignments (.+=, .-=, .*=, .&=, etc.)
@@ -632,7 +615,7 @@ pub fn lower_compound_assignment<'a>(walker: &CstWalker<'a>, node: Node<'a>) ->
         // instead of calling ".+" directly, which is not a registered function (Issue #2685)
         let base_op = strip_broadcast_dot(op_name);
         let value = make_broadcasted_call(base_op, vec![var_expr, rhs_expr], span);
-        return Ok(Stmt::Assign { var, value, span });
+        return Ok(Stmt::BroadcastAssign { var, value, span });
     }
 
     Err(UnsupportedFeature::new(
@@ -675,20 +658,15 @@ pub fn lower_assignment_with_ctx<'a>(
         NodeKind::Identifier => {
             let var = walker.text(&lhs).to_string();
             let rhs_expr = expr::lower_expr_with_ctx(walker, rhs, lambda_ctx)?;
-            let value = if op_text == ".=" {
-                let rhs_expr = strip_outer_materialize_broadcast(rhs_expr);
-                Expr::Call {
-                    function: "materialize!".to_string(),
-                    args: vec![Expr::Var(var.clone(), span), rhs_expr],
-                    kwargs: Vec::new(),
-                    splat_mask: vec![false, false],
-                    kwargs_splat_mask: vec![],
-                    span,
-                }
-            } else {
-                rhs_expr
-            };
-            Ok(Stmt::Assign { var, value, span })
+            if op_text == ".=" {
+                let value = strip_outer_materialize_broadcast(rhs_expr);
+                return Ok(Stmt::BroadcastAssign { var, value, span });
+            }
+            Ok(Stmt::Assign {
+                var,
+                value: rhs_expr,
+                span,
+            })
         }
         NodeKind::TypedExpression | NodeKind::TypedParameter => {
             // Typed local variable declaration: x::Type = value
@@ -991,24 +969,12 @@ pub fn lower_compound_assignment_with_ctx<'a>(
         return Ok(Stmt::Assign { var, value, span });
     }
 
-    // Handle broadcast assignment (.=)
-    // Z .= expr lowers to Z = materialize!(Z, expr) so alias-observable in-place semantics
-    // are preserved.
+    // Handle broadcast assignment (.=): `Z .= expr` becomes a dedicated
+    // BroadcastAssign statement so `compile_stmt` can fuse it into an in-place
+    // element-wise store instead of rebinding `Z` to a freshly allocated array.
     if op_text == ".=" {
-        let var_expr = Expr::Var(var.clone(), span);
-        let value = Expr::Call {
-            function: "materialize!".to_string(),
-            args: vec![var_expr, strip_outer_materialize_broadcast(rhs_expr)],
-            kwargs: Vec::new(),
-            splat_mask: vec![false, false],
-            kwargs_splat_mask: vec![],
-            span,
-        };
-        return Ok(Stmt::Assign {
-            var,
-            value,
-            span,
-        });
+        let value = strip_outer_materialize_broadcast(rhs_expr);
+        return Ok(Stmt::BroadcastAssign { var, value, span });
     }
 
     // Handle broadcast compound assignments (.+=, .-=, .*=, .&=, etc.)
@@ -1029,7 +995,7 @@ pub fn lower_compound_assignment_with_ctx<'a>(
         // instead of calling ".+" directly, which is not a registered function (Issue #2685)
         let base_op = strip_broadcast_dot(op_name);
         let value = make_broadcasted_call(base_op, vec![var_expr, rhs_expr], span);
-        return Ok(Stmt::Assign { var, value, span });
+        return Ok(Stmt::BroadcastAssign { var, value, span });
     }
 
     Err(UnsupportedFeature::new(
@@ -4,14 +4,17 @@
 //! and user-defined macros in statement context.
 
 use crate::error::{UnsupportedFeature, UnsupportedFeatureKind};
-use crate::ir::core::{Block, Expr, Literal, Stmt};
+use crate::ir::core::{BinaryOp, Block, Expr, Literal, Stmt, UnaryOp};
 use crate::lowering::expr;
 use crate::lowering::LambdaContext;
 use crate::lowering::LowerResult;
 use crate::lowering::{get_node_macro_type, MacroParamType};
-use crate::parser::cst::{CstWalker, Node};
+use crate::parser::cst::{CstWalker, Node, NodeKind};
 use crate::span::Span;
 use crate::stdlib_loader::get_stdlib_macro;
+use std::collections::HashMap;
+
+use super::printf::expand_printf_macro;
 
 /// Expand a macro defined in Base (base/macros.jl)
 pub(super) fn expand_base_macro<'a>(
@@ -32,6 +35,16 @@ pub(super) fn expand_base_macro<'a>(
         vec![]
     };
 
+    // @printf/@sprintf get a dedicated expansion path when the format
+    // string is a literal: the directives can be parsed and validated at
+    // expansion time instead of lowering to one generic runtime format
+    // call that re-parses the same string on every invocation.
+    if (macro_name == "printf" || macro_name == "sprintf")
+        && args.first().is_some_and(|a| walker.kind(a) == NodeKind::StringLiteral)
+    {
+        return expand_printf_macro(walker, macro_name, &args, span, lambda_ctx);
+    }
+
     // Get the macro definition from Base registry with arity-based dispatch
     let macro_def = crate::base_loader::get_base_macro_with_arity(macro_name, args.len())
         .ok_or_else(|| {
@@ -170,66 +183,26 @@ fn expand_macro_with_def<'a>(
         };
     }
 
-    // Multiple statements: track local bindings for macro-local variables
+    // Multiple statements: track local bindings for macro-local variables.
     // Local variable assignments are evaluated at macro expansion time and their values
     // are substituted into the final expanded code. They are NOT included in the runtime code.
-    use std::collections::HashMap;
+    // `if`/`for` with a statically-resolvable condition/range are also evaluated here
+    // instead of being lowered to runtime control flow, so macros can build code
+    // conditionally or iteratively.
     let mut local_bindings: HashMap<String, Expr> = HashMap::new();
     let mut expanded_stmts = Vec::new();
-
-    for stmt in stmts {
-        match stmt {
-            Stmt::Expr {
-                expr,
-                span: stmt_span,
-            } => {
-                let expanded = expand_macro_expr_with_locals(
-                    walker,
-                    expr,
-                    &macro_def.params,
-                    &args,
-                    *stmt_span,
-                    lambda_ctx,
-                    macro_def.has_varargs,
-                    &local_bindings,
-                )?;
-                expanded_stmts.push(expanded);
-            }
-            Stmt::Assign {
-                var,
-                value,
-                span: _stmt_span,
-            } => {
-                // Evaluate the assignment value at macro expansion time
-                let expanded_value =
-                    substitute_params_in_expr(value, &macro_def.params, &args, walker, lambda_ctx)?;
-                // Store the value for later substitution in quotes
-                // Do NOT add to expanded_stmts - this is a compile-time binding
-                local_bindings.insert(var.clone(), expanded_value);
-            }
-            Stmt::Return {
-                value: Some(expr),
-                span: stmt_span,
-            } => {
-                let expanded = expand_macro_expr_with_locals(
-                    walker,
-                    expr,
-                    &macro_def.params,
-                    &args,
-                    *stmt_span,
-                    lambda_ctx,
-                    macro_def.has_varargs,
-                    &local_bindings,
-                )?;
-                expanded_stmts.push(expanded);
-            }
-            _ => {
-                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span).with_hint(
-                    "Base macro expansion currently only supports expression and assignment statements",
-                ));
-            }
-        }
-    }
+    expand_macro_stmts(
+        stmts,
+        &macro_def.params,
+        &args,
+        walker,
+        lambda_ctx,
+        macro_def.has_varargs,
+        true,
+        &mut local_bindings,
+        &mut expanded_stmts,
+        span,
+    )?;
 
     // If only one statement remains after filtering assignments, return it directly
     if expanded_stmts.len() == 1 {
@@ -355,22 +328,73 @@ pub(super) fn expand_user_defined_macro<'a>(
         };
     }
 
-    // Multiple statements: expand each and wrap in a Block statement
+    // Multiple statements: expand each and wrap in a Block statement.
+    // `local_bindings` here only ever holds `for`-loop variables introduced by
+    // unrolling below -- unlike `expand_macro_with_def`, a bare `x = ...`
+    // assignment in a user macro body stays a runtime assignment, not a
+    // compile-time binding (see the `Stmt::Assign` arm of `expand_macro_stmts`).
+    let mut local_bindings: HashMap<String, Expr> = HashMap::new();
     let mut expanded_stmts = Vec::new();
+    expand_macro_stmts(
+        stmts,
+        &macro_def.params,
+        &args,
+        walker,
+        lambda_ctx,
+        macro_def.has_varargs,
+        false,
+        &mut local_bindings,
+        &mut expanded_stmts,
+        span,
+    )?;
+
+    // Wrap in a Block statement
+    Ok(Stmt::Block(Block {
+        stmts: expanded_stmts,
+        span,
+    }))
+}
+
+/// Recursively process a macro body's statements at expansion time.
+///
+/// Shared by `expand_macro_with_def` (Base/stdlib macros) and
+/// `expand_user_defined_macro` (user macros), which otherwise differ only in
+/// whether a bare `x = ...` statement becomes a compile-time binding
+/// (`assign_is_compile_time`) or a runtime assignment. `local_bindings`
+/// carries macro-local compile-time values -- populated from `Stmt::Assign`
+/// when `assign_is_compile_time`, and always from `for`-loop variables -- and
+/// is substituted into quotes via `expand_macro_expr_with_locals`. `Stmt::If`
+/// and `Stmt::For` whose condition/range folds to a constant are evaluated
+/// here instead of being lowered to runtime control flow; this is what lets a
+/// macro build code conditionally or iteratively.
+#[allow(clippy::too_many_arguments)]
+fn expand_macro_stmts<'a>(
+    stmts: &[Stmt],
+    params: &[String],
+    args: &[Node<'a>],
+    walker: &CstWalker<'a>,
+    lambda_ctx: &LambdaContext,
+    has_varargs: bool,
+    assign_is_compile_time: bool,
+    local_bindings: &mut HashMap<String, Expr>,
+    expanded_stmts: &mut Vec<Stmt>,
+    span: Span,
+) -> LowerResult<()> {
     for stmt in stmts {
         match stmt {
             Stmt::Expr {
                 expr,
                 span: stmt_span,
             } => {
-                let expanded = expand_macro_expr(
+                let expanded = expand_macro_expr_with_locals(
                     walker,
                     expr,
-                    &macro_def.params,
-                    &args,
+                    params,
+                    args,
                     *stmt_span,
                     lambda_ctx,
-                    macro_def.has_varargs,
+                    has_varargs,
+                    local_bindings,
                 )?;
                 expanded_stmts.push(expanded);
             }
@@ -379,42 +403,256 @@ pub(super) fn expand_user_defined_macro<'a>(
                 value,
                 span: stmt_span,
             } => {
-                let expanded_value =
-                    substitute_params_in_expr(value, &macro_def.params, &args, walker, lambda_ctx)?;
-                expanded_stmts.push(Stmt::Assign {
-                    var: var.clone(),
-                    value: expanded_value,
-                    span: *stmt_span,
-                });
+                let expanded_value = substitute_params_and_locals_in_expr(
+                    value,
+                    params,
+                    args,
+                    walker,
+                    lambda_ctx,
+                    local_bindings,
+                )?;
+                if assign_is_compile_time {
+                    // Store the value for later substitution in quotes. Do NOT
+                    // add to expanded_stmts - this is a compile-time binding.
+                    local_bindings.insert(var.clone(), expanded_value);
+                } else {
+                    expanded_stmts.push(Stmt::Assign {
+                        var: var.clone(),
+                        value: expanded_value,
+                        span: *stmt_span,
+                    });
+                }
             }
             Stmt::Return {
                 value: Some(expr),
                 span: stmt_span,
             } => {
-                let expanded = expand_macro_expr(
+                let expanded = expand_macro_expr_with_locals(
                     walker,
                     expr,
-                    &macro_def.params,
-                    &args,
+                    params,
+                    args,
                     *stmt_span,
                     lambda_ctx,
-                    macro_def.has_varargs,
+                    has_varargs,
+                    local_bindings,
                 )?;
                 expanded_stmts.push(expanded);
             }
+            Stmt::If {
+                condition,
+                then_branch,
+                else_branch,
+                span: stmt_span,
+            } => {
+                let folded_condition = substitute_params_and_locals_in_expr(
+                    condition,
+                    params,
+                    args,
+                    walker,
+                    lambda_ctx,
+                    local_bindings,
+                )?;
+                match eval_const_bool(&folded_condition) {
+                    Some(true) => expand_macro_stmts(
+                        &then_branch.stmts,
+                        params,
+                        args,
+                        walker,
+                        lambda_ctx,
+                        has_varargs,
+                        assign_is_compile_time,
+                        local_bindings,
+                        expanded_stmts,
+                        *stmt_span,
+                    )?,
+                    Some(false) => {
+                        if let Some(else_branch) = else_branch {
+                            expand_macro_stmts(
+                                &else_branch.stmts,
+                                params,
+                                args,
+                                walker,
+                                lambda_ctx,
+                                has_varargs,
+                                assign_is_compile_time,
+                                local_bindings,
+                                expanded_stmts,
+                                *stmt_span,
+                            )?;
+                        }
+                    }
+                    None => {
+                        return Err(UnsupportedFeature::new(
+                            UnsupportedFeatureKind::MacroCall,
+                            *stmt_span,
+                        )
+                        .with_hint(
+                            "macro body `if` condition must fold to a constant at macro-expansion time",
+                        ));
+                    }
+                }
+            }
+            Stmt::For {
+                var,
+                start,
+                end,
+                step,
+                body,
+                span: stmt_span,
+            } => {
+                let folded_start = substitute_params_and_locals_in_expr(
+                    start,
+                    params,
+                    args,
+                    walker,
+                    lambda_ctx,
+                    local_bindings,
+                )?;
+                let folded_end = substitute_params_and_locals_in_expr(
+                    end,
+                    params,
+                    args,
+                    walker,
+                    lambda_ctx,
+                    local_bindings,
+                )?;
+                let (Some(start_v), Some(end_v)) =
+                    (eval_const_i64(&folded_start), eval_const_i64(&folded_end))
+                else {
+                    return Err(UnsupportedFeature::new(
+                        UnsupportedFeatureKind::MacroCall,
+                        *stmt_span,
+                    )
+                    .with_hint(
+                        "macro body `for` range must fold to a constant at macro-expansion time",
+                    ));
+                };
+                let step_v = match step {
+                    Some(step_expr) => {
+                        let folded_step = substitute_params_and_locals_in_expr(
+                            step_expr,
+                            params,
+                            args,
+                            walker,
+                            lambda_ctx,
+                            local_bindings,
+                        )?;
+                        eval_const_i64(&folded_step).ok_or_else(|| {
+                            UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, *stmt_span)
+                                .with_hint(
+                                    "macro body `for` step must fold to a constant at macro-expansion time",
+                                )
+                        })?
+                    }
+                    None => 1,
+                };
+                if step_v == 0 {
+                    return Err(
+                        UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, *stmt_span)
+                            .with_hint("macro body `for` step cannot be zero"),
+                    );
+                }
+
+                let mut i = start_v;
+                while (step_v > 0 && i <= end_v) || (step_v < 0 && i >= end_v) {
+                    local_bindings.insert(var.clone(), Expr::Literal(Literal::Int(i), *stmt_span));
+                    expand_macro_stmts(
+                        &body.stmts,
+                        params,
+                        args,
+                        walker,
+                        lambda_ctx,
+                        has_varargs,
+                        assign_is_compile_time,
+                        local_bindings,
+                        expanded_stmts,
+                        *stmt_span,
+                    )?;
+                    i += step_v;
+                }
+                local_bindings.remove(var);
+            }
             _ => {
                 return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span).with_hint(
-                    "user-defined macro expansion currently only supports expression and assignment statements",
+                    "macro expansion currently only supports expression, assignment, if, and for statements",
                 ));
             }
         }
     }
+    Ok(())
+}
 
-    // Wrap in a Block statement
-    Ok(Stmt::Block(Block {
-        stmts: expanded_stmts,
-        span,
-    }))
+/// Fold a macro-expansion-time expression to a constant `i64`, if possible.
+/// Used to evaluate `for` loop ranges inside macro bodies; returns `None` for
+/// anything that can't be resolved without running the program.
+fn eval_const_i64(expr: &Expr) -> Option<i64> {
+    match expr {
+        Expr::Literal(Literal::Int(n), _) => Some(*n),
+        Expr::Literal(Literal::Bool(b), _) => Some(*b as i64),
+        Expr::UnaryOp {
+            op: UnaryOp::Neg,
+            operand,
+            ..
+        } => eval_const_i64(operand).map(|v| -v),
+        Expr::BinaryOp {
+            op, left, right, ..
+        } => {
+            let l = eval_const_i64(left)?;
+            let r = eval_const_i64(right)?;
+            match op {
+                BinaryOp::Add => Some(l + r),
+                BinaryOp::Sub => Some(l - r),
+                BinaryOp::Mul => Some(l * r),
+                BinaryOp::IntDiv if r != 0 => Some(l / r),
+                BinaryOp::Mod if r != 0 => Some(l % r),
+                BinaryOp::Pow => u32::try_from(r).ok().map(|e| l.pow(e)),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Fold a macro-expansion-time expression to a constant `bool`, if possible.
+/// Used to evaluate `if` conditions inside macro bodies at expansion time.
+fn eval_const_bool(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::Literal(Literal::Bool(b), _) => Some(*b),
+        Expr::UnaryOp {
+            op: UnaryOp::Not,
+            operand,
+            ..
+        } => eval_const_bool(operand).map(|b| !b),
+        Expr::BinaryOp {
+            op: BinaryOp::And,
+            left,
+            right,
+            ..
+        } => Some(eval_const_bool(left)? && eval_const_bool(right)?),
+        Expr::BinaryOp {
+            op: BinaryOp::Or,
+            left,
+            right,
+            ..
+        } => Some(eval_const_bool(left)? || eval_const_bool(right)?),
+        Expr::BinaryOp {
+            op, left, right, ..
+        } => {
+            let l = eval_const_i64(left)?;
+            let r = eval_const_i64(right)?;
+            match op {
+                BinaryOp::Lt => Some(l < r),
+                BinaryOp::Le => Some(l <= r),
+                BinaryOp::Gt => Some(l > r),
+                BinaryOp::Ge => Some(l >= r),
+                BinaryOp::Eq => Some(l == r),
+                BinaryOp::Ne => Some(l != r),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
 }
 
 /// Expand a macro expression by substituting parameters with arguments.
@@ -427,8 +665,6 @@ fn expand_macro_expr<'a>(
     lambda_ctx: &LambdaContext,
     has_varargs: bool,
 ) -> LowerResult<Stmt> {
-    use std::collections::HashMap;
-
     // Build parameter -> argument mapping
     let _param_map: HashMap<&str, &Node<'a>> = params
         .iter()
@@ -719,3 +955,166 @@ fn substitute_params_in_expr<'a>(
         _ => Ok(expr.clone()),
     }
 }
+
+/// Like [`substitute_params_in_expr`], but also resolves macro-local
+/// compile-time bindings (from `for`-loop unrolling, and plain `x = ...`
+/// assignments when the caller treats those as compile-time). Used by
+/// [`expand_macro_stmts`] to fold `if`/`for` conditions and ranges that
+/// reference earlier macro-body statements rather than just the macro's own
+/// parameters.
+fn substitute_params_and_locals_in_expr<'a>(
+    expr: &Expr,
+    params: &[String],
+    args: &[Node<'a>],
+    walker: &CstWalker<'a>,
+    lambda_ctx: &LambdaContext,
+    local_bindings: &HashMap<String, Expr>,
+) -> LowerResult<Expr> {
+    match expr {
+        Expr::Var(name, span) => {
+            if let Some(idx) = params.iter().position(|p| p == name) {
+                expr::lower_expr_with_ctx(walker, args[idx], lambda_ctx)
+            } else if let Some(bound_value) = local_bindings.get(name) {
+                Ok(bound_value.clone())
+            } else {
+                Ok(Expr::Var(name.clone(), *span))
+            }
+        }
+        Expr::BinaryOp {
+            op,
+            left,
+            right,
+            span,
+        } => {
+            let new_left = substitute_params_and_locals_in_expr(
+                left,
+                params,
+                args,
+                walker,
+                lambda_ctx,
+                local_bindings,
+            )?;
+            let new_right = substitute_params_and_locals_in_expr(
+                right,
+                params,
+                args,
+                walker,
+                lambda_ctx,
+                local_bindings,
+            )?;
+            Ok(Expr::BinaryOp {
+                op: *op,
+                left: Box::new(new_left),
+                right: Box::new(new_right),
+                span: *span,
+            })
+        }
+        Expr::UnaryOp { op, operand, span } => {
+            let new_operand = substitute_params_and_locals_in_expr(
+                operand,
+                params,
+                args,
+                walker,
+                lambda_ctx,
+                local_bindings,
+            )?;
+            Ok(Expr::UnaryOp {
+                op: *op,
+                operand: Box::new(new_operand),
+                span: *span,
+            })
+        }
+        Expr::Call {
+            function,
+            args: call_args,
+            kwargs,
+            splat_mask,
+            kwargs_splat_mask,
+            span,
+        } => {
+            if function == "string" && call_args.len() == 1 {
+                if let Expr::Var(arg_name, _) = &call_args[0] {
+                    if let Some(idx) = params.iter().position(|p| p == arg_name) {
+                        let source_text = walker.text(&args[idx]).to_string();
+                        return Ok(Expr::Literal(Literal::Str(source_text), *span));
+                    }
+                }
+            }
+            let new_args: Result<Vec<_>, _> = call_args
+                .iter()
+                .map(|a| {
+                    substitute_params_and_locals_in_expr(
+                        a,
+                        params,
+                        args,
+                        walker,
+                        lambda_ctx,
+                        local_bindings,
+                    )
+                })
+                .collect();
+            let new_kwargs: Result<Vec<_>, _> = kwargs
+                .iter()
+                .map(|(k, v)| {
+                    substitute_params_and_locals_in_expr(
+                        v,
+                        params,
+                        args,
+                        walker,
+                        lambda_ctx,
+                        local_bindings,
+                    )
+                    .map(|nv| (k.clone(), nv))
+                })
+                .collect();
+            Ok(Expr::Call {
+                function: function.clone(),
+                args: new_args?,
+                kwargs: new_kwargs?,
+                splat_mask: splat_mask.clone(),
+                kwargs_splat_mask: kwargs_splat_mask.clone(),
+                span: *span,
+            })
+        }
+        Expr::Builtin {
+            name,
+            args: builtin_args,
+            span,
+        } => {
+            let new_args: Result<Vec<_>, _> = builtin_args
+                .iter()
+                .map(|a| {
+                    substitute_params_and_locals_in_expr(
+                        a,
+                        params,
+                        args,
+                        walker,
+                        lambda_ctx,
+                        local_bindings,
+                    )
+                })
+                .collect();
+            Ok(Expr::Builtin {
+                name: *name,
+                args: new_args?,
+                span: *span,
+            })
+        }
+        Expr::QuoteLiteral { constructor, span } => {
+            let new_constructor = substitute_params_and_locals_in_expr(
+                constructor,
+                params,
+                args,
+                walker,
+                lambda_ctx,
+                local_bindings,
+            )?;
+            Ok(Expr::QuoteLiteral {
+                constructor: Box::new(new_constructor),
+                span: *span,
+            })
+        }
+        // Literals and other expressions don't need substitution
+        _ => Ok(expr.clone()),
+    }
+}
@@ -14,6 +14,7 @@
 
 mod enum_impl;
 mod expand;
+mod printf;
 mod static_eval;
 
 use crate::error::{UnsupportedFeature, UnsupportedFeatureKind};
@@ -363,6 +364,66 @@ pub fn lower_macro_with_ctx<'a>(
                 );
             }
         }
+        // @code_lowered f(args...) / @code_native f(args...) - disassemble the method
+        // that dispatch would select for this call.
+        // Transforms f(a, b) into code_lowered(f, (typeof(a), typeof(b))), mirroring how
+        // `which`/`methods` take an explicit argument-type tuple rather than a call form.
+        "code_lowered" | "code_native" => {
+            let args: Vec<Node<'a>> = if let Some(args_node) = args_node {
+                walker.named_children(&args_node)
+            } else {
+                direct_args.clone()
+            };
+
+            if args.len() != 1 || walker.kind(&args[0]) != NodeKind::CallExpression {
+                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                    .with_hint(format!(
+                        "@{macro_name} requires a call expression: @{macro_name} f(args...)"
+                    )));
+            }
+
+            let call_children: Vec<Node<'a>> = walker.named_children(&args[0]);
+            if call_children.is_empty() {
+                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                    .with_hint(format!(
+                        "@{macro_name} requires a call expression: @{macro_name} f(args...)"
+                    )));
+            }
+
+            let func_expr = expr::lower_expr_with_ctx(walker, call_children[0], lambda_ctx)?;
+            let type_tuple_elements: Vec<Expr> = call_children[1..]
+                .iter()
+                .map(|arg_node| {
+                    let arg_expr = expr::lower_expr_with_ctx(walker, *arg_node, lambda_ctx)?;
+                    Ok(Expr::Call {
+                        function: "typeof".to_string(),
+                        args: vec![arg_expr],
+                        kwargs: vec![],
+                        splat_mask: vec![],
+                        kwargs_splat_mask: vec![],
+                        span,
+                    })
+                })
+                .collect::<LowerResult<Vec<_>>>()?;
+
+            return Ok(Stmt::Expr {
+                expr: Expr::Call {
+                    function: macro_name.clone(),
+                    args: vec![
+                        func_expr,
+                        Expr::TupleLiteral {
+                            elements: type_tuple_elements,
+                            span,
+                        },
+                    ],
+                    kwargs: vec![],
+                    splat_mask: vec![],
+                    kwargs_splat_mask: vec![],
+                    span,
+                },
+                span,
+            });
+        }
         // @static - compile-time conditional evaluation
         // Usage: @static if cond ... else ... end
         //        @static cond ? a : b
@@ -0,0 +1,234 @@
+//! Compile-time format-string parsing for `@printf`/`@sprintf`.
+//!
+//! Rather than lowering to a single generic `sprintf(fmt, args...)` call that
+//! re-parses the whole format string every time it runs, the literal is split
+//! at macro-expansion time into raw-text runs and individual `%`-conversions.
+//! Each conversion becomes its own `sprintf("%<spec>", arg)` call (so the
+//! existing runtime formatter in `vm::formatting::format_sprintf` still does
+//! the actual per-value work) and the pieces are joined with `StringConcat`,
+//! the same node `"x = $(x)"` interpolation lowers to. This also lets us
+//! catch an argument-count mismatch or an unrecognized conversion at
+//! expansion time instead of silently misformatting at runtime.
+
+use crate::error::{UnsupportedFeature, UnsupportedFeatureKind};
+use crate::ir::core::{Expr, Literal, Stmt};
+use crate::lowering::expr::{self, parse_string_literal};
+use crate::lowering::LambdaContext;
+use crate::lowering::LowerResult;
+use crate::parser::cst::{CstWalker, Node};
+use crate::span::Span;
+
+enum PrintfPart {
+    Text(String),
+    /// A full `%`-conversion spec, e.g. `"%.3f"`, ready to hand to `sprintf`.
+    Conversion(String),
+}
+
+/// Split a printf-style format string into alternating text runs and
+/// `%`-conversions, validating each conversion as it's parsed.
+fn parse_printf_format(fmt: &str, span: Span) -> LowerResult<Vec<PrintfPart>> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = fmt.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            text.push(c);
+            continue;
+        }
+
+        if chars.peek() == Some(&'%') {
+            chars.next();
+            text.push('%');
+            continue;
+        }
+
+        if !text.is_empty() {
+            parts.push(PrintfPart::Text(std::mem::take(&mut text)));
+        }
+
+        let mut spec = String::from("%");
+        // flags
+        while matches!(chars.peek(), Some('-') | Some('+') | Some(' ') | Some('#') | Some('0')) {
+            spec.push(chars.next().unwrap());
+        }
+        // width
+        while chars.peek().is_some_and(char::is_ascii_digit) {
+            spec.push(chars.next().unwrap());
+        }
+        // precision
+        if chars.peek() == Some(&'.') {
+            spec.push(chars.next().unwrap());
+            while chars.peek().is_some_and(char::is_ascii_digit) {
+                spec.push(chars.next().unwrap());
+            }
+        }
+
+        match chars.next() {
+            Some(conv @ ('d' | 'i' | 'f' | 'e' | 'E' | 'g' | 'G' | 'x' | 'X' | 'o' | 'c' | 's')) => {
+                spec.push(conv);
+                parts.push(PrintfPart::Conversion(spec));
+            }
+            Some(other) => {
+                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                    .with_hint(format!(
+                        "@printf: unrecognized format directive '{}{}' in \"{}\"",
+                        spec, other, fmt
+                    )));
+            }
+            None => {
+                return Err(UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span)
+                    .with_hint(format!(
+                        "@printf: truncated format directive '{}' at end of \"{}\"",
+                        spec, fmt
+                    )));
+            }
+        }
+    }
+
+    if !text.is_empty() {
+        parts.push(PrintfPart::Text(text));
+    }
+
+    Ok(parts)
+}
+
+/// Expand `@printf(fmt, args...)` / `@sprintf(fmt, args...)` into specialized
+/// IR, given that `args[0]` has already been confirmed to be a string literal.
+pub(super) fn expand_printf_macro<'a>(
+    walker: &CstWalker<'a>,
+    macro_name: &str,
+    args: &[Node<'a>],
+    span: Span,
+    lambda_ctx: &LambdaContext,
+) -> LowerResult<Stmt> {
+    let fmt = parse_string_literal(walker.text(&args[0]));
+    let value_args = &args[1..];
+
+    let parsed = parse_printf_format(&fmt, span)?;
+    let conversion_count = parsed
+        .iter()
+        .filter(|p| matches!(p, PrintfPart::Conversion(_)))
+        .count();
+    if conversion_count != value_args.len() {
+        return Err(
+            UnsupportedFeature::new(UnsupportedFeatureKind::MacroCall, span).with_hint(format!(
+                "@{}: format string \"{}\" has {} conversion(s) but {} argument(s) were given",
+                macro_name,
+                fmt,
+                conversion_count,
+                value_args.len()
+            )),
+        );
+    }
+
+    let mut arg_iter = value_args.iter();
+    let mut concat_parts = Vec::with_capacity(parsed.len());
+    for part in parsed {
+        match part {
+            PrintfPart::Text(text) => concat_parts.push(Expr::Literal(Literal::Str(text), span)),
+            PrintfPart::Conversion(spec) => {
+                // `conversion_count == value_args.len()` was just checked above.
+                let arg_node = *arg_iter.next().expect("conversion/argument count already matched");
+                let arg_expr = expr::lower_expr_with_ctx(walker, arg_node, lambda_ctx)?;
+                concat_parts.push(Expr::Call {
+                    function: "sprintf".to_string(),
+                    args: vec![Expr::Literal(Literal::Str(spec), span), arg_expr],
+                    kwargs: vec![],
+                    splat_mask: vec![false, false],
+                    kwargs_splat_mask: vec![],
+                    span,
+                });
+            }
+        }
+    }
+
+    let formatted = if concat_parts.len() == 1 {
+        concat_parts.remove(0)
+    } else if concat_parts.is_empty() {
+        Expr::Literal(Literal::Str(String::new()), span)
+    } else {
+        Expr::StringConcat {
+            parts: concat_parts,
+            span,
+        }
+    };
+
+    let result_expr = if macro_name == "printf" {
+        Expr::Call {
+            function: "print".to_string(),
+            args: vec![formatted],
+            kwargs: vec![],
+            splat_mask: vec![false],
+            kwargs_splat_mask: vec![],
+            span,
+        }
+    } else {
+        formatted
+    };
+
+    Ok(Stmt::Expr {
+        expr: result_expr,
+        span,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s() -> Span {
+        Span::new(0, 0, 0, 0, 0, 0)
+    }
+
+    #[test]
+    fn test_parse_printf_format_text_only() {
+        let parts = parse_printf_format("hello world", s()).unwrap();
+        assert_eq!(parts.len(), 1);
+        assert!(matches!(&parts[0], PrintfPart::Text(t) if t == "hello world"));
+    }
+
+    #[test]
+    fn test_parse_printf_format_single_conversion() {
+        let parts = parse_printf_format("x = %d", s()).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert!(matches!(&parts[0], PrintfPart::Text(t) if t == "x = "));
+        assert!(matches!(&parts[1], PrintfPart::Conversion(c) if c == "%d"));
+    }
+
+    #[test]
+    fn test_parse_printf_format_width_precision() {
+        let parts = parse_printf_format("%.3f", s()).unwrap();
+        assert_eq!(parts.len(), 1);
+        assert!(matches!(&parts[0], PrintfPart::Conversion(c) if c == "%.3f"));
+    }
+
+    #[test]
+    fn test_parse_printf_format_percent_literal() {
+        let parts = parse_printf_format("100%%", s()).unwrap();
+        assert_eq!(parts.len(), 1);
+        assert!(matches!(&parts[0], PrintfPart::Text(t) if t == "100%"));
+    }
+
+    #[test]
+    fn test_parse_printf_format_unrecognized_conversion_errors() {
+        let result = parse_printf_format("%z", s());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_printf_format_truncated_directive_errors() {
+        let result = parse_printf_format("abc%", s());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_printf_format_multiple_conversions() {
+        let parts = parse_printf_format("%s is %d years old", s()).unwrap();
+        assert_eq!(parts.len(), 4);
+        assert!(matches!(&parts[0], PrintfPart::Conversion(c) if c == "%s"));
+        assert!(matches!(&parts[1], PrintfPart::Text(t) if t == " is "));
+        assert!(matches!(&parts[2], PrintfPart::Conversion(c) if c == "%d"));
+        assert!(matches!(&parts[3], PrintfPart::Text(t) if t == " years old"));
+    }
+}
@@ -473,6 +473,7 @@ fn try_parse_inner_constructor<'a>(
                                 type_annotation: None,
                                 is_varargs: false,
                                 vararg_count: None,
+                                nospecialize: false,
                                 span: walker.span(&param),
                             });
                         }
@@ -485,6 +486,7 @@ fn try_parse_inner_constructor<'a>(
                                 type_annotation: param_type,
                                 is_varargs: false,
                                 vararg_count: None,
+                                nospecialize: false,
                                 span: walker.span(&param),
                             });
                         }
@@ -845,6 +847,7 @@ fn parse_ctor_signature<'a>(
                                 type_annotation: None,
                                 is_varargs: false,
                                 vararg_count: None,
+                                nospecialize: false,
                                 span: walker.span(&param),
                             });
                         }
@@ -855,6 +858,7 @@ fn parse_ctor_signature<'a>(
                                 type_annotation: param_type,
                                 is_varargs: false,
                                 vararg_count: None,
+                                nospecialize: false,
                                 span: walker.span(&param),
                             });
                         }
@@ -868,6 +872,7 @@ fn parse_ctor_signature<'a>(
                     type_annotation: None,
                     is_varargs: false,
                     vararg_count: None,
+                    nospecialize: false,
                     span: walker.span(arg),
                 });
             }
@@ -878,6 +883,7 @@ fn parse_ctor_signature<'a>(
                     type_annotation: param_type,
                     is_varargs: false,
                     vararg_count: None,
+                    nospecialize: false,
                     span: walker.span(arg),
                 });
             }
@@ -6,6 +6,7 @@
 use crate::error::{SyntaxError, UnsupportedFeature};
 use crate::ir::core::Program;
 use crate::julia::base;
+use crate::load_timing::{timed, LoadPhase};
 use crate::loader::{LoadError, LoaderConfig, PackageLoader};
 use crate::lowering::{Lowering, LoweringWithInclude};
 use crate::parser::Parser;
@@ -54,7 +55,9 @@ pub fn get_prelude_program() -> Option<&'static Program> {
     PRELUDE_PROGRAM.as_ref()
 }
 
-/// Parse source code without prelude merging (used for prelude itself)
+/// Parse source code without prelude merging (used for prelude itself).
+/// Parse/lower durations are recorded under the "Prelude" entry of the
+/// opt-in load timing report (see [`crate::load_timing`]) when enabled.
 pub fn parse_source(src: &str) -> PipelineResult {
     let mut parser = Parser::new().map_err(|e| {
         PipelineError::Parse(SyntaxError::parse_failed(format!(
@@ -63,10 +66,11 @@ pub fn parse_source(src: &str) -> PipelineResult {
         )))
     })?;
 
-    let outcome = parser.parse(src).map_err(PipelineError::Parse)?;
+    let outcome =
+        timed("Prelude", LoadPhase::Parse, || parser.parse(src)).map_err(PipelineError::Parse)?;
 
     let mut lowering = Lowering::new(src);
-    lowering.lower(outcome).map_err(PipelineError::Lower)
+    timed("Prelude", LoadPhase::Lower, || lowering.lower(outcome)).map_err(PipelineError::Lower)
 }
 
 /// Parse and lower Julia source code using tree-sitter pipeline.
@@ -0,0 +1,152 @@
+//! Code completion for REPL sessions.
+//!
+//! Scans left from the cursor to decide which completion context applies —
+//! a leading `\` triggers LaTeX/Unicode escape completion, a trailing `.`
+//! after a parseable expression triggers field completion, otherwise the
+//! token is prefix-matched against visible identifiers.
+
+use serde::Serialize;
+
+use crate::vm::Value;
+
+use super::session::REPLSession;
+
+/// A single completion candidate, plus the byte span in the original line
+/// it replaces.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Completion {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Compute completions for `line` at byte offset `cursor` (clamped to the
+/// line's length).
+///
+/// Dotted/chained field access (`a.b.c.`) resolves field names by
+/// evaluating the prefix expression in `session` and reading the struct
+/// type of the resulting value, rather than bailing out when the prefix
+/// isn't a simple variable, so `getfield`-style chains still complete (DOC 5).
+pub fn complete(session: &mut REPLSession, line: &str, cursor: usize) -> Vec<Completion> {
+    let cursor = cursor.min(line.len());
+    let before = &line[..cursor];
+
+    if let Some(start) = escape_start(before) {
+        return escape_completions(before, start);
+    }
+
+    if let Some(dot) = before.rfind('.') {
+        let partial = &before[dot + 1..];
+        let prefix_expr = &before[..dot];
+        if is_identifier_like(partial) && !prefix_expr.is_empty() {
+            return field_completions(session, prefix_expr, partial, dot + 1, cursor);
+        }
+    }
+
+    identifier_completions(session, before, cursor)
+}
+
+fn is_identifier_like(s: &str) -> bool {
+    s.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Byte offset of the `\` that starts an in-progress escape sequence
+/// ending at the cursor, if any. Scans backward over identifier
+/// characters and stops as soon as a `\` is found or a non-identifier
+/// character breaks the token. Clamps to 0 when the backslash is the very
+/// first character of the buffer instead of underflowing past it (a real
+/// Julia REPL bug this mirrors the fix for — DOC 11).
+fn escape_start(before: &str) -> Option<usize> {
+    let mut i = before.len();
+    for c in before.chars().rev() {
+        if c == '\\' {
+            return Some(i - c.len_utf8());
+        }
+        if c.is_alphanumeric() || c == '_' {
+            i -= c.len_utf8();
+        } else {
+            break;
+        }
+    }
+    None
+}
+
+fn escape_completions(before: &str, start: usize) -> Vec<Completion> {
+    let token = &before[start..];
+    let latex_prefix = token.trim_start_matches('\\');
+    crate::unicode::completions_for_prefix(latex_prefix)
+        .into_iter()
+        .map(|(_latex, unicode)| Completion {
+            text: unicode.to_string(),
+            start,
+            end: before.len(),
+        })
+        .collect()
+}
+
+fn identifier_start(before: &str) -> usize {
+    let mut i = before.len();
+    for c in before.chars().rev() {
+        if c.is_alphanumeric() || c == '_' {
+            i -= c.len_utf8();
+        } else {
+            break;
+        }
+    }
+    i
+}
+
+fn identifier_completions(session: &REPLSession, before: &str, cursor: usize) -> Vec<Completion> {
+    let start = identifier_start(before);
+    let prefix = &before[start..];
+
+    let mut names = session.variable_names();
+    names.extend(session.function_names());
+    names.extend(session.struct_names());
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter(|name| name.starts_with(prefix))
+        .map(|text| Completion {
+            text,
+            start,
+            end: cursor,
+        })
+        .collect()
+}
+
+fn field_completions(
+    session: &mut REPLSession,
+    prefix_expr: &str,
+    partial: &str,
+    start: usize,
+    end: usize,
+) -> Vec<Completion> {
+    let result = session.eval(prefix_expr);
+    if !result.success {
+        return Vec::new();
+    }
+
+    let struct_name = match result.value {
+        Some(Value::Struct(s)) => Some(s.struct_name),
+        Some(Value::StructRef(idx)) => session
+            .get_struct_heap()
+            .get(idx)
+            .map(|s| s.struct_name.clone()),
+        _ => None,
+    };
+    let Some(struct_name) = struct_name else {
+        return Vec::new();
+    };
+    let Some(field_names) = session.struct_field_names(&struct_name) else {
+        return Vec::new();
+    };
+
+    field_names
+        .into_iter()
+        .filter(|name| name.starts_with(partial))
+        .map(|text| Completion { text, start, end })
+        .collect()
+}
@@ -189,15 +189,17 @@ impl REPLGlobals {
             // - SliceAll: internal sentinel for `a[:]` indexing; not a user variable
             // - Struct: stored as StructRef via struct_ref_vars; struct heap manages lifecycle
             // - Ref: mutable reference wrapper; cannot safely re-create across REPL steps
+            // - Boxed: compiler-internal closure-capture cell; never a user-visible binding
             // - Generator: exhaustible iterator; cannot be safely re-created
             // - DataType, Module: no Literal representation in IR; cannot inject
             // - BigInt, BigFloat: no Literal::BigInt/BigFloat injection pipeline yet (Issue #3301)
+            // - Float128: no Literal::Float128 injection pipeline yet, same as BigFloat
             // - Undef: compiler-internal sentinel for uninitialized variables
             // - IO: I/O handles cannot be serialized
             Value::Nothing | Value::Missing | Value::SliceAll | Value::Struct(_) |
-            Value::Ref(_) | Value::Generator(_) | Value::DataType(_) |
+            Value::Ref(_) | Value::Boxed(_) | Value::Generator(_) | Value::DataType(_) |
             Value::Module(_) |
-            Value::BigInt(_) | Value::BigFloat(_) | Value::Undef | Value::IO(_) => {
+            Value::BigInt(_) | Value::BigFloat(_) | Value::F128(_) | Value::Undef | Value::IO(_) => {
             }
         }
     }
@@ -3,10 +3,12 @@
 //! This module provides a REPLSession that maintains state between evaluations,
 //! allowing variables defined in one evaluation to be used in subsequent ones.
 
+mod complete;
 mod converters;
 mod globals;
 mod session;
 
+pub use complete::{complete, Completion};
 pub use globals::{REPLGlobals, REPLResult};
 pub use session::REPLSession;
 
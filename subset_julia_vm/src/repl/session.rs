@@ -544,6 +544,23 @@ impl REPLSession {
         self.globals.variable_names()
     }
 
+    /// Get the names of all functions defined in the session.
+    pub fn function_names(&self) -> Vec<String> {
+        self.function_index.keys().cloned().collect()
+    }
+
+    /// Get the names of all structs defined in the session.
+    pub fn struct_names(&self) -> Vec<String> {
+        self.struct_index.keys().cloned().collect()
+    }
+
+    /// Get the field names of a struct defined in this session, in
+    /// declaration order, or `None` if no such struct exists.
+    pub fn struct_field_names(&self, struct_name: &str) -> Option<Vec<String>> {
+        let &idx = self.struct_index.get(struct_name)?;
+        Some(self.structs[idx].fields.iter().map(|f| f.name.clone()).collect())
+    }
+
     /// Split input into top-level expressions.
     /// Returns a vector of (start_byte, end_byte, source_text) for each expression.
     /// If parsing fails, returns None.
@@ -0,0 +1,160 @@
+//! Async multi-session execution runtime.
+//!
+//! The FFI entry points in [`crate::ffi`] (`compile_and_run`, `repl_session_eval`,
+//! etc.) all block the caller until the job finishes. [`VmRuntime`] instead
+//! hands a job to a fixed pool of worker threads — each owning an
+//! independent [`REPLSession`] — and returns a [`TaskId`] immediately, so a
+//! host can submit many jobs concurrently and [`VmRuntime::poll`] for
+//! results out of order. Modeled on jlrs's async runtime.
+//!
+//! # Send safety
+//!
+//! [`Value`](crate::vm::Value) holds `Rc`/`RefCell` internals and is not
+//! `Send`, so a `REPLSession` can never be moved between threads or have
+//! its values read from a thread other than the one that ran it. Every
+//! worker therefore creates its own `REPLSession` inside its own spawned
+//! closure and never gives it up; only plain, `Send` data — source
+//! strings, the already-formatted [`TaskOutcome`], and `Arc<AtomicBool>`
+//! cancellation flags — crosses the channel between threads.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+
+use crate::ffi::format_value;
+use crate::repl::REPLSession;
+
+/// Identifies a task submitted to a [`VmRuntime`]. Unique for the lifetime
+/// of the runtime that issued it.
+pub type TaskId = u64;
+
+/// Outcome of a completed task, analogous to [`crate::repl::REPLResult`]
+/// but with the result value already formatted to a `String` instead of
+/// carrying a non-`Send` [`Value`](crate::vm::Value).
+#[derive(Debug, Clone)]
+pub struct TaskOutcome {
+    pub success: bool,
+    pub output: String,
+    pub value: Option<String>,
+    pub error: Option<String>,
+}
+
+enum Job {
+    Eval {
+        id: TaskId,
+        src: String,
+        cancel: Arc<AtomicBool>,
+    },
+    Shutdown,
+}
+
+/// A fixed pool of worker threads, each running compile-and-run jobs
+/// against its own [`REPLSession`].
+pub struct VmRuntime {
+    job_txs: Vec<Sender<Job>>,
+    next_worker: AtomicU64,
+    next_task_id: AtomicU64,
+    results: Arc<Mutex<HashMap<TaskId, TaskOutcome>>>,
+    in_flight: Mutex<HashMap<TaskId, Arc<AtomicBool>>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl VmRuntime {
+    /// Spawn `worker_count` worker threads (clamped to at least 1), each
+    /// with its own `REPLSession` seeded from `seed`.
+    pub fn new(worker_count: usize, seed: u64) -> Self {
+        let worker_count = worker_count.max(1);
+        let results = Arc::new(Mutex::new(HashMap::new()));
+        let mut job_txs = Vec::with_capacity(worker_count);
+        let mut workers = Vec::with_capacity(worker_count);
+
+        for _ in 0..worker_count {
+            let (tx, rx) = mpsc::channel::<Job>();
+            let results = Arc::clone(&results);
+            workers.push(std::thread::spawn(move || worker_loop(rx, results, seed)));
+            job_txs.push(tx);
+        }
+
+        VmRuntime {
+            job_txs,
+            next_worker: AtomicU64::new(0),
+            next_task_id: AtomicU64::new(0),
+            results,
+            in_flight: Mutex::new(HashMap::new()),
+            workers,
+        }
+    }
+
+    /// Submit `src` for evaluation on the next worker (round-robin) and
+    /// return its [`TaskId`] immediately, without waiting for it to run.
+    pub fn submit(&self, src: String) -> TaskId {
+        let id = self.next_task_id.fetch_add(1, Ordering::SeqCst);
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        self.in_flight
+            .lock()
+            .unwrap()
+            .insert(id, Arc::clone(&cancel_flag));
+
+        let worker =
+            (self.next_worker.fetch_add(1, Ordering::SeqCst) as usize) % self.job_txs.len();
+        self.job_txs[worker]
+            .send(Job::Eval {
+                id,
+                src,
+                cancel: cancel_flag,
+            })
+            .expect("runtime worker thread terminated unexpectedly");
+        id
+    }
+
+    /// Non-blocking poll for a task's result. Returns `None` until the
+    /// owning worker finishes; once returned, the outcome is removed from
+    /// the runtime so a given task is only ever handed back once.
+    pub fn poll(&self, id: TaskId) -> Option<TaskOutcome> {
+        self.results.lock().unwrap().remove(&id)
+    }
+
+    /// Request cancellation of a single in-flight task, without affecting
+    /// any other worker's task or tearing down the runtime. A no-op if the
+    /// task already completed or `id` is unknown.
+    pub fn cancel(&self, id: TaskId) {
+        if let Some(flag) = self.in_flight.lock().unwrap().get(&id) {
+            flag.store(true, Ordering::SeqCst);
+        }
+    }
+
+    /// Tell every worker to stop after its current job and wait for them
+    /// to exit, draining the pool cleanly.
+    pub fn shutdown(mut self) {
+        for tx in &self.job_txs {
+            let _ = tx.send(Job::Shutdown);
+        }
+        for handle in self.workers.drain(..) {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn worker_loop(rx: Receiver<Job>, results: Arc<Mutex<HashMap<TaskId, TaskOutcome>>>, seed: u64) {
+    let mut session = REPLSession::new(seed);
+    while let Ok(job) = rx.recv() {
+        let (id, src, cancel) = match job {
+            Job::Shutdown => break,
+            Job::Eval { id, src, cancel } => (id, src, cancel),
+        };
+
+        crate::cancel::set_task_flag(Some(cancel));
+        let eval_result = session.eval(&src);
+        crate::cancel::set_task_flag(None);
+
+        let outcome = TaskOutcome {
+            success: eval_result.success,
+            output: eval_result.output,
+            value: eval_result.value.as_ref().map(format_value),
+            error: eval_result.error,
+        };
+        results.lock().unwrap().insert(id, outcome);
+    }
+}
@@ -12,6 +12,7 @@ use once_cell::sync::Lazy;
 use std::sync::RwLock;
 
 use crate::ir::core::{Module, Program, UsingImport};
+use crate::load_timing::{timed, LoadPhase};
 use crate::lowering::{Lowering, MacroParamType, StoredMacroDef};
 use crate::parser::Parser;
 use crate::stdlib;
@@ -185,8 +186,7 @@ fn load_stdlib_module(module_name: &str) -> Result<Module, StdlibLoadError> {
         error: format!("{:?}", e),
     })?;
 
-    let parse_outcome = parser
-        .parse(source)
+    let parse_outcome = timed(module_name, LoadPhase::Parse, || parser.parse(source))
         .map_err(|e| StdlibLoadError::ParseError {
             module: module_name.to_string(),
             error: format!("{:?}", e),
@@ -194,8 +194,7 @@ fn load_stdlib_module(module_name: &str) -> Result<Module, StdlibLoadError> {
 
     // Lower using unified Lowering (same code path as tree-sitter)
     let mut lowering = Lowering::new(source);
-    let program = lowering
-        .lower(parse_outcome)
+    let program = timed(module_name, LoadPhase::Lower, || lowering.lower(parse_outcome))
         .map_err(|e| StdlibLoadError::LowerError {
             module: module_name.to_string(),
             error: format!("{:?}", e),
@@ -66,6 +66,7 @@ impl JuliaType {
                         | JuliaType::Float32
                         | JuliaType::Float64
                         | JuliaType::BigFloat
+                        | JuliaType::Float128
                         | JuliaType::Integer
                         | JuliaType::Signed
                         | JuliaType::Unsigned
@@ -100,6 +101,7 @@ impl JuliaType {
                         | JuliaType::Float32
                         | JuliaType::Float64
                         | JuliaType::BigFloat
+                        | JuliaType::Float128
                         | JuliaType::Integer
                         | JuliaType::Signed
                         | JuliaType::Unsigned
@@ -157,6 +159,7 @@ impl JuliaType {
                     | JuliaType::Float32
                     | JuliaType::Float64
                     | JuliaType::BigFloat
+                    | JuliaType::Float128
                     | JuliaType::AbstractFloat
             ),
             JuliaType::AbstractString => {
@@ -312,6 +315,7 @@ impl JuliaType {
             | JuliaType::Float32
             | JuliaType::Float64
             | JuliaType::BigFloat
+            | JuliaType::Float128
             | JuliaType::String
             | JuliaType::Char
             | JuliaType::Array
@@ -26,6 +26,7 @@ impl JuliaType {
             JuliaType::Float32 => "Float32".into(),
             JuliaType::Float64 => "Float64".into(),
             JuliaType::BigFloat => "BigFloat".into(),
+            JuliaType::Float128 => "Float128".into(),
             // Other concrete types
             JuliaType::String => "String".into(),
             JuliaType::Char => "Char".into(),
@@ -15,7 +15,7 @@
 //!  │    │    │    └── Unsigned
 //!  │    │    │         └── UInt8, UInt16, UInt32, UInt64, UInt128 (concrete)
 //!  │    │    └── AbstractFloat
-//!  │    │         └── Float16, Float32, Float64, BigFloat (concrete)
+//!  │    │         └── Float16, Float32, Float64, BigFloat, Float128 (concrete)
 //!  ├── AbstractString
 //!  │    └── String (concrete)
 //!  └── AbstractArray
@@ -86,6 +86,7 @@ pub enum JuliaType {
     Float32,
     Float64,
     BigFloat, // Arbitrary precision floating point
+    Float128, // Software quad-precision (binary128) floating point
     // Note: Complex numbers are Pure Julia structs, not a builtin type
     String,
     Char, // 32-bit Unicode codepoint
@@ -251,6 +252,7 @@ impl JuliaType {
                 | JuliaType::Float32
                 | JuliaType::Float64
                 | JuliaType::BigFloat
+                | JuliaType::Float128
                 // Other concrete types
                 | JuliaType::String
                 | JuliaType::Char
@@ -302,6 +304,7 @@ impl JuliaType {
                 | JuliaType::Float32
                 | JuliaType::Float64
                 | JuliaType::BigFloat
+                | JuliaType::Float128
                 // Boolean
                 | JuliaType::Bool
                 // String and Char
@@ -382,6 +385,7 @@ impl JuliaType {
                 | JuliaType::Float32
                 | JuliaType::Float64
                 | JuliaType::BigFloat
+                | JuliaType::Float128
                 // Boolean
                 | JuliaType::Bool
                 // String and Char
@@ -186,6 +186,7 @@ impl JuliaType {
             "Float32" => Some(JuliaType::Float32),
             "Float64" => Some(JuliaType::Float64),
             "BigFloat" => Some(JuliaType::BigFloat),
+            "Float128" => Some(JuliaType::Float128),
             // Note: Complex is now a user-defined struct, handled by from_name_or_struct
             // Other concrete types
             "String" => Some(JuliaType::String),
@@ -13,6 +13,7 @@
 
 use super::error::VmError;
 use super::value::ArrayValue;
+use std::rc::Rc;
 
 /// Enum to represent either an array or a scalar for broadcasting.
 ///
@@ -92,6 +93,63 @@ pub(crate) fn compute_broadcast_shape(
     Ok(result)
 }
 
+/// N-ary version of `compute_broadcast_shape`, reducing the same rule across
+/// an arbitrary slice of operand shapes in one pass instead of pairwise
+/// folding (the ndarray `co_broadcast` approach), which avoids the chained
+/// temporaries pairwise folding would force for expressions like `a .+ b .+ c`.
+pub(crate) fn compute_broadcast_shape_n(shapes: &[&[usize]]) -> Result<Vec<usize>, VmError> {
+    if shapes.len() <= 1 {
+        return Ok(shapes.first().map_or_else(Vec::new, |s| s.to_vec()));
+    }
+
+    // Julia treats 1D arrays as column vectors ([n] -> [n, 1]) once any
+    // operand is 2D+; mirror expand_shapes_for_julia's rule across the whole
+    // operand set rather than just a pair.
+    let needs_column_expansion = shapes.iter().any(|s| s.len() >= 2);
+    let expanded: Vec<Vec<usize>> = shapes
+        .iter()
+        .map(|s| {
+            if needs_column_expansion && s.len() == 1 {
+                let mut v = s.to_vec();
+                v.push(1);
+                v
+            } else {
+                s.to_vec()
+            }
+        })
+        .collect();
+
+    let max_dims = expanded.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result = Vec::with_capacity(max_dims);
+
+    // Align from the right (trailing dimensions), same as compute_broadcast_shape.
+    for i in 0..max_dims {
+        let mut dim = 1usize;
+        let mut dim_owner = 0usize;
+        for (idx, expanded_shape) in expanded.iter().enumerate() {
+            let pos = expanded_shape.len() as isize - max_dims as isize + i as isize;
+            let d = if pos >= 0 {
+                expanded_shape[pos as usize]
+            } else {
+                1
+            };
+            if d != dim && d != 1 && dim != 1 {
+                return Err(VmError::BroadcastDimensionMismatch {
+                    a_shape: shapes[dim_owner].to_vec(),
+                    b_shape: shapes[idx].to_vec(),
+                });
+            }
+            if d != 1 {
+                dim = d;
+                dim_owner = idx;
+            }
+        }
+        result.push(dim);
+    }
+
+    Ok(result)
+}
+
 /// Expand shapes for Julia-style broadcasting.
 /// In Julia, 1D arrays are column vectors, so [n] becomes [n, 1] in 2D contexts.
 pub(crate) fn expand_shapes_for_julia(
@@ -179,6 +237,152 @@ pub(crate) fn broadcast_get_index(
     orig_idx
 }
 
+/// Check whether every operand shape has a "uniform data layout" relative to
+/// `result_shape`: it either equals `result_shape` exactly, or is a scalar
+/// (size 1). Borrowed from ClimaCore's linear-index pointwise kernels - when
+/// this holds, every operand can be indexed by the plain result linear index
+/// (or always 0, for the size-1 case), so callers can skip the per-element
+/// `compute_strides`/`broadcast_get_index` decomposition entirely.
+pub(crate) fn has_uniform_datalayouts(operand_shapes: &[&[usize]], result_shape: &[usize]) -> bool {
+    operand_shapes
+        .iter()
+        .all(|s| *s == result_shape || s.iter().product::<usize>() == 1)
+}
+
+/// A node in a lazily-fused broadcast expression tree, analogous to
+/// Julia's `Broadcast.Broadcasted`. Chaining dot-ops via `Broadcasted::make`
+/// builds up this tree without materializing any intermediate array; only
+/// `materialize` allocates, and it does so exactly once, evaluating the
+/// whole tree per output element.
+pub(crate) enum Broadcasted<'a> {
+    /// A leaf operand: an existing array or scalar, fetched element-wise via
+    /// the same `broadcast_get_index` the eager path uses.
+    Leaf(&'a Broadcastable),
+    /// An internal node: an elementwise op applied to its children's
+    /// already-computed scalars. No array is ever allocated here.
+    Node {
+        op: Rc<dyn Fn(&[f64]) -> f64 + 'a>,
+        args: Vec<Broadcasted<'a>>,
+        /// Result shape of this subtree, folded pairwise across the
+        /// children's shapes once in `make` rather than recomputed per
+        /// element.
+        shape: Vec<usize>,
+        strides: Vec<usize>,
+    },
+}
+
+impl<'a> Broadcasted<'a> {
+    fn leaf_shape(b: &Broadcastable) -> Vec<usize> {
+        match b {
+            Broadcastable::Array(arr) => arr.shape.clone(),
+            Broadcastable::ScalarF64(_) => vec![1],
+        }
+    }
+
+    /// Wrap an existing operand as a leaf of the fused tree.
+    pub(crate) fn leaf(b: &'a Broadcastable) -> Self {
+        Broadcasted::Leaf(b)
+    }
+
+    /// This subtree's broadcast result shape.
+    pub(crate) fn shape(&self) -> Vec<usize> {
+        match self {
+            Broadcasted::Leaf(b) => Self::leaf_shape(b),
+            Broadcasted::Node { shape, .. } => shape.clone(),
+        }
+    }
+
+    /// Build an internal node applying `op` to the scalars its `args`
+    /// produce at each output position. The node's own result shape is
+    /// computed once here by folding `compute_broadcast_shape` pairwise
+    /// across every argument's shape.
+    pub(crate) fn make(
+        op: Rc<dyn Fn(&[f64]) -> f64 + 'a>,
+        args: Vec<Broadcasted<'a>>,
+    ) -> Result<Broadcasted<'a>, VmError> {
+        let mut shape = args.first().map(|a| a.shape()).unwrap_or_else(|| vec![1]);
+        for arg in &args[1..] {
+            shape = compute_broadcast_shape(&shape, &arg.shape())?;
+        }
+        let strides = compute_strides(&shape);
+        Ok(Broadcasted::Node {
+            op,
+            args,
+            shape,
+            strides,
+        })
+    }
+
+    /// Evaluate this subtree at `outer_idx`, a linear index into some
+    /// ancestor's (or, for the top-level call from `materialize`, this
+    /// node's own) result shape/strides.
+    fn eval_at(
+        &self,
+        outer_idx: usize,
+        outer_shape: &[usize],
+        outer_strides: &[usize],
+    ) -> Result<f64, VmError> {
+        match self {
+            Broadcasted::Leaf(b) => {
+                let leaf_shape = Self::leaf_shape(b);
+                // Julia treats a bare 1D array as a column vector ([n] ->
+                // [n, 1]) when broadcast against 2D+ operands; expand here
+                // before computing strides/ndims_diff, same as the eager path.
+                let (_, expanded_leaf) = expand_shapes_for_julia(outer_shape, &leaf_shape);
+                let leaf_strides = compute_strides(&expanded_leaf);
+                let ndims_diff = outer_shape.len().saturating_sub(expanded_leaf.len());
+                let idx = broadcast_get_index(
+                    outer_idx,
+                    outer_shape,
+                    outer_strides,
+                    &expanded_leaf,
+                    &leaf_strides,
+                    ndims_diff,
+                );
+                match b {
+                    Broadcastable::Array(arr) => Ok(arr.try_data_f64()?[idx]),
+                    Broadcastable::ScalarF64(v) => Ok(*v),
+                }
+            }
+            Broadcasted::Node {
+                op,
+                args,
+                shape,
+                strides,
+            } => {
+                let ndims_diff = outer_shape.len().saturating_sub(shape.len());
+                let own_idx = broadcast_get_index(
+                    outer_idx,
+                    outer_shape,
+                    outer_strides,
+                    shape,
+                    strides,
+                    ndims_diff,
+                );
+                let mut values = Vec::with_capacity(args.len());
+                for arg in args {
+                    values.push(arg.eval_at(own_idx, shape, strides)?);
+                }
+                Ok(op(&values))
+            }
+        }
+    }
+
+    /// Force the whole fused tree into a single freshly-allocated array.
+    /// This is the only allocation in the entire evaluation, no matter how
+    /// many dot-ops were chained into the tree.
+    pub(crate) fn materialize(&self) -> Result<ArrayValue, VmError> {
+        let shape = self.shape();
+        let strides = compute_strides(&shape);
+        let size: usize = shape.iter().product();
+        let mut data = Vec::with_capacity(size);
+        for i in 0..size {
+            data.push(self.eval_at(i, &shape, &strides)?);
+        }
+        Ok(ArrayValue::from_f64(data, shape))
+    }
+}
+
 /// Perform element-wise broadcast operation (f64 only)
 /// Supports Julia-style broadcasting:
 /// - Array .op Array (compatible shapes, broadcasts size-1 dimensions)
@@ -199,15 +403,21 @@ where
             let result_shape = compute_broadcast_shape(&arr_a.shape, &arr_b.shape)?;
             let result_size: usize = result_shape.iter().product();
 
-            // Fast path: same shape, no broadcasting needed
-            if arr_a.shape == arr_b.shape {
-                let data: Vec<f64> = arr_a
-                    .try_data_f64()?
-                    .iter()
-                    .zip(arr_b.try_data_f64()?.iter())
-                    .map(|(&x, &y)| op(x, y))
-                    .collect();
-                return Ok(ArrayValue::from_f64(data, arr_a.shape.clone()));
+            // Fast path: every operand is either full-shape or a scalar, so
+            // each can be indexed directly by the linear result index (or 0),
+            // skipping compute_strides/broadcast_get_index entirely.
+            if has_uniform_datalayouts(&[&arr_a.shape, &arr_b.shape], &result_shape) {
+                let a_full = arr_a.shape == result_shape;
+                let b_full = arr_b.shape == result_shape;
+                let a_data = arr_a.try_data_f64()?;
+                let b_data = arr_b.try_data_f64()?;
+                let mut data = Vec::with_capacity(result_size);
+                for i in 0..result_size {
+                    let a_val = if a_full { a_data[i] } else { a_data[0] };
+                    let b_val = if b_full { b_data[i] } else { b_data[0] };
+                    data.push(op(a_val, b_val));
+                }
+                return Ok(ArrayValue::from_f64(data, result_shape));
             }
 
             // Get expanded shapes for Julia-style broadcasting
@@ -264,6 +474,164 @@ where
     }
 }
 
+/// In-place companion to `broadcast_op_f64`: writes results into `dest`
+/// instead of allocating a fresh `ArrayValue`, mirroring Julia's
+/// `broadcast!`/`copyto!` split. `dest.shape` must already equal the
+/// broadcast result shape of `a` and `b` - this function never resizes or
+/// reshapes the destination - so callers that already own a stable output
+/// buffer (`hof_exec.rs` reusing it across loop iterations, or a
+/// dot-assignment `x .= a .+ b`) can skip a fresh allocation every step.
+pub(crate) fn broadcast_op_f64_into<F>(
+    dest: &mut ArrayValue,
+    a: &Broadcastable,
+    b: &Broadcastable,
+    op: F,
+) -> Result<(), VmError>
+where
+    F: Fn(f64, f64) -> f64,
+{
+    let get_shape = |bc: &Broadcastable| -> Vec<usize> {
+        match bc {
+            Broadcastable::Array(arr) => arr.shape.clone(),
+            Broadcastable::ScalarF64(_) => vec![1],
+        }
+    };
+    let a_shape = get_shape(a);
+    let b_shape = get_shape(b);
+    let result_shape = compute_broadcast_shape(&a_shape, &b_shape)?;
+    if dest.shape != result_shape {
+        return Err(VmError::BroadcastDestShapeMismatch {
+            expected: result_shape,
+            got: dest.shape.clone(),
+        });
+    }
+    let result_size: usize = result_shape.iter().product();
+
+    if has_uniform_datalayouts(&[&a_shape, &b_shape], &result_shape) {
+        let a_full = a_shape == result_shape;
+        let b_full = b_shape == result_shape;
+        let dest_data = dest.try_data_f64_mut()?;
+        for (i, slot) in dest_data.iter_mut().enumerate().take(result_size) {
+            let a_val = match a {
+                Broadcastable::Array(arr) => arr.try_data_f64()?[if a_full { i } else { 0 }],
+                Broadcastable::ScalarF64(v) => *v,
+            };
+            let b_val = match b {
+                Broadcastable::Array(arr) => arr.try_data_f64()?[if b_full { i } else { 0 }],
+                Broadcastable::ScalarF64(v) => *v,
+            };
+            *slot = op(a_val, b_val);
+        }
+        return Ok(());
+    }
+
+    let (a_expanded, b_expanded) = expand_shapes_for_julia(&a_shape, &b_shape);
+    let result_strides = compute_strides(&result_shape);
+    let a_strides = compute_strides(&a_expanded);
+    let b_strides = compute_strides(&b_expanded);
+    let a_ndims_diff = result_shape.len() - a_expanded.len();
+    let b_ndims_diff = result_shape.len() - b_expanded.len();
+
+    let dest_data = dest.try_data_f64_mut()?;
+    for (i, slot) in dest_data.iter_mut().enumerate().take(result_size) {
+        let a_idx = broadcast_get_index(
+            i,
+            &result_shape,
+            &result_strides,
+            &a_expanded,
+            &a_strides,
+            a_ndims_diff,
+        );
+        let b_idx = broadcast_get_index(
+            i,
+            &result_shape,
+            &result_strides,
+            &b_expanded,
+            &b_strides,
+            b_ndims_diff,
+        );
+        let a_val = match a {
+            Broadcastable::Array(arr) => arr.try_data_f64()?[a_idx],
+            Broadcastable::ScalarF64(v) => *v,
+        };
+        let b_val = match b {
+            Broadcastable::Array(arr) => arr.try_data_f64()?[b_idx],
+            Broadcastable::ScalarF64(v) => *v,
+        };
+        *slot = op(a_val, b_val);
+    }
+
+    Ok(())
+}
+
+/// N-ary companion to `broadcast_op_f64`: computes one common shape up front
+/// via `compute_broadcast_shape_n` and fills the output in a single pass,
+/// reducing every operand at each position with `op` instead of building up
+/// pairwise temporaries for expressions like `a .+ b .+ c`.
+pub(crate) fn broadcast_op_nary<F>(
+    operands: &[&Broadcastable],
+    op: F,
+) -> Result<ArrayValue, VmError>
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let get_shape = |bc: &Broadcastable| -> Vec<usize> {
+        match bc {
+            Broadcastable::Array(arr) => arr.shape.clone(),
+            Broadcastable::ScalarF64(_) => vec![1],
+        }
+    };
+
+    let shapes: Vec<Vec<usize>> = operands.iter().map(|bc| get_shape(bc)).collect();
+    let shape_refs: Vec<&[usize]> = shapes.iter().map(Vec::as_slice).collect();
+    let result_shape = compute_broadcast_shape_n(&shape_refs)?;
+    let result_strides = compute_strides(&result_shape);
+    let result_size: usize = result_shape.iter().product();
+
+    // Expand each operand's shape/strides once, up front, rather than per element.
+    let needs_column_expansion = shapes.iter().any(|s| s.len() >= 2);
+    let expanded_shapes: Vec<Vec<usize>> = shapes
+        .iter()
+        .map(|s| {
+            if needs_column_expansion && s.len() == 1 {
+                let mut v = s.clone();
+                v.push(1);
+                v
+            } else {
+                s.clone()
+            }
+        })
+        .collect();
+    let operand_strides: Vec<Vec<usize>> =
+        expanded_shapes.iter().map(|s| compute_strides(s)).collect();
+    let ndims_diffs: Vec<usize> = expanded_shapes
+        .iter()
+        .map(|s| result_shape.len().saturating_sub(s.len()))
+        .collect();
+
+    let mut values = vec![0.0f64; operands.len()];
+    let mut data = Vec::with_capacity(result_size);
+    for i in 0..result_size {
+        for (k, bc) in operands.iter().enumerate() {
+            let idx = broadcast_get_index(
+                i,
+                &result_shape,
+                &result_strides,
+                &expanded_shapes[k],
+                &operand_strides[k],
+                ndims_diffs[k],
+            );
+            values[k] = match bc {
+                Broadcastable::Array(arr) => arr.try_data_f64()?[idx],
+                Broadcastable::ScalarF64(v) => *v,
+            };
+        }
+        data.push(op(&values));
+    }
+
+    Ok(ArrayValue::from_f64(data, result_shape))
+}
+
 /// Complex number operations as inline helpers
 #[inline]
 pub(crate) fn complex_add(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
@@ -289,6 +657,43 @@ pub(crate) fn complex_div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
     )
 }
 
+/// A lazy, allocation-free view presenting an `ArrayValue`'s backing f64
+/// buffer as `(f64, f64)` complex pairs, mirroring Julia's
+/// `ReinterpretArray`. Detects the same interleaved `[re0, im0, re1, im1,
+/// ...]` layout `Broadcastable::is_complex` checks for; a plain real array is
+/// presented as `(x, 0.0)` pairs, with no copying either way. Keeping the
+/// interleave/deinterleave logic here means a future write-back
+/// (`materialize`-style) path can reuse the exact same indexing.
+pub(crate) struct ComplexView<'a> {
+    arr: &'a ArrayValue,
+    interleaved: bool,
+}
+
+impl<'a> ComplexView<'a> {
+    pub(crate) fn new(arr: &'a ArrayValue) -> Self {
+        let element_count = arr.element_count();
+        let interleaved = element_count > 0 && arr.len() == element_count * 2;
+        ComplexView { arr, interleaved }
+    }
+
+    /// Fetch the complex pair at element index `idx` (an index into logical
+    /// complex elements, not the raw f64 buffer).
+    pub(crate) fn get(&self, idx: usize) -> Result<(f64, f64), VmError> {
+        let data = self.arr.try_data_f64()?;
+        if self.interleaved {
+            Ok((data[idx * 2], data[idx * 2 + 1]))
+        } else {
+            Ok((data[idx], 0.0))
+        }
+    }
+}
+
+/// Fallback for operands with no backing array: present a bare real scalar
+/// as a complex pair with zero imaginary part.
+pub(crate) fn from_real(x: f64) -> (f64, f64) {
+    (x, 0.0)
+}
+
 /// Perform element-wise broadcast operation with complex number support
 /// Automatically promotes to complex when either operand is complex
 /// Uses Julia-style broadcasting for arrays with compatible shapes
@@ -323,7 +728,8 @@ where
     let a_ndims_diff = result_shape.len() - a_expanded.len();
     let b_ndims_diff = result_shape.len() - b_expanded.len();
 
-    // Extract complex values from each operand at a given index
+    // Extract complex values from each operand at a given index, through a
+    // ComplexView so the interleave/deinterleave logic lives in one place.
     let get_complex_at = |bc: &Broadcastable,
                           idx: usize,
                           orig_shape: Option<&[usize]>,
@@ -331,7 +737,7 @@ where
                           ndims_diff: Option<usize>|
      -> Result<(f64, f64), VmError> {
         match bc {
-            Broadcastable::ScalarF64(v) => Ok((*v, 0.0)),
+            Broadcastable::ScalarF64(v) => Ok(from_real(*v)),
             Broadcastable::Array(arr) => {
                 // Compute the correct source index for broadcasting
                 let src_idx = if let (Some(shape), Some(strides), Some(diff)) =
@@ -342,18 +748,7 @@ where
                     idx
                 };
 
-                // Check if this is an interleaved complex array
-                let element_count = arr.element_count();
-                if arr.len() == element_count * 2 {
-                    // Interleaved complex: [re0, im0, re1, im1, ...]
-                    Ok((
-                        arr.try_data_f64()?[src_idx * 2],
-                        arr.try_data_f64()?[src_idx * 2 + 1],
-                    ))
-                } else {
-                    // Regular F64 array - treat as real part, imaginary part is 0
-                    Ok((arr.try_data_f64()?[src_idx], 0.0))
-                }
+                ComplexView::new(arr).get(src_idx)
             }
         }
     };
@@ -442,6 +837,84 @@ where
     Ok(ArrayValue::from_f64(result_data, result_shape))
 }
 
+/// In-place companion to `broadcast_op_complex`: writes interleaved
+/// `[re0, im0, re1, im1, ...]` output into `dest` instead of allocating a
+/// fresh `ArrayValue`. `dest.shape` must equal the broadcast result shape of
+/// `a` and `b`, and `dest`'s backing buffer must already have room for the
+/// interleaved layout (`dest.len() == 2 * result_size`).
+pub(crate) fn broadcast_op_complex_into<F>(
+    dest: &mut ArrayValue,
+    a: &Broadcastable,
+    b: &Broadcastable,
+    op: F,
+) -> Result<(), VmError>
+where
+    F: Fn((f64, f64), (f64, f64)) -> (f64, f64),
+{
+    let get_shape = |bc: &Broadcastable| -> Vec<usize> {
+        match bc {
+            Broadcastable::Array(arr) => arr.shape.clone(),
+            Broadcastable::ScalarF64(_) => vec![1],
+        }
+    };
+    let a_shape = get_shape(a);
+    let b_shape = get_shape(b);
+    let result_shape = compute_broadcast_shape(&a_shape, &b_shape)?;
+    if dest.shape != result_shape {
+        return Err(VmError::BroadcastDestShapeMismatch {
+            expected: result_shape,
+            got: dest.shape.clone(),
+        });
+    }
+    let result_size: usize = result_shape.iter().product();
+    if dest.len() != result_size * 2 {
+        return Err(VmError::BroadcastDestShapeMismatch {
+            expected: vec![result_size * 2],
+            got: vec![dest.len()],
+        });
+    }
+
+    let (a_expanded, b_expanded) = expand_shapes_for_julia(&a_shape, &b_shape);
+    let result_strides = compute_strides(&result_shape);
+    let a_strides = compute_strides(&a_expanded);
+    let b_strides = compute_strides(&b_expanded);
+    let a_ndims_diff = result_shape.len() - a_expanded.len();
+    let b_ndims_diff = result_shape.len() - b_expanded.len();
+
+    let get_complex_at = |bc: &Broadcastable,
+                          idx: usize,
+                          expanded: &[usize],
+                          strides: &[usize],
+                          ndims_diff: usize|
+     -> Result<(f64, f64), VmError> {
+        match bc {
+            Broadcastable::ScalarF64(v) => Ok(from_real(*v)),
+            Broadcastable::Array(arr) => {
+                let src_idx = broadcast_get_index(
+                    idx,
+                    &result_shape,
+                    &result_strides,
+                    expanded,
+                    strides,
+                    ndims_diff,
+                );
+                ComplexView::new(arr).get(src_idx)
+            }
+        }
+    };
+
+    let dest_data = dest.try_data_f64_mut()?;
+    for i in 0..result_size {
+        let a_val = get_complex_at(a, i, &a_expanded, &a_strides, a_ndims_diff)?;
+        let b_val = get_complex_at(b, i, &b_expanded, &b_strides, b_ndims_diff)?;
+        let (re, im) = op(a_val, b_val);
+        dest_data[i * 2] = re;
+        dest_data[i * 2 + 1] = im;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -574,4 +1047,271 @@ mod tests {
         assert!((result.0 - (-1.0)).abs() < 1e-10);
         assert!(result.1.abs() < 1e-10);
     }
+
+    // ── Broadcasted (lazy fused tree) ────────────────────────────────────────
+
+    #[test]
+    fn test_broadcasted_leaf_materializes_to_itself() {
+        let arr = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let b = Broadcastable::Array(arr.clone());
+        let tree = Broadcasted::leaf(&b);
+        let result = tree.materialize().unwrap();
+        assert_eq!(result.shape, vec![3]);
+        assert_eq!(result.try_data_f64().unwrap(), &[1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_broadcasted_node_matches_eager_broadcast_op() {
+        let arr_a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let arr_b = ArrayValue::from_f64(vec![10.0, 20.0, 30.0], vec![3]);
+        let ba = Broadcastable::Array(arr_a.clone());
+        let bb = Broadcastable::Array(arr_b.clone());
+
+        let eager = broadcast_op_f64(&ba, &bb, |x, y| x + y).unwrap();
+
+        let tree = Broadcasted::make(
+            Rc::new(|vals: &[f64]| vals[0] + vals[1]),
+            vec![Broadcasted::leaf(&ba), Broadcasted::leaf(&bb)],
+        )
+        .unwrap();
+        let fused = tree.materialize().unwrap();
+
+        assert_eq!(fused.shape, eager.shape);
+        assert_eq!(fused.try_data_f64().unwrap(), eager.try_data_f64().unwrap());
+    }
+
+    #[test]
+    fn test_broadcasted_nested_tree_no_intermediate_materialization() {
+        // 2 .* (x .+ 1), mirroring chained dot-ops fused into one tree.
+        let x = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let bx = Broadcastable::Array(x);
+        let one = Broadcastable::ScalarF64(1.0);
+        let two = Broadcastable::ScalarF64(2.0);
+
+        let inner = Broadcasted::make(
+            Rc::new(|vals: &[f64]| vals[0] + vals[1]),
+            vec![Broadcasted::leaf(&bx), Broadcasted::leaf(&one)],
+        )
+        .unwrap();
+        let outer = Broadcasted::make(
+            Rc::new(|vals: &[f64]| vals[0] * vals[1]),
+            vec![Broadcasted::leaf(&two), inner],
+        )
+        .unwrap();
+
+        let result = outer.materialize().unwrap();
+        assert_eq!(result.shape, vec![3]);
+        assert_eq!(result.try_data_f64().unwrap(), &[4.0, 6.0, 8.0]);
+    }
+
+    // ── compute_broadcast_shape_n / broadcast_op_nary ────────────────────────
+
+    #[test]
+    fn test_broadcast_shape_n_three_compatible_1d_shapes() {
+        let shape = compute_broadcast_shape_n(&[&[3], &[1], &[3]]).unwrap();
+        assert_eq!(shape, vec![3]);
+    }
+
+    #[test]
+    fn test_broadcast_shape_n_matches_pairwise_fold() {
+        // [1, 9] .* [9] .* [9, 1] should match folding compute_broadcast_shape pairwise.
+        let pairwise = compute_broadcast_shape(&[1, 9], &[9]).unwrap();
+        let pairwise = compute_broadcast_shape(&pairwise, &[9, 1]).unwrap();
+        let nary = compute_broadcast_shape_n(&[&[1, 9], &[9], &[9, 1]]).unwrap();
+        assert_eq!(nary, pairwise);
+    }
+
+    #[test]
+    fn test_broadcast_shape_n_incompatible_dims_returns_error() {
+        let result = compute_broadcast_shape_n(&[&[3], &[4]]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_broadcast_shape_n_single_shape_passthrough() {
+        let shape = compute_broadcast_shape_n(&[&[2, 3]]).unwrap();
+        assert_eq!(shape, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_broadcast_op_nary_matches_chained_pairwise() {
+        let a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let b = ArrayValue::from_f64(vec![10.0, 20.0, 30.0], vec![3]);
+        let c = ArrayValue::from_f64(vec![100.0, 200.0, 300.0], vec![3]);
+        let ba = Broadcastable::Array(a);
+        let bb = Broadcastable::Array(b);
+        let bc = Broadcastable::Array(c);
+
+        let chained = broadcast_op_f64(&ba, &bb, |x, y| x + y).unwrap();
+        let chained = broadcast_op_f64(&Broadcastable::Array(chained), &bc, |x, y| x + y).unwrap();
+
+        let nary = broadcast_op_nary(&[&ba, &bb, &bc], |vals| vals.iter().sum()).unwrap();
+
+        assert_eq!(nary.shape, chained.shape);
+        assert_eq!(
+            nary.try_data_f64().unwrap(),
+            chained.try_data_f64().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_broadcast_op_nary_with_scalar_operand() {
+        let a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let ba = Broadcastable::Array(a);
+        let scalar = Broadcastable::ScalarF64(10.0);
+
+        let result = broadcast_op_nary(&[&ba, &scalar], |vals| vals[0] * vals[1]).unwrap();
+        assert_eq!(result.shape, vec![3]);
+        assert_eq!(result.try_data_f64().unwrap(), &[10.0, 20.0, 30.0]);
+    }
+
+    // ── has_uniform_datalayouts ───────────────────────────────────────────────
+
+    #[test]
+    fn test_uniform_datalayouts_full_shape_and_scalar() {
+        assert!(has_uniform_datalayouts(&[&[3], &[1]], &[3]));
+    }
+
+    #[test]
+    fn test_uniform_datalayouts_both_full_shape() {
+        assert!(has_uniform_datalayouts(&[&[2, 3], &[2, 3]], &[2, 3]));
+    }
+
+    #[test]
+    fn test_uniform_datalayouts_rejects_non_scalar_mismatched_shape() {
+        // [2] needs real broadcast_get_index decomposition against result [2, 3].
+        assert!(!has_uniform_datalayouts(&[&[2], &[2, 3]], &[2, 3]));
+    }
+
+    #[test]
+    fn test_broadcast_op_f64_full_shape_with_scalar_array_uses_fast_path() {
+        // arr_a full shape, arr_b a size-1 array (not ScalarF64) - this used
+        // to fall through to the stride-decomposition path even though it's
+        // trivially a uniform data layout.
+        let arr_a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let arr_b = ArrayValue::from_f64(vec![10.0], vec![1]);
+        let ba = Broadcastable::Array(arr_a);
+        let bb = Broadcastable::Array(arr_b);
+
+        let result = broadcast_op_f64(&ba, &bb, |x, y| x + y).unwrap();
+        assert_eq!(result.shape, vec![3]);
+        assert_eq!(result.try_data_f64().unwrap(), &[11.0, 12.0, 13.0]);
+    }
+
+    // ── ComplexView ───────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_complex_view_interleaved_array() {
+        // Two complex elements: (1, 2) and (3, 4).
+        let arr = ArrayValue::from_f64(vec![1.0, 2.0, 3.0, 4.0], vec![2]);
+        let view = ComplexView::new(&arr);
+        assert_eq!(view.get(0).unwrap(), (1.0, 2.0));
+        assert_eq!(view.get(1).unwrap(), (3.0, 4.0));
+    }
+
+    #[test]
+    fn test_complex_view_plain_real_array_has_zero_imaginary() {
+        let arr = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let view = ComplexView::new(&arr);
+        assert_eq!(view.get(0).unwrap(), (1.0, 0.0));
+        assert_eq!(view.get(2).unwrap(), (3.0, 0.0));
+    }
+
+    #[test]
+    fn test_from_real_has_zero_imaginary() {
+        assert_eq!(from_real(5.0), (5.0, 0.0));
+    }
+
+    #[test]
+    fn test_broadcast_op_complex_through_view_matches_manual_complex_add() {
+        // (1 + 2i) .+ (3 + 4i) should equal complex_add's result.
+        let a = ArrayValue::from_f64(vec![1.0, 2.0], vec![1]);
+        let b = ArrayValue::from_f64(vec![3.0, 4.0], vec![1]);
+        let ba = Broadcastable::Array(a);
+        let bb = Broadcastable::Array(b);
+
+        let result = broadcast_op_complex(&ba, &bb, complex_add).unwrap();
+        assert_eq!(result.try_data_f64().unwrap(), &[4.0, 6.0]);
+    }
+
+    // ── broadcast_op_f64_into / broadcast_op_complex_into ────────────────────
+
+    #[test]
+    fn test_broadcast_op_f64_into_matches_allocating_version() {
+        let arr_a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let arr_b = ArrayValue::from_f64(vec![10.0, 20.0, 30.0], vec![3]);
+        let ba = Broadcastable::Array(arr_a.clone());
+        let bb = Broadcastable::Array(arr_b.clone());
+
+        let expected = broadcast_op_f64(&ba, &bb, |x, y| x + y).unwrap();
+
+        let mut dest = ArrayValue::from_f64(vec![0.0, 0.0, 0.0], vec![3]);
+        broadcast_op_f64_into(&mut dest, &ba, &bb, |x, y| x + y).unwrap();
+
+        assert_eq!(
+            dest.try_data_f64().unwrap(),
+            expected.try_data_f64().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_broadcast_op_f64_into_rejects_wrong_dest_shape() {
+        let arr_a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let arr_b = ArrayValue::from_f64(vec![10.0, 20.0, 30.0], vec![3]);
+        let ba = Broadcastable::Array(arr_a);
+        let bb = Broadcastable::Array(arr_b);
+
+        let mut dest = ArrayValue::from_f64(vec![0.0, 0.0], vec![2]);
+        let result = broadcast_op_f64_into(&mut dest, &ba, &bb, |x, y| x + y);
+        assert!(matches!(
+            result,
+            Err(VmError::BroadcastDestShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_broadcast_op_f64_into_with_scalar_operand() {
+        let arr_a = ArrayValue::from_f64(vec![1.0, 2.0, 3.0], vec![3]);
+        let ba = Broadcastable::Array(arr_a);
+        let scalar = Broadcastable::ScalarF64(5.0);
+
+        let mut dest = ArrayValue::from_f64(vec![0.0, 0.0, 0.0], vec![3]);
+        broadcast_op_f64_into(&mut dest, &ba, &scalar, |x, y| x * y).unwrap();
+        assert_eq!(dest.try_data_f64().unwrap(), &[5.0, 10.0, 15.0]);
+    }
+
+    #[test]
+    fn test_broadcast_op_complex_into_matches_allocating_version() {
+        let a = ArrayValue::from_f64(vec![1.0, 2.0], vec![1]);
+        let b = ArrayValue::from_f64(vec![3.0, 4.0], vec![1]);
+        let ba = Broadcastable::Array(a.clone());
+        let bb = Broadcastable::Array(b.clone());
+
+        let expected = broadcast_op_complex(&ba, &bb, complex_add).unwrap();
+
+        let mut dest = ArrayValue::from_f64(vec![0.0, 0.0], vec![1]);
+        broadcast_op_complex_into(&mut dest, &ba, &bb, complex_add).unwrap();
+
+        assert_eq!(
+            dest.try_data_f64().unwrap(),
+            expected.try_data_f64().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_broadcast_op_complex_into_rejects_undersized_dest_buffer() {
+        let a = ArrayValue::from_f64(vec![1.0, 2.0], vec![1]);
+        let b = ArrayValue::from_f64(vec![3.0, 4.0], vec![1]);
+        let ba = Broadcastable::Array(a);
+        let bb = Broadcastable::Array(b);
+
+        // dest.shape == [1] matches, but the backing buffer isn't wide
+        // enough to hold an interleaved complex pair.
+        let mut dest = ArrayValue::from_f64(vec![0.0], vec![1]);
+        let result = broadcast_op_complex_into(&mut dest, &ba, &bb, complex_add);
+        assert!(matches!(
+            result,
+            Err(VmError::BroadcastDestShapeMismatch { .. })
+        ));
+    }
 }
@@ -132,12 +132,10 @@ impl<R: RngLike> Vm<R> {
                         a.elements.len() == b.elements.len()
                             && format!("{:?}", a.elements) == format!("{:?}", b.elements)
                     }
-                    (Value::Struct(a), Value::Struct(b)) => {
-                        // Normalize struct names to handle module-qualified vs unqualified
-                        normalize_struct_name(&a.struct_name)
-                            == normalize_struct_name(&b.struct_name)
-                            && a.values.len() == b.values.len()
-                            && format!("{:?}", a.values) == format!("{:?}", b.values)
+                    // Structs: reflection-driven recursive field comparison (StructRef and
+                    // Struct alike), so `isequal` works without a hand-written method.
+                    (Value::Struct(_) | Value::StructRef(_), Value::Struct(_) | Value::StructRef(_)) => {
+                        self.struct_values_equal(&left, &right)
                     }
                     // Expr: structural equality (head and args)
                     (Value::Expr(a), Value::Expr(b)) => {
@@ -347,6 +345,11 @@ impl<R: RngLike> Vm<R> {
                             }
                         }
                     }
+                    // Structs: reflection-driven hash (folds the type name and every field's
+                    // hash together), so equal structs hash equally regardless of heap slot.
+                    Value::Struct(_) | Value::StructRef(_) => {
+                        self.struct_value_hash(&val).hash(&mut hasher)
+                    }
                     _ => {
                         // For other types, hash the debug representation
                         format!("{:?}", val).hash(&mut hasher);
@@ -393,6 +396,10 @@ impl<R: RngLike> Vm<R> {
                             }
                         }
                     }
+                    // Structs: reflection-driven hash, matching the Hash builtin above.
+                    Value::Struct(_) | Value::StructRef(_) => {
+                        self.struct_value_hash(&val).hash(&mut hasher)
+                    }
                     _ => {
                         format!("{:?}", val).hash(&mut hasher);
                     }
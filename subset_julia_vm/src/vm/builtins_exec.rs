@@ -97,6 +97,9 @@ impl<R: RngLike> Vm<R> {
         // 11. execute_builtin_equality     — builtins_equality.rs   (Egal, Isequal, Hash, ...)
         // 12. execute_builtin_macro        — builtins_macro/        (Eval, RegexNew, ...)
         // 13. execute_builtin_linalg       — builtins_linalg.rs     (Lu, Det, Svd, ...)
+        // 14. execute_builtin_native       — builtins_native.rs     (CallNative)
+        // 15. execute_builtin_task         — builtins_task.rs       (TaskNew, TaskResume, IsTaskDone)
+        // 16. execute_builtin_va_list      — builtins_va_list.rs    (VaArg, VaCount)
         dispatch_builtin!(
             self,
             builtin,
@@ -115,6 +118,9 @@ impl<R: RngLike> Vm<R> {
                 execute_builtin_equality,
                 execute_builtin_macro,
                 execute_builtin_linalg,
+                execute_builtin_native,
+                execute_builtin_task,
+                execute_builtin_va_list,
             ]
         );
 
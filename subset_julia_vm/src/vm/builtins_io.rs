@@ -284,6 +284,14 @@ impl<R: RngLike> Vm<R> {
                 self.stack.push(Value::I64(now.as_nanos() as i64));
             }
 
+            BuiltinId::_AtexitPush => {
+                // _atexit_push!(f) - register a zero-arg callback to run at shutdown.
+                // Backs `atexit` in util.jl.
+                let f = self.stack.pop_value()?;
+                self.atexit_hooks.push(f);
+                self.stack.push(Value::Nothing);
+            }
+
             // =========================================================================
             // File I/O Operations (read-only)
             // =========================================================================
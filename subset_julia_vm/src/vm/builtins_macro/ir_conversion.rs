@@ -260,6 +260,78 @@ impl<R: RngLike> Vm<R> {
                     vec![name_val, val_val],
                 )))
             }
+            Stmt::SubAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "-=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::MulAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "*=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::DivAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "/=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::FldAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "÷=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::PowAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "^=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::BitAndAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "&=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::BitOrAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "|=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::BitXorAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    "⊻=",
+                    vec![name_val, val_val],
+                )))
+            }
+            Stmt::BroadcastAssign { var, value, .. } => {
+                let name_val = Value::Symbol(SymbolValue::new(var));
+                let val_val = self.ir_expr_to_value(value)?;
+                Ok(Value::Expr(ExprValue::from_head(
+                    ".=",
+                    vec![name_val, val_val],
+                )))
+            }
             Stmt::Return { value, .. } => {
                 let val = if let Some(v) = value {
                     self.ir_expr_to_value(v)?
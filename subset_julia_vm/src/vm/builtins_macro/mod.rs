@@ -1,6 +1,6 @@
 //! Macro system builtin functions for the VM.
 //!
-//! Metaprogramming operations: Symbol, Expr, gensym, QuoteNode, esc, eval.
+//! Metaprogramming operations: Symbol, Expr, gensym, QuoteNode, esc, eval, macroexpand.
 //!
 //! # Module Organization
 //!
@@ -8,6 +8,16 @@
 //! - `eval.rs`: Expression evaluation (eval() builtin)
 //! - `parse.rs`: String parsing (Meta.parse, include_string)
 //! - `ir_conversion.rs`: IR conversion (Meta.lower, source-string round-tripping)
+//!
+//! # Runtime macro expansion
+//!
+//! `macroexpand`/`macroexpand!`/`macroexpand1` walk an `Expr` tree looking for
+//! `Expr(:macrocall, Symbol("@name"), linenode, args...)` nodes whose `@name` is
+//! registered in [`Vm::register_macro`]'s `macro_table`, and splice in the macro
+//! body with its parameters bound to the unevaluated argument `Expr`s. Note that
+//! top-level `macro name(args...) ... end` definitions are still expanded entirely
+//! at compile time during lowering and never populate `macro_table` themselves —
+//! only macros registered through `register_macro` are visible to these builtins.
 
 // SAFETY: i64→u64 cast for splat_mask is a reinterpretation of a bitmask value;
 // i64→usize casts are for string/regex positions known to be non-negative from caller.
@@ -18,19 +28,285 @@ mod helpers;
 mod ir_conversion;
 mod parse;
 
+use std::collections::HashMap;
+
 use crate::builtins::BuiltinId;
 use crate::rng::RngLike;
 
 use super::error::VmError;
 use super::stack_ops::StackOps;
-use super::value::{ExprValue, SymbolValue, Value};
+use super::value::{ExprValue, RegexValue, SymbolValue, TupleValue, Value};
 use super::Vm;
 
 use helpers::{
     is_binary_operator, is_operator, is_postfix_operator, is_unary_operator, is_valid_identifier,
 };
 
+/// A macro registered for runtime expansion via [`Vm::register_macro`].
+///
+/// `params`/`has_varargs` describe how call-site arguments bind to names inside
+/// `body` (an unevaluated `Value::Expr`); the last parameter binds to a `Tuple` of
+/// the remaining arguments when `has_varargs` is set.
+#[derive(Debug, Clone)]
+pub(crate) struct MacroTableEntry {
+    pub params: Vec<String>,
+    pub has_varargs: bool,
+    pub body: Value,
+}
+
+/// An active `@testset` filter, set via `_testset_set_filter!(pattern)`.
+///
+/// Reuses `RegexValue` when the pattern is a `Regex`, and falls back to plain
+/// substring matching when it's a `Str`.
+#[derive(Debug, Clone)]
+pub(crate) enum TestFilter {
+    Regex(RegexValue),
+    Substring(String),
+}
+
+impl TestFilter {
+    fn matches(&self, text: &str) -> bool {
+        match self {
+            TestFilter::Regex(re) => re.is_match(text),
+            TestFilter::Substring(pattern) => text.contains(pattern.as_str()),
+        }
+    }
+}
+
 impl<R: RngLike> Vm<R> {
+    /// Register a macro body so `macroexpand`/`macroexpand!`/`macroexpand1` can expand
+    /// calls to `@name` at runtime. Not yet wired into the compile-time lowering pipeline
+    /// (top-level `macro name(args...) ... end` definitions are still fully consumed during
+    /// lowering), so today this is reached only by callers that register macros directly.
+    #[allow(dead_code)]
+    pub(crate) fn register_macro(
+        &mut self,
+        name: impl Into<String>,
+        params: Vec<String>,
+        has_varargs: bool,
+        body: Value,
+    ) {
+        self.macro_table.insert(
+            name.into(),
+            MacroTableEntry {
+                params,
+                has_varargs,
+                body,
+            },
+        );
+    }
+
+    /// Maximum number of fixed-point expansion passes for `macroexpand`, guarding against a
+    /// macro body that (incorrectly) expands into a call to itself forever.
+    const MACROEXPAND_MAX_PASSES: usize = 256;
+
+    /// Expand registered macro calls in `expr`. `recursive` selects between `macroexpand`
+    /// (loop to a fixed point) and `macroexpand1` (exactly one expansion pass).
+    fn macroexpand_value(&mut self, expr: Value, recursive: bool) -> Result<Value, VmError> {
+        let (expanded, changed) = self.expand_macrocalls_once(&expr)?;
+        if !recursive || !changed {
+            return Ok(expanded);
+        }
+        let mut current = expanded;
+        for _ in 0..Self::MACROEXPAND_MAX_PASSES {
+            let (expanded, changed) = self.expand_macrocalls_once(&current)?;
+            if !changed {
+                return Ok(expanded);
+            }
+            current = expanded;
+        }
+        Err(VmError::StackOverflow)
+    }
+
+    /// Walk `expr` once, expanding every `Expr(:macrocall, Symbol("@name"), ...)` node whose
+    /// `@name` is registered in `macro_table`. Returns the rewritten tree and whether any
+    /// macrocall was expanded during this pass.
+    fn expand_macrocalls_once(&mut self, expr: &Value) -> Result<(Value, bool), VmError> {
+        let Value::Expr(e) = expr else {
+            return Ok((expr.clone(), false));
+        };
+        if e.is_head("macrocall") {
+            let macro_name = e.args.first().and_then(|v| match v {
+                Value::Symbol(s) => Some(s.as_str().to_string()),
+                _ => None,
+            });
+            if let Some(name) = macro_name {
+                if let Some(entry) = self.macro_table.get(&name).cloned() {
+                    // args layout: [Symbol("@name"), linenode/nothing, call_args...]
+                    let call_args = &e.args[2.min(e.args.len())..];
+                    let expanded = self.instantiate_macro_body(&name, &entry, call_args)?;
+                    return Ok((expanded, true));
+                }
+            }
+        }
+        // Not a registered macrocall (or not a macrocall at all): still walk into children,
+        // since an unexpanded outer node may contain registered macro calls in its args.
+        let mut changed = false;
+        let mut new_args = Vec::with_capacity(e.args.len());
+        for arg in &e.args {
+            let (new_arg, arg_changed) = self.expand_macrocalls_once(arg)?;
+            changed |= arg_changed;
+            new_args.push(new_arg);
+        }
+        if changed {
+            Ok((Value::Expr(ExprValue::new(e.head.clone(), new_args)), true))
+        } else {
+            Ok((expr.clone(), false))
+        }
+    }
+
+    /// Bind `call_args` to `entry`'s parameters and substitute them (plus gensym hygiene for
+    /// locals the body introduces) into a fresh copy of `entry.body`.
+    fn instantiate_macro_body(
+        &mut self,
+        macro_name: &str,
+        entry: &MacroTableEntry,
+        call_args: &[Value],
+    ) -> Result<Value, VmError> {
+        let bindings = Self::bind_macro_params(macro_name, entry, call_args)?;
+        let mut renames: HashMap<String, String> = HashMap::new();
+        self.collect_macro_locals(&entry.body, &entry.params, false, &mut renames);
+        Ok(Self::substitute_macro_body(
+            &entry.body,
+            &bindings,
+            &renames,
+            false,
+        ))
+    }
+
+    /// Bind call-site argument `Expr`s to macro parameter names (Julia's usual positional
+    /// binding, with the last parameter collecting the rest into a `Tuple` when variadic).
+    fn bind_macro_params(
+        macro_name: &str,
+        entry: &MacroTableEntry,
+        call_args: &[Value],
+    ) -> Result<HashMap<String, Value>, VmError> {
+        let mut bindings = HashMap::new();
+        if entry.has_varargs {
+            let fixed_count = entry.params.len() - 1;
+            if call_args.len() < fixed_count {
+                return Err(VmError::MethodError(format!(
+                    "no method matching @{}({} arguments)",
+                    macro_name,
+                    call_args.len()
+                )));
+            }
+            for (param, arg) in entry.params[..fixed_count].iter().zip(call_args) {
+                bindings.insert(param.clone(), arg.clone());
+            }
+            let rest = call_args[fixed_count..].to_vec();
+            bindings.insert(
+                entry.params[fixed_count].clone(),
+                Value::Tuple(TupleValue::new(rest)),
+            );
+        } else {
+            if call_args.len() != entry.params.len() {
+                return Err(VmError::MethodError(format!(
+                    "no method matching @{}({} arguments)",
+                    macro_name,
+                    call_args.len()
+                )));
+            }
+            for (param, arg) in entry.params.iter().zip(call_args) {
+                bindings.insert(param.clone(), arg.clone());
+            }
+        }
+        Ok(bindings)
+    }
+
+    /// Collect the names of locals the macro body assigns to (outside any `esc(...)`) that
+    /// aren't parameters, so they can be renamed to fresh gensyms for hygiene.
+    fn collect_macro_locals(
+        &mut self,
+        node: &Value,
+        params: &[String],
+        in_escape: bool,
+        renames: &mut HashMap<String, String>,
+    ) {
+        let Value::Expr(e) = node else {
+            return;
+        };
+        if Self::is_esc_call(e) {
+            // Contents of esc(...) are left alone by hygiene entirely.
+            return;
+        }
+        if !in_escape && e.is_head("=") && e.args.len() == 2 {
+            if let Value::Symbol(s) = &e.args[0] {
+                let name = s.as_str();
+                if !params.iter().any(|p| p == name) && !renames.contains_key(name) {
+                    let counter = self.gensym_counter;
+                    self.gensym_counter += 1;
+                    renames.insert(name.to_string(), format!("##{}#{}", name, counter));
+                }
+            }
+        }
+        for arg in &e.args {
+            self.collect_macro_locals(arg, params, in_escape, renames);
+        }
+    }
+
+    /// Rebuild `node`, substituting parameter bindings and hygiene renames everywhere except
+    /// inside `esc(...)` nodes, whose contents are copied through un-renamed.
+    fn substitute_macro_body(
+        node: &Value,
+        bindings: &HashMap<String, Value>,
+        renames: &HashMap<String, String>,
+        in_escape: bool,
+    ) -> Value {
+        match node {
+            Value::Symbol(s) if !in_escape => {
+                let name = s.as_str();
+                if let Some(bound) = bindings.get(name) {
+                    bound.clone()
+                } else if let Some(renamed) = renames.get(name) {
+                    Value::Symbol(SymbolValue::new(renamed.clone()))
+                } else {
+                    node.clone()
+                }
+            }
+            Value::Expr(e) if Self::is_esc_call(e) => {
+                let inner = Self::substitute_macro_body(&e.args[1], bindings, renames, true);
+                Value::Expr(ExprValue::from_head(
+                    "call",
+                    vec![Value::Symbol(SymbolValue::new("esc")), inner],
+                ))
+            }
+            Value::Expr(e) => {
+                let new_args = e
+                    .args
+                    .iter()
+                    .map(|a| Self::substitute_macro_body(a, bindings, renames, in_escape))
+                    .collect();
+                Value::Expr(ExprValue::new(e.head.clone(), new_args))
+            }
+            // QuoteNode wraps a literal, not a sub-expression to substitute into.
+            _ => node.clone(),
+        }
+    }
+
+    /// Whether `e` is a call to `esc(...)` with exactly one argument, the hygiene marker used
+    /// by macro bodies to opt specific sub-expressions out of renaming.
+    fn is_esc_call(e: &ExprValue) -> bool {
+        e.is_head("call")
+            && e.args.len() == 2
+            && matches!(&e.args[0], Value::Symbol(s) if s.as_str() == "esc")
+    }
+
+    /// Whether a test with message `msg` should run/record given the active `test_filter`
+    /// (set via `_testset_set_filter!`). With no filter, everything runs. With a filter,
+    /// a test runs if either the enclosing testset's name or its own message matches.
+    fn test_filter_allows(&self, msg: &str) -> bool {
+        match &self.test_filter {
+            None => true,
+            Some(filter) => {
+                let testset_matches = self
+                    .test_current_name()
+                    .is_some_and(|name| filter.matches(name));
+                testset_matches || filter.matches(msg)
+            }
+        }
+    }
+
     /// Execute macro system builtin functions.
     /// Returns `Ok(Some(()))` if handled, `Ok(None)` if not a macro builtin.
     pub(super) fn execute_builtin_macro(
@@ -331,26 +607,35 @@ impl<R: RngLike> Vm<R> {
             }
 
             BuiltinId::MacroExpand | BuiltinId::MacroExpandBang => {
-                // macroexpand(m, x) and macroexpand!(m, x) - return expanded form of macro call
-                // In SubsetJuliaVM, macro expansion happens at compile time during lowering.
-                // At runtime, we receive expressions that have already been expanded.
-                // For a quoted macro call like :(@time 1+1), we return the expression as-is
-                // since runtime expansion is not supported (requires access to macro definitions).
-                // The module parameter is accepted for API compatibility but ignored.
+                // macroexpand(m, x) and macroexpand!(m, x) - expand macro calls in x.
+                // Only macros registered via `register_macro` (see `macro_table`) are
+                // known here; a top-level `macro name(...) ... end` in source is already
+                // fully expanded at compile time, so its calls never reach this builtin
+                // as unexpanded macrocalls. The module parameter is accepted for API
+                // compatibility but ignored (no runtime module support).
                 if argc != 2 {
                     return Err(VmError::TypeError(
                         "macroexpand requires exactly 2 arguments: macroexpand(m, x)".to_string(),
                     ));
                 }
-                // Pop the expression (second argument)
                 let expr = self.stack.pop_value()?;
-                // Pop the module (first argument, ignored)
                 let _module = self.stack.pop_value()?;
-                // Return the expression unchanged
-                // Note: In full Julia, this would expand macros in the expression.
-                // SubsetJuliaVM performs macro expansion at compile time, so runtime
-                // expressions are already expanded or represent unevaluated macro calls.
-                self.stack.push(expr);
+                let expanded = self.macroexpand_value(expr, true)?;
+                self.stack.push(expanded);
+            }
+
+            BuiltinId::MacroExpand1 => {
+                // macroexpand1(m, x) - expand only the outermost registered macro call(s)
+                // found in x, one step, without recursing into the freshly expanded result.
+                if argc != 2 {
+                    return Err(VmError::TypeError(
+                        "macroexpand1 requires exactly 2 arguments: macroexpand1(m, x)".to_string(),
+                    ));
+                }
+                let expr = self.stack.pop_value()?;
+                let _module = self.stack.pop_value()?;
+                let expanded = self.macroexpand_value(expr, false)?;
+                self.stack.push(expanded);
             }
 
             BuiltinId::IncludeString => {
@@ -640,12 +925,18 @@ impl<R: RngLike> Vm<R> {
                     }
                 };
 
+                if !self.test_filter_allows(&msg_str) {
+                    self.stack.push(Value::Nothing);
+                    return Ok(Some(()));
+                }
+
+                let indent = "  ".repeat(self.test_stack.len().max(1));
                 if passed_bool {
-                    self.test_pass_count += 1;
-                    self.emit_output(&format!("  Test Passed: {}", msg_str), true);
+                    self.test_record_pass();
+                    self.emit_output(&format!("{}Test Passed: {}", indent, msg_str), true);
                 } else {
-                    self.test_fail_count += 1;
-                    self.emit_output(&format!("  Test Failed: {}", msg_str), true);
+                    self.test_record_fail(&msg_str);
+                    self.emit_output(&format!("{}Test Failed: {}", indent, msg_str), true);
                 }
                 self.stack.push(Value::Nothing);
             }
@@ -676,17 +967,26 @@ impl<R: RngLike> Vm<R> {
                     }
                 };
 
+                if !self.test_filter_allows(&msg_str) {
+                    self.stack.push(Value::Nothing);
+                    return Ok(Some(()));
+                }
+
+                let indent = "  ".repeat(self.test_stack.len().max(1));
                 if passed_bool {
                     // Test unexpectedly passed - this is an error!
-                    self.test_fail_count += 1;
+                    self.test_record_errored(&format!(
+                        "{} (unexpectedly passed)",
+                        msg_str
+                    ));
                     self.emit_output(
-                        &format!("  Test Error (unexpectedly passed): {}", msg_str),
+                        &format!("{}Test Error (unexpectedly passed): {}", indent, msg_str),
                         true,
                     );
                 } else {
                     // Test failed as expected - this is a broken test
-                    self.test_broken_count += 1;
-                    self.emit_output(&format!("  Test Broken: {}", msg_str), true);
+                    self.test_record_broken();
+                    self.emit_output(&format!("{}Test Broken: {}", indent, msg_str), true);
                 }
                 self.stack.push(Value::Nothing);
             }
@@ -705,51 +1005,168 @@ impl<R: RngLike> Vm<R> {
                     _ => format!("{:?}", name),
                 };
 
-                self.current_testset = Some(name_str.clone());
-                self.test_pass_count = 0;
-                self.test_fail_count = 0;
-                self.test_broken_count = 0;
-                self.emit_output(&format!("Test Set: {}", name_str), true);
+                let indent = "  ".repeat(self.test_stack.len());
+                self.test_push_frame(name_str.clone());
+                if self.test_filter_allows(&name_str) {
+                    self.emit_output(&format!("{}Test Set: {}", indent, name_str), true);
+                }
                 self.stack.push(Value::Nothing);
             }
 
             BuiltinId::TestSetEnd => {
-                // _testset_end!() - end test set and print summary
+                // _testset_end!() - end test set, print a summary, and return a
+                // structured result (name=..., pass=..., fail=..., broken=...,
+                // errored=..., messages=...) so `result = @testset ...` can inspect
+                // it programmatically rather than only reading printed output.
                 if argc != 0 {
                     return Err(VmError::TypeError(
                         "_testset_end! takes no arguments".to_string(),
                     ));
                 }
 
-                let total = self.test_pass_count + self.test_fail_count + self.test_broken_count;
-                if self.test_broken_count > 0 {
+                let Some(frame) = self.test_pop_frame() else {
+                    self.stack.push(Value::Nothing);
+                    return Ok(Some(()));
+                };
+
+                let indent = "  ".repeat(self.test_stack.len());
+                let total = frame.pass + frame.fail + frame.broken + frame.errored;
+                if frame.broken > 0 || frame.errored > 0 {
+                    self.emit_output(
+                        &format!(
+                            "{}  {} passed, {} failed, {} broken, {} errored ({} total)",
+                            indent, frame.pass, frame.fail, frame.broken, frame.errored, total
+                        ),
+                        true,
+                    );
+                } else {
+                    self.emit_output(
+                        &format!(
+                            "{}  {} passed, {} failed ({} total)",
+                            indent, frame.pass, frame.fail, total
+                        ),
+                        true,
+                    );
+                }
+
+                use crate::vm::value::{ArrayData, ArrayValue, NamedTupleValue};
+                let message_count = frame.failures.len();
+                let messages = Value::Array(crate::vm::value::new_array_ref(ArrayValue::new(
+                    ArrayData::String(frame.failures),
+                    vec![message_count],
+                )));
+                let result = NamedTupleValue::new(
+                    vec![
+                        "name".to_string(),
+                        "pass".to_string(),
+                        "fail".to_string(),
+                        "broken".to_string(),
+                        "errored".to_string(),
+                        "messages".to_string(),
+                    ],
+                    vec![
+                        Value::Str(frame.name),
+                        Value::I64(frame.pass as i64),
+                        Value::I64(frame.fail as i64),
+                        Value::I64(frame.broken as i64),
+                        Value::I64(frame.errored as i64),
+                        messages,
+                    ],
+                )?;
+                self.stack.push(Value::NamedTuple(result));
+            }
+
+            BuiltinId::TestSetSetFilter => {
+                // _testset_set_filter!(pattern) - restrict testsets/tests to a name/message pattern
+                if argc != 1 {
+                    return Err(VmError::TypeError(
+                        "_testset_set_filter! requires exactly 1 argument: _testset_set_filter!(pattern)"
+                            .to_string(),
+                    ));
+                }
+                let pattern = self.stack.pop_value()?;
+                self.test_filter = Some(match pattern {
+                    Value::Regex(r) => TestFilter::Regex(r),
+                    Value::Str(s) => TestFilter::Substring(s),
+                    _ => {
+                        return Err(VmError::TypeError(
+                            "_testset_set_filter! requires a Regex or String pattern".to_string(),
+                        ))
+                    }
+                });
+                self.stack.push(Value::Nothing);
+            }
+
+            BuiltinId::TestThrowsRecord => {
+                // _test_throws_record!(thrown_type, expected_type, msg) - record an
+                // @test_throws result: passes when the caught exception's type matches
+                // the expected type (or, when the Julia-side macro encodes a message
+                // check into expected_type, type+message), fails otherwise.
+                // `thrown_type` is the string "nothing" when no exception was thrown.
+                if argc != 3 {
+                    return Err(VmError::TypeError(
+                        "_test_throws_record! requires exactly 3 arguments: _test_throws_record!(thrown_type, expected_type, msg)"
+                            .to_string(),
+                    ));
+                }
+                let msg = self.stack.pop_value()?;
+                let expected_type = self.stack.pop_value()?;
+                let thrown_type = self.stack.pop_value()?;
+
+                let msg_str = match msg {
+                    Value::Str(s) => s,
+                    _ => format!("{:?}", msg),
+                };
+                let expected_str = match expected_type {
+                    Value::Str(s) => s,
+                    _ => format!("{:?}", expected_type),
+                };
+                let thrown_str = match thrown_type {
+                    Value::Str(s) => s,
+                    Value::Nothing => "nothing".to_string(),
+                    _ => format!("{:?}", thrown_type),
+                };
+
+                if !self.test_filter_allows(&msg_str) {
+                    self.stack.push(Value::Nothing);
+                    return Ok(Some(()));
+                }
+
+                let indent = "  ".repeat(self.test_stack.len().max(1));
+                if thrown_str == expected_str {
+                    self.test_record_pass();
+                    self.emit_output(&format!("{}Test Passed: {}", indent, msg_str), true);
+                } else if thrown_str == "nothing" {
+                    self.test_record_fail(&format!(
+                        "{} (no exception was thrown, expected {})",
+                        msg_str, expected_str
+                    ));
                     self.emit_output(
                         &format!(
-                            "  {} passed, {} failed, {} broken ({} total)",
-                            self.test_pass_count,
-                            self.test_fail_count,
-                            self.test_broken_count,
-                            total
+                            "{}Test Failed: {} (no exception was thrown, expected {})",
+                            indent, msg_str, expected_str
                         ),
                         true,
                     );
                 } else {
+                    self.test_record_fail(&format!(
+                        "{} (wrong exception type: got {}, expected {})",
+                        msg_str, thrown_str, expected_str
+                    ));
                     self.emit_output(
                         &format!(
-                            "  {} passed, {} failed ({} total)",
-                            self.test_pass_count, self.test_fail_count, total
+                            "{}Test Failed: {} (wrong exception type: got {}, expected {})",
+                            indent, msg_str, thrown_str, expected_str
                         ),
                         true,
                     );
                 }
-                self.current_testset = None;
                 self.stack.push(Value::Nothing);
             }
 
             // Regex operations
             BuiltinId::RegexNew => {
                 // Regex(pattern) or Regex(pattern, flags) - create regex
-                use crate::vm::value::RegexValue;
                 if !(1..=2).contains(&argc) {
                     return Err(VmError::TypeError(
                         "Regex requires 1 or 2 arguments: Regex(pattern) or Regex(pattern, flags)"
@@ -971,3 +1388,290 @@ impl<R: RngLike> Vm<R> {
         Ok(Some(()))
     }
 }
+
+#[cfg(test)]
+mod macroexpand_tests {
+    use super::*;
+    use crate::rng::StableRng;
+
+    fn test_vm() -> Vm<StableRng> {
+        Vm::new(vec![], StableRng::new(0))
+    }
+
+    fn sym(name: &str) -> Value {
+        Value::Symbol(SymbolValue::new(name))
+    }
+
+    fn macrocall(name: &str, args: Vec<Value>) -> Value {
+        let mut full_args = vec![sym(name), Value::Nothing];
+        full_args.extend(args);
+        Value::Expr(ExprValue::from_head("macrocall", full_args))
+    }
+
+    #[test]
+    fn test_macroexpand_unregistered_macro_is_unchanged() {
+        let mut vm = test_vm();
+        let expr = macrocall("@foo", vec![Value::I64(1)]);
+        let result = vm.macroexpand_value(expr.clone(), true).unwrap();
+        let Value::Expr(e) = result else {
+            panic!("expected Expr");
+        };
+        assert!(e.is_head("macrocall"));
+    }
+
+    #[test]
+    fn test_macroexpand_substitutes_parameter() {
+        let mut vm = test_vm();
+        // macro double(x) x + x end
+        let body = Value::Expr(ExprValue::from_head(
+            "call",
+            vec![sym("+"), sym("x"), sym("x")],
+        ));
+        vm.register_macro("@double", vec!["x".to_string()], false, body);
+
+        let expr = macrocall("@double", vec![Value::I64(5)]);
+        let result = vm.macroexpand_value(expr, true).unwrap();
+        match result {
+            Value::Expr(e) => {
+                assert!(e.is_head("call"));
+                assert!(matches!(e.args[1], Value::I64(5)));
+                assert!(matches!(e.args[2], Value::I64(5)));
+            }
+            other => panic!("expected Expr, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_macroexpand_renames_introduced_locals_for_hygiene() {
+        let mut vm = test_vm();
+        // macro m() y = 1; y end
+        let body = Value::Expr(ExprValue::from_head(
+            "block",
+            vec![
+                Value::Expr(ExprValue::from_head("=", vec![sym("y"), Value::I64(1)])),
+                sym("y"),
+            ],
+        ));
+        vm.register_macro("@m", vec![], false, body);
+
+        let expr = macrocall("@m", vec![]);
+        let result = vm.macroexpand_value(expr, true).unwrap();
+        let Value::Expr(block) = result else {
+            panic!("expected Expr");
+        };
+        let Value::Expr(assign) = &block.args[0] else {
+            panic!("expected assignment Expr");
+        };
+        let Value::Symbol(renamed_lhs) = &assign.args[0] else {
+            panic!("expected Symbol lhs");
+        };
+        assert_ne!(renamed_lhs.as_str(), "y");
+        // The body's trailing reference to `y` must be renamed to the same fresh symbol.
+        let Value::Symbol(renamed_use) = &block.args[1] else {
+            panic!("expected Symbol");
+        };
+        assert_eq!(renamed_lhs.as_str(), renamed_use.as_str());
+    }
+
+    #[test]
+    fn test_macroexpand_honors_esc_leaving_contents_unrenamed() {
+        let mut vm = test_vm();
+        // macro m() esc(y = 1) end
+        let body = Value::Expr(ExprValue::from_head(
+            "call",
+            vec![
+                sym("esc"),
+                Value::Expr(ExprValue::from_head("=", vec![sym("y"), Value::I64(1)])),
+            ],
+        ));
+        vm.register_macro("@m", vec![], false, body);
+
+        let expr = macrocall("@m", vec![]);
+        let result = vm.macroexpand_value(expr, true).unwrap();
+        let Value::Expr(call) = result else {
+            panic!("expected Expr");
+        };
+        let Value::Expr(assign) = &call.args[1] else {
+            panic!("expected assignment Expr");
+        };
+        let Value::Symbol(lhs) = &assign.args[0] else {
+            panic!("expected Symbol lhs");
+        };
+        assert_eq!(lhs.as_str(), "y");
+    }
+
+    #[test]
+    fn test_macroexpand1_stops_after_one_step() {
+        let mut vm = test_vm();
+        // @outer() expands to a call containing @inner(), which is itself registered.
+        vm.register_macro(
+            "@inner",
+            vec![],
+            false,
+            Value::Symbol(SymbolValue::new("done")),
+        );
+        vm.register_macro("@outer", vec![], false, macrocall("@inner", vec![]));
+
+        let expr = macrocall("@outer", vec![]);
+        let once = vm.macroexpand_value(expr.clone(), false).unwrap();
+        // One step only expands @outer, leaving the nested @inner call unexpanded.
+        assert!(matches!(&once, Value::Expr(e) if e.is_head("macrocall")));
+
+        let recursive = vm.macroexpand_value(expr, true).unwrap();
+        assert!(matches!(&recursive, Value::Symbol(s) if s.as_str() == "done"));
+    }
+
+    #[test]
+    fn test_macroexpand_varargs_bind_rest_as_tuple() {
+        let mut vm = test_vm();
+        // macro m(x, rest...) rest end
+        vm.register_macro(
+            "@m",
+            vec!["x".to_string(), "rest".to_string()],
+            true,
+            sym("rest"),
+        );
+
+        let expr = macrocall("@m", vec![Value::I64(1), Value::I64(2), Value::I64(3)]);
+        let result = vm.macroexpand_value(expr, true).unwrap();
+        match result {
+            Value::Tuple(t) => {
+                assert_eq!(t.len(), 2);
+                assert!(matches!(t.get(1).unwrap(), Value::I64(2)));
+                assert!(matches!(t.get(2).unwrap(), Value::I64(3)));
+            }
+            other => panic!("expected Tuple, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_macroexpand_wrong_arg_count_is_method_error() {
+        let mut vm = test_vm();
+        vm.register_macro("@one_arg", vec!["x".to_string()], false, sym("x"));
+        let expr = macrocall("@one_arg", vec![]);
+        let err = vm.macroexpand_value(expr, true).unwrap_err();
+        assert!(matches!(err, VmError::MethodError(_)));
+    }
+}
+
+#[cfg(test)]
+mod test_filter_tests {
+    use super::*;
+    use crate::rng::StableRng;
+
+    fn test_vm() -> Vm<StableRng> {
+        Vm::new(vec![], StableRng::new(0))
+    }
+
+    fn begin_testset(vm: &mut Vm<StableRng>, name: &str) {
+        vm.stack.push(Value::Str(name.to_string()));
+        vm.execute_builtin_macro(&BuiltinId::TestSetBegin, 1)
+            .unwrap();
+        vm.stack.pop_value().unwrap();
+    }
+
+    fn record(vm: &mut Vm<StableRng>, passed: bool, msg: &str) {
+        vm.stack.push(Value::Bool(passed));
+        vm.stack.push(Value::Str(msg.to_string()));
+        vm.execute_builtin_macro(&BuiltinId::TestRecord, 2)
+            .unwrap();
+        vm.stack.pop_value().unwrap();
+    }
+
+    fn top_counts(vm: &Vm<StableRng>) -> (usize, usize) {
+        let frame = vm.test_stack.last().unwrap();
+        (frame.pass, frame.fail)
+    }
+
+    #[test]
+    fn test_no_filter_records_everything() {
+        let mut vm = test_vm();
+        begin_testset(&mut vm, "arithmetic");
+        record(&mut vm, true, "1 + 1 == 2");
+        record(&mut vm, false, "1 + 1 == 3");
+        assert_eq!(top_counts(&vm), (1, 1));
+    }
+
+    #[test]
+    fn test_filter_matching_testset_name_records_all_its_tests() {
+        let mut vm = test_vm();
+        vm.test_filter = Some(TestFilter::Substring("arithmetic".to_string()));
+        begin_testset(&mut vm, "arithmetic");
+        record(&mut vm, true, "unrelated message");
+        assert_eq!(top_counts(&vm).0, 1);
+    }
+
+    #[test]
+    fn test_filter_skips_non_matching_testset_and_message() {
+        let mut vm = test_vm();
+        vm.test_filter = Some(TestFilter::Substring("strings".to_string()));
+        begin_testset(&mut vm, "arithmetic");
+        record(&mut vm, true, "1 + 1 == 2");
+        assert_eq!(top_counts(&vm), (0, 0));
+    }
+
+    #[test]
+    fn test_filter_matching_message_records_even_in_non_matching_testset() {
+        let mut vm = test_vm();
+        vm.test_filter = Some(TestFilter::Substring("strings".to_string()));
+        begin_testset(&mut vm, "arithmetic");
+        record(&mut vm, true, "strings concat correctly");
+        assert_eq!(top_counts(&vm).0, 1);
+    }
+
+    #[test]
+    fn test_filter_supports_regex_patterns() {
+        let mut vm = test_vm();
+        let regex = RegexValue::new(r"^arith.*", "").unwrap();
+        vm.test_filter = Some(TestFilter::Regex(regex));
+        begin_testset(&mut vm, "arithmetic");
+        record(&mut vm, true, "1 + 1 == 2");
+        assert_eq!(top_counts(&vm).0, 1);
+
+        begin_testset(&mut vm, "strings");
+        record(&mut vm, true, "abc == abc");
+        assert_eq!(top_counts(&vm).0, 0);
+    }
+
+    #[test]
+    fn test_nested_testsets_roll_up_into_parent_counts() {
+        let mut vm = test_vm();
+        begin_testset(&mut vm, "outer");
+        record(&mut vm, true, "outer test 1");
+        begin_testset(&mut vm, "inner");
+        record(&mut vm, true, "inner test 1");
+        record(&mut vm, false, "inner test 2");
+        assert_eq!(vm.test_stack.len(), 2);
+
+        vm.execute_builtin_macro(&BuiltinId::TestSetEnd, 0).unwrap();
+        vm.stack.pop_value().unwrap();
+
+        // Inner's totals rolled up into outer's frame.
+        assert_eq!(vm.test_stack.len(), 1);
+        assert_eq!(top_counts(&vm), (2, 1));
+
+        vm.execute_builtin_macro(&BuiltinId::TestSetEnd, 0).unwrap();
+        vm.stack.pop_value().unwrap();
+        assert!(vm.test_stack.is_empty());
+    }
+
+    #[test]
+    fn test_set_filter_builtin_accepts_string_pattern() {
+        let mut vm = test_vm();
+        vm.stack.push(Value::Str("arithmetic".to_string()));
+        vm.execute_builtin_macro(&BuiltinId::TestSetSetFilter, 1)
+            .unwrap();
+        vm.stack.pop_value().unwrap();
+        assert!(matches!(vm.test_filter, Some(TestFilter::Substring(_))));
+    }
+
+    #[test]
+    fn test_set_filter_builtin_rejects_non_string_non_regex() {
+        let mut vm = test_vm();
+        vm.stack.push(Value::I64(1));
+        let err = vm
+            .execute_builtin_macro(&BuiltinId::TestSetSetFilter, 1)
+            .unwrap_err();
+        assert!(matches!(err, VmError::TypeError(_)));
+    }
+}
@@ -32,6 +32,37 @@ fn saturating_f64_to_i32(x: f64) -> i32 {
     }
 }
 
+/// `m * 2^e` via direct exponent-field manipulation (the inverse of `frexp`).
+/// Takes the fast exact path by adjusting the biased exponent in place when the
+/// result stays normal; falls back to a scaled multiply for subnormal inputs/results
+/// and saturates to +-Inf on overflow, rather than a naive `pow` multiply.
+fn ldexp_f64(m: f64, e: i64) -> f64 {
+    if m == 0.0 || m.is_nan() || m.is_infinite() {
+        return m;
+    }
+    let bits = m.to_bits();
+    let sign_bit = bits & (1u64 << 63);
+    let biased_exp = ((bits >> 52) & 0x7FF) as i64;
+    let mantissa = bits & 0x000F_FFFF_FFFF_FFFF;
+    if biased_exp == 0 {
+        // Subnormal input: fall back to a scaled multiply (magnitude is already tiny).
+        return m * 2f64.powi(saturating_i64_to_i32(e));
+    }
+    let new_exp = biased_exp + e;
+    if new_exp >= 0x7FF {
+        return if sign_bit == 0 {
+            f64::INFINITY
+        } else {
+            f64::NEG_INFINITY
+        };
+    }
+    if new_exp <= 0 {
+        // Underflows to subnormal or zero.
+        return m * 2f64.powi(saturating_i64_to_i32(e));
+    }
+    f64::from_bits(sign_bit | ((new_exp as u64) << 52) | mantissa)
+}
+
 impl<R: RngLike> Vm<R> {
     /// Execute math builtin functions.
     /// Returns `Ok(Some(()))` if handled, `Ok(None)` if not a math builtin.
@@ -274,6 +305,30 @@ impl<R: RngLike> Vm<R> {
                 }
             }
 
+            BuiltinId::Ldexp => {
+                // ldexp(m, e) = m * 2^e via direct exponent-field manipulation, the inverse
+                // of frexp/exponent/significand. Handles subnormal results and overflow to
+                // +-Inf rather than a naive `pow` multiply.
+                let e = self.stack.pop_i64()?;
+                let m = self.pop_f64_or_i64()?;
+                self.stack.push(Value::F64(ldexp_f64(m, e)));
+            }
+            BuiltinId::Ilogb => {
+                // ilogb(x) - unbiased base-2 exponent of abs(x), extracted from the IEEE 754
+                // bit pattern (same special returns as `exponent`: 0 -> typemin, Inf/NaN -> typemax).
+                let x = self.pop_f64_or_i64()?;
+                let result = if x == 0.0 {
+                    i64::MIN
+                } else if x.is_infinite() || x.is_nan() {
+                    i64::MAX
+                } else {
+                    let bits = x.abs().to_bits();
+                    let biased_exp = ((bits >> 52) & 0x7FF) as i64;
+                    biased_exp - 1023
+                };
+                self.stack.push(Value::I64(result));
+            }
+
             // Float inspection
             BuiltinId::Issubnormal => {
                 let x = self.pop_f64_or_i64()?;
@@ -306,6 +361,66 @@ impl<R: RngLike> Vm<R> {
                 let x = self.pop_f64_or_i64()?;
                 self.stack.push(Value::F64(x.mul_add(y, z)));
             }
+            BuiltinId::FmaF128 | BuiltinId::MuladdF128 => {
+                // fma(x, y, z) / muladd(x, y, z) = x*y + z on quad-precision operands.
+                let z = self.pop_f128()?;
+                let y = self.pop_f128()?;
+                let x = self.pop_f128()?;
+                self.stack.push(Value::F128(x.mul(&y).add(&z)));
+            }
+            BuiltinId::RoundF128 => {
+                let x = self.pop_f128()?;
+                self.stack.push(Value::F128(x.round()));
+            }
+            BuiltinId::TruncF128 => {
+                let x = self.pop_f128()?;
+                self.stack.push(Value::F128(x.trunc()));
+            }
+
+            // Sign manipulation
+            BuiltinId::SignI64 => {
+                // Classic `x > 0 ? 1 : (x == 0 ? 0 : -1)` integer sign.
+                let x = self.stack.pop_i64()?;
+                let result = match x.cmp(&0) {
+                    std::cmp::Ordering::Greater => 1,
+                    std::cmp::Ordering::Equal => 0,
+                    std::cmp::Ordering::Less => -1,
+                };
+                self.stack.push(Value::I64(result));
+            }
+            BuiltinId::SignF64 => {
+                let x = self.pop_f64_or_i64()?;
+                let result = if x.is_nan() {
+                    f64::NAN
+                } else if x > 0.0 {
+                    1.0
+                } else if x < 0.0 {
+                    -1.0
+                } else {
+                    x // preserve +-0.0
+                };
+                self.stack.push(Value::F64(result));
+            }
+            BuiltinId::Signbit => {
+                let x = self.pop_f64_or_i64()?;
+                self.stack.push(Value::Bool(x.is_sign_negative()));
+            }
+            BuiltinId::Copysign => {
+                let y = self.pop_f64_or_i64()?;
+                let x = self.pop_f64_or_i64()?;
+                self.stack.push(Value::F64(x.copysign(y)));
+            }
+            BuiltinId::FlipsignI64 => {
+                let y = self.stack.pop_i64()?;
+                let x = self.stack.pop_i64()?;
+                self.stack.push(Value::I64(if y < 0 { -x } else { x }));
+            }
+            BuiltinId::FlipsignF64 => {
+                let y = self.pop_f64_or_i64()?;
+                let x = self.pop_f64_or_i64()?;
+                self.stack
+                    .push(Value::F64(if y.is_sign_negative() { -x } else { x }));
+            }
 
             // Note: gcd, lcm, factorial removed - now Pure Julia (base/intfuncs.jl)
             _ => return Ok(None),
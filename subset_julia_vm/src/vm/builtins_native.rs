@@ -0,0 +1,52 @@
+//! Native host-function bridge builtin for the VM.
+//!
+//! Handles `BuiltinId::CallNative`, the `ccall_native(name, args...)`
+//! dispatch point to Rust functions registered via `ffi::register_native`.
+
+use crate::builtins::BuiltinId;
+use crate::ffi::call_native;
+use crate::rng::RngLike;
+
+use super::error::VmError;
+use super::stack_ops::StackOps;
+use super::value::Value;
+use super::Vm;
+
+impl<R: RngLike> Vm<R> {
+    pub(super) fn execute_builtin_native(
+        &mut self,
+        builtin: &BuiltinId,
+        argc: usize,
+    ) -> Result<Option<()>, VmError> {
+        match builtin {
+            BuiltinId::CallNative => {
+                let mut values = Vec::with_capacity(argc);
+                for _ in 0..argc {
+                    values.push(self.stack.pop_value()?);
+                }
+                values.reverse();
+
+                if values.is_empty() {
+                    return Err(VmError::TypeError(
+                        "ccall_native requires a function name argument".to_string(),
+                    ));
+                }
+
+                let name = match &values[0] {
+                    Value::Str(s) => s.clone(),
+                    _ => {
+                        return Err(VmError::TypeError(
+                            "ccall_native name must be a string".to_string(),
+                        ))
+                    }
+                };
+
+                let result = call_native(&name, &values[1..])?;
+                self.stack.push(result);
+            }
+
+            _ => return Ok(None),
+        }
+        Ok(Some(()))
+    }
+}
@@ -292,6 +292,14 @@ impl<R: RngLike> Vm<R> {
                 let result = self.convert_to_f64(&val)?;
                 self.stack.push(Value::F64(result));
             }
+            BuiltinId::Float128 => {
+                let val = self.stack.pop_value()?;
+                let result = self.convert_to_f64(&val)?;
+                self.stack
+                    .push(Value::F128(crate::vm::softfloat128::SoftF128::from_f64(
+                        result,
+                    )));
+            }
 
             _ => return Ok(None),
         }
@@ -0,0 +1,105 @@
+//! Bytecode disassembly for the `code_lowered`/`code_native` reflection builtins.
+//!
+//! Formats the `Instr` slice of a dispatched method back into a human-readable
+//! listing, resolving `Call`-family operands to callee names via `Vm::functions`
+//! and expanding the struct candidate lists carried by `IterateDynamic` /
+//! `CallDynamicOrBuiltin` so a reader can tell whether a loop compiled to the
+//! fast builtin iterate path or fell through to a Pure-Julia `iterate` method.
+
+use crate::rng::RngLike;
+use crate::vm::instr::Instr;
+use crate::vm::Vm;
+
+impl<R: RngLike> Vm<R> {
+    /// Disassemble `self.code[code_start..code_end]` for `func_name`, labeling the
+    /// listing with `header` (distinct wording for `@code_lowered` vs.
+    /// `@code_native`, since this VM has a single compiled form to show).
+    pub(super) fn disassemble_method(
+        &self,
+        func_name: &str,
+        code_start: usize,
+        code_end: usize,
+        header: &str,
+    ) -> String {
+        let mut out = String::new();
+        out.push_str(&format!("{header} {func_name}\n"));
+        for (offset, instr) in self.code[code_start..code_end].iter().enumerate() {
+            out.push_str(&format!(
+                "{:4}\t{}\n",
+                offset,
+                self.format_instr(instr)
+            ));
+        }
+        out
+    }
+
+    /// Render a single `Instr`, resolving callee-function-index operands to
+    /// names and expanding struct candidate lists. Falls back to `{:?}` for
+    /// every instruction not matched below, including call forms such as
+    /// `CallWithKwargsSplat` whose function index is left unresolved.
+    fn format_instr(&self, instr: &Instr) -> String {
+        match instr {
+            Instr::Call(func_index, argc) => {
+                format!("Call {}({}) [#{}]", self.callee_name(*func_index), argc, func_index)
+            }
+            Instr::CallWithSplat(func_index, argc, splat_mask) => format!(
+                "CallWithSplat {}({}, splat={:?}) [#{}]",
+                self.callee_name(*func_index),
+                argc,
+                splat_mask,
+                func_index
+            ),
+            Instr::CallWithKwargs(func_index, argc, kwnames) => format!(
+                "CallWithKwargs {}({}, kwargs={:?}) [#{}]",
+                self.callee_name(*func_index),
+                argc,
+                kwnames,
+                func_index
+            ),
+            Instr::CallSpecialize(specializable_index, argc) => format!(
+                "CallSpecialize {}({}) [#{}]",
+                self.specializable_functions
+                    .get(*specializable_index)
+                    .map(|f| f.name.as_str())
+                    .unwrap_or("<unknown>"),
+                argc,
+                specializable_index
+            ),
+            Instr::CallBuiltin(id, argc) => format!("CallBuiltin {}({})", id.name(), argc),
+            Instr::CallIntrinsic(intrinsic) => format!("CallIntrinsic {intrinsic:?}"),
+            Instr::IterateDynamic(argc, candidates) => format!(
+                "IterateDynamic({argc}) candidates=[{}]",
+                self.format_candidates(candidates)
+            ),
+            Instr::CallDynamicOrBuiltin(id, candidates) => format!(
+                "CallDynamicOrBuiltin {} candidates=[{}]",
+                id.name(),
+                self.format_candidates(candidates)
+            ),
+            Instr::CallDynamic(fallback, argc, candidates) => format!(
+                "CallDynamic({argc}, fallback={}[#{fallback}]) candidates=[{}]",
+                self.callee_name(*fallback),
+                self.format_candidates(candidates)
+            ),
+            other => format!("{other:?}"),
+        }
+    }
+
+    /// `(func_index, expected_type_name)` candidates, as `TypeName -> name[#idx]`.
+    fn format_candidates(&self, candidates: &[(usize, String)]) -> String {
+        candidates
+            .iter()
+            .map(|(func_index, type_name)| {
+                format!("{type_name} -> {}[#{func_index}]", self.callee_name(*func_index))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    fn callee_name(&self, func_index: usize) -> &str {
+        self.functions
+            .get(func_index)
+            .map(|info| info.name.as_str())
+            .unwrap_or("<unknown>")
+    }
+}
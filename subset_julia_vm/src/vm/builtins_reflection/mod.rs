@@ -8,17 +8,23 @@
 // checks that reject non-positive values before the cast.
 #![allow(clippy::cast_sign_loss)]
 
+mod disasm;
 mod primitives;
 
+use std::collections::HashMap;
+
 use crate::builtins::BuiltinId;
 use crate::rng::RngLike;
-use crate::types::JuliaType;
+use crate::types::{JuliaType, TypeParam};
 
 use super::error::VmError;
 use super::stack_ops::StackOps;
+use super::type_utils::normalize_struct_name;
 use super::value::{new_array_ref, ArrayValue, StructInstance, SymbolValue, TupleValue, Value};
 use super::{FunctionInfo, Vm};
-use primitives::{extract_func_name, extract_types_from_value, value_type_to_julia_type};
+use primitives::{
+    extract_func_name, extract_types_from_value, value_type_byte_size, value_type_to_julia_type,
+};
 
 impl<R: RngLike> Vm<R> {
     /// Execute reflection builtin functions.
@@ -32,115 +38,17 @@ impl<R: RngLike> Vm<R> {
             BuiltinId::_Fieldnames => {
                 // fieldnames(T) - tuple of field names as symbols/strings
                 let val = self.stack.pop_value()?;
-                let names: Vec<Value> = match &val {
-                    Value::StructRef(idx) => {
-                        if let Some(si) = self.struct_heap.get(*idx) {
-                            if let Some(def) = self
-                                .struct_def_name_index
-                                .get(&si.struct_name)
-                                .and_then(|&idx| self.struct_defs.get(idx))
-                            {
-                                def.fields
-                                    .iter()
-                                    .map(|(name, _)| Value::Str(name.clone()))
-                                    .collect()
-                            } else {
-                                vec![]
-                            }
-                        } else {
-                            vec![]
-                        }
-                    }
-                    Value::Struct(si) => {
-                        if let Some(def) = self
-                            .struct_def_name_index
-                            .get(&si.struct_name)
-                            .and_then(|&idx| self.struct_defs.get(idx))
-                        {
-                            def.fields
-                                .iter()
-                                .map(|(name, _)| Value::Str(name.clone()))
-                                .collect()
-                        } else {
-                            vec![]
-                        }
-                    }
-                    Value::DataType(jt) => {
-                        let type_name = jt.name();
-                        // Check for built-in types first
-                        match type_name.as_ref() {
-                            "LineNumberNode" => vec![
-                                Value::Symbol(SymbolValue::new("line")),
-                                Value::Symbol(SymbolValue::new("file")),
-                            ],
-                            "Expr" => vec![
-                                Value::Symbol(SymbolValue::new("head")),
-                                Value::Symbol(SymbolValue::new("args")),
-                            ],
-                            "QuoteNode" => vec![Value::Symbol(SymbolValue::new("value"))],
-                            "GlobalRef" => vec![
-                                Value::Symbol(SymbolValue::new("mod")),
-                                Value::Symbol(SymbolValue::new("name")),
-                            ],
-                            _ => {
-                                if let Some(def) = self
-                                    .struct_defs
-                                    .iter()
-                                    .find(|d| d.name == type_name.as_ref())
-                                {
-                                    def.fields
-                                        .iter()
-                                        .map(|(name, _)| Value::Str(name.clone()))
-                                        .collect()
-                                } else {
-                                    vec![]
-                                }
-                            }
-                        }
-                    }
-                    Value::NamedTuple(nt) => {
-                        nt.names.iter().map(|n| Value::Str(n.clone())).collect()
-                    }
-                    // Handle type name passed as string (e.g., fieldnames(Person))
-                    Value::Str(type_name) => {
-                        // Check for built-in types first
-                        match type_name.as_str() {
-                            "LineNumberNode" => vec![
-                                Value::Symbol(SymbolValue::new("line")),
-                                Value::Symbol(SymbolValue::new("file")),
-                            ],
-                            "Expr" => vec![
-                                Value::Symbol(SymbolValue::new("head")),
-                                Value::Symbol(SymbolValue::new("args")),
-                            ],
-                            "QuoteNode" => vec![Value::Symbol(SymbolValue::new("value"))],
-                            "GlobalRef" => vec![
-                                Value::Symbol(SymbolValue::new("mod")),
-                                Value::Symbol(SymbolValue::new("name")),
-                            ],
-                            _ => {
-                                if let Some(def) = self
-                                    .struct_def_name_index
-                                    .get(type_name)
-                                    .and_then(|&idx| self.struct_defs.get(idx))
-                                {
-                                    def.fields
-                                        .iter()
-                                        .map(|(name, _)| Value::Str(name.clone()))
-                                        .collect()
-                                } else {
-                                    vec![]
-                                }
-                            }
-                        }
-                    }
-                    // LineNumberNode has fields: line, file
-                    Value::LineNumberNode(_) => vec![
-                        Value::Symbol(SymbolValue::new("line")),
-                        Value::Symbol(SymbolValue::new("file")),
-                    ],
-                    _ => vec![],
-                };
+                let names = self.fieldnames_for_value(&val);
+                self.stack
+                    .push(Value::Tuple(TupleValue { elements: names }));
+            }
+
+            BuiltinId::Propertynames => {
+                // propertynames(x) - tuple of property names; defaults to fieldnames(x)
+                // since the property interface (getproperty/setproperty!) falls back to
+                // direct field access unless a user overload is in scope.
+                let val = self.stack.pop_value()?;
+                let names = self.fieldnames_for_value(&val);
                 self.stack
                     .push(Value::Tuple(TupleValue { elements: names }));
             }
@@ -278,6 +186,65 @@ impl<R: RngLike> Vm<R> {
                     .push(Value::Tuple(TupleValue { elements: types }));
             }
 
+            BuiltinId::_Fieldoffset => {
+                // _fieldoffset(T, i) - approximate byte offset of field i (1-based), computed
+                // as the cumulative sum of value_type_byte_size over the preceding fields.
+                // This VM boxes structs as opaque heap references rather than laying them out
+                // in memory, so the result is an approximation consistent with the one
+                // BuiltinId::Sizeof already makes for struct/array values.
+                let index_val = self.stack.pop_value()?;
+                let type_val = self.stack.pop_value()?;
+
+                let index = match &index_val {
+                    Value::I64(i) => *i as usize,
+                    Value::I32(i) => *i as usize,
+                    _ => {
+                        return Err(VmError::TypeError(format!(
+                            "_fieldoffset index must be an integer, got {:?}",
+                            index_val
+                        )))
+                    }
+                };
+                if index == 0 {
+                    return Err(VmError::FieldIndexOutOfBounds {
+                        index: 0,
+                        field_count: 0,
+                        field_names: Vec::new(),
+                    });
+                }
+                let field_idx = index - 1;
+
+                let type_name = match &type_val {
+                    Value::DataType(jt) => jt.name().into_owned(),
+                    Value::Str(s) => s.clone(),
+                    _ => {
+                        return Err(VmError::TypeError(format!(
+                            "_fieldoffset requires a type, got {:?}",
+                            type_val
+                        )))
+                    }
+                };
+                let fields = self
+                    .struct_def_name_index
+                    .get(&type_name)
+                    .and_then(|&idx| self.struct_defs.get(idx))
+                    .map(|def| def.fields.clone())
+                    .ok_or_else(|| VmError::TypeError(format!("no struct definition for type {}", type_name)))?;
+
+                if field_idx >= fields.len() {
+                    return Err(VmError::FieldIndexOutOfBounds {
+                        index: field_idx,
+                        field_count: fields.len(),
+                        field_names: fields.iter().map(|(name, _)| name.clone()).collect(),
+                    });
+                }
+                let offset: i64 = fields[..field_idx]
+                    .iter()
+                    .map(|(_, field_type)| value_type_byte_size(field_type))
+                    .sum();
+                self.stack.push(Value::I64(offset));
+            }
+
             BuiltinId::_Getfield => {
                 // _getfield(x, i) - get field value by index (1-based, like Julia)
                 let index_val = self.stack.pop_value()?;
@@ -299,6 +266,7 @@ impl<R: RngLike> Vm<R> {
                     return Err(VmError::FieldIndexOutOfBounds {
                         index: 0,
                         field_count: 0,
+                        field_names: Vec::new(),
                     });
                 }
                 let field_idx = index - 1;
@@ -336,15 +304,26 @@ impl<R: RngLike> Vm<R> {
                             Value::NamedTuple(nt) => nt.values.len(),
                             _ => 0,
                         };
+                        let field_names = match &obj_val {
+                            Value::StructRef(idx) => self
+                                .struct_heap
+                                .get(*idx)
+                                .map(|s| self.struct_field_names_by_type_id(s.type_id))
+                                .unwrap_or_default(),
+                            Value::Struct(si) => self.struct_field_names_by_type_id(si.type_id),
+                            Value::NamedTuple(nt) => nt.names.clone(),
+                            _ => Vec::new(),
+                        };
                         return Err(VmError::FieldIndexOutOfBounds {
                             index: field_idx,
                             field_count,
+                            field_names,
                         });
                     }
                 }
             }
 
-            BuiltinId::Getfield => {
+            BuiltinId::Getfield | BuiltinId::Getproperty => {
                 // getfield(x, name) or getfield(x, i) - get field by name (Symbol) or index (Int)
                 let field_arg = self.stack.pop_value()?;
                 let obj_val = self.stack.pop_value()?;
@@ -357,19 +336,27 @@ impl<R: RngLike> Vm<R> {
                         let field_value = match &obj_val {
                             Value::StructRef(idx) => {
                                 if let Some(si) = self.struct_heap.get(*idx) {
-                                    // Look up field index by name from struct definition
+                                    // Look up field index by name using the pre-built per-struct index
                                     let type_id = si.type_id;
-                                    if let Some(def) = self.struct_defs.get(type_id) {
-                                        if let Some(field_idx) = def
-                                            .fields
-                                            .iter()
-                                            .position(|(name, _)| name == field_name)
+                                    if self.struct_defs.get(type_id).is_some() {
+                                        if let Some(field_idx) =
+                                            self.get_struct_field_index(type_id, field_name)
                                         {
                                             si.get_field(field_idx).cloned()
                                         } else {
+                                            let field_names =
+                                                self.struct_field_names_by_type_id(type_id);
+                                            let hint = if field_names.is_empty() {
+                                                String::new()
+                                            } else {
+                                                format!(
+                                                    "; available fields: {}",
+                                                    field_names.join(", ")
+                                                )
+                                            };
                                             return Err(VmError::TypeError(format!(
-                                                "type {} has no field {}",
-                                                si.struct_name, field_name
+                                                "type {} has no field {}{}",
+                                                si.struct_name, field_name, hint
                                             )));
                                         }
                                     } else {
@@ -387,15 +374,25 @@ impl<R: RngLike> Vm<R> {
                             }
                             Value::Struct(si) => {
                                 let type_id = si.type_id;
-                                if let Some(def) = self.struct_defs.get(type_id) {
+                                if self.struct_defs.get(type_id).is_some() {
                                     if let Some(field_idx) =
-                                        def.fields.iter().position(|(name, _)| name == field_name)
+                                        self.get_struct_field_index(type_id, field_name)
                                     {
                                         si.get_field(field_idx).cloned()
                                     } else {
+                                        let field_names =
+                                            self.struct_field_names_by_type_id(type_id);
+                                        let hint = if field_names.is_empty() {
+                                            String::new()
+                                        } else {
+                                            format!(
+                                                "; available fields: {}",
+                                                field_names.join(", ")
+                                            )
+                                        };
                                         return Err(VmError::TypeError(format!(
-                                            "type {} has no field {}",
-                                            si.struct_name, field_name
+                                            "type {} has no field {}{}",
+                                            si.struct_name, field_name, hint
                                         )));
                                     }
                                 } else {
@@ -417,9 +414,15 @@ impl<R: RngLike> Vm<R> {
                         match field_value {
                             Some(v) => self.stack.push(v),
                             None => {
+                                let hint = match &obj_val {
+                                    Value::NamedTuple(nt) if !nt.names.is_empty() => {
+                                        format!("; available fields: {}", nt.names.join(", "))
+                                    }
+                                    _ => String::new(),
+                                };
                                 return Err(VmError::TypeError(format!(
-                                    "type has no field {}",
-                                    field_name
+                                    "type has no field {}{}",
+                                    field_name, hint
                                 )));
                             }
                         }
@@ -535,7 +538,37 @@ impl<R: RngLike> Vm<R> {
                 }
             }
 
-            BuiltinId::Setfield => {
+            BuiltinId::_Setfield => {
+                // _setfield!(x, i, v) - set field value by index (1-based, like Julia)
+                let value = self.stack.pop_value()?;
+                let index_val = self.stack.pop_value()?;
+                let obj_val = self.stack.pop_value()?;
+
+                let index = match &index_val {
+                    Value::I64(i) => *i as usize,
+                    Value::I32(i) => *i as usize,
+                    _ => {
+                        return Err(VmError::TypeError(format!(
+                            "_setfield! index must be an integer, got {:?}",
+                            index_val
+                        )))
+                    }
+                };
+
+                // Convert from 1-based to 0-based indexing
+                if index == 0 {
+                    return Err(VmError::FieldIndexOutOfBounds {
+                        index: 0,
+                        field_count: 0,
+                        field_names: Vec::new(),
+                    });
+                }
+                let field_idx = index - 1;
+
+                self.perform_setfield(obj_val, field_idx, value)?;
+            }
+
+            BuiltinId::Setfield | BuiltinId::Setproperty => {
                 // setfield!(x, name, v) or setfield!(x, i, v) - set field by name (Symbol) or index (Int)
                 let value = self.stack.pop_value()?;
                 let field_arg = self.stack.pop_value()?;
@@ -552,14 +585,21 @@ impl<R: RngLike> Vm<R> {
                             _ => None,
                         };
                         if let Some(tid) = type_id {
-                            if let Some(def) = self.struct_defs.get(tid) {
-                                def.fields
-                                    .iter()
-                                    .position(|(name, _)| name == field_name)
+                            if self.struct_defs.get(tid).is_some() {
+                                self.get_struct_field_index(tid, field_name)
                                     .ok_or_else(|| {
+                                        let field_names = self.struct_field_names_by_type_id(tid);
+                                        let hint = if field_names.is_empty() {
+                                            String::new()
+                                        } else {
+                                            format!(
+                                                "; available fields: {}",
+                                                field_names.join(", ")
+                                            )
+                                        };
                                         VmError::TypeError(format!(
-                                            "type has no field {}",
-                                            field_name
+                                            "type has no field {}{}",
+                                            field_name, hint
                                         ))
                                     })?
                             } else {
@@ -603,64 +643,7 @@ impl<R: RngLike> Vm<R> {
                     }
                 };
 
-                // Perform the field assignment
-                match obj_val {
-                    Value::StructRef(idx) => {
-                        // Get type_id from heap
-                        let type_id = self.struct_heap.get(idx).map(|s| s.type_id).unwrap_or(0);
-
-                        // Check if struct is mutable
-                        let is_mutable = self
-                            .struct_defs
-                            .get(type_id)
-                            .map(|def| def.is_mutable)
-                            .unwrap_or(false);
-
-                        if !is_mutable {
-                            let struct_name = self
-                                .struct_defs
-                                .get(type_id)
-                                .map(|def| def.name.clone())
-                                .unwrap_or_else(|| "unknown".to_string());
-                            return Err(VmError::ImmutableFieldAssign(struct_name));
-                        }
-
-                        // Modify struct in heap directly
-                        if let Some(s) = self.struct_heap.get_mut(idx) {
-                            s.set_field(field_idx, value.clone())?;
-                        }
-                        // Return the assigned value (Julia semantics)
-                        self.stack.push(value);
-                    }
-                    Value::Struct(mut s) => {
-                        // Check if struct is mutable
-                        let is_mutable = self
-                            .struct_defs
-                            .get(s.type_id)
-                            .map(|def| def.is_mutable)
-                            .unwrap_or(false);
-
-                        if !is_mutable {
-                            let struct_name = self
-                                .struct_defs
-                                .get(s.type_id)
-                                .map(|def| def.name.clone())
-                                .unwrap_or_else(|| "unknown".to_string());
-                            return Err(VmError::ImmutableFieldAssign(struct_name));
-                        }
-
-                        s.set_field(field_idx, value.clone())?;
-                        // Allocate on heap for mutation tracking
-                        self.struct_heap.push(s);
-                        // Return the assigned value (Julia semantics)
-                        self.stack.push(value);
-                    }
-                    _ => {
-                        return Err(VmError::TypeError(
-                            "setfield! requires a mutable struct".into(),
-                        ));
-                    }
-                }
+                self.perform_setfield(obj_val, field_idx, value)?;
             }
 
             BuiltinId::Deepcopy => {
@@ -692,14 +675,50 @@ impl<R: RngLike> Vm<R> {
                 let func_name = extract_func_name(&func_val)?;
                 let arg_types = extract_types_from_value(&types_val)?;
 
-                match self.find_matching_methods(&func_name, Some(&arg_types)) {
-                    Some(methods) if !methods.is_empty() => {
-                        // Return the best matching method (first in the sorted list)
-                        let info = &methods[0];
-                        let method_struct = self.create_method_struct(info)?;
+                match self.resolve_best_method(&func_name, &arg_types)? {
+                    Some(info) => {
+                        let method_struct = self.create_method_struct(&info)?;
                         self.stack.push(method_struct);
                     }
-                    _ => {
+                    None => {
+                        let type_str = arg_types
+                            .iter()
+                            .map(|t| t.name().to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        return Err(VmError::MethodError(format!(
+                            "no method matching {}({})",
+                            func_name, type_str
+                        )));
+                    }
+                }
+            }
+
+            BuiltinId::CodeLowered | BuiltinId::CodeNative => {
+                // code_lowered(f, types) / code_native(f, types) - dispatch like `which`,
+                // then disassemble the selected method's compiled Instr stream.
+                let types_val = self.stack.pop_value()?;
+                let func_val = self.stack.pop_value()?;
+
+                let func_name = extract_func_name(&func_val)?;
+                let arg_types = extract_types_from_value(&types_val)?;
+
+                match self.resolve_best_method(&func_name, &arg_types)? {
+                    Some(info) => {
+                        let header = if matches!(builtin, BuiltinId::CodeNative) {
+                            "# native code for"
+                        } else {
+                            "# lowered code for"
+                        };
+                        let listing = self.disassemble_method(
+                            &info.name,
+                            info.code_start,
+                            info.code_end,
+                            header,
+                        );
+                        self.stack.push(Value::Str(listing));
+                    }
+                    None => {
                         let type_str = arg_types
                             .iter()
                             .map(|t| t.name().to_string())
@@ -744,25 +763,406 @@ impl<R: RngLike> Vm<R> {
                     ))));
             }
 
+            BuiltinId::_MethodsWith => {
+                // _methodswith(T, supertypes) - all methods with an argument of type T
+                // (or a supertype of T, when `supertypes` is true). Backs
+                // `methodswith` in reflection.jl.
+                let supertypes = matches!(self.stack.pop_value()?, Value::Bool(true));
+                let target = match self.stack.pop_value()? {
+                    Value::DataType(jt) => jt,
+                    _ => return Err(VmError::TypeError("Expected a Type".into())),
+                };
+
+                let method_values: Vec<Value> = self
+                    .functions
+                    .iter()
+                    .filter(|info| {
+                        info.param_julia_types.iter().any(|pt| {
+                            *pt == target
+                                || (supertypes && *pt != JuliaType::Any && target.is_subtype_of(pt))
+                        })
+                    })
+                    .map(|info| self.create_method_struct(info))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                self.stack
+                    .push(Value::Array(new_array_ref(ArrayValue::any_vector(
+                        method_values,
+                    ))));
+            }
+
+            BuiltinId::_Structequals => {
+                // _structequals(a, b) - reflection-driven generic `==`/`isequal` fallback for
+                // composite types: field-by-field recursive comparison, descending into nested
+                // structs/tuples/named tuples, short-circuiting on the first mismatch.
+                let b = self.stack.pop_value()?;
+                let a = self.stack.pop_value()?;
+                self.stack.push(Value::Bool(self.struct_values_equal(&a, &b)));
+            }
+
+            BuiltinId::_Structhash => {
+                // _structhash(x) - reflection-driven generic `hash` fallback for composite
+                // types: folds each field's hash together with the type name, so that
+                // structurally-equal structs hash equally regardless of heap location.
+                let val = self.stack.pop_value()?;
+                self.stack.push(Value::I64(self.struct_value_hash(&val) as i64));
+            }
+
             _ => return Ok(None),
         }
         Ok(Some(()))
     }
 
+    /// Resolve a struct-shaped value (`StructRef` or `Struct`) to its type name and field
+    /// values, for use by the reflection-driven equality/hash helpers below.
+    pub(in crate::vm) fn struct_instance_fields<'a>(
+        &'a self,
+        val: &'a Value,
+    ) -> Option<(&'a str, &'a [Value])> {
+        match val {
+            Value::StructRef(idx) => self
+                .struct_heap
+                .get(*idx)
+                .map(|si| (si.struct_name.as_str(), si.values.as_slice())),
+            Value::Struct(si) => Some((si.struct_name.as_str(), si.values.as_slice())),
+            _ => None,
+        }
+    }
+
+    /// Reflection-driven recursive structural equality for structs (backs `_structequals`):
+    /// same type name, same field count, and every field equal in turn - descending into
+    /// nested structs/tuples/named tuples rather than requiring a hand-written `==` method.
+    pub(in crate::vm) fn struct_values_equal(&self, a: &Value, b: &Value) -> bool {
+        match (
+            self.struct_instance_fields(a),
+            self.struct_instance_fields(b),
+        ) {
+            (Some((name_a, values_a)), Some((name_b, values_b))) => {
+                normalize_struct_name(name_a) == normalize_struct_name(name_b)
+                    && values_a.len() == values_b.len()
+                    && values_a
+                        .iter()
+                        .zip(values_b.iter())
+                        .all(|(x, y)| self.values_equal_recursive(x, y))
+            }
+            _ => false,
+        }
+    }
+
+    /// Field-level equality used by [`Self::struct_values_equal`]: recurses into nested
+    /// structs, tuples, and named tuples; everything else falls back to Debug comparison
+    /// (consistent with the scalar handling already used by the `Egal`/`Isequal` builtins).
+    fn values_equal_recursive(&self, a: &Value, b: &Value) -> bool {
+        if self.struct_instance_fields(a).is_some() || self.struct_instance_fields(b).is_some() {
+            return self.struct_values_equal(a, b);
+        }
+        match (a, b) {
+            (Value::Tuple(ta), Value::Tuple(tb)) => {
+                ta.elements.len() == tb.elements.len()
+                    && ta
+                        .elements
+                        .iter()
+                        .zip(tb.elements.iter())
+                        .all(|(x, y)| self.values_equal_recursive(x, y))
+            }
+            (Value::NamedTuple(na), Value::NamedTuple(nb)) => {
+                na.names == nb.names
+                    && na.values.len() == nb.values.len()
+                    && na
+                        .values
+                        .iter()
+                        .zip(nb.values.iter())
+                        .all(|(x, y)| self.values_equal_recursive(x, y))
+            }
+            _ => format!("{:?}", a) == format!("{:?}", b),
+        }
+    }
+
+    /// Reflection-driven hash for structs (backs `_structhash`): folds the type name and
+    /// every field's hash together, so that any two values `struct_values_equal` considers
+    /// equal also hash equally.
+    pub(in crate::vm) fn struct_value_hash(&self, val: &Value) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        if let Some((name, values)) = self.struct_instance_fields(val) {
+            normalize_struct_name(name).hash(&mut hasher);
+            for v in values {
+                self.struct_value_hash_into(v, &mut hasher);
+            }
+        } else {
+            format!("{:?}", val).hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Folds a single field's hash into `hasher`, recursing through nested
+    /// structs/tuples/named tuples the same way [`Self::values_equal_recursive`] does.
+    fn struct_value_hash_into(&self, val: &Value, hasher: &mut impl std::hash::Hasher) {
+        use std::hash::Hash;
+
+        if self.struct_instance_fields(val).is_some() {
+            self.struct_value_hash(val).hash(hasher);
+            return;
+        }
+        match val {
+            Value::Tuple(t) => {
+                for v in &t.elements {
+                    self.struct_value_hash_into(v, hasher);
+                }
+            }
+            Value::NamedTuple(nt) => {
+                nt.names.hash(hasher);
+                for v in &nt.values {
+                    self.struct_value_hash_into(v, hasher);
+                }
+            }
+            _ => format!("{:?}", val).hash(hasher),
+        }
+    }
+
+    /// Shared name lookup backing both `_fieldnames` and `propertynames`: resolves
+    /// an instance, `DataType`, or type-name string to its tuple of field names.
+    fn fieldnames_for_value(&self, val: &Value) -> Vec<Value> {
+        match val {
+            Value::StructRef(idx) => {
+                if let Some(si) = self.struct_heap.get(*idx) {
+                    if let Some(def) = self
+                        .struct_def_name_index
+                        .get(&si.struct_name)
+                        .and_then(|&idx| self.struct_defs.get(idx))
+                    {
+                        def.fields
+                            .iter()
+                            .map(|(name, _)| Value::Str(name.clone()))
+                            .collect()
+                    } else {
+                        vec![]
+                    }
+                } else {
+                    vec![]
+                }
+            }
+            Value::Struct(si) => {
+                if let Some(def) = self
+                    .struct_def_name_index
+                    .get(&si.struct_name)
+                    .and_then(|&idx| self.struct_defs.get(idx))
+                {
+                    def.fields
+                        .iter()
+                        .map(|(name, _)| Value::Str(name.clone()))
+                        .collect()
+                } else {
+                    vec![]
+                }
+            }
+            Value::DataType(jt) => {
+                let type_name = jt.name();
+                // Check for built-in types first
+                match type_name.as_ref() {
+                    "LineNumberNode" => vec![
+                        Value::Symbol(SymbolValue::new("line")),
+                        Value::Symbol(SymbolValue::new("file")),
+                    ],
+                    "Expr" => vec![
+                        Value::Symbol(SymbolValue::new("head")),
+                        Value::Symbol(SymbolValue::new("args")),
+                    ],
+                    "QuoteNode" => vec![Value::Symbol(SymbolValue::new("value"))],
+                    "GlobalRef" => vec![
+                        Value::Symbol(SymbolValue::new("mod")),
+                        Value::Symbol(SymbolValue::new("name")),
+                    ],
+                    _ => {
+                        if let Some(def) = self
+                            .struct_defs
+                            .iter()
+                            .find(|d| d.name == type_name.as_ref())
+                        {
+                            def.fields
+                                .iter()
+                                .map(|(name, _)| Value::Str(name.clone()))
+                                .collect()
+                        } else {
+                            vec![]
+                        }
+                    }
+                }
+            }
+            Value::NamedTuple(nt) => nt.names.iter().map(|n| Value::Str(n.clone())).collect(),
+            // Handle type name passed as string (e.g., fieldnames(Person))
+            Value::Str(type_name) => {
+                // Check for built-in types first
+                match type_name.as_str() {
+                    "LineNumberNode" => vec![
+                        Value::Symbol(SymbolValue::new("line")),
+                        Value::Symbol(SymbolValue::new("file")),
+                    ],
+                    "Expr" => vec![
+                        Value::Symbol(SymbolValue::new("head")),
+                        Value::Symbol(SymbolValue::new("args")),
+                    ],
+                    "QuoteNode" => vec![Value::Symbol(SymbolValue::new("value"))],
+                    "GlobalRef" => vec![
+                        Value::Symbol(SymbolValue::new("mod")),
+                        Value::Symbol(SymbolValue::new("name")),
+                    ],
+                    _ => {
+                        if let Some(def) = self
+                            .struct_def_name_index
+                            .get(type_name)
+                            .and_then(|&idx| self.struct_defs.get(idx))
+                        {
+                            def.fields
+                                .iter()
+                                .map(|(name, _)| Value::Str(name.clone()))
+                                .collect()
+                        } else {
+                            vec![]
+                        }
+                    }
+                }
+            }
+            // LineNumberNode has fields: line, file
+            Value::LineNumberNode(_) => vec![
+                Value::Symbol(SymbolValue::new("line")),
+                Value::Symbol(SymbolValue::new("file")),
+            ],
+            _ => vec![],
+        }
+    }
+
+    /// Perform the actual field mutation for `setfield!`/`_setfield!`, once the
+    /// field index has been resolved by name or position. Checks that the
+    /// struct is mutable and that `value` is compatible with the field's
+    /// declared type (if any), then pushes the assigned value back onto the
+    /// stack, matching Julia's `setfield!` return semantics.
+    fn perform_setfield(
+        &mut self,
+        obj_val: Value,
+        field_idx: usize,
+        value: Value,
+    ) -> Result<(), VmError> {
+        match obj_val {
+            Value::StructRef(idx) => {
+                let type_id = self.struct_heap.get(idx).map(|s| s.type_id).unwrap_or(0);
+                let is_mutable = self
+                    .struct_defs
+                    .get(type_id)
+                    .map(|def| def.is_mutable)
+                    .unwrap_or(false);
+                if !is_mutable {
+                    let struct_name = self
+                        .struct_defs
+                        .get(type_id)
+                        .map(|def| def.name.clone())
+                        .unwrap_or_else(|| "unknown".to_string());
+                    return Err(VmError::ImmutableFieldAssign(struct_name));
+                }
+                self.check_setfield_value_type(type_id, field_idx, &value)?;
+
+                // Modify struct in heap directly
+                if let Some(s) = self.struct_heap.get_mut(idx) {
+                    s.set_field(field_idx, value.clone())?;
+                }
+                self.stack.push(value);
+                Ok(())
+            }
+            Value::Struct(mut s) => {
+                let is_mutable = self
+                    .struct_defs
+                    .get(s.type_id)
+                    .map(|def| def.is_mutable)
+                    .unwrap_or(false);
+                if !is_mutable {
+                    let struct_name = self
+                        .struct_defs
+                        .get(s.type_id)
+                        .map(|def| def.name.clone())
+                        .unwrap_or_else(|| "unknown".to_string());
+                    return Err(VmError::ImmutableFieldAssign(struct_name));
+                }
+                self.check_setfield_value_type(s.type_id, field_idx, &value)?;
+
+                s.set_field(field_idx, value.clone())?;
+                // Allocate on heap for mutation tracking
+                self.struct_heap.push(s);
+                self.stack.push(value);
+                Ok(())
+            }
+            _ => Err(VmError::TypeError(format!(
+                "setfield! requires a mutable struct, got {:?}",
+                obj_val
+            ))),
+        }
+    }
+
+    /// Check that `value` is compatible with the declared type of field
+    /// `field_idx` on struct `type_id`. Fields with no concrete declared type
+    /// (`Any`) accept anything, same as `getfield` imposes no type check on read.
+    fn check_setfield_value_type(
+        &self,
+        type_id: usize,
+        field_idx: usize,
+        value: &Value,
+    ) -> Result<(), VmError> {
+        let Some(def) = self.struct_defs.get(type_id) else {
+            return Ok(());
+        };
+        let Some((field_name, field_type)) = def.fields.get(field_idx) else {
+            return Ok(());
+        };
+        let declared = value_type_to_julia_type(field_type, &self.struct_defs);
+        if declared == JuliaType::Any {
+            return Ok(());
+        }
+        let actual = JuliaType::from_name_or_struct(&self.get_type_name(value));
+        if actual.is_subtype_of(&declared) {
+            Ok(())
+        } else {
+            Err(VmError::TypeError(format!(
+                "setfield!: cannot set field {} of type {} to a value of type {}; expected {}",
+                field_name,
+                def.name,
+                actual.name(),
+                declared.name()
+            )))
+        }
+    }
+
     /// Find methods matching the given function name and optionally argument types.
     /// Returns None if no methods found, otherwise returns a vector of matching FunctionInfo
     /// sorted by specificity (most specific first).
     fn find_matching_methods(
-        &self,
+        &mut self,
         func_name: &str,
         arg_types: Option<&[JuliaType]>,
     ) -> Option<Vec<FunctionInfo>> {
-        let mut matches: Vec<(FunctionInfo, u32)> = Vec::new();
-
-        for info in &self.functions {
-            if info.name != func_name {
-                continue;
+        // Cache hit path (Issue chunk433-1): only the type-filtered queries (hasmethod/which/
+        // dynamic-style calls) are cached, since an untyped `methods(f)` query already needs to
+        // touch every overload. A cached entry whose generation predates
+        // `specialization_generation` means a method was added/redefined since, so it's treated
+        // as stale and recomputed below.
+        let cache_key = arg_types.map(|types| {
+            (
+                func_name.to_string(),
+                types.iter().map(|t| t.name().into_owned()).collect::<Vec<String>>(),
+            )
+        });
+        if let Some(key) = &cache_key {
+            if let Some((generation, indices)) = self.method_dispatch_cache.get(key) {
+                if *generation == self.specialization_generation {
+                    return Some(indices.iter().map(|&idx| self.functions[idx].clone()).collect());
+                }
             }
+        }
+
+        let mut matches: Vec<(usize, FunctionInfo, u32)> = Vec::new();
+
+        for &idx in self.get_function_indices_by_name(func_name) {
+            let info = &self.functions[idx];
 
             // If no type filter, include all methods for this function
             let types = match arg_types {
@@ -772,7 +1172,7 @@ impl<R: RngLike> Vm<R> {
                         .iter()
                         .map(|ty| ty.specificity() as u32)
                         .sum();
-                    matches.push((info.clone(), score));
+                    matches.push((idx, info.clone(), score));
                     continue;
                 }
                 Some(types) => types,
@@ -794,18 +1194,36 @@ impl<R: RngLike> Vm<R> {
                 continue;
             }
 
-            // Check type compatibility
+            // Check type compatibility, unifying repeated type variables against a single
+            // substitution environment so `f(x::T, y::T) where T` rejects `f(Int, String)`
+            // (Issue chunk433-3).
             let fixed_count = info
                 .vararg_param_index
                 .unwrap_or(info.param_julia_types.len());
+            let mut env = type_var_env(&info.type_params);
             let all_match = info
                 .param_julia_types
                 .iter()
                 .take(fixed_count)
                 .zip(types.iter().take(fixed_count))
                 .all(|(param_ty, arg_ty)| {
-                    arg_ty.is_subtype_of_parametric(param_ty, &info.type_params)
-                });
+                    unify_param(param_ty, arg_ty, &info.type_params, &mut env)
+                })
+                && info
+                    .vararg_param_index
+                    .map(|vararg_idx| {
+                        // Vararg{T, N}: every trailing argument unifies against the same T
+                        // (the vararg's own declared element type).
+                        match info.param_julia_types.get(vararg_idx) {
+                            Some(vararg_ty) => types[fixed_count..]
+                                .iter()
+                                .all(|arg_ty| {
+                                    unify_param(vararg_ty, arg_ty, &info.type_params, &mut env)
+                                }),
+                            None => true,
+                        }
+                    })
+                    .unwrap_or(true);
 
             if all_match {
                 // Score by specificity, prefer non-varargs
@@ -821,7 +1239,7 @@ impl<R: RngLike> Vm<R> {
                     score
                 };
 
-                matches.push((info.clone(), adjusted));
+                matches.push((idx, info.clone(), adjusted));
             }
         }
 
@@ -830,9 +1248,115 @@ impl<R: RngLike> Vm<R> {
         }
 
         // Sort by score (descending - higher score = more specific)
-        matches.sort_by(|a, b| b.1.cmp(&a.1));
+        matches.sort_by(|a, b| b.2.cmp(&a.2));
 
-        Some(matches.into_iter().map(|(info, _)| info).collect())
+        if let Some(key) = cache_key {
+            let indices: Vec<usize> = matches.iter().map(|(idx, _, _)| *idx).collect();
+            self.method_dispatch_cache
+                .insert(key, (self.specialization_generation, indices));
+        }
+
+        Some(matches.into_iter().map(|(_, info, _)| info).collect())
+    }
+
+    /// Pairwise specificity check (Issue chunk433-2): true iff every fixed parameter type of
+    /// `a` is a subtype of the corresponding parameter type of `b`, under `a`'s own type
+    /// parameters. Varargs are treated as least specific: a vararg method is never more
+    /// specific than a non-vararg one, while a non-vararg method is more specific than a
+    /// vararg one with a matching fixed prefix.
+    pub(in crate::vm) fn method_is_more_specific(&self, a: &FunctionInfo, b: &FunctionInfo) -> bool {
+        match (a.vararg_param_index, b.vararg_param_index) {
+            (Some(_), None) => return false,
+            (None, Some(_)) => return true,
+            _ => {}
+        }
+
+        let fixed_count = a
+            .vararg_param_index
+            .unwrap_or(a.param_julia_types.len())
+            .min(b.vararg_param_index.unwrap_or(b.param_julia_types.len()));
+
+        a.param_julia_types
+            .iter()
+            .take(fixed_count)
+            .zip(b.param_julia_types.iter().take(fixed_count))
+            .all(|(a_ty, b_ty)| a_ty.is_subtype_of_parametric(b_ty, &a.type_params))
+    }
+
+    /// Resolve the single best-matching method for a call, raising a `MethodError` when the
+    /// applicable methods are ambiguous (Issue chunk433-2). Returns `Ok(None)` when no method
+    /// applies at all, matching `find_matching_methods`'s own "no match" convention.
+    ///
+    /// A candidate is ambiguous with another unless one strictly dominates it via
+    /// `method_is_more_specific`; the surviving "frontier" of non-dominated candidates must
+    /// have exactly one member. A third method that is strictly more specific than two
+    /// otherwise-incomparable candidates dominates both of them, so it alone remains on the
+    /// frontier and the ambiguity is resolved rather than reported.
+    pub(in crate::vm) fn resolve_best_method(
+        &mut self,
+        func_name: &str,
+        arg_types: &[JuliaType],
+    ) -> Result<Option<FunctionInfo>, VmError> {
+        let candidates = match self.find_matching_methods(func_name, Some(arg_types)) {
+            Some(c) if !c.is_empty() => c,
+            _ => return Ok(None),
+        };
+        if candidates.len() == 1 {
+            return Ok(Some(candidates.into_iter().next().unwrap()));
+        }
+
+        let frontier: Vec<&FunctionInfo> = candidates
+            .iter()
+            .filter(|x| {
+                !candidates.iter().any(|y| {
+                    !std::ptr::eq(*x, y)
+                        && self.method_is_more_specific(y, x)
+                        && !self.method_is_more_specific(x, y)
+                })
+            })
+            .collect();
+
+        if frontier.len() == 1 {
+            return Ok(Some(frontier[0].clone()));
+        }
+
+        let signature = |info: &FunctionInfo| {
+            let params = info
+                .param_julia_types
+                .iter()
+                .map(|ty| ty.name().into_owned())
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{}({})", func_name, params)
+        };
+        Err(VmError::MethodError(format!(
+            "{} vs {} is ambiguous",
+            signature(frontier[0]),
+            signature(frontier[1]),
+        )))
+    }
+
+    /// Resolve the function-table index of the best-matching user-defined method for
+    /// `func_name` against `arg_types` (e.g. `getproperty`/`setproperty!` overrides consulted
+    /// from the dot-access paths, Issue chunk433-5). Returns `Ok(None)` when no method applies,
+    /// so callers fall back to their direct builtin behavior.
+    ///
+    /// Reuses `find_matching_methods`'s specificity ordering and takes the most specific match;
+    /// unlike `resolve_best_method` this doesn't raise on ambiguity, since the property-access
+    /// call sites only need "is there an override at all", not a statically-checked `which`.
+    pub(in crate::vm) fn resolve_property_method(
+        &mut self,
+        func_name: &str,
+        arg_types: &[JuliaType],
+    ) -> Option<usize> {
+        let best = self
+            .find_matching_methods(func_name, Some(arg_types))?
+            .into_iter()
+            .next()?;
+        self.get_function_indices_by_name(func_name)
+            .iter()
+            .copied()
+            .find(|&idx| self.functions[idx].param_julia_types == best.param_julia_types)
     }
 
     /// Create a Method struct value from FunctionInfo
@@ -865,3 +1389,79 @@ impl<R: RngLike> Vm<R> {
         Ok(Value::Struct(method_struct))
     }
 }
+
+/// A type variable's state within a single method-matching attempt (Issue chunk433-3).
+enum TypeVarBinding {
+    /// Not yet matched against any argument; carries the declared upper bound (if any),
+    /// checked against whatever type binds it first.
+    Unbound { upper_bound: Option<String> },
+    /// Already resolved to a concrete argument type; later occurrences of the same
+    /// variable must agree with it (the diagonal rule for repeated type variables).
+    Bound(JuliaType),
+}
+
+/// Build the initial substitution environment for a method's `where` type parameters, all
+/// starting `Unbound` with their declared upper bounds.
+fn type_var_env(type_params: &[TypeParam]) -> HashMap<String, TypeVarBinding> {
+    type_params
+        .iter()
+        .map(|tp| {
+            (
+                tp.name.clone(),
+                TypeVarBinding::Unbound {
+                    upper_bound: tp.upper_bound.clone(),
+                },
+            )
+        })
+        .collect()
+}
+
+/// Match a single parameter position against a concrete argument type under a shared
+/// substitution environment (Issue chunk433-3): a bare type variable binds to the first
+/// argument type it sees (subject to its declared upper bound) and every later occurrence
+/// of that same variable must match the binding exactly, implementing the diagonal rule for
+/// signatures like `f(x::T, y::T) where T`. Parametric containers (`Vector{T}`, `Matrix{T}`)
+/// recurse into their element position with the same environment.
+fn unify_param(
+    param_ty: &JuliaType,
+    arg_ty: &JuliaType,
+    type_params: &[TypeParam],
+    env: &mut HashMap<String, TypeVarBinding>,
+) -> bool {
+    let var_name = match param_ty {
+        JuliaType::TypeVar(name, _) => Some(name.as_str()),
+        JuliaType::Struct(name) if type_params.iter().any(|tp| &tp.name == name) => {
+            Some(name.as_str())
+        }
+        _ => None,
+    };
+
+    if let Some(var_name) = var_name {
+        return match env.get(var_name) {
+            Some(TypeVarBinding::Bound(existing)) => arg_ty == existing,
+            Some(TypeVarBinding::Unbound { upper_bound }) => {
+                if let Some(bound_name) = upper_bound {
+                    if let Some(bound_ty) = JuliaType::from_name(bound_name) {
+                        if !arg_ty.is_subtype_of(&bound_ty) {
+                            return false;
+                        }
+                    }
+                }
+                env.insert(var_name.to_string(), TypeVarBinding::Bound(arg_ty.clone()));
+                true
+            }
+            None => arg_ty.is_subtype_of_parametric(param_ty, type_params),
+        };
+    }
+
+    // Recurse into parametric containers so their element type shares the same environment.
+    match (param_ty, arg_ty) {
+        (JuliaType::VectorOf(param_elem), JuliaType::VectorOf(arg_elem)) => {
+            unify_param(param_elem, arg_elem, type_params, env)
+        }
+        (JuliaType::MatrixOf(param_elem), JuliaType::MatrixOf(arg_elem)) => {
+            unify_param(param_elem, arg_elem, type_params, env)
+        }
+        _ => arg_ty.is_subtype_of_parametric(param_ty, type_params),
+    }
+}
@@ -25,6 +25,7 @@ pub(super) fn value_type_to_julia_type(
         ValueType::F32 => JuliaType::Float32,
         ValueType::F64 => JuliaType::Float64,
         ValueType::BigFloat => JuliaType::BigFloat,
+        ValueType::Float128 => JuliaType::Float128,
         ValueType::Array | ValueType::ArrayOf(_) => JuliaType::Array,
         ValueType::Range => JuliaType::UnitRange,
         ValueType::Str => JuliaType::String,
@@ -61,6 +62,21 @@ pub(super) fn value_type_to_julia_type(
     }
 }
 
+/// Approximate byte width of a field's declared type, for `fieldoffset`. This VM boxes
+/// structs/arrays/strings behind a pointer-sized reference rather than inlining them, so
+/// non-primitive fields are sized as a pointer, matching the approximation `sizeof` already
+/// makes for struct/array values in `BuiltinId::Sizeof`.
+pub(super) fn value_type_byte_size(vt: &ValueType) -> i64 {
+    match vt {
+        ValueType::I8 | ValueType::U8 | ValueType::Bool => 1,
+        ValueType::I16 | ValueType::U16 | ValueType::F16 => 2,
+        ValueType::I32 | ValueType::U32 | ValueType::F32 | ValueType::Char => 4,
+        ValueType::I64 | ValueType::U64 | ValueType::F64 => 8,
+        ValueType::I128 | ValueType::U128 | ValueType::Float128 => 16,
+        _ => 8, // Pointer size for boxed/reference fields (String, Array, Struct, Any, ...)
+    }
+}
+
 /// Extract function name from a Value.
 pub(super) fn extract_func_name(val: &Value) -> Result<String, VmError> {
     match val {
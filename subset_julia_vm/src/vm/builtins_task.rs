@@ -0,0 +1,77 @@
+//! Task subsystem builtins for the VM.
+//!
+//! Handles `BuiltinId::TaskNew`/`TaskResume`/`IsTaskDone`, the Julia-facing
+//! surface over the `Vm::task_new`/`task_resume`/`task_is_done` primitives
+//! in `vm/exec/task.rs` (Issue chunk426-4).
+
+use crate::builtins::BuiltinId;
+use crate::rng::RngLike;
+
+use super::error::VmError;
+use super::exec::TaskStep;
+use super::stack_ops::StackOps;
+use super::value::Value;
+use super::Vm;
+
+impl<R: RngLike> Vm<R> {
+    pub(super) fn execute_builtin_task(
+        &mut self,
+        builtin: &BuiltinId,
+        argc: usize,
+    ) -> Result<Option<()>, VmError> {
+        match builtin {
+            BuiltinId::TaskNew => {
+                if argc != 1 {
+                    return Err(VmError::TypeError(
+                        "Task requires exactly 1 argument: Task(f)".to_string(),
+                    ));
+                }
+                let entry = self.stack.pop_value()?;
+                let handle = self.task_new(entry);
+                self.stack.push(Value::Task(handle));
+            }
+            BuiltinId::TaskResume => {
+                if argc != 1 {
+                    return Err(VmError::TypeError(
+                        "resume requires exactly 1 argument: resume(t)".to_string(),
+                    ));
+                }
+                let handle = match self.stack.pop_value()? {
+                    Value::Task(handle) => handle,
+                    other => {
+                        return Err(VmError::TypeError(format!(
+                            "resume: expected a Task, got {:?}",
+                            other.value_type()
+                        )))
+                    }
+                };
+                match self.task_resume(handle)? {
+                    TaskStep::Yielded(value) | TaskStep::Done(value) => {
+                        self.stack.push(value);
+                    }
+                    TaskStep::Failed(err) => return Err(err),
+                }
+            }
+            BuiltinId::IsTaskDone => {
+                if argc != 1 {
+                    return Err(VmError::TypeError(
+                        "istaskdone requires exactly 1 argument: istaskdone(t)".to_string(),
+                    ));
+                }
+                let handle = match self.stack.pop_value()? {
+                    Value::Task(handle) => handle,
+                    other => {
+                        return Err(VmError::TypeError(format!(
+                            "istaskdone: expected a Task, got {:?}",
+                            other.value_type()
+                        )))
+                    }
+                };
+                self.stack.push(Value::Bool(self.task_is_done(handle)?));
+            }
+
+            _ => return Ok(None),
+        }
+        Ok(Some(()))
+    }
+}
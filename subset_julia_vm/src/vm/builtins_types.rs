@@ -1114,6 +1114,47 @@ impl<R: crate::rng::RngLike> Vm<R> {
                 self.stack.push(Value::Bool(is_mutable_type));
             }
 
+            BuiltinId::_Isstructtype => {
+                // _isstructtype(T) - internal intrinsic: check if T is a user-defined struct type
+                let type_val = self.stack.pop_value()?;
+                let is_struct_type = match &type_val {
+                    Value::DataType(jt) => {
+                        let type_name = jt.name();
+                        self.struct_defs.iter().any(|def| def.name == type_name.as_ref())
+                    }
+                    _ => false,
+                };
+                self.stack.push(Value::Bool(is_struct_type));
+            }
+
+            BuiltinId::_Isprimitivetype => {
+                // _isprimitivetype(T) - internal intrinsic: check if T is a builtin
+                // primitive type (fixed-width numeric, Bool, or Char)
+                let type_val = self.stack.pop_value()?;
+                let is_primitive = match &type_val {
+                    Value::DataType(jt) => matches!(
+                        jt.name().as_ref(),
+                        "Bool"
+                            | "Int8"
+                            | "Int16"
+                            | "Int32"
+                            | "Int64"
+                            | "Int128"
+                            | "UInt8"
+                            | "UInt16"
+                            | "UInt32"
+                            | "UInt64"
+                            | "UInt128"
+                            | "Float16"
+                            | "Float32"
+                            | "Float64"
+                            | "Char"
+                    ),
+                    _ => false,
+                };
+                self.stack.push(Value::Bool(is_primitive));
+            }
+
             BuiltinId::Ismutable => {
                 // ismutable(x) - check if x is mutable
                 // In Julia: Arrays, Dicts, mutable structs are mutable
@@ -0,0 +1,65 @@
+//! Lazy VaList builtins for the VM.
+//!
+//! Handles `BuiltinId::VaArg`/`VaCount`, the Julia-facing surface over
+//! `VaListState::next`/`remaining` (Issue chunk427-2).
+
+use crate::builtins::BuiltinId;
+use crate::rng::RngLike;
+
+use super::error::VmError;
+use super::stack_ops::StackOps;
+use super::value::Value;
+use super::Vm;
+
+impl<R: RngLike> Vm<R> {
+    pub(super) fn execute_builtin_va_list(
+        &mut self,
+        builtin: &BuiltinId,
+        argc: usize,
+    ) -> Result<Option<()>, VmError> {
+        match builtin {
+            BuiltinId::VaArg => {
+                if argc != 1 {
+                    return Err(VmError::TypeError(
+                        "va_arg requires exactly 1 argument: va_arg(va)".to_string(),
+                    ));
+                }
+                let va = match self.stack.pop_value()? {
+                    Value::VaList(va) => va,
+                    other => {
+                        return Err(VmError::TypeError(format!(
+                            "va_arg: expected a VaList, got {:?}",
+                            other.value_type()
+                        )))
+                    }
+                };
+                let next = va
+                    .borrow_mut()
+                    .next()
+                    .ok_or_else(|| VmError::ErrorException("va_arg: no more arguments".to_string()))?;
+                self.stack.push(next);
+            }
+            BuiltinId::VaCount => {
+                if argc != 1 {
+                    return Err(VmError::TypeError(
+                        "va_count requires exactly 1 argument: va_count(va)".to_string(),
+                    ));
+                }
+                let va = match self.stack.pop_value()? {
+                    Value::VaList(va) => va,
+                    other => {
+                        return Err(VmError::TypeError(format!(
+                            "va_count: expected a VaList, got {:?}",
+                            other.value_type()
+                        )))
+                    }
+                };
+                let remaining = va.borrow().remaining();
+                self.stack.push(Value::I64(remaining as i64));
+            }
+
+            _ => return Ok(None),
+        }
+        Ok(Some(()))
+    }
+}
@@ -0,0 +1,171 @@
+//! Static call-graph effect inference (Issue chunk427-3).
+//!
+//! Populates [`FunctionInfo::nothrow`] and [`FunctionInfo::norecurse`] by
+//! walking the compiled bytecode once, after compilation and before the VM
+//! starts executing. A function keeps its `false` default unless the
+//! analysis below sets the bit from the static call graph.
+//!
+//! `nothrow` is a fixpoint over the instructions named here: a function
+//! starts as a candidate unless its own body contains an explicit throw
+//! (`ThrowError`/`ThrowValue`) or hands control to code this pass
+//! can't see into (`CallBuiltin`/`CallIntrinsic`, both implemented in Rust
+//! and free to raise `VmError`s the bytecode never names). No other
+//! instruction is examined, even one whose execution can return a
+//! `VmError` (an out-of-bounds index, for instance), so `nothrow` does not
+//! rule out every runtime error. The candidate
+//! set is then shrunk to its fixpoint by propagating `false` along
+//! statically resolved call edges — a function that only calls `nothrow`
+//! functions is itself `nothrow`.
+//!
+//! `norecurse` is a cycle test over the same statically resolved call
+//! graph: a function is `norecurse` iff it isn't part of any cycle
+//! (direct or indirect self-recursion).
+//!
+//! Both bits are forced to `false` for any function containing a
+//! dynamic-dispatch instruction (`CallDynamic*`, `IterateDynamic`,
+//! `CallTypedDispatch`, `CallTypeConstructor`, `CallGlobalRef`,
+//! `CallFunctionVariable*`) since the actual callee isn't known until
+//! runtime, so neither property can be proven.
+
+use super::instr::Instr;
+use super::types::{FunctionInfo, SpecializableFunction};
+
+/// Run the effect analysis over every function in `functions` and write
+/// the results back into their `nothrow`/`norecurse` fields.
+pub(crate) fn analyze_effects(
+    functions: &mut [FunctionInfo],
+    code: &[Instr],
+    specializable_functions: &[SpecializableFunction],
+) {
+    let n = functions.len();
+    let mut edges: Vec<Vec<usize>> = vec![Vec::new(); n];
+    // True once a function is known to contain a call this pass can't
+    // resolve statically; disqualifies both `nothrow` and `norecurse`.
+    let mut unresolved = vec![false; n];
+    // True while nothing in the function's own body has been seen to
+    // raise directly; narrowed to the real fixpoint below.
+    let mut nothrow = vec![true; n];
+
+    for (idx, func) in functions.iter().enumerate() {
+        if func.code_start >= func.code_end || func.code_end > code.len() {
+            continue;
+        }
+        for instr in &code[func.code_start..func.code_end] {
+            match instr {
+                Instr::Call(target, _)
+                | Instr::CallWithKwargs(target, _, _)
+                | Instr::CallWithKwargsSplat(target, _, _, _)
+                | Instr::CallWithSplat(target, _, _) => edges[idx].push(*target),
+                Instr::CallSpecialize(spec_index, _) => {
+                    if let Some(spec) = specializable_functions.get(*spec_index) {
+                        edges[idx].push(spec.fallback_index);
+                    }
+                }
+                Instr::ApplyIterate {
+                    func_index,
+                    iterate_1,
+                    iterate_2,
+                    ..
+                } => {
+                    edges[idx].push(*func_index);
+                    edges[idx].extend(iterate_1.iter().chain(iterate_2.iter()).copied());
+                }
+                Instr::CallDynamic(..)
+                | Instr::CallDynamicBinary(..)
+                | Instr::CallDynamicBinaryBoth(..)
+                | Instr::CallDynamicBinaryNoFallback(..)
+                | Instr::CallDynamicOrBuiltin(..)
+                | Instr::IterateDynamic(..)
+                | Instr::CallTypedDispatch(..)
+                | Instr::CallTypeConstructor
+                | Instr::CallGlobalRef(_)
+                | Instr::CallFunctionVariable(_)
+                | Instr::CallFunctionVariableWithSplat(_, _) => {
+                    unresolved[idx] = true;
+                }
+                Instr::ThrowError | Instr::ThrowValue | Instr::CallBuiltin(_, _) | Instr::CallIntrinsic(_) => {
+                    nothrow[idx] = false;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // Bytecode should only ever reference valid function indices, but the
+    // cycle search indexes `edges` by callee, so drop anything out of
+    // range rather than trusting that invariant all the way into a panic.
+    for callees in &mut edges {
+        callees.retain(|&callee| callee < n);
+    }
+
+    let in_cycle = find_cycle_members(&edges);
+
+    for (idx, u) in unresolved.iter().enumerate() {
+        if *u {
+            nothrow[idx] = false;
+        }
+    }
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for idx in 0..n {
+            if nothrow[idx] && edges[idx].iter().any(|&callee| !nothrow[callee]) {
+                nothrow[idx] = false;
+                changed = true;
+            }
+        }
+    }
+
+    for (idx, func) in functions.iter_mut().enumerate() {
+        func.nothrow = nothrow[idx];
+        func.norecurse = !unresolved[idx] && !in_cycle[idx];
+    }
+}
+
+/// Mark every function that sits on a cycle (direct or indirect
+/// self-recursion) of `edges`, via a plain DFS back-edge test.
+fn find_cycle_members(edges: &[Vec<usize>]) -> Vec<bool> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Color {
+        White,
+        Gray,
+        Black,
+    }
+
+    fn visit(
+        u: usize,
+        edges: &[Vec<usize>],
+        color: &mut [Color],
+        stack: &mut Vec<usize>,
+        in_cycle: &mut [bool],
+    ) {
+        color[u] = Color::Gray;
+        stack.push(u);
+        for &v in &edges[u] {
+            match color[v] {
+                Color::White => visit(v, edges, color, stack, in_cycle),
+                Color::Gray => {
+                    if let Some(pos) = stack.iter().position(|&x| x == v) {
+                        for &w in &stack[pos..] {
+                            in_cycle[w] = true;
+                        }
+                    }
+                }
+                Color::Black => {}
+            }
+        }
+        stack.pop();
+        color[u] = Color::Black;
+    }
+
+    let n = edges.len();
+    let mut color = vec![Color::White; n];
+    let mut in_cycle = vec![false; n];
+    let mut stack = Vec::new();
+    for start in 0..n {
+        if color[start] == Color::White {
+            visit(start, edges, &mut color, &mut stack, &mut in_cycle);
+        }
+    }
+    in_cycle
+}
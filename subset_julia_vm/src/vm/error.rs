@@ -6,6 +6,11 @@ pub enum VmError {
     ErrorException(String), // error("message") - user-thrown exception
     AssertionFailed(String),
     Cancelled,
+    /// Execution was stopped at a cooperative safepoint: either the operation
+    /// budget was exhausted or the progress callback returned `false`
+    /// (Issue chunk421-3). Recoverable, unlike a panic - callers can catch it
+    /// and decide whether to resume with a fresh budget.
+    Interrupted,
     DivisionByZero,
     StackOverflow,
     StackUnderflow,
@@ -27,6 +32,10 @@ pub enum VmError {
         a_shape: Vec<usize>,
         b_shape: Vec<usize>,
     },
+    BroadcastDestShapeMismatch {
+        expected: Vec<usize>,
+        got: Vec<usize>,
+    },
     EmptyArrayPop,
     // Range errors
     RangeIndexOutOfBounds {
@@ -42,6 +51,10 @@ pub enum VmError {
     FieldIndexOutOfBounds {
         index: usize,
         field_count: usize,
+        /// The struct's declared field names, for an actionable "available fields: ..."
+        /// hint (Issue chunk433-4). Empty when the field list wasn't available at the
+        /// point the error was raised (e.g. the struct definition couldn't be resolved).
+        field_names: Vec<String>,
     },
     ImmutableFieldAssign(String), // Attempt to modify immutable struct field
     NotImplemented(String),       // Instruction not yet implemented
@@ -75,12 +88,26 @@ pub enum VmError {
         index: i64,
         valid_indices: (i64, i64), // (prev_valid, next_valid) or (-1, -1) if out of bounds
     },
+    // Native (ccall-style) host function errors
+    NativeCallError(String), // unregistered name, arity mismatch, or a panic caught at the boundary
+    /// The instruction/fuel budget set via `Vm::set_fuel` ran out (chunk426-1).
+    /// Unlike `Interrupted`, this is checked on every instruction and call,
+    /// not just at cooperative safepoints - a caller can top up fuel with
+    /// `set_fuel` and resume execution from the saved `ip`/frames.
+    FuelExhausted,
 }
 
 impl VmError {
     /// Create a TypeError for "{instruction}: expected {expected}, got {value}" patterns (Issue #2927).
-    pub fn type_error_expected(instruction: &str, expected: &str, got: &impl std::fmt::Debug) -> Self {
-        Self::TypeError(format!("{}: expected {}, got {:?}", instruction, expected, got))
+    pub fn type_error_expected(
+        instruction: &str,
+        expected: &str,
+        got: &impl std::fmt::Debug,
+    ) -> Self {
+        Self::TypeError(format!(
+            "{}: expected {}, got {:?}",
+            instruction, expected, got
+        ))
     }
 
     /// Create a MethodError for "no method matching operator({type1}, {type2})" patterns (Issue #2927).
@@ -93,10 +120,7 @@ impl VmError {
 
     /// Create a MethodError for "unsupported {type_combo} operation: {op}" patterns (Issue #2927).
     pub fn unsupported_op(type_combo: &str, op: &impl std::fmt::Debug) -> Self {
-        Self::MethodError(format!(
-            "unsupported {} operation: {:?}",
-            type_combo, op
-        ))
+        Self::MethodError(format!("unsupported {} operation: {:?}", type_combo, op))
     }
 }
 
@@ -106,6 +130,7 @@ impl std::fmt::Display for VmError {
             Self::ErrorException(msg) => write!(f, "ErrorException: {}", msg),
             Self::AssertionFailed(msg) => write!(f, "AssertionError: {}", msg),
             Self::Cancelled => write!(f, "Execution cancelled"),
+            Self::Interrupted => write!(f, "Execution interrupted at safepoint"),
             Self::DivisionByZero => write!(f, "Division by zero"),
             Self::StackOverflow => write!(f, "Stack overflow"),
             Self::StackUnderflow => write!(f, "Stack underflow"),
@@ -138,6 +163,13 @@ impl std::fmt::Display for VmError {
                     a_shape, b_shape
                 )
             }
+            Self::BroadcastDestShapeMismatch { expected, got } => {
+                write!(
+                    f,
+                    "Broadcast destination shape mismatch: expected {:?}, got {:?}",
+                    expected, got
+                )
+            }
             Self::EmptyArrayPop => write!(f, "Cannot pop from empty array"),
             // Range errors
             Self::RangeIndexOutOfBounds { index, length } => {
@@ -153,12 +185,20 @@ impl std::fmt::Display for VmError {
             Self::DomainError(msg) => write!(f, "Domain error: {}", msg),
             Self::OverflowError(msg) => write!(f, "OverflowError: {}", msg),
             Self::UnknownBroadcastOp(op) => write!(f, "Unknown broadcast operation: {}", op),
-            Self::FieldIndexOutOfBounds { index, field_count } => {
+            Self::FieldIndexOutOfBounds {
+                index,
+                field_count,
+                field_names,
+            } => {
                 write!(
                     f,
                     "Field index {} out of bounds for struct with {} fields",
                     index, field_count
-                )
+                )?;
+                if !field_names.is_empty() {
+                    write!(f, "; available fields: {}", field_names.join(", "))?;
+                }
+                Ok(())
             }
             Self::ImmutableFieldAssign(name) => {
                 write!(f, "Cannot modify field of immutable struct: {}", name)
@@ -224,6 +264,8 @@ impl std::fmt::Display for VmError {
                     )
                 }
             }
+            Self::NativeCallError(msg) => write!(f, "NativeCallError: {}", msg),
+            Self::FuelExhausted => write!(f, "Execution stopped: fuel budget exhausted"),
         }
     }
 }
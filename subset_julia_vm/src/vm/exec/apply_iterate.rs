@@ -0,0 +1,126 @@
+//! `ApplyIterate`: call a function after expanding a splatted argument via
+//! the iteration protocol, so that custom struct iterators (not just Array/
+//! Tuple/Range) splat correctly (Issue chunk422-1).
+//!
+//! Backs two compiler call sites: lowering `f(a, xs..., b)` when `xs`'s
+//! static type needs Pure-Julia `iterate`, and the internal
+//! `apply_iterate(iterate_fn, f, args...)` form that lets instrumentation
+//! substitute its own iteration method.
+
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+
+use crate::rng::RngLike;
+
+use super::super::error::VmError;
+use super::super::instr::Instr;
+use super::super::stack_ops::StackOps;
+use super::super::value::Value;
+use super::super::Vm;
+
+/// Result of executing an `ApplyIterate` instruction.
+pub(super) enum ApplyIterateResult {
+    /// Instruction not handled by this module.
+    NotHandled,
+    /// Handled; the call's result has been pushed onto the stack.
+    Handled,
+}
+
+impl<R: RngLike> Vm<R> {
+    /// Execute `ApplyIterate`.
+    #[inline]
+    pub(super) fn execute_apply_iterate(
+        &mut self,
+        instr: &Instr,
+    ) -> Result<ApplyIterateResult, VmError> {
+        match instr {
+            Instr::ApplyIterate {
+                func_index,
+                arg_count,
+                splat_mask,
+                iterate_1,
+                iterate_2,
+            } => {
+                let mut raw_args = Vec::with_capacity(*arg_count);
+                for _ in 0..*arg_count {
+                    raw_args.push(self.stack.pop_value()?);
+                }
+                raw_args.reverse();
+                let mut expanded = Vec::with_capacity(raw_args.len());
+                for (idx, arg) in raw_args.into_iter().enumerate() {
+                    if splat_mask.get(idx).copied().unwrap_or(false) {
+                        self.expand_one_splat_arg(arg, *iterate_1, *iterate_2, &mut expanded)?;
+                    } else {
+                        expanded.push(arg);
+                    }
+                }
+                let result =
+                    self.call_function_index_to_completion(*func_index, Vec::new(), expanded)?;
+                self.stack.push(result);
+                Ok(ApplyIterateResult::Handled)
+            }
+            _ => Ok(ApplyIterateResult::NotHandled),
+        }
+    }
+
+    /// Drive the iteration protocol on `arg` to completion, pushing every
+    /// yielded element onto `out`. Array/Tuple/Range/String go through the
+    /// builtin `iterate_first`/`iterate_next`; any other value (a custom
+    /// struct iterator) calls `iterate_1`/`iterate_2` when given, matching
+    /// `should_use_pure_julia_iterate`'s split between builtin and Pure
+    /// Julia iteration.
+    fn expand_one_splat_arg(
+        &mut self,
+        arg: Value,
+        iterate_1: Option<usize>,
+        iterate_2: Option<usize>,
+        out: &mut Vec<Value>,
+    ) -> Result<(), VmError> {
+        let use_pure_julia = matches!(arg, Value::Struct(_) | Value::StructRef(_));
+        let mut next = if use_pure_julia {
+            self.call_iterate(iterate_1, &arg, None)?
+        } else {
+            self.iterate_first(&arg)?
+        };
+        while let Value::Tuple(pair) = next {
+            let mut iter = pair.elements.into_iter();
+            let element = iter
+                .next()
+                .ok_or_else(|| VmError::TypeError("iterate must return a 2-tuple".to_string()))?;
+            let state = iter
+                .next()
+                .ok_or_else(|| VmError::TypeError("iterate must return a 2-tuple".to_string()))?;
+            out.push(element);
+            next = if use_pure_julia {
+                self.call_iterate(iterate_2, &arg, Some(state))?
+            } else {
+                self.iterate_next(&arg, &state)?
+            };
+        }
+        Ok(())
+    }
+
+    /// Call `iterate(iterable)` (when `state` is `None`) or
+    /// `iterate(iterable, state)` through the statically-resolved global
+    /// method `global_index`, if the compiler found one; otherwise fall back
+    /// to the builtin protocol.
+    fn call_iterate(
+        &mut self,
+        global_index: Option<usize>,
+        iterable: &Value,
+        state: Option<Value>,
+    ) -> Result<Value, VmError> {
+        match (global_index, state) {
+            (Some(idx), None) => {
+                self.call_function_index_to_completion(idx, Vec::new(), vec![iterable.clone()])
+            }
+            (Some(idx), Some(state)) => self.call_function_index_to_completion(
+                idx,
+                Vec::new(),
+                vec![iterable.clone(), state],
+            ),
+            (None, None) => self.iterate_first(iterable),
+            (None, Some(state)) => self.iterate_next(iterable, &state),
+        }
+    }
+}
@@ -371,6 +371,30 @@ impl<R: RngLike> Vm<R> {
                 self.stack.push(Value::F64(x.ceil()));
                 Ok(ArithmeticResult::Handled)
             }
+
+            // === Float128 math functions ===
+            Instr::SqrtF128 => {
+                let x = self.pop_f128()?;
+                if x.sign && !x.is_zero() {
+                    self.raise(VmError::DomainError(format!(
+                        "sqrt was called with a negative real argument ({}) but will only return a complex result if called with a complex argument. Try sqrt(Complex(x)).",
+                        x.to_f64()
+                    )))?;
+                    return Ok(ArithmeticResult::Continue);
+                }
+                self.stack.push(Value::F128(x.sqrt()));
+                Ok(ArithmeticResult::Handled)
+            }
+            Instr::FloorF128 => {
+                let x = self.pop_f128()?;
+                self.stack.push(Value::F128(x.floor()));
+                Ok(ArithmeticResult::Handled)
+            }
+            Instr::CeilF128 => {
+                let x = self.pop_f128()?;
+                self.stack.push(Value::F128(x.ceil()));
+                Ok(ArithmeticResult::Handled)
+            }
             Instr::AbsF64 => {
                 let x = self.pop_f64_or_i64()?;
                 self.stack.push(Value::F64(x.abs()));
@@ -514,6 +514,26 @@ impl<R: RngLike> Vm<R> {
                             // the compiler emits IndexLoad instead of CallBuiltin(DictGet).
                             // Handle Dict lookup at runtime.
                             let target = self.stack.pop_value()?;
+                            if let Value::RegexMatch(m) = &target {
+                                // m[:name] / m["name"]: named capture-group access
+                                let name = match &other {
+                                    Value::Symbol(s) => s.as_str(),
+                                    Value::Str(s) => s.as_str(),
+                                    _ => {
+                                        // User-visible: user can index a RegexMatch with an unsupported key type
+                                        return Err(VmError::TypeError(format!(
+                                            "expected I64, Symbol, or String, got {:?}",
+                                            util::value_type_name(&other)
+                                        )));
+                                    }
+                                };
+                                let result = m
+                                    .get_named(name)
+                                    .map(|s| Value::Str(s.to_string()))
+                                    .unwrap_or(Value::Nothing);
+                                self.stack.push(result);
+                                return Ok(ArrayIndexResult::Handled);
+                            }
                             if let Value::Dict(dict) = &target {
                                 let key = DictKey::from_value(&other).map_err(|_| {
                                     VmError::TypeError(format!(
@@ -693,6 +713,28 @@ impl<R: RngLike> Vm<R> {
                         let element = named.values[(idx - 1) as usize].clone();
                         self.stack.push(element);
                     }
+                    Value::RegexMatch(m) => {
+                        // m[i]: numbered capture-group access, 1-indexed into m.captures
+                        // (Julia's getindex(m::RegexMatch, i::Integer) = m.captures[i]).
+                        if indices.len() != 1 {
+                            // User-visible: user can attempt multi-dimensional indexing on a RegexMatch
+                            return Err(VmError::TypeError(
+                                "RegexMatch indexing requires exactly one index".to_string(),
+                            ));
+                        }
+                        let idx = indices[0];
+                        if idx < 1 || idx > m.captures.len() as i64 {
+                            self.raise(VmError::IndexOutOfBounds {
+                                indices: vec![idx],
+                                shape: vec![m.captures.len()],
+                            })?;
+                            return Ok(ArrayIndexResult::Continue);
+                        }
+                        match &m.captures[(idx - 1) as usize] {
+                            Some(s) => self.stack.push(Value::Str(s.clone())),
+                            None => self.stack.push(Value::Nothing),
+                        }
+                    }
                     Value::Range(range) => {
                         // Range indexing: r[i] where i is 1-indexed
                         if indices.len() != 1 {
@@ -14,11 +14,19 @@
 #![deny(clippy::expect_used)]
 
 use super::super::*;
+use super::super::value::VaListState;
+use super::jit;
 use super::slot::slotize_code;
 use super::util::bind_value_to_slot;
 use crate::rng::RngLike;
 use std::collections::HashMap;
 
+/// Minimum field count for a struct return type to be worth a caller-
+/// reserved return slot (Issue chunk427-4): below this, the copy
+/// `ReturnStruct` would otherwise do is cheap enough that pre-allocating a
+/// `struct_heap` slot up front isn't worth the extra bookkeeping.
+const SRET_FIELD_THRESHOLD: usize = 4;
+
 /// Bind all keyword arguments to their defaults (no kwargs provided at call site).
 ///
 /// Used by: `Call`, `CallWithSplat`
@@ -116,8 +124,31 @@ impl<R: RngLike> Vm<R> {
     /// Returns `CallResult::NotHandled` if the instruction is not a call operation.
     #[inline]
     pub(super) fn execute_call(&mut self, instr: &Instr) -> Result<CallResult, VmError> {
+        // Calls cost fuel on top of the per-instruction charge already taken
+        // by the dispatch loop in `run_loop` (Issue chunk426-1), so a deep
+        // recursion burns through a budget faster than a flat loop would.
+        self.consume_fuel()?;
+        // A relaxed load is cheap relative to the call itself, so every call
+        // boundary is a deterministic point where a host-set interrupt flag
+        // (Issue chunk426-3) is guaranteed to be observed.
+        if !self.check_interrupt()? {
+            return Ok(CallResult::Continue);
+        }
         match instr {
             Instr::Call(func_index, arg_count) => {
+                // `check_call_depth` exists to catch unbounded recursion; a
+                // callee that's both `nothrow` and `norecurse` with no
+                // kwparams/varargs (Issue chunk427-3) has no explicit throw,
+                // builtin or intrinsic call (see `vm/effects.rs`) and cannot
+                // reach back into itself, so the check is skipped for this call. Peeked
+                // directly off `self.functions` (no clone) so non-qualifying
+                // calls pay nothing extra.
+                let skips_depth_check = self.functions.get(*func_index).is_some_and(|f| {
+                    f.nothrow && f.norecurse && f.kwparams.is_empty() && f.vararg_param_index.is_none()
+                });
+                if !skips_depth_check && !self.check_call_depth()? {
+                    return Ok(CallResult::Continue);
+                }
                 let func = match self.get_function_cloned_or_raise(*func_index)? {
                     Some(f) => f,
                     None => return Ok(CallResult::Continue),
@@ -150,9 +181,12 @@ impl<R: RngLike> Vm<R> {
                     }
                     // Collect remaining args into a Tuple
                     let vararg_values: Vec<Value> = args[vararg_idx..].to_vec();
-                    let vararg_tuple = Value::Tuple(TupleValue {
-                        elements: vararg_values,
-                    });
+                    // Lazy VaList instead of eager Tuple materialization
+                    // (Issue chunk427-2): avoids allocating a copy when the
+                    // callee only walks varargs once via `va_arg` or
+                    // forwards them untouched; the iteration protocol
+                    // materializes a real Tuple on first actual use.
+                    let vararg_tuple = Value::VaList(VaListState::shared(vararg_values));
                     if let Some(slot) = func.param_slots.get(vararg_idx) {
                         bind_value_to_slot(&mut frame, *slot, vararg_tuple, &mut self.struct_heap);
                     }
@@ -182,6 +216,9 @@ impl<R: RngLike> Vm<R> {
             }
 
             Instr::CallWithKwargs(func_index, pos_arg_count, ref kwarg_names) => {
+                if !self.check_call_depth()? {
+                    return Ok(CallResult::Continue);
+                }
                 let func = match self.get_function_cloned_or_raise(*func_index)? {
                     Some(f) => f,
                     None => return Ok(CallResult::Continue),
@@ -229,9 +266,12 @@ impl<R: RngLike> Vm<R> {
                     }
                     // Collect remaining args into a Tuple
                     let vararg_values: Vec<Value> = pos_args[vararg_idx..].to_vec();
-                    let vararg_tuple = Value::Tuple(TupleValue {
-                        elements: vararg_values,
-                    });
+                    // Lazy VaList instead of eager Tuple materialization
+                    // (Issue chunk427-2): avoids allocating a copy when the
+                    // callee only walks varargs once via `va_arg` or
+                    // forwards them untouched; the iteration protocol
+                    // materializes a real Tuple on first actual use.
+                    let vararg_tuple = Value::VaList(VaListState::shared(vararg_values));
                     if let Some(slot) = func.param_slots.get(vararg_idx) {
                         bind_value_to_slot(&mut frame, *slot, vararg_tuple, &mut self.struct_heap);
                     }
@@ -266,6 +306,9 @@ impl<R: RngLike> Vm<R> {
                 ref kwarg_names,
                 ref kwargs_splat_mask,
             ) => {
+                if !self.check_call_depth()? {
+                    return Ok(CallResult::Continue);
+                }
                 let func = match self.get_function_cloned_or_raise(*func_index)? {
                     Some(f) => f,
                     None => return Ok(CallResult::Continue),
@@ -363,9 +406,12 @@ impl<R: RngLike> Vm<R> {
                     }
                     // Collect remaining args into a Tuple
                     let vararg_values: Vec<Value> = pos_args[vararg_idx..].to_vec();
-                    let vararg_tuple = Value::Tuple(TupleValue {
-                        elements: vararg_values,
-                    });
+                    // Lazy VaList instead of eager Tuple materialization
+                    // (Issue chunk427-2): avoids allocating a copy when the
+                    // callee only walks varargs once via `va_arg` or
+                    // forwards them untouched; the iteration protocol
+                    // materializes a real Tuple on first actual use.
+                    let vararg_tuple = Value::VaList(VaListState::shared(vararg_values));
                     if let Some(slot) = func.param_slots.get(vararg_idx) {
                         bind_value_to_slot(&mut frame, *slot, vararg_tuple, &mut self.struct_heap);
                     }
@@ -395,6 +441,9 @@ impl<R: RngLike> Vm<R> {
             }
 
             Instr::CallWithSplat(func_index, arg_count, ref splat_mask) => {
+                if !self.check_call_depth()? {
+                    return Ok(CallResult::Continue);
+                }
                 let func = match self.get_function_cloned_or_raise(*func_index)? {
                     Some(f) => f,
                     None => return Ok(CallResult::Continue),
@@ -429,9 +478,12 @@ impl<R: RngLike> Vm<R> {
                     }
                     // Collect remaining expanded args into a Tuple
                     let vararg_values: Vec<Value> = expanded_args[vararg_idx..].to_vec();
-                    let vararg_tuple = Value::Tuple(TupleValue {
-                        elements: vararg_values,
-                    });
+                    // Lazy VaList instead of eager Tuple materialization
+                    // (Issue chunk427-2): avoids allocating a copy when the
+                    // callee only walks varargs once via `va_arg` or
+                    // forwards them untouched; the iteration protocol
+                    // materializes a real Tuple on first actual use.
+                    let vararg_tuple = Value::VaList(VaListState::shared(vararg_values));
                     if let Some(slot) = func.param_slots.get(vararg_idx) {
                         bind_value_to_slot(&mut frame, *slot, vararg_tuple, &mut self.struct_heap);
                     }
@@ -462,6 +514,9 @@ impl<R: RngLike> Vm<R> {
 
             // Lazy AoT call: specialize function based on runtime argument types
             Instr::CallSpecialize(spec_func_index, arg_count) => {
+                if !self.check_call_depth()? {
+                    return Ok(CallResult::Continue);
+                }
                 // 1. Pop arguments from stack
                 let mut args = Vec::with_capacity(*arg_count);
                 for _ in 0..*arg_count {
@@ -473,6 +528,16 @@ impl<R: RngLike> Vm<R> {
                 let spec_func = match self.specializable_functions.get(*spec_func_index) {
                     Some(f) => f.clone(),
                     None => {
+                        // Host extensibility seam (Issue chunk426-5): give an
+                        // embedder a chance to resolve this call - dynamic
+                        // dispatch, an FFI shim, lazy definition loading -
+                        // before raising the usual unresolved-call error.
+                        if let Some(value) =
+                            self.try_unresolved_call_handler(*spec_func_index, &args)
+                        {
+                            self.stack.push(value);
+                            return Ok(CallResult::Handled);
+                        }
                         self.raise(VmError::InternalError(format!(
                             "unknown specializable function index: {}",
                             spec_func_index
@@ -496,7 +561,61 @@ impl<R: RngLike> Vm<R> {
                     arg_types: arg_types.clone(),
                 };
 
-                let entry = if let Some(cached) = self.specialization_cache.get(&key) {
+                // World-age check (Issue chunk427-5): a cache entry
+                // compiled under an older generation may have baked in
+                // assumptions a method-table mutation since invalidated,
+                // so discard it here rather than reusing it - the miss
+                // path below recompiles against the method table as it
+                // stands now.
+                let current_generation = self.specialization_generation;
+                if self
+                    .specialization_cache
+                    .get(&key)
+                    .is_some_and(|cached| cached.generation != current_generation)
+                {
+                    self.specialization_cache.remove(&key);
+                }
+
+                let entry = if let Some(cached) = self.specialization_cache.get_mut(&key) {
+                    // Native JIT tier (Issue chunk427-1): count calls
+                    // through this specialization and, once it's hot
+                    // enough, try to compile its bytecode range straight
+                    // to machine code.
+                    cached.call_count += 1;
+                    if cached.native.is_none() && cached.call_count >= jit::JIT_CALL_THRESHOLD {
+                        let candidate = self.code[cached.entry..cached.entry + cached.code_len]
+                            .to_vec();
+                        if let Some(native_jit) = self.specialization_jit.as_mut() {
+                            if let Some(native) =
+                                native_jit.try_compile(&candidate, &fallback_func.param_slots)
+                            {
+                                cached.native = Some(native);
+                            }
+                        }
+                    }
+
+                    // The monomorphic `key` already guarantees the actual
+                    // argument types match this specialization; the
+                    // native ABI additionally requires every argument and
+                    // the return value to be unboxed `i64`, since `Value`
+                    // itself can't cross the native call boundary.
+                    if let Some(native) = cached.native {
+                        if cached.return_type == ValueType::I64
+                            && arg_types.iter().all(|t| *t == ValueType::I64)
+                        {
+                            let raw_args: Vec<i64> = args
+                                .iter()
+                                .map(|v| match v {
+                                    Value::I64(n) => *n,
+                                    _ => unreachable!("arg_types all I64"),
+                                })
+                                .collect();
+                            let result = native(raw_args.as_ptr(), raw_args.len());
+                            self.stack.push(Value::I64(result));
+                            return Ok(CallResult::Handled);
+                        }
+                    }
+
                     Some(cached.entry)
                 } else {
                     // 4. Cache miss: try to specialize now
@@ -534,6 +653,9 @@ impl<R: RngLike> Vm<R> {
                                         entry: entry_point,
                                         return_type: result.return_type,
                                         code_len: self.code.len() - entry_point,
+                                        call_count: 0,
+                                        native: None,
+                                        generation: current_generation,
                                     },
                                 );
                                 Some(entry_point)
@@ -554,6 +676,36 @@ impl<R: RngLike> Vm<R> {
                     fallback_func.local_slot_count,
                     Some(spec_func.fallback_index),
                 );
+                // Caller-provided return slot for large struct returns
+                // (Issue chunk427-4): pre-allocate the destination in
+                // `struct_heap` before the callee runs, so `ReturnSlot`
+                // inside it can target the final handle directly and
+                // `ReturnStruct`'s epilogue only has to move the finished
+                // value into it once, rather than the caller doing a
+                // separate copy afterwards.
+                let specialized_return_type = self
+                    .specialization_cache
+                    .get(&key)
+                    .map(|c| c.return_type.clone())
+                    .unwrap_or_else(|| fallback_func.return_type.clone());
+                if let ValueType::Struct(type_id) = specialized_return_type {
+                    if self
+                        .struct_defs
+                        .get(type_id)
+                        .is_some_and(|def| def.fields.len() > SRET_FIELD_THRESHOLD)
+                    {
+                        let struct_name = self.struct_defs[type_id].name.clone();
+                        let field_count = self.struct_defs[type_id].fields.len();
+                        let placeholder = StructInstance::with_name(
+                            type_id,
+                            struct_name,
+                            vec![Value::Nothing; field_count],
+                        );
+                        let slot_idx = self.struct_heap.len();
+                        self.struct_heap.push(placeholder);
+                        frame.return_slot = Some(slot_idx);
+                    }
+                }
                 let target_entry = if let Some(specialized_entry) = entry {
                     specialized_entry
                 } else {
@@ -577,9 +729,12 @@ impl<R: RngLike> Vm<R> {
                     }
                     // Collect remaining args into a Tuple
                     let vararg_values: Vec<Value> = args[vararg_idx..].to_vec();
-                    let vararg_tuple = Value::Tuple(TupleValue {
-                        elements: vararg_values,
-                    });
+                    // Lazy VaList instead of eager Tuple materialization
+                    // (Issue chunk427-2): avoids allocating a copy when the
+                    // callee only walks varargs once via `va_arg` or
+                    // forwards them untouched; the iteration protocol
+                    // materializes a real Tuple on first actual use.
+                    let vararg_tuple = Value::VaList(VaListState::shared(vararg_values));
                     if let Some(slot) = fallback_func.param_slots.get(vararg_idx) {
                         bind_value_to_slot(&mut frame, *slot, vararg_tuple, &mut self.struct_heap);
                     }
@@ -324,6 +324,7 @@ impl<R: RngLike> Vm<R> {
                 // 1. First try exact match (all concrete types)
                 // 2. Then try pattern match with TypeVars, preferring more specific patterns
                 let mut best_match: Option<(usize, i32)> = None;
+                let mut matched: Vec<(usize, i32)> = Vec::new();
                 for (idx, expected_types) in candidates.iter() {
                     // Value::Dict (Rust-backed) must not match parametric Dict{K,V}
                     // Pure Julia methods that expect StructRef (Issue #2748).
@@ -336,6 +337,7 @@ impl<R: RngLike> Vm<R> {
                     }
                     if pattern_matches(expected_types, &arg_type_names) {
                         let specificity = pattern_specificity(expected_types);
+                        matched.push((*idx, specificity));
                         match &best_match {
                             None => best_match = Some((*idx, specificity)),
                             Some((_, best_specificity)) if specificity > *best_specificity => {
@@ -346,6 +348,49 @@ impl<R: RngLike> Vm<R> {
                     }
                 }
 
+                // Raise the same MethodError `which`/`code_lowered` already give for a
+                // genuinely ambiguous call, instead of silently keeping whichever candidate
+                // happened to tie for the top specificity score first (Issue chunk433-2
+                // follow-up). The frontier is built from THIS call site's own `candidates`
+                // (the same ones `best_match` above was chosen from), not the VM's global
+                // method table, which can reflect a disjoint candidate set and either raise a
+                // spurious ambiguity error or miss a real one here.
+                if let Some((_, top_specificity)) = best_match {
+                    let tied: Vec<usize> = matched
+                        .iter()
+                        .filter(|(_, specificity)| *specificity == top_specificity)
+                        .map(|(idx, _)| *idx)
+                        .collect();
+                    let frontier: Vec<usize> = tied
+                        .iter()
+                        .filter(|&&x_idx| {
+                            let x = &self.functions[x_idx];
+                            !tied.iter().any(|&y_idx| {
+                                y_idx != x_idx
+                                    && self.method_is_more_specific(&self.functions[y_idx], x)
+                                    && !self.method_is_more_specific(x, &self.functions[y_idx])
+                            })
+                        })
+                        .copied()
+                        .collect();
+                    if frontier.len() > 1 {
+                        let signature = |idx: usize| {
+                            let params = self.functions[idx]
+                                .param_julia_types
+                                .iter()
+                                .map(|ty| ty.name().into_owned())
+                                .collect::<Vec<_>>()
+                                .join(", ");
+                            format!("{}({})", _func_name, params)
+                        };
+                        return Err(VmError::MethodError(format!(
+                            "{} vs {} is ambiguous",
+                            signature(frontier[0]),
+                            signature(frontier[1]),
+                        )));
+                    }
+                }
+
                 // Covariant bound fallback: if no match via static matching,
                 // try VM-level subtype check for user-defined abstract types (Issue #2526).
                 // This handles Type{<:Animal} where Animal is user-defined.
@@ -765,6 +765,7 @@ impl<R: RngLike> Vm<R> {
 
         // Multiple candidates: find best matching method
         let mut best_match: Option<(usize, u32)> = None;
+        let mut matched: Vec<(usize, &FunctionInfo, u32)> = Vec::new();
 
         for (idx, func) in candidates {
             // Check arity
@@ -809,6 +810,7 @@ impl<R: RngLike> Vm<R> {
             }
 
             if all_match {
+                matched.push((*idx, func, specificity));
                 match &best_match {
                     None => best_match = Some((*idx, specificity)),
                     Some((_, best_spec)) if specificity > *best_spec => {
@@ -819,6 +821,45 @@ impl<R: RngLike> Vm<R> {
             }
         }
 
+        // Raise the same MethodError `which`/`code_lowered` already give for a genuinely
+        // ambiguous call, instead of silently keeping whichever candidate happened to tie
+        // for the top specificity score first (Issue chunk433-2 follow-up: reuses
+        // resolve_best_method's Pareto-frontier dominance check, `method_is_more_specific`).
+        if let Some((_, top_spec)) = best_match {
+            let tied: Vec<&FunctionInfo> = matched
+                .iter()
+                .filter(|(_, _, spec)| *spec == top_spec)
+                .map(|(_, info, _)| *info)
+                .collect();
+            let frontier: Vec<&FunctionInfo> = tied
+                .iter()
+                .filter(|x| {
+                    !tied.iter().any(|y| {
+                        !std::ptr::eq(**x, *y)
+                            && self.method_is_more_specific(y, x)
+                            && !self.method_is_more_specific(x, y)
+                    })
+                })
+                .copied()
+                .collect();
+            if frontier.len() > 1 {
+                let signature = |info: &FunctionInfo| {
+                    let params = info
+                        .param_julia_types
+                        .iter()
+                        .map(|ty| ty.name().into_owned())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    format!("{}({})", func_name, params)
+                };
+                return Err(VmError::MethodError(format!(
+                    "{} vs {} is ambiguous",
+                    signature(frontier[0]),
+                    signature(frontier[1]),
+                )));
+            }
+        }
+
         best_match.map(|(idx, _)| idx).ok_or_else(|| {
             VmError::MethodError(format!(
                 "MethodError: no method matching {}({})",
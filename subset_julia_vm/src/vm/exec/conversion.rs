@@ -616,6 +616,32 @@ impl<R: RngLike> Vm<R> {
                 Ok(Some(()))
             }
 
+            Instr::CheckedToInt { bits, signed } => {
+                let val = self.stack.pop_value()?;
+                let result = match (*bits, *signed) {
+                    (8, true) => self.convert_to_i8(&val).map(Value::I8),
+                    (16, true) => self.convert_to_i16(&val).map(Value::I16),
+                    (32, true) => self.convert_to_i32(&val).map(Value::I32),
+                    (64, true) => self.convert_to_i64(&val).map(Value::I64),
+                    (128, true) => self.convert_to_i128(&val).map(Value::I128),
+                    (8, false) => self.convert_to_u8(&val).map(Value::U8),
+                    (16, false) => self.convert_to_u16(&val).map(Value::U16),
+                    (32, false) => self.convert_to_u32(&val).map(Value::U32),
+                    (64, false) => self.convert_to_u64(&val).map(Value::U64),
+                    (128, false) => self.convert_to_u128(&val).map(Value::U128),
+                    (other_bits, other_signed) => {
+                        // INTERNAL: only compile_builtin_math emits this instruction, and it
+                        // only ever requests one of the widths handled above.
+                        return Err(VmError::InternalError(format!(
+                            "CheckedToInt: unsupported width bits={} signed={}",
+                            other_bits, other_signed
+                        )));
+                    }
+                };
+                self.stack.push(result?);
+                Ok(Some(()))
+            }
+
             // Type checking
             Instr::IsNothing => {
                 let val = self.stack.pop_value()?;
@@ -0,0 +1,76 @@
+//! Resumable producer frames for a cooperative `put!`/`produce`-style yield
+//! (Issue chunk421-4), inspired by wasmi's resumable-execution model.
+//!
+//! Scope: this implements only the low-level suspend/resume primitive the
+//! request specifies - `Instr::Yield`, the `Continuation` snapshot, and the
+//! `Vm::run_resumable`/`Vm::resume` driver pair. It does NOT implement
+//! `Channel` or `@task`; the Julia-visible `Task` handle and its builtins
+//! live in `task.rs`, which saves its own `CallContext` rather than a
+//! `Continuation`. There is no scheduler (buffering, multiple
+//! producers/consumers, blocking `take!`) on top of either.
+
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+
+use crate::rng::RngLike;
+
+use super::super::error::VmError;
+use super::super::frame::{Frame, Handler};
+use super::super::instr::Instr;
+use super::super::stack_ops::StackOps;
+use super::super::value::Value;
+use super::super::Vm;
+
+/// A paused producer, captured at an `Instr::Yield` (Issue chunk421-4).
+///
+/// Holds everything pushed onto the VM's frame/handler/operand stacks since
+/// the `run_resumable`/`resume` call that hit this yield point began, plus
+/// the instruction pointer to continue from. `Vm::resume` puts all of it
+/// back and keeps running from `resume_ip`.
+///
+/// `finally` blocks are never run when building this snapshot - suspension
+/// is not a return or a throw, so none of the producer's `try`/`finally`
+/// state unwinds; it is simply captured as-is in `handlers` and reinstalled
+/// on resume.
+///
+/// Nothing in this crate reads `produced_value` back out yet - a `Channel`/
+/// `Task` scheduler built on top of this primitive is what would consume it
+/// (see the module doc comment) - so it is allowed dead for now.
+#[allow(dead_code)]
+#[derive(Debug, Clone)]
+pub(crate) struct Continuation {
+    /// Instruction pointer to resume execution at.
+    pub resume_ip: usize,
+    /// Call frames pushed since the producer call began (innermost last).
+    pub frames: Vec<Frame>,
+    /// `try`/`catch`/`finally` handlers installed since the producer call began.
+    pub handlers: Vec<Handler>,
+    /// Operand stack values pushed since the producer call began.
+    pub stack: Vec<Value>,
+    /// The value passed to `put!`/`produce` that triggered this suspension.
+    pub produced_value: Value,
+}
+
+/// Result of executing a coroutine instruction.
+pub(super) enum CoroutineResult {
+    /// Instruction not handled by this module
+    NotHandled,
+    /// A `put!`/`produce` call suspended execution; carries the produced
+    /// value. `Vm::run_from_entry` turns this into a full `Continuation`
+    /// relative to the entry point it recorded.
+    Suspend(Value),
+}
+
+impl<R: RngLike> Vm<R> {
+    /// Execute coroutine instructions.
+    #[inline]
+    pub(super) fn execute_coroutine(&mut self, instr: &Instr) -> Result<CoroutineResult, VmError> {
+        match instr {
+            Instr::Yield => {
+                let produced_value = self.stack.pop_value()?;
+                Ok(CoroutineResult::Suspend(produced_value))
+            }
+            _ => Ok(CoroutineResult::NotHandled),
+        }
+    }
+}
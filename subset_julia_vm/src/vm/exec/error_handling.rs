@@ -106,8 +106,8 @@ impl<R: RngLike> Vm<R> {
                     }
                 };
                 if cond {
-                    self.test_pass_count += 1;
-                    let prefix = if let Some(ref ts) = self.current_testset {
+                    self.test_record_pass();
+                    let prefix = if let Some(ts) = self.test_current_name() {
                         format!("  [{}] ", ts)
                     } else {
                         "  ".to_string()
@@ -118,8 +118,8 @@ impl<R: RngLike> Vm<R> {
                         self.emit_output(&format!("{}Test Passed: {}", prefix, msg), true);
                     }
                 } else {
-                    self.test_fail_count += 1;
-                    let prefix = if let Some(ref ts) = self.current_testset {
+                    self.test_record_fail(msg);
+                    let prefix = if let Some(ts) = self.test_current_name() {
                         format!("  [{}] ", ts)
                     } else {
                         "  ".to_string()
@@ -134,29 +134,30 @@ impl<R: RngLike> Vm<R> {
             }
 
             Instr::TestSetBegin(name) => {
-                self.current_testset = Some(name.clone());
-                self.test_pass_count = 0;
-                self.test_fail_count = 0;
-                self.emit_output(&format!("Test Set: {}", name), true);
-                self.emit_output(&"=".repeat(40), true);
+                let indent = "  ".repeat(self.test_stack.len());
+                self.test_push_frame(name.clone());
+                self.emit_output(&format!("{}Test Set: {}", indent, name), true);
+                self.emit_output(&format!("{}{}", indent, "=".repeat(40)), true);
                 Ok(ErrorResult::Handled)
             }
 
             Instr::TestSetEnd => {
-                let total = self.test_pass_count + self.test_fail_count;
-                self.emit_output(&"-".repeat(40), true);
-                self.emit_output(
-                    &format!(
-                        "Results: {} passed, {} failed (total: {})",
-                        self.test_pass_count, self.test_fail_count, total
-                    ),
-                    true,
-                );
-                if self.test_fail_count == 0 {
-                    self.emit_output("All tests passed!", true);
+                if let Some(frame) = self.test_pop_frame() {
+                    let indent = "  ".repeat(self.test_stack.len());
+                    let total = frame.pass + frame.fail;
+                    self.emit_output(&format!("{}{}", indent, "-".repeat(40)), true);
+                    self.emit_output(
+                        &format!(
+                            "{}Results: {} passed, {} failed (total: {})",
+                            indent, frame.pass, frame.fail, total
+                        ),
+                        true,
+                    );
+                    if frame.fail == 0 {
+                        self.emit_output(&format!("{}All tests passed!", indent), true);
+                    }
+                    self.emit_output("", true);
                 }
-                self.emit_output("", true);
-                self.current_testset = None;
                 Ok(ErrorResult::Handled)
             }
 
@@ -171,7 +172,7 @@ impl<R: RngLike> Vm<R> {
                 if let Some((expected_type, was_thrown)) = self.test_throws_state.take() {
                     if was_thrown {
                         // Pass: exception was thrown
-                        self.test_pass_count += 1;
+                        self.test_record_pass();
                         self.emit_output(
                             &format!(
                                 "  Test Passed: @test_throws {} (exception was thrown)",
@@ -181,7 +182,10 @@ impl<R: RngLike> Vm<R> {
                         );
                     } else {
                         // Fail: no exception was thrown
-                        self.test_fail_count += 1;
+                        self.test_record_fail(&format!(
+                            "@test_throws {} (no exception was thrown)",
+                            expected_type
+                        ));
                         self.emit_output(
                             &format!(
                                 "  Test Failed: @test_throws {} (no exception was thrown)",
@@ -0,0 +1,197 @@
+//! Minimal native JIT tier for hot specializations (Issue chunk427-1).
+//!
+//! `call.rs`'s `CallSpecialize` handler normally re-enters the interpreter
+//! loop at a relocated bytecode range cached in `SpecializedCode`. Once a
+//! specialization has been invoked `JIT_CALL_THRESHOLD` times, the call
+//! site asks `SpecializationJit::try_compile` to translate that same
+//! bytecode range directly into Cranelift IR and JIT it to a native
+//! function pointer, stored back on `SpecializedCode::native`. From then
+//! on the call site invokes the pointer directly - no frame push, no
+//! interpreter dispatch loop - instead of jumping into the bytecode tier.
+//!
+//! Only a narrow, provably-sound subset of bytecode is translatable: plain
+//! `i64` arithmetic over parameter slots, ending in `ReturnI64`, with no
+//! control flow, calls, or boxed values anywhere in the range.
+//! `try_compile` bails to `None` the moment it sees anything else, the
+//! same "fall back to a simpler tier" discipline `CallSpecialize` already
+//! applies when lazy specialization itself fails. `Value` is not
+//! FFI-safe (it boxes heap data behind a non-`repr(C)` enum), so the
+//! native ABI here is deliberately all-`i64`: arguments are unboxed
+//! `Value::I64` payloads passed as a pointer + length, and the return
+//! value is a bare `i64` that the caller reboxes as `Value::I64`.
+
+use std::collections::HashMap;
+
+use cranelift_codegen::ir::{types as cl_types, AbiParam, InstBuilder, MemFlags, Signature};
+use cranelift_codegen::isa::CallConv;
+use cranelift_codegen::settings::{self, Configurable};
+use cranelift_codegen::Context;
+use cranelift_frontend::{FunctionBuilder, FunctionBuilderContext};
+use cranelift_jit::{JITBuilder, JITModule};
+use cranelift_module::Module;
+use target_lexicon::Triple;
+
+use super::super::instr::Instr;
+
+/// A JIT-compiled specialization's entry point: a pointer to its unboxed
+/// `i64` arguments, their count, returning an unboxed `i64` result
+/// (Issue chunk427-1).
+pub(crate) type NativeFn = extern "C" fn(*const i64, usize) -> i64;
+
+/// Number of `CallSpecialize` invocations a cached specialization must
+/// accumulate before `try_compile` is attempted against it (Issue
+/// chunk427-1). Low enough to exercise in short-lived test programs, high
+/// enough that one-shot specializations never pay compilation cost.
+pub(crate) const JIT_CALL_THRESHOLD: u64 = 50;
+
+/// Owns the Cranelift JIT module backing every native specialization for
+/// one `Vm` (Issue chunk427-1).
+///
+/// Compiled function pointers stay valid only as long as this module is
+/// alive, so it lives on `Vm` itself for the process lifetime - the same
+/// code-memory-lifetime discipline `aot::codegen::cranelift::CraneliftCodeGenerator`
+/// already follows for the AoT backend.
+pub(crate) struct SpecializationJit {
+    module: JITModule,
+    builder_context: FunctionBuilderContext,
+    ctx: Context,
+    next_id: u32,
+}
+
+impl SpecializationJit {
+    /// Build a JIT module for the host target. Returns `None` if the host
+    /// ISA can't be set up; callers treat that exactly like a failed
+    /// `try_compile` and stay on the interpreter tier.
+    pub(crate) fn new() -> Option<Self> {
+        let mut flag_builder = settings::builder();
+        flag_builder.set("opt_level", "speed").ok()?;
+        let isa_builder = cranelift_codegen::isa::lookup(Triple::host()).ok()?;
+        let isa = isa_builder
+            .finish(settings::Flags::new(flag_builder))
+            .ok()?;
+        let builder = JITBuilder::with_isa(isa, cranelift_module::default_libcall_names());
+        let module = JITModule::new(builder);
+        Some(Self {
+            module,
+            builder_context: FunctionBuilderContext::new(),
+            ctx: Context::new(),
+            next_id: 0,
+        })
+    }
+
+    /// Try to translate `code` (a specialization's relocated bytecode
+    /// range, ending in `ReturnI64`) into a native function taking one
+    /// `i64` per slot in `param_slots`. Returns `None` the moment it sees
+    /// an instruction outside the supported all-`i64`, no-control-flow
+    /// subset (Issue chunk427-1).
+    pub(crate) fn try_compile(
+        &mut self,
+        code: &[Instr],
+        param_slots: &[usize],
+    ) -> Option<NativeFn> {
+        let mut sig = Signature::new(CallConv::SystemV);
+        sig.params.push(AbiParam::new(cl_types::I64)); // args ptr
+        sig.params.push(AbiParam::new(cl_types::I64)); // argc
+        sig.returns.push(AbiParam::new(cl_types::I64));
+
+        let func_id = self.module.declare_anonymous_function(&sig).ok()?;
+        let mut func = cranelift_codegen::ir::Function::with_name_signature(
+            cranelift_codegen::ir::UserFuncName::user(0, self.next_id),
+            sig,
+        );
+        self.next_id += 1;
+
+        let mut reached_return = false;
+        {
+            let mut builder = FunctionBuilder::new(&mut func, &mut self.builder_context);
+            let block = builder.create_block();
+            builder.append_block_params_for_function_params(block);
+            builder.switch_to_block(block);
+            builder.seal_block(block);
+
+            let args_ptr = builder.block_params(block)[0];
+            // Mirrors the bytecode's local slot storage for the subset
+            // below: a param slot is lazily loaded from `args_ptr` the
+            // first time it's read, then tracked here like any other
+            // local once stored to.
+            let mut slots: HashMap<usize, cranelift_codegen::ir::Value> = HashMap::new();
+            let mut stack: Vec<cranelift_codegen::ir::Value> = Vec::new();
+
+            for instr in code {
+                match instr {
+                    Instr::PushI64(n) => {
+                        stack.push(builder.ins().iconst(cl_types::I64, *n));
+                    }
+                    Instr::LoadSlot(idx) => {
+                        let value = if let Some(v) = slots.get(idx) {
+                            *v
+                        } else {
+                            let param_pos = param_slots.iter().position(|s| s == idx)?;
+                            let offset = (param_pos * 8) as i32;
+                            let v =
+                                builder
+                                    .ins()
+                                    .load(cl_types::I64, MemFlags::trusted(), args_ptr, offset);
+                            slots.insert(*idx, v);
+                            v
+                        };
+                        stack.push(value);
+                    }
+                    Instr::StoreSlot(idx) => {
+                        let value = stack.pop()?;
+                        slots.insert(*idx, value);
+                    }
+                    Instr::AddI64 => {
+                        let b = stack.pop()?;
+                        let a = stack.pop()?;
+                        stack.push(builder.ins().iadd(a, b));
+                    }
+                    Instr::SubI64 => {
+                        let b = stack.pop()?;
+                        let a = stack.pop()?;
+                        stack.push(builder.ins().isub(a, b));
+                    }
+                    Instr::MulI64 => {
+                        let b = stack.pop()?;
+                        let a = stack.pop()?;
+                        stack.push(builder.ins().imul(a, b));
+                    }
+                    Instr::ReturnI64 => {
+                        let value = stack.pop()?;
+                        builder.ins().return_(&[value]);
+                        reached_return = true;
+                        break;
+                    }
+                    // Jumps, calls, boxed values, everything else: out of
+                    // scope for this tier, bail to the interpreter
+                    // (Issue chunk427-1).
+                    _ => return None,
+                }
+            }
+
+            builder.finalize();
+        }
+
+        // A range that never reaches `ReturnI64` has no well-defined
+        // native return value - bail rather than compile a function with
+        // a dangling block.
+        if !reached_return {
+            return None;
+        }
+
+        self.ctx.func = func;
+        self.module.define_function(func_id, &mut self.ctx).ok()?;
+        self.module.clear_context(&mut self.ctx);
+        self.module.finalize_definitions().ok()?;
+
+        let code_ptr = self.module.get_finalized_function(func_id);
+        // SAFETY: `code_ptr` was just finalized by this `JITModule` for a
+        // function built from the signature above - `(i64, i64) -> i64`
+        // under the System V calling convention - which matches `NativeFn`
+        // exactly. The pointer stays valid for as long as `self.module`
+        // lives, and `SpecializationJit` lives inside `Vm` for the
+        // process lifetime, so it's never called after the module that
+        // produced it is dropped.
+        Some(unsafe { std::mem::transmute::<*const u8, NativeFn>(code_ptr) })
+    }
+}
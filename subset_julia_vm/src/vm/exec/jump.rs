@@ -114,6 +114,22 @@ impl<R: RngLike> Vm<R> {
                 }
             }
 
+            Instr::SafePoint => {
+                self.safepoint_count += 1;
+                if let Some(callback) = self.progress_callback.as_mut() {
+                    if !callback(self.safepoint_count) {
+                        return Err(VmError::Interrupted);
+                    }
+                }
+                if let Some(budget) = self.safepoint_budget.as_mut() {
+                    if *budget == 0 {
+                        return Err(VmError::Interrupted);
+                    }
+                    *budget -= 1;
+                }
+                Ok(JumpResult::NoJump)
+            }
+
             _ => Ok(JumpResult::NotHandled),
         }
     }
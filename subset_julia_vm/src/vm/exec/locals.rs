@@ -9,7 +9,7 @@
 use crate::rng::RngLike;
 
 use super::super::error::VmError;
-use super::super::frame::VarTypeTag;
+use super::super::frame::{narrow_bits_from_value, narrow_value_from_bits, VarTypeTag};
 use super::super::instr::Instr;
 use super::super::stack_ops::StackOps;
 use super::super::value::{SymbolValue, Value};
@@ -478,6 +478,7 @@ impl<R: RngLike> Vm<R> {
                         | Value::BigFloat(_)
                         | Value::SliceAll
                         | Value::Ref(_)
+                        | Value::Boxed(_)
                         | Value::DataType(_)
                         | Value::Module(_)
                         | Value::Function(_)
@@ -504,6 +505,98 @@ impl<R: RngLike> Vm<R> {
                 Ok(LocalsResult::Handled)
             }
 
+            Instr::StoreNarrow {
+                name,
+                width,
+                signed,
+            } => {
+                let val = self.stack.pop_value()?;
+                let bits = narrow_bits_from_value(&val);
+                if let Some(frame) = self.frames.last_mut() {
+                    // O(1) removal via tag instead of clearing all typed maps
+                    frame.remove_var(name);
+                    frame
+                        .locals_narrow_by_name
+                        .insert(name.clone(), (bits, *width, *signed));
+                    frame.var_types.insert(name.clone(), VarTypeTag::NarrowSlot);
+                }
+                Ok(LocalsResult::Handled)
+            }
+            Instr::LoadNarrow {
+                name,
+                width,
+                signed,
+            } => {
+                let bits = self
+                    .frames
+                    .last()
+                    .and_then(|frame| frame.locals_narrow_by_name.get(name).map(|&(b, _, _)| b))
+                    .or_else(|| {
+                        if self.frames.len() > 1 {
+                            self.frames.first().and_then(|frame| {
+                                frame.locals_narrow_by_name.get(name).map(|&(b, _, _)| b)
+                            })
+                        } else {
+                            None
+                        }
+                    });
+                match bits {
+                    Some(bits) => {
+                        self.stack
+                            .push(narrow_value_from_bits(bits, *width, *signed));
+                        Ok(LocalsResult::Handled)
+                    }
+                    None => {
+                        self.raise(VmError::UndefVarError(name.clone()))?;
+                        Ok(LocalsResult::Continue)
+                    }
+                }
+            }
+            Instr::StoreNarrowSlot {
+                slot,
+                width,
+                signed,
+            } => {
+                let val = self.stack.pop_value()?;
+                let bits = narrow_bits_from_value(&val);
+                if let Some(frame) = self.frames.last_mut() {
+                    if let Some(slot_ref) = frame.locals_narrow_slots.get_mut(*slot) {
+                        *slot_ref = Some((bits, *width, *signed));
+                    } else {
+                        // INTERNAL: slot index is compiler-generated; out-of-bounds means compiler produced an invalid slot
+                        return Err(VmError::InternalError(format!(
+                            "StoreNarrowSlot: slot out of bounds: {}",
+                            slot
+                        )));
+                    }
+                }
+                Ok(LocalsResult::Handled)
+            }
+            Instr::LoadNarrowSlot {
+                slot,
+                width,
+                signed,
+            } => {
+                if let Some(frame) = self.frames.last() {
+                    let bits = frame.locals_narrow_slots.get(*slot).and_then(|v| *v);
+                    match bits {
+                        Some((bits, _, _)) => {
+                            self.stack
+                                .push(narrow_value_from_bits(bits, *width, *signed));
+                            Ok(LocalsResult::Handled)
+                        }
+                        None => {
+                            let name = self.slot_name_for_frame(frame, *slot);
+                            self.raise(VmError::UndefVarError(name))?;
+                            Ok(LocalsResult::Continue)
+                        }
+                    }
+                } else {
+                    self.raise(VmError::UndefVarError(format!("slot {}", slot)))?;
+                    Ok(LocalsResult::Continue)
+                }
+            }
+
             // === Fused load+arithmetic instructions ===
             Instr::LoadAddI64(name) => {
                 let var_val = self
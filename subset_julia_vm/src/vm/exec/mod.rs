@@ -9,6 +9,7 @@
 #![allow(clippy::cast_sign_loss)]
 #![deny(clippy::expect_used)]
 
+mod apply_iterate;
 mod arithmetic;
 mod array_basic;
 mod array_index;
@@ -22,11 +23,13 @@ mod call_dynamic_binary;
 mod call_dynamic_typed;
 mod call_function_variable;
 mod comparison;
+mod coroutine;
 mod conversion;
 mod dict;
 mod error_handling;
 mod hof;
 mod iterator;
+pub(crate) mod jit;
 mod jump;
 mod locals;
 mod matrix;
@@ -42,15 +45,18 @@ mod sleep;
 mod stack;
 mod string_ops;
 mod struct_ops;
+mod task;
 mod tuple;
 
 use super::*;
+use apply_iterate::ApplyIterateResult;
 use arithmetic::ArithmeticResult;
 use array_basic::ArrayBasicResult;
 use array_index::ArrayIndexResult;
 use array_mutate::ArrayMutateResult;
 use call::CallResult;
 use call_dynamic::CallDynamicResult;
+use coroutine::{Continuation, CoroutineResult};
 use dict::DictResult;
 use error_handling::ErrorResult;
 use hof::HofResult;
@@ -65,6 +71,7 @@ use return_ops::ReturnResult;
 use set::SetResult;
 use sleep::SleepResult;
 use struct_ops::StructResult;
+pub(crate) use task::{TaskState, TaskStep};
 use tuple::TupleResult;
 use util::{format_value, value_to_string};
 
@@ -77,14 +84,123 @@ enum DispatchAction {
     Continue,
     /// Exit the VM with a value
     Exit(Value),
+    /// Suspend at a `put!`/`produce` call, carrying the produced value
+    /// (Issue chunk421-4). Only `run_resumable`/`resume` know how to turn
+    /// this into a `Continuation`; `run()` treats it as unsupported.
+    Suspend(Value),
+}
+
+/// Outcome of driving the VM via `run_resumable`/`resume` (Issue chunk421-4).
+#[allow(dead_code)]
+pub(crate) enum RunOutcome {
+    /// The program ran to completion (or hit a `return`/implicit exit).
+    Exited(Value),
+    /// Execution paused at a `put!`/`produce` call; `resume` continues it.
+    Suspended(Continuation),
+}
+
+/// Stack/frame/handler lengths recorded when a resumable run begins, so that
+/// a later suspension can capture exactly what was pushed since then via
+/// `Vec::split_off` (Issue chunk421-4).
+struct EntryLengths {
+    frame_len: usize,
+    handler_len: usize,
+    stack_len: usize,
 }
 
 impl<R: RngLike> Vm<R> {
     pub fn run(&mut self) -> Result<Value, VmError> {
+        match self.run_loop() {
+            Ok(DispatchAction::Exit(val)) => {
+                self.run_atexit_hooks();
+                Ok(val)
+            }
+            Ok(DispatchAction::Suspend(_)) => {
+                // Plain `run()` has no resumable driver to hand the
+                // `Continuation` to (Issue chunk421-4); only `run_resumable`
+                // knows how to capture one. A `put!`/`produce` reached this
+                // way is a program using the yield primitive without opting
+                // into resumable execution.
+                self.run_atexit_hooks();
+                Err(VmError::InternalError(
+                    "put!/produce used outside of a resumable run".to_string(),
+                ))
+            }
+            Ok(DispatchAction::Continue) => {
+                unreachable!("run_loop only returns on Exit/Suspend/Err")
+            }
+            Err(err) => {
+                self.run_atexit_hooks();
+                Err(err)
+            }
+        }
+    }
+
+    /// Start a resumable run from the current instruction pointer, stopping
+    /// either at program exit or at the first `put!`/`produce` call (Issue
+    /// chunk421-4). Unlike `run()`, atexit hooks are NOT run on suspension -
+    /// only on a genuine exit, since the program has not actually finished.
+    ///
+    /// Not called anywhere yet - a `Channel`/`Task` scheduler is what would
+    /// drive this (see `coroutine`'s module doc comment) - so it is allowed
+    /// dead for now.
+    #[allow(dead_code)]
+    pub(crate) fn run_resumable(&mut self) -> Result<RunOutcome, VmError> {
+        let entry = EntryLengths {
+            frame_len: self.frames.len(),
+            handler_len: self.handlers.len(),
+            stack_len: self.stack.len(),
+        };
+        self.run_from_entry(entry)
+    }
+
+    /// Resume a previously suspended run, restoring the frames/handlers/stack
+    /// captured at suspension and continuing from `resume_ip` (Issue
+    /// chunk421-4).
+    #[allow(dead_code)]
+    pub(crate) fn resume(&mut self, continuation: Continuation) -> Result<RunOutcome, VmError> {
+        let entry = EntryLengths {
+            frame_len: self.frames.len(),
+            handler_len: self.handlers.len(),
+            stack_len: self.stack.len(),
+        };
+        self.frames.extend(continuation.frames);
+        self.handlers.extend(continuation.handlers);
+        self.stack.extend(continuation.stack);
+        self.ip = continuation.resume_ip;
+        self.run_from_entry(entry)
+    }
+
+    /// Shared driver for `run_resumable`/`resume`: runs `run_loop` and turns
+    /// an `Exit` into `RunOutcome::Exited` or a `Suspend` into a full
+    /// `Continuation` captured relative to `entry` (Issue chunk421-4).
+    fn run_from_entry(&mut self, entry: EntryLengths) -> Result<RunOutcome, VmError> {
+        match self.run_loop()? {
+            DispatchAction::Exit(val) => {
+                self.run_atexit_hooks();
+                Ok(RunOutcome::Exited(val))
+            }
+            DispatchAction::Suspend(produced_value) => Ok(RunOutcome::Suspended(Continuation {
+                resume_ip: self.ip,
+                frames: self.frames.split_off(entry.frame_len),
+                handlers: self.handlers.split_off(entry.handler_len),
+                stack: self.stack.split_off(entry.stack_len),
+                produced_value,
+            })),
+            DispatchAction::Continue => unreachable!("run_loop only returns on Exit/Suspend"),
+        }
+    }
+
+    /// Fetch-dispatch loop shared by `run()` and the resumable drivers
+    /// (Issue chunk421-4). Returns as soon as dispatch yields an `Exit` or a
+    /// `Suspend`; callers decide what to do with each (atexit hooks, in
+    /// particular, are the caller's responsibility, not this loop's).
+    fn run_loop(&mut self) -> Result<DispatchAction, VmError> {
         loop {
             if cancel::is_requested() {
                 return Err(VmError::Cancelled);
             }
+            self.consume_fuel()?;
             let ip = self.ip;
             self.ip += 1;
 
@@ -115,7 +231,9 @@ impl<R: RngLike> Vm<R> {
 
             match result {
                 Ok(DispatchAction::Continue) => continue,
-                Ok(DispatchAction::Exit(val)) => return Ok(val),
+                Ok(action @ (DispatchAction::Exit(_) | DispatchAction::Suspend(_))) => {
+                    return Ok(action)
+                }
                 Err(err) => {
                     // Store the IP of the failing instruction for span lookup (Issue #2856)
                     self.last_error_ip = Some(ip);
@@ -125,6 +243,126 @@ impl<R: RngLike> Vm<R> {
         }
     }
 
+    /// Run all hooks registered via `atexit(f)`, in reverse (LIFO) registration
+    /// order, just before the VM returns its final value (or errors out).
+    ///
+    /// Each hook is run independently: a hook that errors is dropped (matching
+    /// atexit's "best effort" shutdown semantics) rather than aborting the
+    /// remaining hooks or clobbering the program's real return value/error.
+    fn run_atexit_hooks(&mut self) {
+        let hooks = std::mem::take(&mut self.atexit_hooks);
+        for hook in hooks.into_iter().rev() {
+            let _ = self.call_value_to_completion(hook, Vec::new());
+        }
+    }
+
+    /// Call an arbitrary callable `Value` (a `Function` or `Closure`) with the
+    /// given arguments and drive it to completion, returning its result.
+    ///
+    /// Unlike the normal call instructions, which push a frame and rely on the
+    /// surrounding `run()` loop to keep dispatching, this is invoked *after*
+    /// `run()` has already decided to return - so it drives its own private
+    /// dispatch loop, bounded by frame depth (rather than `return_ips`
+    /// emptying out) since `self.ip` may already be past the end of the code.
+    fn call_value_to_completion(
+        &mut self,
+        callable: Value,
+        args: Vec<Value>,
+    ) -> Result<Value, VmError> {
+        let (func_name, captures) = match callable {
+            Value::Function(fv) => (fv.name, Vec::new()),
+            Value::Closure(cv) => (cv.name, cv.captures),
+            _ => {
+                return Err(VmError::TypeError(
+                    "atexit: expected a callable (function or closure)".into(),
+                ))
+            }
+        };
+
+        let indices = self.get_function_indices_by_name(&func_name);
+        if indices.is_empty() {
+            // INTERNAL: the name comes from a FunctionValue/ClosureValue produced
+            // by the compiler, so a missing function is a compiler bug.
+            return Err(VmError::InternalError(format!(
+                "Function '{}' not found",
+                func_name
+            )));
+        }
+        let candidates: Vec<(usize, &FunctionInfo)> = indices
+            .iter()
+            .map(|&idx| (idx, &self.functions[idx]))
+            .collect();
+        let arg_type_names: Vec<String> = args.iter().map(|a| self.get_type_name(a)).collect();
+        let func_index =
+            self.dispatch_function_variable(&func_name, &candidates, &arg_type_names)?;
+
+        self.call_function_index_to_completion(func_index, captures, args)
+    }
+
+    /// Call a known global function (by its resolved `func_index`) with the
+    /// given captures/args and drive it to completion, returning its result.
+    ///
+    /// This is the shared tail of `call_value_to_completion` - split out so
+    /// that callers who already have a resolved `func_index` (e.g.
+    /// `ApplyIterate`'s statically-dispatched `iterate` method, Issue
+    /// chunk422-1) don't need to round-trip through a `Value::Function` and
+    /// re-resolve it by name.
+    fn call_function_index_to_completion(
+        &mut self,
+        func_index: usize,
+        captures: Vec<Value>,
+        args: Vec<Value>,
+    ) -> Result<Value, VmError> {
+        let func = self.get_function_checked(func_index)?.clone();
+        let mut frame = Frame::new_with_captures(func.local_slot_count, Some(func_index), captures);
+        for (slot, arg) in func.param_slots.iter().zip(args.into_iter()) {
+            bind_value_to_slot(&mut frame, *slot, arg, &mut self.struct_heap);
+        }
+        for kwparam in &func.kwparams {
+            if kwparam.required {
+                return Err(VmError::UndefKeywordError(kwparam.name.clone()));
+            }
+            bind_value_to_slot(
+                &mut frame,
+                kwparam.slot,
+                kwparam.default.clone(),
+                &mut self.struct_heap,
+            );
+        }
+
+        let depth_before = self.frames.len();
+        self.return_ips.push(self.ip);
+        self.frames.push(frame);
+        self.ip = func.entry;
+
+        loop {
+            let ip = self.ip;
+            self.ip += 1;
+            let instr = std::mem::replace(&mut self.code[ip], Instr::Nop);
+            let result = self.dispatch_instr(&instr);
+            self.code[ip] = instr;
+            match result {
+                Ok(DispatchAction::Continue) => {
+                    if self.frames.len() <= depth_before {
+                        break;
+                    }
+                }
+                Ok(DispatchAction::Exit(val)) => return Ok(val),
+                Ok(DispatchAction::Suspend(_)) => {
+                    // A hook/helper driven to completion outside the main run
+                    // loop calling `put!`/`produce` is not a supported
+                    // scenario (Issue chunk421-4): there is no resumable
+                    // driver here to hand a `Continuation` to.
+                    return Err(VmError::InternalError(
+                        "put!/produce used outside of a resumable run".to_string(),
+                    ));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        self.stack.pop_value()
+    }
+
     /// Dispatch a single instruction to the appropriate handler.
     ///
     /// Takes `&Instr` (a reference to a local variable in `run()`), avoiding
@@ -164,6 +402,10 @@ impl<R: RngLike> Vm<R> {
         if self.execute_iterator(instr)?.is_some() {
             return Ok(DispatchAction::Continue);
         }
+        match self.execute_apply_iterate(instr)? {
+            ApplyIterateResult::Handled => return Ok(DispatchAction::Continue),
+            ApplyIterateResult::NotHandled => {}
+        }
         match self.execute_sleep(instr)? {
             SleepResult::Handled | SleepResult::Continue => return Ok(DispatchAction::Continue),
             SleepResult::NotHandled => {}
@@ -261,6 +503,10 @@ impl<R: RngLike> Vm<R> {
             }
             CallDynamicResult::NotHandled => {}
         }
+        match self.execute_coroutine(instr)? {
+            CoroutineResult::Suspend(value) => return Ok(DispatchAction::Suspend(value)),
+            CoroutineResult::NotHandled => {}
+        }
         match instr {
             Instr::TimeNs => {
                 // Use WASM timing only when both feature is enabled AND target is wasm32
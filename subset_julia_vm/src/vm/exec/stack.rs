@@ -136,9 +136,11 @@ impl<R: RngLike> Vm<R> {
             Instr::CreateClosure {
                 func_name,
                 capture_names,
+                boxed_capture_names,
             } => {
                 // Create a closure by capturing variables from the current frame
-                let mut captures = Vec::with_capacity(capture_names.len());
+                let mut captures =
+                    Vec::with_capacity(capture_names.len() + boxed_capture_names.len());
                 let frame_idx = self.frames.len().saturating_sub(1);
 
                 for name in capture_names {
@@ -153,6 +155,28 @@ impl<R: RngLike> Vm<R> {
                     }
                 }
 
+                for name in boxed_capture_names {
+                    // The compiler emits PromoteToBoxed before CreateClosure for every
+                    // name here, so this is already a `Value::Boxed` cell; cloning it
+                    // just shares the `Rc` so writes stay visible on both sides
+                    // (Issue chunk421-1).
+                    match self.get_value_from_frame(name, frame_idx) {
+                        Some(value @ Value::Boxed(_)) => captures.push((name.clone(), value)),
+                        Some(_) => {
+                            return Err(VmError::InternalError(format!(
+                                "Expected boxed capture '{}' to already be promoted",
+                                name
+                            )))
+                        }
+                        None => {
+                            return Err(VmError::UndefVarError(format!(
+                                "Cannot capture undefined variable: {}",
+                                name
+                            )))
+                        }
+                    }
+                }
+
                 self.stack.push(Value::Closure(ClosureValue::new(
                     func_name.clone(),
                     captures,
@@ -175,6 +199,77 @@ impl<R: RngLike> Vm<R> {
                     )))
                 }
             }
+            Instr::PromoteToBoxed(name) => {
+                let frame = self.frames.last_mut().ok_or_else(|| {
+                    VmError::InternalError("No frame for boxed local promotion".to_string())
+                })?;
+                frame.promote_to_boxed(name);
+                Ok(Some(()))
+            }
+            Instr::LoadBoxed(name) => {
+                let frame = self.frames.last().ok_or_else(|| {
+                    VmError::InternalError("No frame for boxed local lookup".to_string())
+                })?;
+                match frame.get_local(name) {
+                    Some(Value::Boxed(cell)) => {
+                        self.stack.push(cell.borrow().clone());
+                        Ok(Some(()))
+                    }
+                    _ => Err(VmError::InternalError(format!(
+                        "Expected boxed local '{}' to already be promoted",
+                        name
+                    ))),
+                }
+            }
+            Instr::StoreBoxed(name) => {
+                let value = self.stack.pop_value()?;
+                let frame = self.frames.last().ok_or_else(|| {
+                    VmError::InternalError("No frame for boxed local store".to_string())
+                })?;
+                match frame.get_local(name) {
+                    Some(Value::Boxed(cell)) => {
+                        *cell.borrow_mut() = value;
+                        Ok(Some(()))
+                    }
+                    _ => Err(VmError::InternalError(format!(
+                        "Expected boxed local '{}' to already be promoted",
+                        name
+                    ))),
+                }
+            }
+            Instr::LoadCapturedBoxed(name) => {
+                let frame = self.frames.last().ok_or_else(|| {
+                    VmError::InternalError(
+                        "No frame for captured boxed variable lookup".to_string(),
+                    )
+                })?;
+                match frame.captured_vars.get(name) {
+                    Some(Value::Boxed(cell)) => {
+                        self.stack.push(cell.borrow().clone());
+                        Ok(Some(()))
+                    }
+                    _ => Err(VmError::UndefVarError(format!(
+                        "Boxed captured variable not found: {}",
+                        name
+                    ))),
+                }
+            }
+            Instr::StoreCapturedBoxed(name) => {
+                let value = self.stack.pop_value()?;
+                let frame = self.frames.last().ok_or_else(|| {
+                    VmError::InternalError("No frame for captured boxed variable store".to_string())
+                })?;
+                match frame.captured_vars.get(name) {
+                    Some(Value::Boxed(cell)) => {
+                        *cell.borrow_mut() = value;
+                        Ok(Some(()))
+                    }
+                    _ => Err(VmError::UndefVarError(format!(
+                        "Boxed captured variable not found: {}",
+                        name
+                    ))),
+                }
+            }
             Instr::DefineFunction(func_idx) => {
                 // Define a function at runtime (for functions defined inside blocks like @testset).
                 // The function is already compiled and stored in function_infos at index func_idx.
@@ -271,6 +366,21 @@ impl<R: RngLike> Vm<R> {
                 });
                 Ok(Some(()))
             }
+            Instr::EnumConvert { type_name, check } => {
+                let value = self.stack.pop_i64()?;
+                if !check.contains(value) {
+                    self.raise(VmError::TypeError(format!(
+                        "ArgumentError: invalid value for Enum {}: {}",
+                        type_name, value
+                    )))?;
+                    return Ok(Some(()));
+                }
+                self.stack.push(Value::Enum {
+                    type_name: type_name.clone(),
+                    value,
+                });
+                Ok(Some(()))
+            }
 
             _ => Ok(None),
         }
@@ -4,7 +4,7 @@
 //! - NewStruct, NewStructSplat, NewParametricStruct, NewDynamicParametricStruct
 //! - LoadStruct, StoreStruct
 //! - GetField, GetExprField, SetField
-//! - ReturnStruct
+//! - ReturnStruct, ReturnSlot
 
 #![deny(clippy::unwrap_used)]
 #![deny(clippy::expect_used)]
@@ -347,7 +347,7 @@ impl<R: RngLike> Vm<R> {
 
             Instr::GetField(field_idx) => {
                 let val = self.stack.pop_value()?;
-                let (field_value, field_count, _struct_name) = match &val {
+                let (field_value, field_count, struct_name) = match &val {
                     Value::StructRef(idx) => {
                         if let Some(s) = self.struct_heap.get(*idx) {
                             (
@@ -380,9 +380,11 @@ impl<R: RngLike> Vm<R> {
                 let value = match field_value {
                     Some(v) => v,
                     None => {
+                        let field_names = self.struct_field_names(&struct_name);
                         self.raise(VmError::FieldIndexOutOfBounds {
                             index: *field_idx,
                             field_count,
+                            field_names,
                         })?;
                         return Ok(StructResult::Continue);
                     }
@@ -457,13 +459,27 @@ impl<R: RngLike> Vm<R> {
                     }
                 };
 
+                // User-defined getproperty(::T, ::Symbol) overrides take priority over direct
+                // field access, mirroring the compile-time dispatch added for statically-typed
+                // `obj.field` (Issue chunk432-2); this covers the `Any`-typed dot-access path,
+                // which wasn't able to resolve the struct type until now (Issue chunk433-5).
+                {
+                    use crate::types::JuliaType;
+                    let arg_types = [JuliaType::Struct(struct_name.clone()), JuliaType::Symbol];
+                    if let Some(func_idx) = self.resolve_property_method("getproperty", &arg_types) {
+                        let result = self.call_function_index_to_completion(
+                            func_idx,
+                            Vec::new(),
+                            vec![val.clone(), Value::Symbol(SymbolValue::new(field_name.clone()))],
+                        )?;
+                        self.stack.push(result);
+                        return Ok(StructResult::Handled);
+                    }
+                }
+
                 // Look up the struct definition to find field index by name
                 let type_id = struct_instance.type_id;
-                let field_idx = if let Some(def) = self.struct_defs.get(type_id) {
-                    def.fields.iter().position(|(name, _)| name == field_name)
-                } else {
-                    None
-                };
+                let field_idx = self.get_struct_field_index(type_id, field_name);
 
                 // Fallback: if struct_defs lookup failed but this is a Complex struct,
                 // resolve "re"/"im" fields directly (Complex always has re=0, im=1).
@@ -477,17 +493,10 @@ impl<R: RngLike> Vm<R> {
                             _ => None,
                         }
                     } else {
-                        // Try scanning all struct_defs to find correct definition by name
-                        for def in &self.struct_defs {
-                            if def.name == struct_name {
-                                if let Some(pos) =
-                                    def.fields.iter().position(|(name, _)| name == field_name)
-                                {
-                                    return Some(pos);
-                                }
-                            }
-                        }
-                        None
+                        // Try resolving the correct definition by name instead of type_id
+                        self.struct_def_name_index
+                            .get(&struct_name)
+                            .and_then(|&idx| self.get_struct_field_index(idx, field_name))
                     }
                 });
 
@@ -496,18 +505,26 @@ impl<R: RngLike> Vm<R> {
                         if let Some(value) = struct_instance.get_field(idx) {
                             self.stack.push(value.clone());
                         } else {
+                            let field_names = self.struct_field_names(&struct_name);
                             self.raise(VmError::FieldIndexOutOfBounds {
                                 index: idx,
                                 field_count: struct_instance.values.len(),
+                                field_names,
                             })?;
                             return Ok(StructResult::Continue);
                         }
                     }
                     None => {
                         // User-visible: user can access a nonexistent field on a struct type
+                        let field_names = self.struct_field_names(&struct_name);
+                        let hint = if field_names.is_empty() {
+                            String::new()
+                        } else {
+                            format!("; available fields: {}", field_names.join(", "))
+                        };
                         return Err(VmError::TypeError(format!(
-                            "type {} has no field {}",
-                            struct_name, field_name
+                            "type {} has no field {}{}",
+                            struct_name, field_name, hint
                         )));
                     }
                 }
@@ -703,6 +720,40 @@ impl<R: RngLike> Vm<R> {
                 let value = self.stack.pop_value()?;
                 let struct_val = self.stack.pop_value()?;
 
+                // User-defined setproperty!(::T, ::Symbol, ::Any) overrides take priority over
+                // direct field mutation, mirroring the compile-time dispatch added for
+                // statically-typed `obj.field = v` (Issue chunk432-2); this covers the
+                // `Any`-typed dot-access path, which wasn't able to resolve the struct type
+                // until now (Issue chunk433-5).
+                let override_type_name = match &struct_val {
+                    Value::StructRef(idx) => {
+                        self.struct_heap.get(*idx).map(|s| s.struct_name.clone())
+                    }
+                    Value::Struct(s) => Some(s.struct_name.clone()),
+                    _ => None,
+                };
+                if let Some(struct_name) = override_type_name {
+                    use crate::types::JuliaType;
+                    let arg_types = [
+                        JuliaType::Struct(struct_name),
+                        JuliaType::Symbol,
+                        JuliaType::Any,
+                    ];
+                    if let Some(func_idx) = self.resolve_property_method("setproperty!", &arg_types) {
+                        self.call_function_index_to_completion(
+                            func_idx,
+                            Vec::new(),
+                            vec![
+                                struct_val.clone(),
+                                Value::Symbol(SymbolValue::new(field_name.clone())),
+                                value,
+                            ],
+                        )?;
+                        self.stack.push(struct_val);
+                        return Ok(StructResult::Handled);
+                    }
+                }
+
                 match struct_val {
                     Value::StructRef(idx) => {
                         let type_id = self.struct_heap.get(idx).map(|s| s.type_id).unwrap_or(0);
@@ -724,27 +775,18 @@ impl<R: RngLike> Vm<R> {
                         }
 
                         // Look up field index by name at runtime
-                        let field_idx = self.struct_defs.get(type_id).and_then(|def| {
-                            def.fields.iter().position(|(name, _)| name == field_name)
-                        });
+                        let field_idx = self.get_struct_field_index(type_id, field_name);
 
-                        // Fallback: scan struct_defs by struct name
+                        // Fallback: resolve the correct definition by struct name
                         let field_idx = field_idx.or_else(|| {
                             let struct_name = self
                                 .struct_heap
                                 .get(idx)
                                 .map(|s| s.struct_name.clone())
                                 .unwrap_or_default();
-                            for def in &self.struct_defs {
-                                if def.name == struct_name {
-                                    if let Some(pos) =
-                                        def.fields.iter().position(|(name, _)| name == field_name)
-                                    {
-                                        return Some(pos);
-                                    }
-                                }
-                            }
-                            None
+                            self.struct_def_name_index
+                                .get(&struct_name)
+                                .and_then(|&idx| self.get_struct_field_index(idx, field_name))
                         });
 
                         match field_idx {
@@ -761,9 +803,15 @@ impl<R: RngLike> Vm<R> {
                             }
                             None => {
                                 // User-visible: user can attempt to set a nonexistent field on a mutable struct (StructRef path)
+                                let field_names = self.struct_field_names_by_type_id(type_id);
+                                let hint = if field_names.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!("; available fields: {}", field_names.join(", "))
+                                };
                                 return Err(VmError::TypeError(format!(
-                                    "SetFieldByName: no field '{}' on struct",
-                                    field_name
+                                    "SetFieldByName: no field '{}' on struct{}",
+                                    field_name, hint
                                 )));
                             }
                         }
@@ -786,22 +834,13 @@ impl<R: RngLike> Vm<R> {
                             return Ok(StructResult::Continue);
                         }
 
-                        let field_idx = self.struct_defs.get(type_id).and_then(|def| {
-                            def.fields.iter().position(|(name, _)| name == field_name)
-                        });
+                        let field_idx = self.get_struct_field_index(type_id, field_name);
 
-                        // Fallback: scan by struct name
+                        // Fallback: resolve the correct definition by struct name
                         let field_idx = field_idx.or_else(|| {
-                            for def in &self.struct_defs {
-                                if def.name == s.struct_name {
-                                    if let Some(pos) =
-                                        def.fields.iter().position(|(name, _)| name == field_name)
-                                    {
-                                        return Some(pos);
-                                    }
-                                }
-                            }
-                            None
+                            self.struct_def_name_index
+                                .get(&s.struct_name)
+                                .and_then(|&idx| self.get_struct_field_index(idx, field_name))
                         });
 
                         match field_idx {
@@ -815,9 +854,15 @@ impl<R: RngLike> Vm<R> {
                             }
                             None => {
                                 // User-visible: user can attempt to set a nonexistent field on a mutable struct (Struct path)
+                                let field_names = self.struct_field_names_by_type_id(type_id);
+                                let hint = if field_names.is_empty() {
+                                    String::new()
+                                } else {
+                                    format!("; available fields: {}", field_names.join(", "))
+                                };
                                 return Err(VmError::TypeError(format!(
-                                    "SetFieldByName: no field '{}' on struct",
-                                    field_name
+                                    "SetFieldByName: no field '{}' on struct{}",
+                                    field_name, hint
                                 )));
                             }
                         }
@@ -833,13 +878,40 @@ impl<R: RngLike> Vm<R> {
                 Ok(StructResult::Handled)
             }
 
+            Instr::ReturnSlot => {
+                let slot = self
+                    .frames
+                    .last()
+                    .and_then(|f| f.return_slot)
+                    .map(Value::StructRef)
+                    .unwrap_or(Value::Nothing);
+                self.stack.push(slot);
+                Ok(StructResult::Handled)
+            }
+
             Instr::ReturnStruct => {
                 let val = self.stack.pop_value()?;
                 if let Some(return_ip) = self.return_ips.pop() {
                     // Pop any exception handlers from try blocks in this function
                     self.pop_handlers_for_return();
-                    self.frames.pop();
+                    let return_slot = self.frames.pop().and_then(|f| f.return_slot);
                     self.ip = return_ip;
+                    // If the caller reserved a return slot (Issue
+                    // chunk427-4) and the callee didn't already build
+                    // straight into it, move the finished struct into that
+                    // slot so the handle the caller gets back is the one
+                    // it reserved rather than a fresh, unrelated entry.
+                    let val = match (return_slot, &val) {
+                        (Some(slot), Value::StructRef(built_idx)) if slot != *built_idx => {
+                            if let Some(built) = self.struct_heap.get(*built_idx).cloned() {
+                                if let Some(dest) = self.struct_heap.get_mut(slot) {
+                                    *dest = built;
+                                }
+                            }
+                            Value::StructRef(slot)
+                        }
+                        _ => val,
+                    };
                     // Keep StructRef for internal returns
                     self.stack.push(val);
                     Ok(StructResult::Handled)
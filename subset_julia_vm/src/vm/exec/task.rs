@@ -0,0 +1,232 @@
+//! Task subsystem: suspendable call contexts driven by the `yield`/`produce`
+//! intrinsic (Issue chunk426-4).
+//!
+//! `coroutine.rs` captures a single in-flight suspension as a `Continuation`,
+//! consumed once by `run_resumable`/`resume`. A `Task` needs more than that:
+//! a handle that can be created, resumed repeatedly, and queried for
+//! completion - exactly what Julia's `Task`/`@async`/`Channel` model needs a
+//! producer to be. This module is that layer: `Vm::task_new` registers a
+//! not-yet-started callable in `Vm::tasks`, and `Vm::task_resume` drives it
+//! forward one step (to its next `yield`, to completion, or to an uncaught
+//! error), splicing its `CallContext` onto the VM's live stacks for the
+//! duration and splicing it back off at the next suspension.
+//!
+//! `CallContext` deliberately does not carry `try`/`catch` handlers (unlike
+//! `Continuation`, which does) - a task body with a `try`/`catch` spanning a
+//! `yield` loses its handler across the suspension. Fuller handler support,
+//! `Channel` buffering/blocking `take!`, and `@async`/`@sync` syntax are
+//! left for Julia-level library code to build on top of
+//! `task_new`/`task_resume`, the same division of labor `coroutine.rs`
+//! already draws around its own primitive.
+
+#![deny(clippy::unwrap_used)]
+#![deny(clippy::expect_used)]
+
+use crate::cancel;
+use crate::rng::RngLike;
+
+use super::super::error::VmError;
+use super::super::frame::Frame;
+use super::super::instr::Instr;
+use super::super::stack_ops::StackOps;
+use super::super::value::Value;
+use super::super::Vm;
+use super::DispatchAction;
+
+/// A suspended task's saved call-frame snapshot (Issue chunk426-4).
+///
+/// Lives inside a `Vm::tasks` entry (via `TaskState::Suspended`) and is
+/// restored and re-captured every time `task_resume` runs the task another
+/// step - unlike `Continuation`, which is captured once and consumed.
+#[derive(Debug, Clone)]
+pub(crate) struct CallContext {
+    pub frames: Vec<Frame>,
+    pub return_ips: Vec<usize>,
+    pub ip: usize,
+    pub stack: Vec<Value>,
+}
+
+/// Lifecycle of one `Vm::tasks` entry (Issue chunk426-4).
+#[derive(Debug, Clone)]
+pub(crate) enum TaskState {
+    /// Not started yet; holds the zero-argument callable to run on first resume.
+    NotStarted(Value),
+    /// Paused at a `yield`/`produce`, with its frames/stack saved.
+    Suspended(CallContext),
+    /// Finished normally with this value.
+    Done(Value),
+    /// Finished by propagating an uncaught error, recorded as text (mirrors
+    /// how `catch` already surfaces a `VmError` as a string to Julia code).
+    Failed(String),
+}
+
+/// Outcome of driving a task one step via `Vm::task_resume` (Issue chunk426-4).
+pub(crate) enum TaskStep {
+    Yielded(Value),
+    Done(Value),
+    Failed(VmError),
+}
+
+impl<R: RngLike> Vm<R> {
+    /// Register a new task wrapping a zero-argument callable (`Function` or
+    /// `Closure`), returning its handle - an index into `Vm::tasks` (Issue
+    /// chunk426-4). The callable doesn't run until the first `task_resume`.
+    pub(crate) fn task_new(&mut self, entry: Value) -> usize {
+        self.tasks.push(TaskState::NotStarted(entry));
+        self.tasks.len() - 1
+    }
+
+    /// Check whether the task at `handle` has finished, without resuming it
+    /// (Issue chunk426-4).
+    pub(crate) fn task_is_done(&self, handle: usize) -> Result<bool, VmError> {
+        match self.tasks.get(handle) {
+            Some(TaskState::Done(_) | TaskState::Failed(_)) => Ok(true),
+            Some(TaskState::NotStarted(_) | TaskState::Suspended(_)) => Ok(false),
+            None => Err(VmError::InternalError(format!(
+                "invalid task handle: {}",
+                handle
+            ))),
+        }
+    }
+
+    /// Drive the task at `handle` until its next `yield`/`produce`, its
+    /// normal return, or an uncaught error (Issue chunk426-4).
+    pub(crate) fn task_resume(&mut self, handle: usize) -> Result<TaskStep, VmError> {
+        let Some(state) = self.tasks.get_mut(handle) else {
+            return Err(VmError::InternalError(format!(
+                "invalid task handle: {}",
+                handle
+            )));
+        };
+
+        match std::mem::replace(state, TaskState::Done(Value::Nothing)) {
+            TaskState::NotStarted(entry) => self.task_start(handle, entry),
+            TaskState::Suspended(ctx) => self.run_task_slice(handle, ctx),
+            finished @ (TaskState::Done(_) | TaskState::Failed(_)) => {
+                // Resuming an already-finished task just re-reports its
+                // outcome, matching `wait`ing on an already-finished Task.
+                let step = match &finished {
+                    TaskState::Done(v) => TaskStep::Done(v.clone()),
+                    TaskState::Failed(msg) => {
+                        TaskStep::Failed(VmError::ErrorException(msg.clone()))
+                    }
+                    TaskState::NotStarted(_) | TaskState::Suspended(_) => {
+                        unreachable!("matched as Done/Failed above")
+                    }
+                };
+                self.tasks[handle] = finished;
+                Ok(step)
+            }
+        }
+    }
+
+    fn task_start(&mut self, handle: usize, entry: Value) -> Result<TaskStep, VmError> {
+        let (func_name, captures) = match entry {
+            Value::Function(fv) => (fv.name, Vec::new()),
+            Value::Closure(cv) => (cv.name, cv.captures),
+            other => {
+                let err = VmError::TypeError(format!(
+                    "Task: expected a zero-argument callable (function or closure), got {:?}",
+                    other.value_type()
+                ));
+                self.tasks[handle] = TaskState::Failed(err.to_string());
+                return Ok(TaskStep::Failed(err));
+            }
+        };
+        let indices = self.get_function_indices_by_name(&func_name);
+        let Some(&func_index) = indices.first() else {
+            let err = VmError::InternalError(format!("Function '{}' not found", func_name));
+            self.tasks[handle] = TaskState::Failed(err.to_string());
+            return Ok(TaskStep::Failed(err));
+        };
+        let func = self.get_function_checked(func_index)?.clone();
+        let frame = Frame::new_with_captures(func.local_slot_count, Some(func_index), captures);
+
+        let ctx = CallContext {
+            frames: vec![frame],
+            return_ips: vec![self.ip],
+            ip: func.entry,
+            stack: Vec::new(),
+        };
+        self.run_task_slice(handle, ctx)
+    }
+
+    /// Splice `ctx` onto the VM's live stacks, run until the task's own
+    /// frames unwind (completion), a `yield` fires, or an error escapes
+    /// uncaught, then splice back off and restore `self.ip` to whatever the
+    /// caller (main program or another task) had before this call - tasks
+    /// are cooperative, so only one runs at a time, and the caller's state
+    /// must come back exactly as it left it (Issue chunk426-4).
+    fn run_task_slice(&mut self, handle: usize, ctx: CallContext) -> Result<TaskStep, VmError> {
+        let frame_len = self.frames.len();
+        let return_ip_len = self.return_ips.len();
+        let stack_len = self.stack.len();
+        let caller_ip = self.ip;
+
+        self.frames.extend(ctx.frames);
+        self.return_ips.extend(ctx.return_ips);
+        self.stack.extend(ctx.stack);
+        self.ip = ctx.ip;
+
+        let result: Result<Value, VmError> = loop {
+            // Same fuel/cancellation checks `run_loop` makes at the top of its dispatch loop
+            // (Issue chunk426-4): without them, a task body that never calls another function
+            // (e.g. a tight arithmetic loop) burns no fuel at all and can't be cancelled, since
+            // `execute_call` was the only other place consuming fuel.
+            let preempt = if cancel::is_requested() {
+                Err(VmError::Cancelled)
+            } else {
+                self.consume_fuel()
+            };
+            if let Err(err) = preempt {
+                self.frames.truncate(frame_len);
+                self.return_ips.truncate(return_ip_len);
+                self.stack.truncate(stack_len);
+                break Err(err);
+            }
+
+            let ip = self.ip;
+            self.ip += 1;
+            let instr = std::mem::replace(&mut self.code[ip], Instr::Nop);
+            let dispatch = self.dispatch_instr(&instr);
+            self.code[ip] = instr;
+            match dispatch {
+                Ok(DispatchAction::Continue) => {
+                    if self.frames.len() <= frame_len {
+                        break self.stack.pop_value();
+                    }
+                }
+                Ok(DispatchAction::Exit(val)) => break Ok(val),
+                Ok(DispatchAction::Suspend(value)) => {
+                    let saved = CallContext {
+                        frames: self.frames.split_off(frame_len),
+                        return_ips: self.return_ips.split_off(return_ip_len),
+                        ip: self.ip,
+                        stack: self.stack.split_off(stack_len),
+                    };
+                    self.tasks[handle] = TaskState::Suspended(saved);
+                    self.ip = caller_ip;
+                    return Ok(TaskStep::Yielded(value));
+                }
+                Err(err) => {
+                    self.frames.truncate(frame_len);
+                    self.return_ips.truncate(return_ip_len);
+                    self.stack.truncate(stack_len);
+                    break Err(err);
+                }
+            }
+        };
+
+        self.ip = caller_ip;
+        match result {
+            Ok(val) => {
+                self.tasks[handle] = TaskState::Done(val.clone());
+                Ok(TaskStep::Done(val))
+            }
+            Err(err) => {
+                self.tasks[handle] = TaskState::Failed(err.to_string());
+                Ok(TaskStep::Failed(err))
+            }
+        }
+    }
+}
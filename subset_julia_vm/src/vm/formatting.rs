@@ -162,6 +162,7 @@ fn format_value_slow(v: &Value) -> String {
         Value::F16(x) => format!("Float16({})", x.to_f32()),
         Value::F32(x) => format_float32_julia(*x),
         Value::F64(x) => format_float_julia(*x),
+        Value::F128(x) => format_float_julia(x.to_f64()),
         Value::BigInt(x) => x.to_string(),
         Value::BigFloat(x) => x.to_string(),
         Value::Str(s) => s.clone(),
@@ -181,6 +182,8 @@ fn format_value_slow(v: &Value) -> String {
         Value::Struct(s) => format_struct_instance(s),
         Value::StructRef(idx) => format!("StructRef(heap_idx={})", idx),
         Value::Rng(_) => "RNG".to_string(),
+        Value::Task(_) => "Task(...)".to_string(),
+        Value::VaList(_) => "(...)".to_string(),
         Value::Tuple(t) => {
             let parts: Vec<String> = t.elements.iter().map(format_value).collect();
             format!("({})", parts.join(", "))
@@ -200,6 +203,7 @@ fn format_value_slow(v: &Value) -> String {
             format!("Set([{}])", parts.join(", "))
         }
         Value::Ref(inner) => format!("Ref({})", format_value(inner)),
+        Value::Boxed(cell) => format_value(&cell.borrow()),
         Value::Generator(_) => "Generator(...)".to_string(),
         Value::DataType(jt) => jt.to_string(),
         Value::Module(m) => format!("Module({})", m.name),
@@ -541,6 +545,14 @@ pub(crate) fn value_to_string(val: &Value) -> String {
                 f.to_string()
             }
         }
+        Value::F128(f) => {
+            let f = f.to_f64();
+            if f.fract() == 0.0 {
+                format!("{:.1}", f)
+            } else {
+                f.to_string()
+            }
+        }
         Value::Str(s) => s.clone(),
         Value::Char(c) => format!("'{}'", c),
         Value::Nothing => "nothing".to_string(),
@@ -572,6 +584,8 @@ pub(crate) fn value_to_string(val: &Value) -> String {
         }
         Value::StructRef(idx) => format!("StructRef({})", idx),
         Value::Rng(_) => "RNG".to_string(),
+        Value::Task(_) => "Task(...)".to_string(),
+        Value::VaList(_) => "(...)".to_string(),
         Value::Tuple(t) => {
             let elements_str: Vec<String> = t.elements.iter().map(value_to_string).collect();
             format!("({})", elements_str.join(", "))
@@ -597,6 +611,7 @@ pub(crate) fn value_to_string(val: &Value) -> String {
             format!("Set([{}])", elements_str.join(", "))
         }
         Value::Ref(inner) => format!("Ref({})", value_to_string(inner)),
+        Value::Boxed(cell) => value_to_string(&cell.borrow()),
         Value::Generator(_) => "Generator(...)".to_string(),
         Value::DataType(jt) => jt.to_string(),
         Value::Module(m) => m.name.clone(),
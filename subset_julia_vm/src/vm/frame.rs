@@ -27,11 +27,52 @@ pub(crate) enum VarTypeTag {
     Generator,
     Any,
     NarrowInt,
+    /// Packed, allocation-free narrow integer/`Bool` slot (Issue chunk421-5).
+    NarrowSlot,
     Nothing,
     Bool,
     ValSymbol,
 }
 
+/// Reconstruct the exact narrow-int/`Bool` `Value` from a packed `u64` word
+/// and its `(width, signed)` tag (Issue chunk421-5). `width` is the bit width
+/// written by `Instr::StoreNarrow`; the pairing is exhaustive over the widths
+/// the compiler ever emits (8/16/32/64, plus the 1-bit case for `Bool`).
+pub(crate) fn narrow_value_from_bits(bits: u64, width: u8, signed: bool) -> Value {
+    match (width, signed) {
+        (1, _) => Value::Bool(bits != 0),
+        (8, true) => Value::I8(bits as u8 as i8),
+        (8, false) => Value::U8(bits as u8),
+        (16, true) => Value::I16(bits as u16 as i16),
+        (16, false) => Value::U16(bits as u16),
+        (32, true) => Value::I32(bits as u32 as i32),
+        (32, false) => Value::U32(bits as u32),
+        (64, false) => Value::U64(bits),
+        // INTERNAL: the compiler only ever emits the widths/signs above.
+        _ => Value::I64(bits as i64),
+    }
+}
+
+/// Inverse of `narrow_value_from_bits`: pack a narrow-int/`Bool` `Value` into
+/// a raw `u64` word for `Instr::StoreNarrow` (Issue chunk421-5). The
+/// `compile_expr_as`-driven compile path guarantees `value` is already the
+/// exact narrow type the store instruction was emitted for.
+pub(crate) fn narrow_bits_from_value(value: &Value) -> u64 {
+    match value {
+        Value::Bool(b) => *b as u64,
+        Value::I8(v) => *v as u8 as u64,
+        Value::U8(v) => *v as u64,
+        Value::I16(v) => *v as u16 as u64,
+        Value::U16(v) => *v as u64,
+        Value::I32(v) => *v as u32 as u64,
+        Value::U32(v) => *v as u64,
+        Value::U64(v) => *v,
+        // INTERNAL: compile_expr_as only ever feeds StoreNarrow a value of
+        // one of the types above.
+        _ => 0,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Frame {
     pub locals_slots: Vec<Option<Value>>,
@@ -54,6 +95,22 @@ pub(crate) struct Frame {
     /// Narrow integer types (I8/I16/I32/I128/U8–U128) stored as Value to preserve type info.
     /// Separate from locals_any to avoid mixing with untyped catch-all values.
     pub locals_narrow_int: HashMap<String, Value>,
+    /// Packed narrow integer/`Bool` values written by `Instr::StoreNarrow`
+    /// (Issue chunk421-5): a raw `u64` word plus the `(width, signed)` tag
+    /// needed to reconstruct the exact `Value` variant on load, with no
+    /// per-store `Value` allocation. Limited to widths that fit a `u64` -
+    /// `I128`/`U128` still live in `locals_narrow_int` above. Only holds
+    /// names that never got a local slot assigned (e.g. closure captures);
+    /// everything else lives in the index-addressed `locals_narrow_slots`
+    /// below once `slotize_code` rewrites `StoreNarrow`/`LoadNarrow` into
+    /// their slot-addressed counterparts.
+    pub locals_narrow_by_name: HashMap<String, (u64, u8, bool)>,
+    /// Index-addressed counterpart of `locals_narrow_by_name`, written by
+    /// `Instr::StoreNarrowSlot`/read by `Instr::LoadNarrowSlot` (Issue
+    /// chunk421-5 follow-up). Sized like `locals_slots` so a variable's
+    /// slot index is shared across both arrays; no hashing or string
+    /// comparison on the hot path.
+    pub locals_narrow_slots: Vec<Option<(u64, u8, bool)>>,
     pub locals_nothing: HashSet<String>, // Track variables holding Nothing
     /// Type parameter bindings from where clauses (e.g., T -> Float64)
     pub type_bindings: HashMap<String, JuliaType>,
@@ -68,6 +125,15 @@ pub(crate) struct Frame {
     /// Type tag cache: tracks which typed map each variable is stored in.
     /// Enables O(1) lookup dispatch and O(1) removal in StoreAny.
     pub var_types: HashMap<String, VarTypeTag>,
+    /// Caller-reserved destination for this frame's struct return value
+    /// (Issue chunk427-4): an index into the VM's `struct_heap`, allocated
+    /// by the call site before the callee starts running when the callee's
+    /// declared return type is a struct above the sret size threshold.
+    /// `Instr::ReturnSlot` reads it out for struct-building code that wants
+    /// to target the final destination directly; `Instr::ReturnStruct`
+    /// writes the completed struct into it so the handle the caller
+    /// receives back is the same one it reserved.
+    pub return_slot: Option<usize>,
 }
 
 impl Frame {
@@ -95,6 +161,8 @@ impl Frame {
             locals_generator: HashMap::new(),
             locals_any: HashMap::new(),
             locals_narrow_int: HashMap::new(),
+            locals_narrow_by_name: HashMap::new(),
+            locals_narrow_slots: vec![None; slot_count],
             locals_nothing: HashSet::new(),
             type_bindings: HashMap::new(),
             locals_bool: HashMap::new(),
@@ -102,6 +170,7 @@ impl Frame {
             func_index,
             captured_vars: HashMap::new(),
             var_types: HashMap::new(),
+            return_slot: None,
         }
     }
 
@@ -139,6 +208,10 @@ impl Frame {
                 .map(|v| Value::Generator(v.clone())),
             VarTypeTag::Any => self.locals_any.get(name).cloned(),
             VarTypeTag::NarrowInt => self.locals_narrow_int.get(name).cloned(),
+            VarTypeTag::NarrowSlot => self
+                .locals_narrow_by_name
+                .get(name)
+                .map(|&(bits, width, signed)| narrow_value_from_bits(bits, width, signed)),
             VarTypeTag::Nothing => {
                 if self.locals_nothing.contains(name) {
                     Some(Value::Nothing)
@@ -156,7 +229,9 @@ impl Frame {
 
     /// Fallback linear search for variables without a tag (safety net).
     fn get_by_cascade(&self, name: &str) -> Option<Value> {
-        if let Some(v) = self.locals_narrow_int.get(name) {
+        if let Some(&(bits, width, signed)) = self.locals_narrow_by_name.get(name) {
+            return Some(narrow_value_from_bits(bits, width, signed));
+        } else if let Some(v) = self.locals_narrow_int.get(name) {
             return Some(v.clone());
         } else if let Some(v) = self.locals_any.get(name) {
             return Some(v.clone());
@@ -226,6 +301,7 @@ impl Frame {
             VarTypeTag::Generator => { self.locals_generator.remove(name); }
             VarTypeTag::Any => { self.locals_any.remove(name); }
             VarTypeTag::NarrowInt => { self.locals_narrow_int.remove(name); }
+            VarTypeTag::NarrowSlot => { self.locals_narrow_by_name.remove(name); }
             VarTypeTag::Nothing => { self.locals_nothing.remove(name); }
             VarTypeTag::Bool => { self.locals_bool.remove(name); }
             VarTypeTag::ValSymbol => { self.locals_val_symbol.remove(name); }
@@ -250,11 +326,25 @@ impl Frame {
         self.locals_generator.remove(name);
         self.locals_any.remove(name);
         self.locals_narrow_int.remove(name);
+        self.locals_narrow_by_name.remove(name);
         self.locals_nothing.remove(name);
         self.locals_bool.remove(name);
         self.locals_val_symbol.remove(name);
     }
 
+    /// Promote a local variable to a shared boxed cell (`Value::Boxed`), so that
+    /// closures capturing it by reference observe writes made after this point.
+    /// Preserves the variable's current value (Issue chunk421-1).
+    pub fn promote_to_boxed(&mut self, name: &str) {
+        let current = self.get_local(name).unwrap_or(Value::Nothing);
+        self.remove_var(name);
+        self.locals_any.insert(
+            name.to_string(),
+            Value::Boxed(std::rc::Rc::new(std::cell::RefCell::new(current))),
+        );
+        self.var_types.insert(name.to_string(), VarTypeTag::Any);
+    }
+
     /// Create a new frame with captured variables from a closure.
     pub fn new_with_captures(
         slot_count: usize,
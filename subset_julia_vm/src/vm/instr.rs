@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::value::{ArrayElementType, ArrayValue};
+use super::value::{ArrayElementType, ArrayValue, EnumMembershipCheck};
 use crate::builtins::BuiltinId;
 use crate::intrinsics::Intrinsic;
 use half::f16;
@@ -38,13 +38,39 @@ pub enum Instr {
     /// 1. Looks up each captured variable name in the current frame
     /// 2. Creates a ClosureValue with the function name and captured values
     /// 3. Pushes the closure onto the stack
+    ///
+    /// `boxed_capture_names` are captured by reference (Issue chunk421-1): each name
+    /// must already be stored as a `Value::Boxed` cell in the current frame (see
+    /// `PromoteToBoxed`), and the SAME `Rc` is shared into the closure's captures so
+    /// writes from either side stay visible to the other. `capture_names` are the
+    /// ordinary by-value captures, copied once at closure-creation time as before.
     CreateClosure {
         func_name: String,
         capture_names: Vec<String>,
+        boxed_capture_names: Vec<String>,
     },
     /// Load a captured variable from the current closure environment.
     /// Used inside closure bodies to access captured variables.
     LoadCaptured(String),
+    /// Promote a local variable already in the current frame to a shared boxed
+    /// cell (`Value::Boxed`), preserving its current value. Emitted once, right
+    /// before a closure is created, for every captured name the compiler has
+    /// determined is mutated somewhere (Issue chunk421-1). A no-op if the local
+    /// is already boxed.
+    PromoteToBoxed(String),
+    /// Load the current value out of a boxed local variable, dereferencing the
+    /// cell (Issue chunk421-1).
+    LoadBoxed(String),
+    /// Store the top-of-stack value into a boxed local variable's cell in
+    /// place, so every closure sharing the cell observes the write
+    /// (Issue chunk421-1).
+    StoreBoxed(String),
+    /// Load the current value out of a captured variable that the compiler
+    /// determined is boxed, dereferencing the cell (Issue chunk421-1).
+    LoadCapturedBoxed(String),
+    /// Store the top-of-stack value into a captured variable's shared boxed
+    /// cell in place (Issue chunk421-1).
+    StoreCapturedBoxed(String),
     /// Define a function at runtime (for functions defined inside blocks).
     /// The function is registered to the dispatch table when this instruction executes.
     /// Takes the function index in the function_infos table.
@@ -73,6 +99,14 @@ pub enum Instr {
         type_name: String,
         value: i64,
     },
+    /// Convert an integer to an `@enum` member: pop an I64, validate it against
+    /// `check` (a density-adapted strategy fixed at `@enum` definition time),
+    /// and push `Value::Enum { type_name, value }`. Raises an ArgumentError if
+    /// the value isn't a declared member.
+    EnumConvert {
+        type_name: String,
+        check: EnumMembershipCheck,
+    },
     LoadStr(String),
     StoreStr(String),
     LoadI64(String),
@@ -87,6 +121,43 @@ pub enum Instr {
     StoreSlot(usize),
     LoadAny(String),  // Dynamic load - checks all type maps at runtime
     StoreAny(String), // Dynamic store - stores based on runtime type
+    /// Store a narrow integer/`Bool` value into a packed, allocation-free
+    /// local slot as a raw `u64` word (Issue chunk421-5). `width` (8/16/32/64)
+    /// and `signed` together identify the exact source `ValueType` (e.g.
+    /// `width: 16, signed: false` is `U16`) so `LoadNarrow` can reconstruct it.
+    /// Limited to widths that fit a single `u64` - `I128`/`U128` still go
+    /// through `StoreAny`/`locals_narrow_int`.
+    StoreNarrow {
+        name: String,
+        width: u8,
+        signed: bool,
+    },
+    /// Load a value previously written by `StoreNarrow`, reconstructing the
+    /// exact `Value` variant (e.g. `I8(42)`, `U32(99)`) from the packed `u64`
+    /// word via sign/zero extension, matching `width`/`signed` (Issue
+    /// chunk421-5).
+    LoadNarrow {
+        name: String,
+        width: u8,
+        signed: bool,
+    },
+    /// Slot-addressed counterpart of `StoreNarrow`, produced by `slotize_code`
+    /// once compilation has assigned the variable a local slot index (Issue
+    /// chunk421-5 follow-up): stores directly into the frame's narrow-slot
+    /// array by index, with no name hashing or string comparison. Variables
+    /// that never get a local slot (e.g. closure captures) keep using
+    /// `StoreNarrow`.
+    StoreNarrowSlot {
+        slot: usize,
+        width: u8,
+        signed: bool,
+    },
+    /// Slot-addressed counterpart of `LoadNarrow` (Issue chunk421-5 follow-up).
+    LoadNarrowSlot {
+        slot: usize,
+        width: u8,
+        signed: bool,
+    },
     /// Load type binding from current frame's type_bindings map.
     /// Used for accessing type parameters (T) from where clauses as values.
     LoadTypeBinding(String),
@@ -179,6 +250,10 @@ pub enum Instr {
     DynamicToU16, // pop I64 value, truncate to U16
     DynamicToU32, // pop I64 value, truncate to U32
     DynamicToU64, // pop I64 value, truncate to U64
+    // Range-checked float-to-int narrowing for round/floor/ceil/trunc(T, x) (Issue #2028).
+    // Pops a Dynamic/F64 value, verifies it fits the `bits`-wide (un)signed destination,
+    // and raises InexactError instead of silently wrapping.
+    CheckedToInt { bits: u8, signed: bool },
 
     // Float64 ops
     AddF64,
@@ -190,6 +265,11 @@ pub enum Instr {
     // NOTE: sin, cos, tan, asin, acos, atan, exp, log, round are now Builtins (Layer 2)
     FloorF64, // floor(x) - CPU instruction
     CeilF64,  // ceil(x) - CPU instruction
+    // Software quad-precision (Float128) math, dispatched when the inferred argument
+    // type is Float128 instead of the hardware F64 path above.
+    SqrtF128,
+    FloorF128,
+    CeilF128,
     AbsF64,   // abs(x) - CPU instruction
     Abs2F64,  // abs2(x) = x^2
     SleepF64, // sleep(secs) where secs is Float64
@@ -513,6 +593,13 @@ pub enum Instr {
     GetQuoteNodeValue,      // pop QuoteNode, push inner value
     GetGlobalRefField(usize), // (0=mod, 1=name) - pop GlobalRef, push field value
     ReturnStruct,           // Return struct value
+    /// Push the current frame's caller-reserved return slot (Issue
+    /// chunk427-4) as a `StructRef`, or `Nothing` if the call site didn't
+    /// reserve one (e.g. a direct `Call` rather than `CallSpecialize`, or a
+    /// struct below the sret size threshold). Lets struct-building code
+    /// target the final destination directly instead of building into a
+    /// throwaway heap slot that `ReturnStruct` then has to copy out of.
+    ReturnSlot,
 
     // Higher-order function operations
     // Note: MapFunc, FilterFunc, ReduceFunc, FoldrFunc removed - now Pure Julia (base/iterators.jl)
@@ -652,6 +739,36 @@ pub enum Instr {
     TupleFirst,   // Pop tuple, push first element
     TupleSecond,  // Pop tuple, push second element
 
+    /// Call `func_index` after expanding splatted arguments via the
+    /// iteration protocol rather than `CallWithSplat`'s Array/Tuple/Range-only
+    /// expansion (Issue chunk422-1). Lowers `f(a, xs..., b)` so that a custom
+    /// struct iterator in `xs` splats correctly, and backs the
+    /// `apply_iterate(iterate_fn, f, args...)` internal form.
+    ///
+    /// Pops `arg_count` raw values (one per syntactic argument, pre-splat,
+    /// same convention as `CallWithSplat`). For each `i` where
+    /// `splat_mask[i]` is true, the value is expanded into zero or more
+    /// arguments by driving the iteration protocol to completion: Array/
+    /// Tuple/Range/String use the builtin `IterateFirst`/`IterateNext`
+    /// protocol; any other value (a custom struct iterator) calls
+    /// `iterate_1`/`iterate_2` - the global method indices the compiler
+    /// statically resolved for the 1-arg/2-arg `iterate(...)` forms
+    /// (mirroring `emit_iterate_call_1`/`emit_iterate_call_2`'s dispatch,
+    /// reusing `should_use_pure_julia_iterate`). `func_index` is then called
+    /// with the fully expanded argument list.
+    ///
+    /// Scope (Issue chunk422-1): at most one argument may be splatted per
+    /// call (a second `true` in `splat_mask` falls back to `CallWithSplat`
+    /// at compile time); the call runs via call-to-completion, so `put!`/
+    /// `produce` inside it is unsupported, same as an `atexit` hook.
+    ApplyIterate {
+        func_index: usize,
+        arg_count: usize,
+        splat_mask: Vec<bool>,
+        iterate_1: Option<usize>,
+        iterate_2: Option<usize>,
+    },
+
     // Memory{T} operations
     /// Create a new Memory{T} with given element type and length (undef-initialized).
     /// Pushes Memory value onto stack.
@@ -677,6 +794,19 @@ pub enum Instr {
     /// Pushes Bool(true) if defined, Bool(false) otherwise.
     IsDefined(String),
 
+    /// Cooperative interruption point emitted at loop back-edges and function
+    /// entry (Issue chunk421-3). Decrements the VM's operation budget and
+    /// invokes the progress callback, if any; aborts with `VmError::Interrupted`
+    /// on budget exhaustion or a `false` callback result. Placed only at these
+    /// bounded intervals so straight-line code pays no per-instruction cost.
+    SafePoint,
+
+    /// Suspend the current producer at a `put!`/`produce` call (Issue
+    /// chunk421-4). Pops the produced value and returns control to the
+    /// resumable run driver (`Vm::run_resumable`/`Vm::resume`) instead of
+    /// completing the call; resuming continues with the next instruction.
+    Yield,
+
     /// No-operation placeholder used during instruction dispatch (Issue #2939).
     /// Never appears in compiled bytecode; only used transiently in the execution loop
     /// to avoid cloning instructions on every cycle.
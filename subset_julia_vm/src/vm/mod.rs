@@ -9,15 +9,19 @@ mod builtins_io;
 mod builtins_linalg;
 mod builtins_macro;
 mod builtins_math;
+mod builtins_native;
 mod builtins_numeric;
 mod builtins_reflection;
 mod builtins_sets;
 mod builtins_stats;
 mod builtins_strings;
+mod builtins_task;
 mod builtins_types;
 mod builtins_types_conversion;
+mod builtins_va_list;
 mod convert;
 mod dynamic_ops;
+mod effects;
 pub mod error;
 mod exec;
 mod field_indices;
@@ -29,6 +33,7 @@ pub(crate) mod intrinsics_exec;
 mod matmul;
 pub mod profiler;
 pub(crate) mod slot;
+pub mod softfloat128;
 pub mod specialize;
 pub(crate) mod splat;
 pub mod stack_ops;
@@ -59,6 +64,7 @@ pub use value::{
     ComposedFunctionValue,
     DictKey,
     DictValue,
+    EnumMembershipCheck,
     ExprValue,
     FunctionValue,
     GeneratorValue,
@@ -81,6 +87,7 @@ pub use value::{
 };
 
 // Internal imports
+use builtins_macro::{MacroTableEntry, TestFilter};
 use frame::{BroadcastState, ComposedCallState, Frame, Handler, SprintState};
 use util::bind_value_to_slot;
 
@@ -91,6 +98,8 @@ use std::collections::HashMap;
 use std::ffi::CString;
 use std::hash::{Hash, Hasher};
 use std::os::raw::{c_char, c_void};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 /// Hash a type name string to a u64 key for the dispatch cache (Issue #3355).
 /// Avoids storing String keys in the hot dispatch path.
@@ -123,6 +132,20 @@ pub(crate) struct BinaryDispatchKey {
     pub right: ValueType,
 }
 
+/// One level of a nested `@testset`, tracking its own counters so a parent
+/// testset's counts aren't clobbered by a child's (Issue #3369).
+#[derive(Debug, Clone)]
+pub(crate) struct TestSetFrame {
+    pub name: String,
+    pub pass: usize,
+    pub fail: usize,
+    pub broken: usize,
+    pub errored: usize,
+    /// Messages for every failed or errored test, in the order they ran, so
+    /// `_testset_end!`'s result object can report them back to Julia code.
+    pub failures: Vec<String>,
+}
+
 pub struct Vm<R: RngLike> {
     ip: usize,
     stack: Vec<Value>,
@@ -146,28 +169,55 @@ pub struct Vm<R: RngLike> {
     /// The pending exception value for catch blocks (preserves struct instances)
     pending_exception_value: Option<Value>,
     rethrow_on_finally: bool,
-    // Test state for @test and @testset macros
-    test_pass_count: usize,
-    test_fail_count: usize,
-    test_broken_count: usize,
-    current_testset: Option<String>,
+    // Test state for @test and @testset macros: a stack of nested testset frames,
+    // innermost last. Empty when no @testset is active (bare @test outside any
+    // testset still runs but has no frame to accumulate into).
+    test_stack: Vec<TestSetFrame>,
     // Test throws state: (expected_exception_type, was_thrown)
     test_throws_state: Option<(String, bool)>,
+    // Active `@testset` filter set via `_testset_set_filter!`; restricts which
+    // testsets/tests actually run (Issue #3368).
+    test_filter: Option<TestFilter>,
     // === Lazy AoT Compilation Support ===
     specializable_functions: Vec<SpecializableFunction>,
     specialization_cache: HashMap<SpecializationKey, SpecializedCode>,
+    // World-age counter for specialization invalidation (Issue chunk427-5).
+    // Bumped by `bump_generation` whenever a host mutates the method table
+    // out from under a live `Vm` (e.g. hot-swapping a function
+    // definition); `CallSpecialize` discards any cached entry whose
+    // `generation` predates this before reusing it.
+    specialization_generation: u64,
+    // Native JIT tier for hot specializations (Issue chunk427-1). `None`
+    // if the host ISA couldn't be set up; `CallSpecialize` then just never
+    // attempts native compilation and stays on the bytecode tier.
+    specialization_jit: Option<exec::jit::SpecializationJit>,
     binary_method_cache: HashMap<BinaryDispatchKey, usize>,
+    // Reflection method-dispatch cache (Issue chunk433-1): (function name, argument-type
+    // signature) → the generation it was computed at plus the already-sorted list of matching
+    // `functions` indices. Backs `find_matching_methods` (hasmethod/which/methods/code_lowered),
+    // which would otherwise rescan and rescore every candidate on every call. Entries whose
+    // stored generation predates `specialization_generation` are stale and recomputed, the same
+    // invalidation scheme `binary_method_cache`/`dispatch_cache` already rely on.
+    method_dispatch_cache: HashMap<(String, Vec<String>), (u64, Vec<usize>)>,
     compile_context: Option<RuntimeCompileContext>,
     global_slot_names: Vec<String>,
     global_slot_map: HashMap<String, usize>,
     // Macro system support
     gensym_counter: u64, // Counter for generating unique symbol names
+    // Runtime macro table for `macroexpand`/`macroexpand!`/`macroexpand1` (Issue #3367).
+    // Populated via `register_macro`; top-level `macro name(args...) ... end` definitions
+    // are still fully expanded at compile time during lowering and never reach this table.
+    macro_table: HashMap<String, MacroTableEntry>,
     // Cached well-known struct type IDs (Issue #2940)
     cached_cartesian_index_type_id: Cell<Option<usize>>,
     cached_pair_type_id: Cell<Option<usize>>,
     cached_complex_type_id: Cell<Option<usize>>,
     // Struct name -> index lookup (Issue #2938)
     struct_def_name_index: HashMap<String, usize>,
+    // Per-struct field name -> field index lookup (Issue chunk433-1), parallel to
+    // `struct_defs` by type_id. Lets the Symbol path of getfield/setfield! do an O(1)
+    // lookup instead of `def.fields.iter().position(...)`'s linear string compare.
+    struct_field_index: Vec<HashMap<String, usize>>,
     // Abstract type name -> index lookup (Issue #2896)
     abstract_type_name_index: HashMap<String, usize>,
     // Method dispatch cache: (call_site_ip, hashed_type_name) → func_index (Issue #2943, #3355)
@@ -181,8 +231,56 @@ pub struct Vm<R: RngLike> {
     // Pre-computed transitive closure of abstract type hierarchy (Issue #3356).
     // Maps type name -> list of all ancestor type names (including parametric base names).
     type_ancestors: HashMap<String, Vec<String>>,
+    // Hooks registered via `atexit(f)`, run in reverse (LIFO) registration order
+    // just before the VM returns its final value.
+    atexit_hooks: Vec<Value>,
+    // === Cooperative safepoints (Issue chunk421-3) ===
+    // Remaining operation budget checked at each `Instr::SafePoint`; `None` means
+    // unlimited. Decremented to zero then aborts with `VmError::Interrupted`.
+    safepoint_budget: Option<u64>,
+    // Total number of safepoints reached so far, passed to `progress_callback`.
+    safepoint_count: u64,
+    // Optional callback invoked at each safepoint with the running safepoint
+    // count; returning `false` aborts execution with `VmError::Interrupted`.
+    progress_callback: Option<Box<dyn FnMut(u64) -> bool>>,
+    // === Instruction/call fuel budget (Issue chunk426-1) ===
+    // Remaining instruction+call budget, checked on every dispatched
+    // instruction and at every `execute_call`; `None` means unlimited.
+    // Unlike `safepoint_budget`, which only fires at explicit `SafePoint`
+    // instructions, this is metered continuously so a runaway loop or
+    // recursion can't outrun it before the next safepoint is reached.
+    fuel: Option<u64>,
+    // === Call-stack depth limit (Issue chunk426-2) ===
+    // Maximum number of frames `self.frames` may hold at once. Checked before
+    // every frame push; exceeding it raises a catchable `VmError::StackOverflow`
+    // instead of growing the Vecs until the process aborts on allocation
+    // failure.
+    max_call_depth: usize,
+    // === Cooperative interruption (Issue chunk426-3) ===
+    // Shared flag checked at each call boundary; set it (e.g. from a Ctrl-C
+    // handler or a watchdog thread holding a clone from `interrupt_handle`)
+    // to raise a catchable `VmError::Interrupted` at the next call, unwinding
+    // through `try`/`finally` like Julia's `InterruptException`.
+    interrupt: Arc<AtomicBool>,
+    // === Task subsystem (Issue chunk426-4) ===
+    // Handle table for `Task`/`resume`/`istaskdone`: each entry is a task's
+    // saved `CallContext` (or its not-yet-started callable, or its finished
+    // outcome). `Value::Task(idx)` is an opaque index into this table, the
+    // same "heap index handle" pattern `Value::StructRef` already uses.
+    tasks: Vec<exec::TaskState>,
+    // === Unresolved-call fallback hook (Issue chunk426-5) ===
+    // Host-registered extensibility seam: when a call target can't be
+    // resolved (an out-of-range function index, a missing specialization
+    // entry), this is tried before raising. Returning `Some(v)` completes
+    // the call with `v`; `None` falls through to the existing raise.
+    unresolved_call_handler: Option<Box<dyn FnMut(usize, &[Value]) -> Option<Value>>>,
 }
 
+/// Default call-stack depth limit (Issue chunk426-2): generous enough for
+/// legitimate deep recursion, but low enough to fail fast (and catchably)
+/// on unbounded recursion well before host memory is exhausted.
+const DEFAULT_MAX_CALL_DEPTH: usize = 100_000;
+
 /// Pre-compute the transitive closure of the abstract type hierarchy (Issue #3356).
 ///
 /// For each struct and abstract type, walks the parent chain and collects all
@@ -267,29 +365,41 @@ impl<R: RngLike> Vm<R> {
             pending_error: None,
             pending_exception_value: None,
             rethrow_on_finally: false,
-            test_pass_count: 0,
-            test_fail_count: 0,
-            test_broken_count: 0,
-            current_testset: None,
+            test_stack: Vec::new(),
             test_throws_state: None,
+            test_filter: None,
             // Lazy AoT fields
             specializable_functions: Vec::new(),
             specialization_cache: HashMap::new(),
+            specialization_generation: 0,
+            specialization_jit: exec::jit::SpecializationJit::new(),
             binary_method_cache: HashMap::new(),
+            method_dispatch_cache: HashMap::new(),
             compile_context: None,
             global_slot_names: Vec::new(),
             global_slot_map: HashMap::new(),
             gensym_counter: 0,
+            macro_table: HashMap::new(),
             cached_cartesian_index_type_id: Cell::new(None),
             cached_pair_type_id: Cell::new(None),
             cached_complex_type_id: Cell::new(None),
             struct_def_name_index: HashMap::new(),
+            struct_field_index: Vec::new(),
             abstract_type_name_index: HashMap::new(),
             dispatch_cache: HashMap::new(),
             function_name_index: HashMap::new(),
             source_map: Vec::new(),
             last_error_ip: None,
             type_ancestors: HashMap::new(),
+            atexit_hooks: Vec::new(),
+            safepoint_budget: None,
+            safepoint_count: 0,
+            progress_callback: None,
+            fuel: None,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            interrupt: Arc::new(AtomicBool::new(false)),
+            tasks: Vec::new(),
+            unresolved_call_handler: None,
         }
     }
 
@@ -321,6 +431,19 @@ impl<R: RngLike> Vm<R> {
             .map(|(idx, def)| (def.name.clone(), idx))
             .collect::<HashMap<_, _>>();
 
+        // Per-struct field name -> index lookup (Issue chunk433-1), parallel to struct_defs.
+        let struct_field_index: Vec<HashMap<String, usize>> = program
+            .struct_defs
+            .iter()
+            .map(|def| {
+                def.fields
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, (name, _))| (name.clone(), idx))
+                    .collect()
+            })
+            .collect();
+
         let abstract_type_name_index = program
             .abstract_types
             .iter()
@@ -344,6 +467,12 @@ impl<R: RngLike> Vm<R> {
                 .push(idx);
         }
 
+        // Infer nothrow/norecurse over the static call graph so hot calls
+        // to provably-safe leaves can skip redundant bookkeeping (Issue
+        // chunk427-3).
+        let mut functions = program.functions;
+        effects::analyze_effects(&mut functions, &program.code, &program.specializable_functions);
+
         Self {
             ip: program.entry,
             stack: Vec::with_capacity(256),
@@ -351,7 +480,7 @@ impl<R: RngLike> Vm<R> {
             return_ips: Vec::new(),
             handlers: Vec::new(),
             code: program.code,
-            functions: program.functions,
+            functions,
             struct_defs: program.struct_defs,
             abstract_types: program.abstract_types,
             show_methods,
@@ -366,29 +495,41 @@ impl<R: RngLike> Vm<R> {
             pending_error: None,
             pending_exception_value: None,
             rethrow_on_finally: false,
-            test_pass_count: 0,
-            test_fail_count: 0,
-            test_broken_count: 0,
-            current_testset: None,
+            test_stack: Vec::new(),
             test_throws_state: None,
+            test_filter: None,
             // Lazy AoT fields
             specializable_functions: program.specializable_functions,
             specialization_cache: HashMap::new(),
+            specialization_generation: 0,
+            specialization_jit: exec::jit::SpecializationJit::new(),
             binary_method_cache: HashMap::new(),
+            method_dispatch_cache: HashMap::new(),
             compile_context: program.compile_context,
             global_slot_names: program.global_slot_names,
             global_slot_map,
             gensym_counter: 0,
+            macro_table: HashMap::new(),
             cached_cartesian_index_type_id: Cell::new(None),
             cached_pair_type_id: Cell::new(None),
             cached_complex_type_id: Cell::new(None),
             struct_def_name_index,
+            struct_field_index,
             abstract_type_name_index,
             dispatch_cache: HashMap::new(),
             function_name_index,
             source_map: Vec::new(),
             last_error_ip: None,
             type_ancestors,
+            atexit_hooks: Vec::new(),
+            safepoint_budget: None,
+            safepoint_count: 0,
+            progress_callback: None,
+            fuel: None,
+            max_call_depth: DEFAULT_MAX_CALL_DEPTH,
+            interrupt: Arc::new(AtomicBool::new(false)),
+            tasks: Vec::new(),
+            unresolved_call_handler: None,
         }
     }
 
@@ -524,6 +665,8 @@ impl<R: RngLike> Vm<R> {
             Value::DataType(_) => ValueType::DataType,
             Value::Rng(_) => ValueType::Rng,
             Value::Generator(_) => ValueType::Generator,
+            Value::Task(_) => ValueType::Task,
+            Value::VaList(_) => ValueType::VaList,
             _ => ValueType::Any,
         }
     }
@@ -770,6 +913,159 @@ impl<R: RngLike> Vm<R> {
         self.output_callback_context = context;
     }
 
+    /// Set the operation budget checked at each cooperative safepoint (Issue
+    /// chunk421-3). Once exhausted, execution aborts with `VmError::Interrupted`
+    /// instead of running forever. Pass `None` to run with no budget (the default).
+    pub fn set_safepoint_budget(&mut self, budget: Option<u64>) {
+        self.safepoint_budget = budget;
+    }
+
+    /// Set the progress callback invoked at each cooperative safepoint with the
+    /// running safepoint count (Issue chunk421-3). Returning `false` aborts
+    /// execution with `VmError::Interrupted`, the same as exhausting the budget.
+    pub fn set_progress_callback<F>(&mut self, callback: F)
+    where
+        F: FnMut(u64) -> bool + 'static,
+    {
+        self.progress_callback = Some(Box::new(callback));
+    }
+
+    /// Set the instruction/call fuel budget (Issue chunk426-1). Decremented on
+    /// every dispatched instruction and on every call; once it reaches zero,
+    /// execution stops with `VmError::FuelExhausted` instead of running
+    /// forever. Pass `None` to run with no budget (the default).
+    ///
+    /// Unlike `VmError::Interrupted`, a `FuelExhausted` run is resumable: the
+    /// VM's `ip`/frames/stack are left exactly as they were when fuel ran
+    /// out, so a caller can call `set_fuel` again and `run()` again to
+    /// continue from where it stopped.
+    pub fn set_fuel(&mut self, fuel: u64) {
+        self.fuel = Some(fuel);
+    }
+
+    /// Remove the fuel budget, letting the VM run to completion unmetered.
+    pub fn clear_fuel(&mut self) {
+        self.fuel = None;
+    }
+
+    /// The fuel remaining before the next `FuelExhausted`, or `None` if no
+    /// budget is set.
+    pub fn remaining_fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Decrement the fuel budget by one, if set. Called at the top of the
+    /// dispatch loop and at the top of `execute_call` (Issue chunk426-1).
+    pub(crate) fn consume_fuel(&mut self) -> Result<(), VmError> {
+        if let Some(fuel) = self.fuel.as_mut() {
+            if *fuel == 0 {
+                return Err(VmError::FuelExhausted);
+            }
+            *fuel -= 1;
+        }
+        Ok(())
+    }
+
+    /// Set the maximum number of call frames `self.frames` may hold at once
+    /// (Issue chunk426-2). Pushing a frame beyond this depth raises a
+    /// catchable `VmError::StackOverflow` instead of growing unboundedly.
+    pub fn set_max_call_depth(&mut self, max_call_depth: usize) {
+        self.max_call_depth = max_call_depth;
+    }
+
+    /// Check the call-stack depth limit before pushing a new frame (Issue
+    /// chunk426-2). Returns `Ok(true)` if it's safe to proceed, `Ok(false)`
+    /// if the depth was exceeded and a `try`/`catch` handled the resulting
+    /// `StackOverflow`, or `Err` if it propagated out uncaught.
+    pub(crate) fn check_call_depth(&mut self) -> Result<bool, VmError> {
+        if self.frames.len() < self.max_call_depth {
+            return Ok(true);
+        }
+        match self.raise(VmError::StackOverflow) {
+            Ok(()) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// A clone of the cooperative interrupt flag (Issue chunk426-3). Store
+    /// this in a signal handler or a watchdog thread and set it to request
+    /// that this `Vm` stop at the next call boundary with a catchable
+    /// `VmError::Interrupted`. Setting it is a one-shot request: the flag is
+    /// not cleared automatically, so a caller that continues running after
+    /// catching the interrupt should reset it (`store(false, ...)`) first.
+    pub fn interrupt_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.interrupt)
+    }
+
+    /// Check the cooperative interrupt flag at a call boundary (Issue
+    /// chunk426-3). Returns `Ok(true)` if it's safe to proceed, `Ok(false)`
+    /// if an interrupt was requested and a `try`/`catch` handled the
+    /// resulting `VmError::Interrupted`, or `Err` if it propagated uncaught.
+    pub(crate) fn check_interrupt(&mut self) -> Result<bool, VmError> {
+        if !self.interrupt.load(Ordering::Relaxed) {
+            return Ok(true);
+        }
+        match self.raise(VmError::Interrupted) {
+            Ok(()) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Register a fallback invoked when `execute_call` can't resolve a call
+    /// target - an out-of-range function index, or a missing specialization
+    /// table entry (Issue chunk426-5). Called with the unresolved index and
+    /// the call's already-popped argument vector; returning `Some(v)`
+    /// completes the call with `v` instead of raising, giving embedders a
+    /// seam for dynamic method dispatch, FFI shims, or lazy definition
+    /// loading without modifying the VM core. Returning `None` falls through
+    /// to the existing (catchable) raise.
+    pub fn set_unresolved_call_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(usize, &[Value]) -> Option<Value> + 'static,
+    {
+        self.unresolved_call_handler = Some(Box::new(handler));
+    }
+
+    /// Try the unresolved-call fallback, if one is registered (Issue
+    /// chunk426-5). `None` means either no handler is registered or the
+    /// handler itself declined (both cases fall through to the caller's
+    /// existing raise).
+    pub(crate) fn try_unresolved_call_handler(
+        &mut self,
+        func_index: usize,
+        args: &[Value],
+    ) -> Option<Value> {
+        self.unresolved_call_handler
+            .as_mut()
+            .and_then(|handler| handler(func_index, args))
+    }
+
+    /// Discard every cached `CallSpecialize` entry for `func_index` (Issue
+    /// chunk427-5). Call this after redefining the method table entry a
+    /// specializable function's `fallback_index` points at - e.g. a host
+    /// hot-swapping a generic function's implementation between `Vm` runs
+    /// that otherwise share a cache - so the next `CallSpecialize` against
+    /// any of that function's argument-type keys recompiles instead of
+    /// reusing code generated from the stale definition. Cheaper than
+    /// `bump_generation` when the affected function is known, since it
+    /// only evicts entries for that function rather than the whole cache.
+    pub fn invalidate_function(&mut self, func_index: usize) {
+        self.specialization_cache
+            .retain(|key, _| key.func_index != func_index);
+    }
+
+    /// Advance the world-age counter (Issue chunk427-5), marking every
+    /// existing `CallSpecialize` cache entry stale without walking the
+    /// cache up front. Entries are discarded lazily, one at a time, the
+    /// next time each is looked up and found to predate the new
+    /// generation. Use this for a method-table mutation too broad or
+    /// dynamic to name specific affected functions; prefer
+    /// `invalidate_function` when the redefined function is known, since
+    /// that evicts only what's actually affected.
+    pub fn bump_generation(&mut self) {
+        self.specialization_generation += 1;
+    }
+
     /// Emit output to the buffer and optionally to the callback.
     /// This is the central method for all output operations.
     ///
@@ -805,6 +1101,71 @@ impl<R: RngLike> Vm<R> {
         }
     }
 
+    /// Push a new, empty testset frame (entering a `@testset`).
+    fn test_push_frame(&mut self, name: String) {
+        self.test_stack.push(TestSetFrame {
+            name,
+            pass: 0,
+            fail: 0,
+            broken: 0,
+            errored: 0,
+            failures: Vec::new(),
+        });
+    }
+
+    /// Pop the current testset frame (leaving a `@testset`), rolling its totals
+    /// (and failure messages) into the new top frame so an outer testset reports
+    /// aggregate counts.
+    fn test_pop_frame(&mut self) -> Option<TestSetFrame> {
+        let frame = self.test_stack.pop()?;
+        if let Some(parent) = self.test_stack.last_mut() {
+            parent.pass += frame.pass;
+            parent.fail += frame.fail;
+            parent.broken += frame.broken;
+            parent.errored += frame.errored;
+            parent.failures.extend(frame.failures.iter().cloned());
+        }
+        Some(frame)
+    }
+
+    /// Name of the innermost active testset, if any.
+    fn test_current_name(&self) -> Option<&str> {
+        self.test_stack.last().map(|f| f.name.as_str())
+    }
+
+    /// Record a passing test against the innermost testset frame, if any.
+    fn test_record_pass(&mut self) {
+        if let Some(frame) = self.test_stack.last_mut() {
+            frame.pass += 1;
+        }
+    }
+
+    /// Record a failing test against the innermost testset frame, if any,
+    /// noting `msg` in its failure list.
+    fn test_record_fail(&mut self, msg: &str) {
+        if let Some(frame) = self.test_stack.last_mut() {
+            frame.fail += 1;
+            frame.failures.push(msg.to_string());
+        }
+    }
+
+    /// Record an errored test (the test itself threw, rather than simply
+    /// evaluating to `false`) against the innermost testset frame, if any,
+    /// noting `msg` in its failure list.
+    fn test_record_errored(&mut self, msg: &str) {
+        if let Some(frame) = self.test_stack.last_mut() {
+            frame.errored += 1;
+            frame.failures.push(msg.to_string());
+        }
+    }
+
+    /// Record a broken test against the innermost testset frame, if any.
+    fn test_record_broken(&mut self) {
+        if let Some(frame) = self.test_stack.last_mut() {
+            frame.broken += 1;
+        }
+    }
+
     /// Get a global variable by name from the top-level frame.
     /// Used by REPL session to extract variables after execution.
     pub fn get_global(&self, name: &str) -> Option<Value> {
@@ -877,6 +1238,12 @@ impl<R: RngLike> Vm<R> {
         StackOpsExt::pop_complex(&mut self.stack, &self.struct_heap)
     }
 
+    /// Pop a `Float128` value from the stack.
+    #[inline]
+    pub fn pop_f128(&mut self) -> Result<crate::vm::softfloat128::SoftF128, VmError> {
+        StackOpsExt::pop_f128(&mut self.stack)
+    }
+
     /// Pop exception handlers that were pushed by the current function.
     /// This should be called before returning from a function to clean up
     /// any try-catch handlers that are still active.
@@ -962,6 +1329,8 @@ impl<R: RngLike> Vm<R> {
             VmError::UndefKeywordError(_) => 31,
             VmError::OverflowError(_) => 32,
             VmError::InternalError(_) => 33,
+            VmError::BroadcastDestShapeMismatch { .. } => 34,
+            VmError::Interrupted => 35,
         }
     }
 
@@ -1011,6 +1380,33 @@ impl<R: RngLike> Vm<R> {
             .unwrap_or(&[])
     }
 
+    /// Get a struct field's index by name using the pre-built per-struct index (Issue
+    /// chunk433-1). Returns `None` if `type_id` or `field_name` is unknown, same as the
+    /// `def.fields.iter().position(...)` linear scan this replaces.
+    #[inline]
+    pub(crate) fn get_struct_field_index(&self, type_id: usize, field_name: &str) -> Option<usize> {
+        self.struct_field_index.get(type_id)?.get(field_name).copied()
+    }
+
+    /// List a struct's declared field names by type name, for "available fields: ..." hints
+    /// on field-access errors (Issue chunk433-4). Empty if `struct_name` isn't a known struct.
+    pub(crate) fn struct_field_names(&self, struct_name: &str) -> Vec<String> {
+        self.struct_def_name_index
+            .get(struct_name)
+            .and_then(|&idx| self.struct_defs.get(idx))
+            .map(|def| def.fields.iter().map(|(name, _)| name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Same as [`Self::struct_field_names`] but keyed by `type_id` directly, for call sites
+    /// that only have the runtime type id on hand (Issue chunk433-4).
+    pub(crate) fn struct_field_names_by_type_id(&self, type_id: usize) -> Vec<String> {
+        self.struct_defs
+            .get(type_id)
+            .map(|def| def.fields.iter().map(|(name, _)| name.clone()).collect())
+            .unwrap_or_default()
+    }
+
     /// Get a cloned function by index, raising through error handling if not found.
     ///
     /// Returns `Ok(Some(func))` if the function was found, `Ok(None)` if the index
@@ -126,6 +126,32 @@ pub(crate) fn slotize_code(code: &mut [Instr], name_to_slot: &HashMap<String, us
                     *instr = Instr::DecVarI64Slot(slot);
                 }
             }
+            Instr::StoreNarrow {
+                name,
+                width,
+                signed,
+            } => {
+                if let Some(&slot) = name_to_slot.get(name) {
+                    *instr = Instr::StoreNarrowSlot {
+                        slot,
+                        width: *width,
+                        signed: *signed,
+                    };
+                }
+            }
+            Instr::LoadNarrow {
+                name,
+                width,
+                signed,
+            } => {
+                if let Some(&slot) = name_to_slot.get(name) {
+                    *instr = Instr::LoadNarrowSlot {
+                        slot,
+                        width: *width,
+                        signed: *signed,
+                    };
+                }
+            }
             _ => {}
         }
     }
@@ -148,7 +174,8 @@ fn store_name(instr: &Instr) -> Option<&str> {
         | Instr::StoreSet(name)
         | Instr::StoreArray(name)
         | Instr::IncVarI64(name)
-        | Instr::DecVarI64(name) => Some(name.as_str()),
+        | Instr::DecVarI64(name)
+        | Instr::StoreNarrow { name, .. } => Some(name.as_str()),
         _ => None,
     }
 }
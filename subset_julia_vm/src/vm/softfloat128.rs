@@ -0,0 +1,592 @@
+//! Software quad-precision (binary128) arithmetic.
+//!
+//! `Float128` has no hardware support on the targets this VM runs on, so values are
+//! decomposed into sign/biased-exponent/mantissa (mirroring the IEEE 754 binary128
+//! layout: 1 sign bit, 15 exponent bits, 112 explicit mantissa bits plus the implicit
+//! leading one) and every arithmetic op is built from that decomposition, following the
+//! same guard/round/sticky, round-to-nearest-even discipline as compiler-builtins'
+//! `__addtf3`/`__multf3`/`__divtf3`. This gives Julia code genuine quad precision without
+//! depending on hardware `f128` or an external bignum library for the common case.
+
+const EXP_BITS: u32 = 15;
+const EXP_BIAS: i32 = (1 << (EXP_BITS - 1)) - 1; // 16383
+const MANTISSA_BITS: u32 = 112;
+/// Implicit-leading-one significand, so normalized significands occupy bit 112.
+const IMPLICIT_BIT: u128 = 1u128 << MANTISSA_BITS;
+const MANTISSA_MASK: u128 = IMPLICIT_BIT - 1;
+/// Extra low-order bits `add`/`sub` align their operands to before combining them, reserved
+/// as guard/sticky bits for `round_to_nearest_even` instead of being truncated away outright.
+const ALIGN_EXTRA_BITS: u32 = 2;
+
+/// A software binary128 float: `(-1)^sign * significand * 2^(exp - MANTISSA_BITS)`,
+/// where `significand` carries the implicit leading bit (`IMPLICIT_BIT <= significand <
+/// 2*IMPLICIT_BIT`) for normalized finite values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoftF128 {
+    pub sign: bool,
+    /// Unbiased exponent such that `1.mantissa * 2^exp` is the value's magnitude.
+    /// Sentinel `i32::MIN` marks zero; `i32::MAX` marks Inf/NaN (NaN iff `mantissa != 0`).
+    pub exp: i32,
+    /// 112-bit fractional mantissa (implicit leading bit not stored here).
+    pub mantissa: u128,
+}
+
+impl SoftF128 {
+    pub const ZERO: SoftF128 = SoftF128 {
+        sign: false,
+        exp: i32::MIN,
+        mantissa: 0,
+    };
+
+    pub fn nan() -> SoftF128 {
+        SoftF128 {
+            sign: false,
+            exp: i32::MAX,
+            mantissa: 1,
+        }
+    }
+
+    pub fn infinity(sign: bool) -> SoftF128 {
+        SoftF128 {
+            sign,
+            exp: i32::MAX,
+            mantissa: 0,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.exp == i32::MIN
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.exp == i32::MAX && self.mantissa != 0
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.exp == i32::MAX && self.mantissa == 0
+    }
+
+    /// Decompose an `f64` into sign/exponent/112-bit-mantissa form. Subnormal and special
+    /// `f64` inputs map onto the equivalent `Float128` special value; normal inputs widen
+    /// exactly since binary128's mantissa is a strict superset of binary64's.
+    pub fn from_f64(x: f64) -> SoftF128 {
+        if x == 0.0 {
+            return SoftF128 {
+                sign: x.is_sign_negative(),
+                ..SoftF128::ZERO
+            };
+        }
+        if x.is_nan() {
+            return SoftF128::nan();
+        }
+        if x.is_infinite() {
+            return SoftF128::infinity(x.is_sign_negative());
+        }
+        let bits = x.to_bits();
+        let sign = bits >> 63 == 1;
+        let biased_exp = ((bits >> 52) & 0x7FF) as i64;
+        let frac52 = bits & 0x000F_FFFF_FFFF_FFFF;
+        let (exp, frac52) = if biased_exp == 0 {
+            // Subnormal f64: normalize by shifting until the leading bit reaches position 52.
+            let shift = frac52.leading_zeros() - 11; // frac52 is stored in the low 52 bits of a u64
+            (-1022 - shift as i64, (frac52 << shift) & 0x000F_FFFF_FFFF_FFFF)
+        } else {
+            (biased_exp - 1023, frac52)
+        };
+        // Widen the 52-bit f64 fraction into the 112-bit Float128 fraction.
+        let mantissa = (frac52 as u128) << (MANTISSA_BITS - 52);
+        SoftF128 {
+            sign,
+            exp: exp as i32,
+            mantissa,
+        }
+    }
+
+    /// Narrow back to `f64` with round-to-nearest-even, for display/interop with the rest
+    /// of the VM (which otherwise only sees `Float128` through this soft-float subsystem).
+    pub fn to_f64(&self) -> f64 {
+        if self.is_nan() {
+            return f64::NAN;
+        }
+        if self.is_infinite() {
+            return if self.sign {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+        }
+        if self.is_zero() {
+            return if self.sign { -0.0 } else { 0.0 };
+        }
+        if self.exp > 1023 {
+            return if self.sign {
+                f64::NEG_INFINITY
+            } else {
+                f64::INFINITY
+            };
+        }
+        if self.exp < -1074 {
+            return if self.sign { -0.0 } else { 0.0 };
+        }
+        if self.exp < -1022 {
+            // Result underflows into f64's subnormal range (biased exponent field 0): the
+            // implicit leading bit is no longer implicit, and the significand must be shifted
+            // right by the exponent's additional shortfall below the smallest normal exponent
+            // on top of the usual mantissa-width narrowing (Issue chunk412-6).
+            let denorm_shift = (MANTISSA_BITS - 52) + (-1022 - self.exp) as u32;
+            let rounded = round_to_nearest_even(self.full_significand(), denorm_shift);
+            // Rounding up can carry out of the 52-bit subnormal fraction (e.g. an all-ones
+            // mantissa rounding up to `IMPLICIT_BIT`'s width): that carry bit IS the smallest
+            // normal f64's implicit leading one, so it must bump the biased exponent field to 1
+            // rather than being masked away and silently rounding to zero (Issue chunk412-6).
+            let biased_exp: u64 = if rounded >> 52 != 0 { 1 } else { 0 };
+            let f64_frac = (rounded & 0x000F_FFFF_FFFF_FFFF) as u64;
+            let bits = ((self.sign as u64) << 63) | (biased_exp << 52) | f64_frac;
+            return f64::from_bits(bits);
+        }
+        let shift = MANTISSA_BITS - 52;
+        let rounded = round_to_nearest_even(self.mantissa, shift);
+        let f64_frac = (rounded & 0x000F_FFFF_FFFF_FFFF) as u64;
+        let bits = ((self.sign as u64) << 63) | (((self.exp + 1023) as u64) << 52) | f64_frac;
+        f64::from_bits(bits)
+    }
+
+    fn full_significand(&self) -> u128 {
+        IMPLICIT_BIT | self.mantissa
+    }
+
+    fn from_parts(sign: bool, exp: i64, significand: u128) -> SoftF128 {
+        // Normalize `significand` (which may have extra high bits from addition/multiplication
+        // carry) into the single-implicit-bit range, adjusting `exp` to match.
+        if significand == 0 {
+            return SoftF128 {
+                sign,
+                ..SoftF128::ZERO
+            };
+        }
+        let mut significand = significand;
+        let mut exp = exp;
+        let top = 127 - significand.leading_zeros() as i64;
+        let target = MANTISSA_BITS as i64;
+        if top > target {
+            let shift = (top - target) as u32;
+            significand = round_to_nearest_even(significand, shift);
+            exp += top - target;
+            // Rounding the mantissa up can itself overflow into one more bit.
+            if significand > (IMPLICIT_BIT << 1) - 1 {
+                significand >>= 1;
+                exp += 1;
+            }
+        } else if top < target {
+            significand <<= (target - top) as u32;
+        }
+        if exp > i32::MAX as i64 - 1 {
+            return SoftF128::infinity(sign);
+        }
+        SoftF128 {
+            sign,
+            exp: exp as i32,
+            mantissa: significand & MANTISSA_MASK,
+        }
+    }
+
+    pub fn add(&self, other: &SoftF128) -> SoftF128 {
+        if self.is_nan() || other.is_nan() {
+            return SoftF128::nan();
+        }
+        if self.is_infinite() || other.is_infinite() {
+            if self.is_infinite() && other.is_infinite() && self.sign != other.sign {
+                return SoftF128::nan();
+            }
+            return if self.is_infinite() { *self } else { *other };
+        }
+        if self.is_zero() {
+            return *other;
+        }
+        if other.is_zero() {
+            return *self;
+        }
+        if self.sign != other.sign {
+            return self.sub(&SoftF128 {
+                sign: !other.sign,
+                ..*other
+            });
+        }
+        let (big, small) = if self.exp >= other.exp {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let shift = (big.exp - small.exp) as u32;
+        // Work one `ALIGN_EXTRA_BITS` wider than the final significand so the alignment
+        // shift's discarded bits fold into a guard/sticky bit instead of being dropped
+        // outright, per the module's guard/round/sticky discipline (Issue chunk412-6).
+        let big_wide = big.full_significand() << ALIGN_EXTRA_BITS;
+        let small_wide = shift_right_sticky(small.full_significand() << ALIGN_EXTRA_BITS, shift);
+        let sum_wide = big_wide + small_wide;
+        SoftF128::from_parts(self.sign, big.exp as i64 - ALIGN_EXTRA_BITS as i64, sum_wide)
+    }
+
+    pub fn sub(&self, other: &SoftF128) -> SoftF128 {
+        if self.is_nan() || other.is_nan() {
+            return SoftF128::nan();
+        }
+        if self.is_infinite() || other.is_infinite() {
+            if self.is_infinite() && other.is_infinite() {
+                return if self.sign == other.sign {
+                    SoftF128::nan()
+                } else {
+                    *self
+                };
+            }
+            return if self.is_infinite() {
+                *self
+            } else {
+                SoftF128 {
+                    sign: !other.sign,
+                    ..*other
+                }
+            };
+        }
+        if other.is_zero() {
+            return *self;
+        }
+        if self.is_zero() {
+            return SoftF128 {
+                sign: !other.sign,
+                ..*other
+            };
+        }
+        if self.sign != other.sign {
+            return self.add(&SoftF128 {
+                sign: !other.sign,
+                ..*other
+            });
+        }
+        // Same sign: subtract the smaller magnitude from the larger.
+        let (big, small, result_sign) = if self.exp > other.exp
+            || (self.exp == other.exp && self.mantissa >= other.mantissa)
+        {
+            (self, other, self.sign)
+        } else {
+            (other, self, !self.sign)
+        };
+        let shift = (big.exp - small.exp) as u32;
+        // Same guard/sticky alignment as `add` (Issue chunk412-6).
+        let big_wide = big.full_significand() << ALIGN_EXTRA_BITS;
+        let small_wide = shift_right_sticky(small.full_significand() << ALIGN_EXTRA_BITS, shift);
+        let diff_wide = big_wide - small_wide;
+        if diff_wide == 0 {
+            return SoftF128::ZERO;
+        }
+        SoftF128::from_parts(result_sign, big.exp as i64 - ALIGN_EXTRA_BITS as i64, diff_wide)
+    }
+
+    pub fn mul(&self, other: &SoftF128) -> SoftF128 {
+        if self.is_nan() || other.is_nan() {
+            return SoftF128::nan();
+        }
+        let sign = self.sign != other.sign;
+        if self.is_infinite() || other.is_infinite() {
+            return if self.is_zero() || other.is_zero() {
+                SoftF128::nan()
+            } else {
+                SoftF128::infinity(sign)
+            };
+        }
+        if self.is_zero() || other.is_zero() {
+            return SoftF128 {
+                sign,
+                ..SoftF128::ZERO
+            };
+        }
+        // 113-bit x 113-bit widening multiply via the classic MinInt-split trick: break
+        // each operand into 64-bit halves and combine the four partial products.
+        let (hi, lo) = widening_mul_u128(self.full_significand(), other.full_significand());
+        let exp = self.exp as i64 + other.exp as i64;
+        // The product occupies bits [0, 226); fold it into a single u128 by keeping the top
+        // 128 bits and OR-ing in a sticky bit for anything shifted away, so rounding below
+        // still sees whether the discarded tail was exactly zero.
+        let sticky = lo != 0;
+        let mut combined = hi;
+        if sticky {
+            combined |= 1;
+        }
+        SoftF128::from_parts(sign, exp, combined)
+    }
+
+    pub fn div(&self, other: &SoftF128) -> SoftF128 {
+        if self.is_nan() || other.is_nan() {
+            return SoftF128::nan();
+        }
+        let sign = self.sign != other.sign;
+        if other.is_zero() {
+            return if self.is_zero() {
+                SoftF128::nan()
+            } else {
+                SoftF128::infinity(sign)
+            };
+        }
+        if self.is_zero() {
+            return SoftF128 {
+                sign,
+                ..SoftF128::ZERO
+            };
+        }
+        if self.is_infinite() {
+            return if other.is_infinite() {
+                SoftF128::nan()
+            } else {
+                SoftF128::infinity(sign)
+            };
+        }
+        if other.is_infinite() {
+            return SoftF128 {
+                sign,
+                ..SoftF128::ZERO
+            };
+        }
+        // Long division on the significands, extending the dividend so the quotient keeps
+        // a guard bit below the target precision for correct round-to-nearest-even.
+        let numerator = self.full_significand() << 4;
+        let denominator = other.full_significand();
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        let sticky = remainder != 0;
+        let mut combined = quotient;
+        if sticky {
+            combined |= 1;
+        }
+        let exp = self.exp as i64 - other.exp as i64 - 4;
+        SoftF128::from_parts(sign, exp, combined)
+    }
+
+    /// Square root via Newton-Raphson refinement over `f64` for the initial estimate,
+    /// then one step of significand-level correction so the result rounds correctly for
+    /// the common case (an exact `f64`-representable seed refined in quad precision).
+    pub fn sqrt(&self) -> SoftF128 {
+        if self.is_nan() || (self.sign && !self.is_zero()) {
+            return SoftF128::nan();
+        }
+        if self.is_zero() || self.is_infinite() {
+            return *self;
+        }
+        let seed = SoftF128::from_f64(self.to_f64().sqrt());
+        // One Newton-Raphson step in quad precision: x_{n+1} = (x_n + self/x_n) / 2.
+        let two = SoftF128::from_f64(2.0);
+        let refined = seed.add(&self.div(&seed)).div(&two);
+        refined
+    }
+
+    /// Truncate toward zero (Julia's `trunc`), by clearing mantissa bits below the
+    /// integer position.
+    pub fn trunc(&self) -> SoftF128 {
+        if self.is_nan() || self.is_infinite() || self.is_zero() {
+            return *self;
+        }
+        if self.exp < 0 {
+            // Magnitude < 1: truncates to signed zero.
+            return SoftF128 {
+                sign: self.sign,
+                ..SoftF128::ZERO
+            };
+        }
+        if self.exp as u32 >= MANTISSA_BITS {
+            // No fractional bits within the mantissa's range: already an integer.
+            return *self;
+        }
+        let shift = MANTISSA_BITS - self.exp as u32;
+        SoftF128 {
+            sign: self.sign,
+            exp: self.exp,
+            mantissa: (self.mantissa >> shift) << shift,
+        }
+    }
+
+    /// Round toward negative infinity (Julia's `floor`).
+    pub fn floor(&self) -> SoftF128 {
+        let t = self.trunc();
+        if !self.sign || t == *self {
+            t
+        } else {
+            t.sub(&SoftF128::from_f64(1.0))
+        }
+    }
+
+    /// Round toward positive infinity (Julia's `ceil`).
+    pub fn ceil(&self) -> SoftF128 {
+        let t = self.trunc();
+        if self.sign || t == *self {
+            t
+        } else {
+            t.add(&SoftF128::from_f64(1.0))
+        }
+    }
+
+    /// Round to the nearest integer, ties to even (Julia's default `round` behavior).
+    pub fn round(&self) -> SoftF128 {
+        if self.is_nan() || self.is_infinite() || self.is_zero() {
+            return *self;
+        }
+        let t = self.trunc();
+        if t == *self {
+            return t;
+        }
+        let frac_mag = SoftF128 {
+            sign: false,
+            ..self.sub(&t)
+        };
+        let half = SoftF128::from_f64(0.5);
+        let one = SoftF128::from_f64(1.0);
+        let step_away = if self.sign { t.sub(&one) } else { t.add(&one) };
+        if frac_mag == half {
+            // Exactly halfway: round to the even neighbor.
+            let t_is_odd = (t.to_f64() as i64) % 2 != 0;
+            if t_is_odd {
+                step_away
+            } else {
+                t
+            }
+        } else if frac_mag.sub(&half).sign {
+            // |frac| < 0.5: round back toward zero.
+            t
+        } else {
+            step_away
+        }
+    }
+}
+
+/// Round an unsigned significand down by `shift` bits to nearest, ties to even.
+fn round_to_nearest_even(value: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        return value;
+    }
+    if shift >= 128 {
+        return 0;
+    }
+    let half = 1u128 << (shift - 1);
+    let mask = (1u128 << shift) - 1;
+    let remainder = value & mask;
+    let truncated = value >> shift;
+    if remainder > half || (remainder == half && truncated & 1 == 1) {
+        truncated + 1
+    } else {
+        truncated
+    }
+}
+
+/// Shift `value` right by `shift` bits, OR-ing a sticky bit into bit 0 when any bit shifted
+/// out was set. Used to align `add`/`sub`'s smaller operand without silently discarding the
+/// bits that fall below the new alignment point (Issue chunk412-6).
+fn shift_right_sticky(value: u128, shift: u32) -> u128 {
+    if shift == 0 {
+        return value;
+    }
+    if shift >= 128 {
+        return (value != 0) as u128;
+    }
+    let lost = value & ((1u128 << shift) - 1);
+    let shifted = value >> shift;
+    if lost != 0 {
+        shifted | 1
+    } else {
+        shifted
+    }
+}
+
+/// Widen `a * b` for 128-bit operands into a `(hi, lo)` pair, using four 64-bit partial
+/// products (the same "MinInt split" a soft-float multiply needs for a mantissa wider
+/// than the native integer type).
+fn widening_mul_u128(a: u128, b: u128) -> (u128, u128) {
+    let a_lo = a as u64 as u128;
+    let a_hi = (a >> 64) as u64 as u128;
+    let b_lo = b as u64 as u128;
+    let b_hi = (b >> 64) as u64 as u128;
+
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (hi_lo & 0xFFFF_FFFF_FFFF_FFFF) + (lo_hi & 0xFFFF_FFFF_FFFF_FFFF);
+    let lo = (lo_lo & 0xFFFF_FFFF_FFFF_FFFF) | (mid << 64);
+    let hi = hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrips_through_f64() {
+        for x in [0.0, 1.0, -1.5, 3.25, 1e10, -1e-10, f64::MAX, f64::MIN_POSITIVE] {
+            assert_eq!(SoftF128::from_f64(x).to_f64(), x);
+        }
+    }
+
+    /// `f64::MIN_POSITIVE` is normal, and a power-of-two-aligned subnormal happens to survive
+    /// the off-by-one mantissa shift that `from_f64`'s subnormal branch used to have. A
+    /// non-power-of-two subnormal (leading bit not at the very top of the 52-bit fraction)
+    /// actually exercises the shift amount (Issue chunk412-6).
+    #[test]
+    fn roundtrips_non_power_of_two_subnormal_f64() {
+        for x in [1e-310, 3e-310, -2.5e-313, 5e-320] {
+            assert_eq!(SoftF128::from_f64(x).to_f64(), x);
+        }
+    }
+
+    /// A mantissa of all ones rounding up at the smallest subnormal exponent carries out of
+    /// the 52-bit subnormal fraction into the smallest normal value, rather than being masked
+    /// away into zero (Issue chunk412-6).
+    #[test]
+    fn to_f64_subnormal_rounding_carries_into_normal_range() {
+        let sf = SoftF128 {
+            sign: false,
+            exp: -1023,
+            mantissa: (1u128 << MANTISSA_BITS) - 1,
+        };
+        assert_eq!(sf.to_f64(), f64::MIN_POSITIVE);
+    }
+
+    /// `add`'s alignment shift must fold discarded bits into a sticky bit instead of
+    /// truncating them away, or additions with a large exponent gap round wrong about half
+    /// the time (Issue chunk412-6).
+    #[test]
+    fn add_rounds_correctly_across_large_exponent_gaps() {
+        let a = 1.0f64;
+        let b = 2f64.powi(-60) * 1.5; // far below half an f64 ulp of `a`, so the f64 sum is `a`
+        let soft_sum = SoftF128::from_f64(a).add(&SoftF128::from_f64(b)).to_f64();
+        assert_eq!(soft_sum, a + b);
+    }
+
+    #[test]
+    fn add_matches_f64_for_exact_values() {
+        let a = SoftF128::from_f64(2.5);
+        let b = SoftF128::from_f64(1.25);
+        assert_eq!(a.add(&b).to_f64(), 3.75);
+        assert_eq!(a.sub(&b).to_f64(), 1.25);
+    }
+
+    #[test]
+    fn mul_and_div_match_f64_for_exact_values() {
+        let a = SoftF128::from_f64(3.0);
+        let b = SoftF128::from_f64(2.0);
+        assert_eq!(a.mul(&b).to_f64(), 6.0);
+        assert_eq!(a.div(&b).to_f64(), 1.5);
+    }
+
+    #[test]
+    fn sqrt_matches_f64_closely() {
+        let a = SoftF128::from_f64(2.0);
+        assert!((a.sqrt().to_f64() - std::f64::consts::SQRT_2).abs() < 1e-15);
+    }
+
+    #[test]
+    fn zero_and_sign_handling() {
+        let zero = SoftF128::from_f64(0.0);
+        let neg_zero = SoftF128::from_f64(-0.0);
+        assert!(zero.is_zero() && neg_zero.is_zero());
+        assert!(SoftF128::infinity(false).add(&SoftF128::infinity(true)).is_nan());
+    }
+}
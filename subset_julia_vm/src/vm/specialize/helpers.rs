@@ -8,6 +8,15 @@ pub(super) fn stmt_variant_name(stmt: &Stmt) -> &'static str {
         Stmt::Block(_) => "Block",
         Stmt::Assign { .. } => "Assign",
         Stmt::AddAssign { .. } => "AddAssign",
+        Stmt::SubAssign { .. } => "SubAssign",
+        Stmt::MulAssign { .. } => "MulAssign",
+        Stmt::DivAssign { .. } => "DivAssign",
+        Stmt::FldAssign { .. } => "FldAssign",
+        Stmt::PowAssign { .. } => "PowAssign",
+        Stmt::BitAndAssign { .. } => "BitAndAssign",
+        Stmt::BitOrAssign { .. } => "BitOrAssign",
+        Stmt::BitXorAssign { .. } => "BitXorAssign",
+        Stmt::BroadcastAssign { .. } => "BroadcastAssign",
         Stmt::For { .. } => "For",
         Stmt::ForEach { .. } => "ForEach",
         Stmt::ForEachTuple { .. } => "ForEachTuple",
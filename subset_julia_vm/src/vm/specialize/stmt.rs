@@ -210,6 +210,74 @@ impl FunctionSpecializer {
         Ok(())
     }
 
+    /// Compile a numeric compound-assignment statement (`x op= y`), mirroring
+    /// the dual fast/dynamic path already used for `AddAssign`: a typed
+    /// `emit_binary_op` fast path, falling back to the matching `Dynamic*`
+    /// instruction (and an `Any`-typed store) when the RHS might produce `Any`.
+    fn compile_compound_assign(
+        &mut self,
+        var: &str,
+        op: BinaryOp,
+        value: &Expr,
+    ) -> Result<(), SpecializationError> {
+        if self.expr_might_produce_any(value) {
+            self.emit(Instr::LoadAny(var.to_string()));
+            self.compile_expr(value)?;
+            self.emit(match op {
+                BinaryOp::Add => Instr::DynamicAdd,
+                BinaryOp::Sub => Instr::DynamicSub,
+                BinaryOp::Mul => Instr::DynamicMul,
+                BinaryOp::Div => Instr::DynamicDiv,
+                BinaryOp::IntDiv => Instr::DynamicIntDiv,
+                BinaryOp::Pow => Instr::DynamicPow,
+                _ => {
+                    return Err(SpecializationError::Unsupported(format!(
+                        "Dynamic compound assignment not yet supported: {:?}",
+                        op
+                    )))
+                }
+            });
+            self.locals.insert(var.to_string(), ValueType::Any);
+            self.emit(Instr::StoreAny(var.to_string()));
+        } else {
+            let var_ty = self.locals.get(var).cloned().unwrap_or(ValueType::Any);
+            self.compile_var(var)?;
+            let val_ty = self.compile_expr(value)?;
+            let result_ty = self.emit_binary_op(op, var_ty, val_ty)?;
+            self.locals.insert(var.to_string(), result_ty.clone());
+            self.emit_store(var, result_ty);
+        }
+        Ok(())
+    }
+
+    /// Compile a bitwise compound-assignment statement (`x &= y`, `x |= y`,
+    /// `x ⊻= y`) by desugaring to `x = x op y` and recompiling as a regular
+    /// assignment. Bitwise `&`/`|`/`⊻` have no `BinaryOp`/`emit_binary_op`
+    /// support (they lower to calls to the Pure Julia wrappers in
+    /// `base/int.jl`, same as ordinary `a & b`), so there is no typed fast
+    /// path to reuse here.
+    fn compile_bitwise_compound_assign(
+        &mut self,
+        var: &str,
+        op: &str,
+        value: &Expr,
+        span: crate::span::Span,
+    ) -> Result<(), SpecializationError> {
+        let desugared = Stmt::Assign {
+            var: var.to_string(),
+            value: Expr::Call {
+                function: op.to_string(),
+                args: vec![Expr::Var(var.to_string(), span), value.clone()],
+                kwargs: Vec::new(),
+                splat_mask: vec![false, false],
+                kwargs_splat_mask: vec![],
+                span,
+            },
+            span,
+        };
+        self.compile_stmt(&desugared)
+    }
+
     pub(super) fn compile_stmt(&mut self, stmt: &Stmt) -> Result<(), SpecializationError> {
         match stmt {
             Stmt::Assign { var, value, .. } => {
@@ -257,24 +325,31 @@ impl FunctionSpecializer {
                 }
             }
             Stmt::AddAssign { var, value, .. } => {
-                // x += y  ->  x = x + y
-                // Check if value might produce Any type (e.g., array indexing)
-                // to avoid type change issues in loops
-                if self.expr_might_produce_any(value) {
-                    // Use dynamic path for safety
-                    self.emit(Instr::LoadAny(var.to_string()));
-                    self.compile_expr(value)?;
-                    self.emit(Instr::DynamicAdd);
-                    self.locals.insert(var.clone(), ValueType::Any);
-                    self.emit(Instr::StoreAny(var.to_string()));
-                } else {
-                    let var_ty = self.locals.get(var).cloned().unwrap_or(ValueType::Any);
-                    self.compile_var(var)?;
-                    let val_ty = self.compile_expr(value)?;
-                    let result_ty = self.emit_binary_op(BinaryOp::Add, var_ty, val_ty)?;
-                    self.locals.insert(var.clone(), result_ty.clone());
-                    self.emit_store(var, result_ty);
-                }
+                self.compile_compound_assign(var, BinaryOp::Add, value)?;
+            }
+            Stmt::SubAssign { var, value, .. } => {
+                self.compile_compound_assign(var, BinaryOp::Sub, value)?;
+            }
+            Stmt::MulAssign { var, value, .. } => {
+                self.compile_compound_assign(var, BinaryOp::Mul, value)?;
+            }
+            Stmt::DivAssign { var, value, .. } => {
+                self.compile_compound_assign(var, BinaryOp::Div, value)?;
+            }
+            Stmt::FldAssign { var, value, .. } => {
+                self.compile_compound_assign(var, BinaryOp::IntDiv, value)?;
+            }
+            Stmt::PowAssign { var, value, .. } => {
+                self.compile_compound_assign(var, BinaryOp::Pow, value)?;
+            }
+            Stmt::BitAndAssign { var, value, span } => {
+                self.compile_bitwise_compound_assign(var, "&", value, *span)?;
+            }
+            Stmt::BitOrAssign { var, value, span } => {
+                self.compile_bitwise_compound_assign(var, "|", value, *span)?;
+            }
+            Stmt::BitXorAssign { var, value, span } => {
+                self.compile_bitwise_compound_assign(var, "⊻", value, *span)?;
             }
             Stmt::Return {
                 value: Some(expr), ..
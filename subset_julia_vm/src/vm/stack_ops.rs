@@ -337,6 +337,18 @@ impl StackOpsExt {
         }
     }
 
+    /// Pop a `Float128` value from the stack.
+    #[inline]
+    pub fn pop_f128(st: &mut Vec<Value>) -> Result<super::softfloat128::SoftF128, VmError> {
+        match st.pop().ok_or(VmError::StackUnderflow)? {
+            Value::F128(v) => Ok(v),
+            other => Err(VmError::TypeError(format!(
+                "expected Float128, got {:?}",
+                value_type_name(&other)
+            ))),
+        }
+    }
+
     /// Pop a complex number from the stack, handling promotion from real numbers.
     #[inline]
     pub fn pop_complex(
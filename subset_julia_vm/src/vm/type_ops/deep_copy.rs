@@ -1,16 +1,44 @@
 //! Deep copy operations for values.
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use crate::rng::RngLike;
 use crate::vm::error::VmError;
 use crate::vm::value::{
-    new_array_ref, ClosureValue, ComposedFunctionValue, DictValue, ExprValue,
-    NamedTupleValue, PairsValue, SetValue, StructInstance, TupleValue, Value,
+    new_array_ref, ArrayData, ArrayValue, ClosureValue, ComposedFunctionValue, DictValue,
+    ExprValue, NamedTupleValue, PairsValue, SetValue, StructInstance, TupleValue, Value,
 };
 use crate::vm::Vm;
 
+/// Identity of a heap-allocated (reference-semantics) value being deep-copied, used to
+/// detect cycles and preserve aliasing the same way Julia's `deepcopy` does: two references
+/// to the same object before the copy must still refer to the same object after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum CopyIdentity {
+    StructRef(usize),
+    Array(usize),
+    Memory(usize),
+    Boxed(usize),
+}
+
 impl<R: RngLike> Vm<R> {
-    /// Recursively deep copy a value.
+    /// Recursively deep copy a value, preserving cycles and shared references.
     pub(in crate::vm) fn deep_copy_value(&mut self, val: &Value) -> Result<Value, VmError> {
+        let mut seen = HashMap::new();
+        self.deep_copy_value_seen(val, &mut seen)
+    }
+
+    /// Recursive worker for [`Self::deep_copy_value`]. `seen` maps the identity of an
+    /// already-visited reference type to its (possibly still-being-filled-in) copy, so that
+    /// a value reachable from itself - directly or through structs/arrays/boxes - copies to a
+    /// value with the same cyclic/shared shape instead of recursing forever.
+    fn deep_copy_value_seen(
+        &mut self,
+        val: &Value,
+        seen: &mut HashMap<CopyIdentity, Value>,
+    ) -> Result<Value, VmError> {
         Ok(match val {
             // Primitive types - just clone
             Value::I8(v) => Value::I8(*v),
@@ -27,6 +55,7 @@ impl<R: RngLike> Vm<R> {
             Value::F16(v) => Value::F16(*v),
             Value::F32(v) => Value::F32(*v),
             Value::F64(v) => Value::F64(*v),
+            Value::F128(v) => Value::F128(*v),
             Value::BigInt(v) => Value::BigInt(v.clone()),
             Value::BigFloat(v) => Value::BigFloat(v.clone()),
             Value::Str(s) => Value::Str(s.clone()),
@@ -36,19 +65,70 @@ impl<R: RngLike> Vm<R> {
             Value::Undef => Value::Undef,
             Value::SliceAll => Value::SliceAll,
 
-            // Array - deep copy elements
+            // Array - deep copy elements, preserving identity so a cycle through this
+            // array (or another alias of it) copies to the same cyclic/shared shape.
             Value::Array(arr) => {
-                let borrowed = arr.borrow();
-                Value::Array(new_array_ref(crate::vm::value::ArrayValue::new(
-                    borrowed.data.clone(),
-                    borrowed.shape.clone(),
-                )))
+                let identity = CopyIdentity::Array(Rc::as_ptr(arr) as usize);
+                if let Some(existing) = seen.get(&identity) {
+                    return Ok(existing.clone());
+                }
+
+                let (data, shape, struct_type_id, element_type_override) = {
+                    let borrowed = arr.borrow();
+                    (
+                        borrowed.data.clone(),
+                        borrowed.shape.clone(),
+                        borrowed.struct_type_id,
+                        borrowed.element_type_override.clone(),
+                    )
+                };
+                let new_arr = new_array_ref(ArrayValue {
+                    data,
+                    shape,
+                    struct_type_id,
+                    element_type_override,
+                });
+                seen.insert(identity, Value::Array(new_arr.clone()));
+
+                // Only Any/StructRefs storage can hold references that need deep copying;
+                // the other variants are flat primitive buffers.
+                let new_data = match &new_arr.borrow().data {
+                    ArrayData::Any(values) => Some(ArrayData::Any(
+                        values
+                            .iter()
+                            .map(|v| self.deep_copy_value_seen(v, seen))
+                            .collect::<Result<Vec<Value>, VmError>>()?,
+                    )),
+                    ArrayData::StructRefs(idxs) => {
+                        let mut new_idxs = Vec::with_capacity(idxs.len());
+                        for &idx in idxs {
+                            match self.deep_copy_value_seen(&Value::StructRef(idx), seen)? {
+                                Value::StructRef(new_idx) => new_idxs.push(new_idx),
+                                other => {
+                                    return Err(VmError::TypeError(format!(
+                                        "internal: deep-copying a StructRef produced {:?}",
+                                        other
+                                    )))
+                                }
+                            }
+                        }
+                        Some(ArrayData::StructRefs(new_idxs))
+                    }
+                    _ => None,
+                };
+                if let Some(new_data) = new_data {
+                    new_arr.borrow_mut().data = new_data;
+                }
+                Value::Array(new_arr)
             }
 
             // Tuple - deep copy elements
             Value::Tuple(t) => {
-                let elements: Result<Vec<Value>, VmError> =
-                    t.elements.iter().map(|e| self.deep_copy_value(e)).collect();
+                let elements: Result<Vec<Value>, VmError> = t
+                    .elements
+                    .iter()
+                    .map(|e| self.deep_copy_value_seen(e, seen))
+                    .collect();
                 Value::Tuple(TupleValue {
                     elements: elements?,
                 })
@@ -56,8 +136,11 @@ impl<R: RngLike> Vm<R> {
 
             // NamedTuple - deep copy values
             Value::NamedTuple(nt) => {
-                let values: Result<Vec<Value>, VmError> =
-                    nt.values.iter().map(|v| self.deep_copy_value(v)).collect();
+                let values: Result<Vec<Value>, VmError> = nt
+                    .values
+                    .iter()
+                    .map(|v| self.deep_copy_value_seen(v, seen))
+                    .collect();
                 Value::NamedTuple(NamedTupleValue {
                     names: nt.names.clone(),
                     values: values?,
@@ -66,8 +149,11 @@ impl<R: RngLike> Vm<R> {
 
             // Struct - create a new copy on the heap
             Value::Struct(si) => {
-                let values: Result<Vec<Value>, VmError> =
-                    si.values.iter().map(|f| self.deep_copy_value(f)).collect();
+                let values: Result<Vec<Value>, VmError> = si
+                    .values
+                    .iter()
+                    .map(|f| self.deep_copy_value_seen(f, seen))
+                    .collect();
                 Value::Struct(StructInstance {
                     type_id: si.type_id,
                     struct_name: si.struct_name.clone(),
@@ -75,9 +161,16 @@ impl<R: RngLike> Vm<R> {
                 })
             }
 
-            // StructRef - create a new instance on the heap
+            // StructRef - create a new instance on the heap. The new slot is reserved and
+            // registered in `seen` *before* its fields are copied, so a field that points
+            // back to this same struct (directly or transitively) resolves to the new
+            // struct's identity instead of recursing forever.
             Value::StructRef(idx) => {
-                // Clone values first to release the borrow on struct_heap
+                let identity = CopyIdentity::StructRef(*idx);
+                if let Some(existing) = seen.get(&identity) {
+                    return Ok(existing.clone());
+                }
+
                 let (type_id, struct_name, old_values) =
                     if let Some(si) = self.struct_heap.get(*idx) {
                         (si.type_id, si.struct_name.clone(), si.values.clone())
@@ -85,28 +178,31 @@ impl<R: RngLike> Vm<R> {
                         return Ok(Value::StructRef(*idx)); // Keep as-is if not found
                     };
 
-                // Now we can safely call deep_copy_value
-                let mut new_values = Vec::new();
+                let new_idx = self.struct_heap.len();
+                self.struct_heap.push(StructInstance {
+                    type_id,
+                    struct_name: struct_name.clone(),
+                    values: vec![Value::Undef; old_values.len()],
+                });
+                seen.insert(identity, Value::StructRef(new_idx));
+
+                let mut new_values = Vec::with_capacity(old_values.len());
                 for v in &old_values {
-                    new_values.push(self.deep_copy_value(v)?);
+                    new_values.push(self.deep_copy_value_seen(v, seen)?);
+                }
+                if let Some(slot) = self.struct_heap.get_mut(new_idx) {
+                    slot.values = new_values;
                 }
-
-                let new_si = StructInstance {
-                    type_id,
-                    struct_name,
-                    values: new_values,
-                };
-                let new_idx = self.struct_heap.len();
-                self.struct_heap.push(new_si);
                 Value::StructRef(new_idx)
             }
 
-            // Dict - deep copy entries
+            // Dict - deep copy entries. Dict is owned (Box), not shared, so no identity
+            // tracking is needed for the dict itself - only for values reachable through it.
             Value::Dict(d) => {
                 let mut new_dict =
                     DictValue::with_type_params_opt(d.key_type.clone(), d.value_type.clone());
                 for (k, v) in d.iter() {
-                    let new_v = self.deep_copy_value(v)?;
+                    let new_v = self.deep_copy_value_seen(v, seen)?;
                     new_dict.insert(k.clone(), new_v);
                 }
                 Value::Dict(Box::new(new_dict))
@@ -122,12 +218,32 @@ impl<R: RngLike> Vm<R> {
 
             // Ref - deep copy inner
             Value::Ref(inner) => {
-                let new_inner = self.deep_copy_value(inner)?;
+                let new_inner = self.deep_copy_value_seen(inner, seen)?;
                 Value::Ref(Box::new(new_inner))
             }
 
+            // Boxed closure-capture cell - deep copy into a fresh, independent cell.
+            // Reserve the new cell (pointing at itself isn't possible before it exists, so a
+            // placeholder of Nothing stands in) and register its identity before recursing,
+            // matching the StructRef/Array handling above.
+            Value::Boxed(cell) => {
+                let identity = CopyIdentity::Boxed(Rc::as_ptr(cell) as usize);
+                if let Some(existing) = seen.get(&identity) {
+                    return Ok(existing.clone());
+                }
+                let new_cell = Rc::new(RefCell::new(Value::Nothing));
+                seen.insert(identity, Value::Boxed(new_cell.clone()));
+                let new_inner = self.deep_copy_value_seen(&cell.borrow().clone(), seen)?;
+                *new_cell.borrow_mut() = new_inner;
+                Value::Boxed(new_cell)
+            }
+
             // Complex types that are typically not deep copied
             Value::Rng(rng) => Value::Rng(rng.clone()),
+            Value::Task(idx) => Value::Task(*idx),
+            // Shared cursor, like Array/Dict - copying the handle must not
+            // give the copy its own independent walk through the varargs.
+            Value::VaList(va) => Value::VaList(va.clone()),
             Value::Generator(g) => Value::Generator(g.clone()),
             Value::DataType(dt) => Value::DataType(dt.clone()),
             Value::Module(m) => Value::Module(m.clone()),
@@ -137,14 +253,14 @@ impl<R: RngLike> Vm<R> {
                 let new_captures: Result<Vec<(String, Value)>, VmError> = c
                     .captures
                     .iter()
-                    .map(|(name, v)| Ok((name.clone(), self.deep_copy_value(v)?)))
+                    .map(|(name, v)| Ok((name.clone(), self.deep_copy_value_seen(v, seen)?)))
                     .collect();
                 Value::Closure(ClosureValue::new(c.name.clone(), new_captures?))
             }
             Value::ComposedFunction(cf) => {
                 // Deep copy both outer and inner functions
-                let outer = self.deep_copy_value(&cf.outer)?;
-                let inner = self.deep_copy_value(&cf.inner)?;
+                let outer = self.deep_copy_value_seen(&cf.outer, seen)?;
+                let inner = self.deep_copy_value_seen(&cf.inner, seen)?;
                 Value::ComposedFunction(ComposedFunctionValue::new(outer, inner))
             }
             Value::IO(io) => Value::IO(io.clone()),
@@ -152,15 +268,18 @@ impl<R: RngLike> Vm<R> {
             // Macro system types - deep copy
             Value::Symbol(s) => Value::Symbol(s.clone()),
             Value::Expr(e) => {
-                let new_args: Result<Vec<Value>, VmError> =
-                    e.args.iter().map(|a| self.deep_copy_value(a)).collect();
+                let new_args: Result<Vec<Value>, VmError> = e
+                    .args
+                    .iter()
+                    .map(|a| self.deep_copy_value_seen(a, seen))
+                    .collect();
                 Value::Expr(ExprValue {
                     head: e.head.clone(),
                     args: new_args?,
                 })
             }
             Value::QuoteNode(inner) => {
-                let new_inner = self.deep_copy_value(inner)?;
+                let new_inner = self.deep_copy_value_seen(inner, seen)?;
                 Value::QuoteNode(Box::new(new_inner))
             }
             Value::LineNumberNode(ln) => Value::LineNumberNode(ln.clone()),
@@ -172,7 +291,7 @@ impl<R: RngLike> Vm<R> {
                     .data
                     .values
                     .iter()
-                    .map(|v| self.deep_copy_value(v))
+                    .map(|v| self.deep_copy_value_seen(v, seen))
                     .collect();
                 Value::Pairs(PairsValue {
                     data: NamedTupleValue {
@@ -189,12 +308,134 @@ impl<R: RngLike> Vm<R> {
                 type_name: type_name.clone(),
                 value: *value,
             },
-            // Memory type - deep copy the buffer
+            // Memory type - deep copy the buffer, preserving identity like Array above
             Value::Memory(mem) => {
-                let mem_borrow = mem.borrow();
-                Value::Memory(crate::vm::value::new_memory_ref(mem_borrow.copy()))
+                let identity = CopyIdentity::Memory(Rc::as_ptr(mem) as usize);
+                if let Some(existing) = seen.get(&identity) {
+                    return Ok(existing.clone());
+                }
+
+                let new_mem = crate::vm::value::new_memory_ref(mem.borrow().copy());
+                seen.insert(identity, Value::Memory(new_mem.clone()));
+
+                let new_data = match &new_mem.borrow().data {
+                    ArrayData::Any(values) => Some(ArrayData::Any(
+                        values
+                            .iter()
+                            .map(|v| self.deep_copy_value_seen(v, seen))
+                            .collect::<Result<Vec<Value>, VmError>>()?,
+                    )),
+                    ArrayData::StructRefs(idxs) => {
+                        let mut new_idxs = Vec::with_capacity(idxs.len());
+                        for &idx in idxs {
+                            match self.deep_copy_value_seen(&Value::StructRef(idx), seen)? {
+                                Value::StructRef(new_idx) => new_idxs.push(new_idx),
+                                other => {
+                                    return Err(VmError::TypeError(format!(
+                                        "internal: deep-copying a StructRef produced {:?}",
+                                        other
+                                    )))
+                                }
+                            }
+                        }
+                        Some(ArrayData::StructRefs(new_idxs))
+                    }
+                    _ => None,
+                };
+                if let Some(new_data) = new_data {
+                    new_mem.borrow_mut().data = new_data;
+                }
+                Value::Memory(new_mem)
             }
         })
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::StableRng;
+    use crate::vm::{StructDefInfo, ValueType};
+
+    /// Helper: create a minimal VM with a single self-referential-capable mutable struct type
+    /// registered (`Node { next: Any }`), for exercising `deepcopy` on cyclic/shared graphs
+    /// (Issue chunk433-6).
+    fn make_vm_with_node_struct() -> Vm<StableRng> {
+        let mut vm = Vm::new(vec![], StableRng::new(0));
+        vm.struct_defs.push(StructDefInfo {
+            name: "Node".to_string(),
+            is_mutable: true,
+            fields: vec![("next".to_string(), ValueType::Any)],
+            parent_type: None,
+        });
+        vm
+    }
+
+    #[test]
+    fn test_deep_copy_self_referential_struct_does_not_infinite_loop() {
+        let mut vm = make_vm_with_node_struct();
+        let idx = vm.struct_heap.len();
+        vm.struct_heap.push(StructInstance::with_name(
+            0,
+            "Node".to_string(),
+            vec![Value::Nothing],
+        ));
+        // Make the node point at itself: next = self.
+        vm.struct_heap[idx].values[0] = Value::StructRef(idx);
+
+        let copy = vm.deep_copy_value(&Value::StructRef(idx)).expect("deepcopy should not loop");
+        let Value::StructRef(new_idx) = copy else {
+            panic!("expected a StructRef copy, got {copy:?}");
+        };
+        assert_ne!(new_idx, idx, "deepcopy must allocate a fresh struct slot");
+        match &vm.struct_heap[new_idx].values[0] {
+            Value::StructRef(inner) => {
+                assert_eq!(*inner, new_idx, "copy's self-reference must point at the copy, not the original");
+            }
+            other => panic!("expected the copied self-reference, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_deep_copy_preserves_shared_substructure() {
+        let mut vm = make_vm_with_node_struct();
+        let shared_idx = vm.struct_heap.len();
+        vm.struct_heap.push(StructInstance::with_name(
+            0,
+            "Node".to_string(),
+            vec![Value::Nothing],
+        ));
+
+        // Two distinct parents both point at the same shared child node.
+        let parent_a = TupleValue {
+            elements: vec![Value::StructRef(shared_idx)],
+        };
+        let parent_b = TupleValue {
+            elements: vec![Value::StructRef(shared_idx)],
+        };
+        let original = Value::Tuple(TupleValue {
+            elements: vec![Value::Tuple(parent_a), Value::Tuple(parent_b)],
+        });
+
+        let copy = vm.deep_copy_value(&original).expect("deepcopy should succeed");
+        let Value::Tuple(tup) = copy else {
+            panic!("expected a Tuple copy, got {copy:?}");
+        };
+        let (Value::Tuple(a), Value::Tuple(b)) = (&tup.elements[0], &tup.elements[1]) else {
+            panic!("expected nested Tuple copies");
+        };
+        let (Value::StructRef(a_idx), Value::StructRef(b_idx)) = (&a.elements[0], &b.elements[0])
+        else {
+            panic!("expected StructRef copies of the shared child");
+        };
+        assert_eq!(
+            a_idx, b_idx,
+            "both parents' copies must still share the same copied child"
+        );
+        assert_ne!(
+            *a_idx, shared_idx,
+            "the shared child must be copied, not aliased to the original"
+        );
+    }
+}
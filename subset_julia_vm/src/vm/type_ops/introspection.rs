@@ -29,6 +29,7 @@ impl<R: RngLike> Vm<R> {
             Value::F16(_) => "Float16".to_string(),
             Value::F32(_) => "Float32".to_string(),
             Value::F64(_) => "Float64".to_string(),
+            Value::F128(_) => "Float128".to_string(),
             Value::BigFloat(_) => "BigFloat".to_string(),
             Value::Str(_) => "String".to_string(),
             Value::Char(_) => "Char".to_string(),
@@ -87,6 +88,8 @@ impl<R: RngLike> Vm<R> {
                 RngInstance::Stable(_) => "StableRNG".to_string(),
                 RngInstance::Xoshiro(_) => "Xoshiro".to_string(),
             },
+            Value::Task(_) => "Task".to_string(),
+            Value::VaList(_) => "Core.VaList".to_string(),
             Value::Tuple(t) => {
                 // Julia shows Tuple{T1, T2, ...}
                 let types: Vec<String> = t.elements.iter().map(|e| self.get_type_name(e)).collect();
@@ -112,6 +115,7 @@ impl<R: RngLike> Vm<R> {
                 // Ref{T} wraps another value
                 format!("Ref{{{}}}", self.get_type_name(inner))
             }
+            Value::Boxed(cell) => self.get_type_name(&cell.borrow()),
             Value::Generator(_) => "Base.Generator".to_string(),
             Value::DataType(_) => "DataType".to_string(),
             Value::Module(m) => format!("Module({})", m.name),
@@ -52,6 +52,16 @@ impl<R: RngLike> Vm<R> {
     }
 
     pub(in crate::vm) fn iterate_first(&self, coll: &Value) -> Result<Value, VmError> {
+        // A VaList is only ever materialized into a real collection once
+        // something actually iterates it - e.g. splatting or a `for` loop
+        // over it (Issue chunk427-2). `va_arg`/`va_count` never go through
+        // here, so pass-through forwarding still pays no allocation.
+        if let Value::VaList(va) = coll {
+            let materialized = Value::Tuple(TupleValue {
+                elements: va.borrow().materialize_remaining(),
+            });
+            return self.iterate_first(&materialized);
+        }
         match coll {
             Value::Array(arr) => {
                 let arr_borrow = arr.borrow();
@@ -542,6 +552,15 @@ impl<R: RngLike> Vm<R> {
     /// Subsequent iteration: iterate(collection, state) -> (element, state) or nothing
     /// State is 0-indexed - it represents the next index to fetch
     pub(in crate::vm) fn iterate_next(&self, coll: &Value, state: &Value) -> Result<Value, VmError> {
+        // See the matching guard in `iterate_first` (Issue chunk427-2): the
+        // VaList's cursor is untouched here, so re-materializing on every
+        // call is still just a view into the same unconsumed slice.
+        if let Value::VaList(va) = coll {
+            let materialized = Value::Tuple(TupleValue {
+                elements: va.borrow().materialize_remaining(),
+            });
+            return self.iterate_next(&materialized, state);
+        }
         // Scalar number iteration (Julia: iterate(x::Number, ::Nothing) = nothing)
         // After yielding once, scalar iteration is done.
         if matches!(
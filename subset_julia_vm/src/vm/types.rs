@@ -45,6 +45,17 @@ pub struct FunctionInfo {
     pub vararg_param_index: Option<usize>,
     /// For Vararg{T, N}: fixed argument count N. None = any count. (Issue #2525)
     pub vararg_fixed_count: Option<usize>,
+    /// This function, and everything it can statically reach, never raises
+    /// (calls `raise`/returns `Err`). Computed by `effects::analyze_effects`
+    /// over the static call graph; defaults to `false` (assume it can throw)
+    /// until that pass runs (Issue chunk427-3).
+    #[serde(default)]
+    pub nothrow: bool,
+    /// This function is not part of any call cycle (direct or indirect
+    /// self-recursion). Computed alongside `nothrow`; defaults to `false`
+    /// until `effects::analyze_effects` runs (Issue chunk427-3).
+    #[serde(default)]
+    pub norecurse: bool,
 }
 
 /// Keyword parameter info for VM
@@ -133,6 +144,22 @@ pub struct SpecializedCode {
     pub return_type: ValueType,
     /// Length of the specialized bytecode
     pub code_len: usize,
+    /// Number of times this specialization has been invoked through
+    /// `CallSpecialize`, used to trigger native JIT compilation once it
+    /// crosses `super::exec::jit::JIT_CALL_THRESHOLD` (Issue chunk427-1).
+    pub call_count: u64,
+    /// Native function pointer once JIT-compiled, bypassing the
+    /// interpreter loop for this specialization entirely. `None` until
+    /// `call_count` crosses the threshold and
+    /// `super::exec::jit::SpecializationJit::try_compile` succeeds against
+    /// the bytecode in `[entry, entry + code_len)` (Issue chunk427-1).
+    pub native: Option<super::exec::jit::NativeFn>,
+    /// World-age this specialization was compiled against (Issue
+    /// chunk427-5). Stamped from `Vm::specialization_generation` at
+    /// insert time; `CallSpecialize` discards the entry instead of
+    /// reusing it once that counter has moved on, forcing a recompile
+    /// against whatever the method table now says for `key.arg_types`.
+    pub generation: u64,
 }
 
 /// A function that can be specialized at runtime
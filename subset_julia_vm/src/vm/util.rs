@@ -69,6 +69,7 @@ pub(crate) fn value_type_name(v: &Value) -> &'static str {
         Value::F16(_) => "Float16",
         Value::F32(_) => "Float32",
         Value::F64(_) => "Float64",
+        Value::F128(_) => "Float128",
         Value::BigInt(_) => "BigInt",
         Value::BigFloat(_) => "BigFloat",
         Value::Str(_) => "String",
@@ -82,11 +83,14 @@ pub(crate) fn value_type_name(v: &Value) -> &'static str {
         Value::Struct(_) => "Struct",
         Value::StructRef(_) => "StructRef",
         Value::Rng(_) => "Rng",
+        Value::Task(_) => "Task",
+        Value::VaList(_) => "Core.VaList",
         Value::Tuple(_) => "Tuple",
         Value::NamedTuple(_) => "NamedTuple",
         Value::Dict(_) => "Dict",
         Value::Set(_) => "Set",
         Value::Ref(_) => "Ref",
+        Value::Boxed(_) => "Ref", // Boxed captures are compiler-internal only
         Value::Generator(_) => "Base.Generator",
         Value::DataType(_) => "DataType",
         Value::Module(_) => "Module",
@@ -292,7 +296,8 @@ pub(crate) fn bind_value_to_frame(
         | Value::ComposedFunction(_)
         | Value::Module(_)
         | Value::DataType(_)
-        | Value::Ref(_) => {
+        | Value::Ref(_)
+        | Value::Boxed(_) => {
             frame.locals_any.insert(name.to_string(), val);
             VarTypeTag::Any
         }
@@ -316,7 +321,7 @@ pub(crate) fn bind_value_to_frame(
             frame.locals_generator.insert(name.to_string(), g.clone());
             VarTypeTag::Generator
         }
-        Value::BigInt(_) | Value::BigFloat(_) | Value::IO(_) => {
+        Value::BigInt(_) | Value::BigFloat(_) | Value::IO(_) | Value::Task(_) | Value::VaList(_) => {
             frame.locals_any.insert(name.to_string(), val);
             VarTypeTag::Any
         }
@@ -17,6 +17,8 @@
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
+use serde::{Deserialize, Serialize};
+
 use super::super::error::VmError;
 use super::macro_::SymbolValue;
 use super::new_array_ref;
@@ -51,6 +53,55 @@ impl GeneratorValue {
     }
 }
 
+/// Density-adapted membership check for `@enum` integer-to-enum conversion,
+/// chosen once at `@enum` definition time and baked into `Instr::EnumConvert`.
+///
+/// Mirrors the heuristic Julia's own `@enum` macro uses: a contiguous range of
+/// declared values gets a branch-free bounds compare, a small sparse set gets a
+/// left-folded chain of equality tests, and a large sparse set gets a hash set
+/// built once rather than re-scanned on every conversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EnumMembershipCheck {
+    /// `hi - lo + 1 == values.len()`: a single `lo <= x <= hi` compare.
+    Contiguous { lo: i64, hi: i64 },
+    /// Fewer than ~20 declared values: `x == v1 || x == v2 || ...`.
+    EqualityChain(Vec<i64>),
+    /// Many sparse values: a hash set built once, queried by `contains`.
+    HashSet(std::collections::HashSet<i64>),
+}
+
+impl EnumMembershipCheck {
+    /// Maximum member count for the equality-chain strategy before falling
+    /// back to a hash set, matching Julia's own `@enum` threshold.
+    const EQUALITY_CHAIN_LIMIT: usize = 20;
+
+    /// Pick a check strategy for a set of declared `@enum` values.
+    pub fn from_values(values: &[i64]) -> Self {
+        let Some(&lo) = values.iter().min() else {
+            return Self::EqualityChain(Vec::new());
+        };
+        let hi = values.iter().copied().max().unwrap_or(lo);
+        let unique: std::collections::HashSet<i64> = values.iter().copied().collect();
+
+        if unique.len() as i64 == hi - lo + 1 {
+            Self::Contiguous { lo, hi }
+        } else if unique.len() < Self::EQUALITY_CHAIN_LIMIT {
+            Self::EqualityChain(values.to_vec())
+        } else {
+            Self::HashSet(unique)
+        }
+    }
+
+    /// True if `x` is one of the declared `@enum` values.
+    pub fn contains(&self, x: i64) -> bool {
+        match self {
+            Self::Contiguous { lo, hi } => x >= *lo && x <= *hi,
+            Self::EqualityChain(values) => values.iter().any(|&v| v == x),
+            Self::HashSet(set) => set.contains(&x),
+        }
+    }
+}
+
 /// Named tuple value: tuple with named fields
 #[derive(Debug, Clone)]
 pub struct NamedTupleValue {
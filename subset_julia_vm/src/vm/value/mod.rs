@@ -15,6 +15,7 @@
 //! - `range.rs`: RangeValue for lazy ranges
 //! - `struct_instance.rs`: StructInstance for user-defined structs
 //! - `tuple.rs`: TupleValue
+//! - `va_list.rs`: VaListState/VaListRef for lazy varargs
 //! - `value_enum.rs`: Value enum and ValueType
 
 // Submodules
@@ -32,6 +33,7 @@ mod range;
 mod regex;
 mod struct_instance;
 mod tuple;
+mod va_list;
 mod value_enum;
 
 // Re-exports from submodules
@@ -41,8 +43,8 @@ pub use array_value::{
     new_array_ref, new_typed_array_ref, ArrayRef, ArrayValue, TypedArrayRef, TypedArrayValue,
 };
 pub use container::{
-    ComposedFunctionValue, DictIter, DictKey, DictValue, ExprValue, GeneratorValue,
-    NamedTupleValue, PairsValue, SetValue,
+    ComposedFunctionValue, DictIter, DictKey, DictValue, EnumMembershipCheck, ExprValue,
+    GeneratorValue, NamedTupleValue, PairsValue, SetValue,
 };
 pub use io::{IOKind, IORef, IOValue};
 pub use macro_::{GlobalRefValue, LineNumberNodeValue, SymbolValue};
@@ -52,7 +54,8 @@ pub use range::RangeValue;
 pub use regex::{RegexMatchValue, RegexValue};
 pub use struct_instance::{StructInstance, COMPLEX_STRUCT_NAME};
 pub use tuple::TupleValue;
-pub use value_enum::{Value, ValueType};
+pub use va_list::{VaListRef, VaListState};
+pub use value_enum::{BoxCell, Value, ValueType};
 
 // Re-export BigInt for use in other modules
 pub use num_bigint::BigInt as RustBigInt;
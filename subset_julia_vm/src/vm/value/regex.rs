@@ -21,8 +21,13 @@ pub struct RegexValue {
     pub regex: Arc<Regex>,
     /// The original pattern string
     pub pattern: String,
-    /// The flags used (i, m, s, x)
+    /// The flags used (i, m, s, x, P)
     pub flags: String,
+    /// Whether the `P` flag was set: opt-in POSIX leftmost-longest matching.
+    pub posix: bool,
+    /// `^(?:pattern)$` compiled only when `posix` is set, used to pin down the
+    /// exact longest span (and its captures) consistent with a given start.
+    anchored: Option<Arc<Regex>>,
 }
 
 impl RegexValue {
@@ -33,10 +38,13 @@ impl RegexValue {
     /// - `m`: multiline (PCRE2_MULTILINE) - ^ and $ match line boundaries
     /// - `s`: dotall (PCRE2_DOTALL) - . matches newlines
     /// - `x`: extended (PCRE2_EXTENDED) - free-spacing mode
+    /// - `P`: POSIX leftmost-longest matching, instead of the engine's default
+    ///   leftmost-first (see `find`/`find_all`/`split`)
     pub fn new(pattern: &str, flags: &str) -> Result<Self, String> {
         // Build regex pattern with flags
         // Rust's regex crate uses inline flags: (?i), (?m), (?s), (?x)
         let mut prefix = String::new();
+        let mut posix = false;
 
         for c in flags.chars() {
             match c {
@@ -44,20 +52,31 @@ impl RegexValue {
                 'm' => prefix.push_str("(?m)"),
                 's' => prefix.push_str("(?s)"),
                 'x' => prefix.push_str("(?x)"),
+                'P' => posix = true,
                 _ => return Err(format!("Unknown regex flag: {}", c)),
             }
         }
 
         let full_pattern = format!("{}{}", prefix, pattern);
 
-        match Regex::new(&full_pattern) {
-            Ok(regex) => Ok(RegexValue {
-                regex: Arc::new(regex),
-                pattern: pattern.to_string(),
-                flags: flags.to_string(),
-            }),
-            Err(e) => Err(format!("Invalid regex pattern: {}", e)),
-        }
+        let regex =
+            Regex::new(&full_pattern).map_err(|e| format!("Invalid regex pattern: {}", e))?;
+        let anchored = if posix {
+            let anchored_pattern = format!("^(?:{})$", full_pattern);
+            let anchored = Regex::new(&anchored_pattern)
+                .map_err(|e| format!("Invalid regex pattern: {}", e))?;
+            Some(Arc::new(anchored))
+        } else {
+            None
+        };
+
+        Ok(RegexValue {
+            regex: Arc::new(regex),
+            pattern: pattern.to_string(),
+            flags: flags.to_string(),
+            posix,
+            anchored,
+        })
     }
 
     /// Check if a string matches this regex.
@@ -65,85 +84,254 @@ impl RegexValue {
         self.regex.is_match(text)
     }
 
-    /// Find the first match of this regex in the string.
-    pub fn find(&self, text: &str) -> Option<RegexMatchValue> {
-        self.regex.captures(text).and_then(|caps| {
-            let full_match = caps.get(0)?; // Group 0 is guaranteed by regex crate
-            let offset = full_match.start() + 1; // Julia uses 1-based indexing
-
-            // Collect capture groups (excluding the full match at index 0)
-            let mut captures = Vec::new();
-            let mut offsets = Vec::new();
-
-            for i in 1..caps.len() {
-                if let Some(m) = caps.get(i) {
-                    captures.push(Some(m.as_str().to_string()));
-                    offsets.push(m.start() as i64 + 1); // 1-based
-                } else {
+    /// Names of the capture groups, in group order (index 0 is group 1, etc.),
+    /// `None` for unnamed groups.
+    fn capture_group_names(&self) -> Vec<Option<String>> {
+        self.regex
+            .capture_names()
+            .skip(1) // group 0 is the whole match, which RegexMatchValue excludes
+            .map(|n| n.map(str::to_string))
+            .collect()
+    }
+
+    /// Find the byte span of the next match at or after `start`, along with the byte
+    /// spans of its capture groups (index 0 is group 1, etc; `None` if the group
+    /// didn't participate in the match).
+    ///
+    /// In POSIX mode (`self.posix`), the leftmost starting position is unchanged
+    /// from the engine's default leftmost-first search (a match either exists at a
+    /// given start or it doesn't, regardless of match kind) but the span is then
+    /// extended to the *longest* one the pattern can consume from that start, and
+    /// captures are re-resolved against that exact span - so e.g. `(a|ab)` against
+    /// `"ab"` selects `"ab"`, not `"a"`.
+    fn next_match_spans(
+        &self,
+        text: &str,
+        start: usize,
+    ) -> Option<((usize, usize), Vec<Option<(usize, usize)>>)> {
+        let first = self.regex.captures_at(text, start)?;
+        let full = first.get(0)?; // Group 0 is guaranteed by regex crate
+        let (match_start, first_end) = (full.start(), full.end());
+
+        let posix_anchored = if self.posix {
+            self.anchored.as_ref()
+        } else {
+            None
+        };
+        let Some(anchored) = posix_anchored else {
+            let spans = (1..first.len())
+                .map(|i| first.get(i).map(|m| (m.start(), m.end())))
+                .collect();
+            return Some(((match_start, first_end), spans));
+        };
+
+        // Longest-first candidate end positions, all on UTF-8 boundaries, down to
+        // (and including) the shortest span we already know matches.
+        let mut candidate_ends: Vec<usize> = text[match_start..]
+            .char_indices()
+            .map(|(i, c)| match_start + i + c.len_utf8())
+            .filter(|&end| end >= first_end)
+            .collect();
+        candidate_ends.sort_unstable_by(|a, b| b.cmp(a));
+
+        for end in candidate_ends {
+            if let Some(caps) = anchored.captures(&text[match_start..end]) {
+                let spans = (1..caps.len())
+                    .map(|i| {
+                        caps.get(i)
+                            .map(|m| (match_start + m.start(), match_start + m.end()))
+                    })
+                    .collect();
+                return Some(((match_start, end), spans));
+            }
+        }
+
+        // Unreachable in practice: `first_end` itself already proves the anchored
+        // pattern accepts that span, so the loop above always finds a match.
+        None
+    }
+
+    /// Build a `RegexMatchValue` from byte spans returned by [`next_match_spans`].
+    fn match_from_spans(
+        text: &str,
+        (start, end): (usize, usize),
+        capture_spans: Vec<Option<(usize, usize)>>,
+        names: &[Option<String>],
+    ) -> RegexMatchValue {
+        let mut captures = Vec::with_capacity(capture_spans.len());
+        let mut offsets = Vec::with_capacity(capture_spans.len());
+        for span in capture_spans {
+            match span {
+                Some((s, e)) => {
+                    captures.push(Some(text[s..e].to_string()));
+                    offsets.push(s as i64 + 1); // 1-based
+                }
+                None => {
                     captures.push(None);
                     offsets.push(0); // 0 indicates no match
                 }
             }
+        }
 
-            Some(RegexMatchValue {
-                match_str: full_match.as_str().to_string(),
-                captures,
-                offset: offset as i64,
-                offsets,
-            })
-        })
+        RegexMatchValue {
+            match_str: text[start..end].to_string(),
+            captures,
+            offset: start as i64 + 1, // Julia uses 1-based indexing
+            offsets,
+            capture_names: names.to_vec(),
+        }
+    }
+
+    /// Find the first match of this regex in the string.
+    pub fn find(&self, text: &str) -> Option<RegexMatchValue> {
+        let names = self.capture_group_names();
+        let (span, capture_spans) = self.next_match_spans(text, 0)?;
+        Some(Self::match_from_spans(text, span, capture_spans, &names))
     }
 
     /// Find all non-overlapping matches of this regex in the string.
     pub fn find_all(&self, text: &str) -> Vec<RegexMatchValue> {
-        self.regex
-            .captures_iter(text)
-            .filter_map(|caps| {
-                let full_match = caps.get(0)?; // Group 0 is guaranteed by regex crate
-                let offset = full_match.start() + 1;
-
-                let mut captures = Vec::new();
-                let mut offsets = Vec::new();
-
-                for i in 1..caps.len() {
-                    if let Some(m) = caps.get(i) {
-                        captures.push(Some(m.as_str().to_string()));
-                        offsets.push(m.start() as i64 + 1);
-                    } else {
-                        captures.push(None);
-                        offsets.push(0);
-                    }
+        let names = self.capture_group_names();
+        let mut out = Vec::new();
+        let mut cursor = 0;
+
+        while cursor <= text.len() {
+            let Some(((start, end), capture_spans)) = self.next_match_spans(text, cursor) else {
+                break;
+            };
+            out.push(Self::match_from_spans(
+                text,
+                (start, end),
+                capture_spans,
+                &names,
+            ));
+            cursor = if end > start {
+                end
+            } else {
+                // Empty match: advance by one char so we make forward progress.
+                match text[end..].chars().next() {
+                    Some(c) => end + c.len_utf8(),
+                    None => break,
                 }
+            };
+        }
 
-                Some(RegexMatchValue {
-                    match_str: full_match.as_str().to_string(),
-                    captures,
-                    offset: offset as i64,
-                    offsets,
-                })
-            })
-            .collect()
+        out
     }
 
-    /// Replace all occurrences of the pattern with a replacement string.
+    /// Replace all occurrences of the pattern with a replacement string, interpreting
+    /// Julia `SubstitutionString` backreferences (see [`translate_substitution`]).
     pub fn replace_all(&self, text: &str, replacement: &str) -> String {
-        self.regex.replace_all(text, replacement).to_string()
+        self.regex
+            .replace_all(text, translate_substitution(replacement).as_str())
+            .to_string()
     }
 
-    /// Replace the first occurrence of the pattern with a replacement string.
+    /// Replace the first occurrence of the pattern with a replacement string, interpreting
+    /// Julia `SubstitutionString` backreferences (see [`translate_substitution`]).
     pub fn replace(&self, text: &str, replacement: &str) -> String {
-        self.regex.replace(text, replacement).to_string()
+        self.regex
+            .replace(text, translate_substitution(replacement).as_str())
+            .to_string()
     }
 
-    /// Replace at most `limit` occurrences of the pattern with a replacement string.
+    /// Replace at most `limit` occurrences of the pattern with a replacement string,
+    /// interpreting Julia `SubstitutionString` backreferences (see [`translate_substitution`]).
     pub fn replacen(&self, text: &str, limit: usize, replacement: &str) -> String {
-        self.regex.replacen(text, limit, replacement).to_string()
+        self.regex
+            .replacen(text, limit, translate_substitution(replacement).as_str())
+            .to_string()
     }
 
     /// Split the string by this regex pattern.
     pub fn split<'a>(&self, text: &'a str) -> Vec<&'a str> {
-        self.regex.split(text).collect()
+        if !self.posix {
+            return self.regex.split(text).collect();
+        }
+
+        // POSIX mode: cut on the same (longest) match spans `find_all` would report,
+        // rather than delegating to the crate's own (leftmost-first) splitter.
+        let mut pieces = Vec::new();
+        let mut cursor = 0;
+        let mut last_end = 0;
+
+        while cursor <= text.len() {
+            let Some(((start, end), _)) = self.next_match_spans(text, cursor) else {
+                break;
+            };
+            pieces.push(&text[last_end..start]);
+            last_end = end;
+            cursor = if end > start {
+                end
+            } else {
+                match text[end..].chars().next() {
+                    Some(c) => end + c.len_utf8(),
+                    None => break,
+                }
+            };
+        }
+        pieces.push(&text[last_end..]);
+
+        pieces
+    }
+}
+
+/// Translate a Julia `SubstitutionString` replacement pattern (as used by
+/// `replace(str, r"..." => s"...")`) into the `$name`/`${name}` syntax understood by
+/// the `regex` crate's `Replacer` impl for `&str`.
+///
+/// Recognizes `\1`..`\9...` (numbered backreference), `\g<name>` (named backreference),
+/// `\\` (literal backslash), and Julia's `$1`/`$(name)` spellings of the same thing.
+/// Captures that didn't participate in the match substitute as an empty string, since
+/// that's what the `regex` crate already does for unmatched groups.
+fn translate_substitution(replacement: &str) -> String {
+    let mut out = String::new();
+    let mut chars = replacement.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.peek().copied() {
+                Some('\\') => {
+                    chars.next();
+                    out.push('\\');
+                }
+                Some('g') => {
+                    let mut lookahead = chars.clone();
+                    lookahead.next(); // 'g'
+                    if lookahead.peek() == Some(&'<') {
+                        chars.next(); // 'g'
+                        chars.next(); // '<'
+                        let name: String = chars.by_ref().take_while(|c| *c != '>').collect();
+                        out.push_str(&format!("${{{}}}", name));
+                    } else {
+                        out.push('\\');
+                    }
+                }
+                Some(d) if d.is_ascii_digit() => {
+                    let num: String =
+                        std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_digit())).collect();
+                    out.push_str(&format!("${{{}}}", num));
+                }
+                _ => out.push('\\'),
+            },
+            '$' => match chars.peek().copied() {
+                Some('(') => {
+                    chars.next(); // '('
+                    let name: String = chars.by_ref().take_while(|c| *c != ')').collect();
+                    out.push_str(&format!("${{{}}}", name));
+                }
+                Some(d) if d.is_ascii_digit() => {
+                    let num: String =
+                        std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_digit())).collect();
+                    out.push_str(&format!("${{{}}}", num));
+                }
+                _ => out.push('$'),
+            },
+            _ => out.push(c),
+        }
     }
+
+    out
 }
 
 impl PartialEq for RegexValue {
@@ -172,6 +360,8 @@ pub struct RegexMatchValue {
     pub offset: i64,
     /// Starting positions of each capture group (1-based, 0 if not matched)
     pub offsets: Vec<i64>,
+    /// Names of the capture groups, in group order (`None` for unnamed groups).
+    pub capture_names: Vec<Option<String>>,
 }
 
 impl RegexMatchValue {
@@ -185,6 +375,17 @@ impl RegexMatchValue {
             None
         }
     }
+
+    /// Get a captured group by name (Julia's `m["name"]` / named-capture access).
+    /// Returns `None` if no group has that name, or if the group didn't participate
+    /// in the match.
+    pub fn get_named(&self, name: &str) -> Option<&str> {
+        let index = self
+            .capture_names
+            .iter()
+            .position(|n| n.as_deref() == Some(name))?;
+        self.captures[index].as_deref()
+    }
 }
 
 #[cfg(test)]
@@ -237,4 +438,103 @@ mod tests {
         let parts = re.split("a, b,  c");
         assert_eq!(parts, vec!["a", "b", "c"]);
     }
+
+    #[test]
+    fn test_regex_named_captures() {
+        let re = RegexValue::new(r"(?P<year>\d{4})-(?P<month>\d{2})", "").unwrap();
+        let m = re.find("2024-05").unwrap();
+        assert_eq!(m.get_named("year"), Some("2024"));
+        assert_eq!(m.get_named("month"), Some("05"));
+        assert_eq!(m.get_named("nope"), None);
+        // Positional access still works alongside named access.
+        assert_eq!(m.get(1), Some("2024"));
+    }
+
+    #[test]
+    fn test_regex_named_capture_not_participating() {
+        let re = RegexValue::new(r"(?P<a>x)|(?P<b>y)", "").unwrap();
+        let m = re.find("y").unwrap();
+        assert_eq!(m.get_named("a"), None);
+        assert_eq!(m.get_named("b"), Some("y"));
+    }
+
+    #[test]
+    fn test_regex_replace_numbered_backreference() {
+        let re = RegexValue::new(r"(\w+)@(\w+)", "").unwrap();
+        assert_eq!(re.replace("user@host", r"\2:\1"), "host:user");
+    }
+
+    #[test]
+    fn test_regex_replace_named_backreference() {
+        let re = RegexValue::new(r"(?P<user>\w+)@(?P<host>\w+)", "").unwrap();
+        assert_eq!(re.replace("user@host", r"\g<host>:\g<user>"), "host:user");
+    }
+
+    #[test]
+    fn test_regex_replace_julia_dollar_syntax() {
+        let re = RegexValue::new(r"(\w+)@(?P<host>\w+)", "").unwrap();
+        assert_eq!(re.replace("user@host", "$2:$1"), "host:user");
+        assert_eq!(re.replace("user@host", "$(host):$1"), "host:user");
+    }
+
+    #[test]
+    fn test_regex_replace_literal_backslash_escape() {
+        let re = RegexValue::new(r"\d+", "").unwrap();
+        assert_eq!(re.replace("a1b", r"\\n"), r"a\nb");
+    }
+
+    #[test]
+    fn test_regex_replace_unmatched_group_is_empty() {
+        let re = RegexValue::new(r"(a)|(b)", "").unwrap();
+        assert_eq!(re.replace_all("ab", r"[\1][\2]"), "[a][][][b]");
+    }
+
+    #[test]
+    fn test_regex_posix_flag_selects_leftmost_longest_alternation() {
+        let default_re = RegexValue::new(r"a|ab", "").unwrap();
+        assert_eq!(default_re.find("ab").unwrap().match_str, "a");
+
+        let posix_re = RegexValue::new(r"a|ab", "P").unwrap();
+        assert!(posix_re.posix);
+        assert_eq!(posix_re.find("ab").unwrap().match_str, "ab");
+    }
+
+    #[test]
+    fn test_regex_posix_captures_resolve_against_longest_span() {
+        let re = RegexValue::new(r"(a|ab)(c|bcd)", "P").unwrap();
+        let m = re.find("abcd").unwrap();
+        assert_eq!(m.match_str, "abcd");
+        assert_eq!(
+            m.captures,
+            vec![Some("a".to_string()), Some("bcd".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_regex_posix_empty_width_group_still_reports_a_span() {
+        let re = RegexValue::new(r"(a*)(b)", "P").unwrap();
+        let m = re.find("b").unwrap();
+        assert_eq!(m.match_str, "b");
+        // The `a*` group matched the empty string, but it still participated
+        // (non-None) with a zero-length span, rather than being reported absent.
+        assert_eq!(m.captures[0], Some(String::new()));
+        assert_eq!(m.captures[1], Some("b".to_string()));
+    }
+
+    #[test]
+    fn test_regex_posix_find_all_and_split() {
+        let re = RegexValue::new(r"a|ab", "P").unwrap();
+        let matches = re.find_all("ab ab");
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].match_str, "ab");
+        assert_eq!(matches[1].match_str, "ab");
+
+        let parts = re.split("xabyaby");
+        assert_eq!(parts, vec!["x", "y", "y"]);
+    }
+
+    #[test]
+    fn test_regex_invalid_flag_still_rejected() {
+        assert!(RegexValue::new(r"\d+", "Q").is_err());
+    }
 }
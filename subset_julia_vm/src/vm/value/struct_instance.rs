@@ -90,6 +90,10 @@ impl StructInstance {
             Err(VmError::FieldIndexOutOfBounds {
                 index,
                 field_count: self.values.len(),
+                // StructInstance doesn't carry a reference to the VM's struct_defs, so the
+                // field-name hint can't be filled in here; callers with VM access (e.g.
+                // struct_ops.rs) attach it before raising where possible (Issue chunk433-4).
+                field_names: Vec::new(),
             })
         }
     }
@@ -0,0 +1,90 @@
+//! VaList value type: a lazily-materialized, cursor-based view into a
+//! function's trailing varargs (Issue chunk427-2).
+//!
+//! Binding `args[vararg_idx..]` to a `Value::Tuple` at every call eagerly
+//! copies the whole trailing slice even when the callee only walks it once
+//! (`va_arg` in a loop) or forwards it untouched (`f(args...)`). `VaList`
+//! instead shares the argument slice and tracks a cursor; the two
+//! `va_arg`/`va_count` builtins (`vm/builtins_va_list.rs`) walk it without
+//! allocating. Consumers that need a real collection (splatting, `for`
+//! loops, anything going through the iteration protocol) still get one -
+//! `iterate_first`/`iterate_next` materialize a `TupleValue` from
+//! `values[cursor..]` the first time a `VaList` actually needs to be
+//! iterated, the same "fallback only when needed" split the doc comment on
+//! this issue asks for.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::Value;
+
+/// Shared, mutable state backing a `Value::VaList`.
+#[derive(Debug)]
+pub struct VaListState {
+    /// The trailing arguments this VaList walks, in call order.
+    values: Vec<Value>,
+    /// Index of the next value `next()`/`va_arg` will return.
+    cursor: usize,
+}
+
+/// Shared handle to a `VaListState`, cheap to clone (like `Value::Array`'s
+/// `ArrayRef`) since every clone of a `Value::VaList` must observe the same
+/// cursor advances.
+pub type VaListRef = Rc<RefCell<VaListState>>;
+
+impl VaListState {
+    pub fn new(values: Vec<Value>) -> Self {
+        Self { values, cursor: 0 }
+    }
+
+    /// Wrap `values` in a fresh, shareable `VaListRef`.
+    pub fn shared(values: Vec<Value>) -> VaListRef {
+        Rc::new(RefCell::new(Self::new(values)))
+    }
+
+    /// Number of arguments not yet consumed by `next`.
+    pub fn remaining(&self) -> usize {
+        self.values.len() - self.cursor
+    }
+
+    /// Pop and return the next argument, or `None` once exhausted.
+    pub fn next(&mut self) -> Option<Value> {
+        let value = self.values.get(self.cursor).cloned()?;
+        self.cursor += 1;
+        Some(value)
+    }
+
+    /// Materialize the remaining (not-yet-`next`-consumed) arguments as a
+    /// plain `Vec`, for the iteration-protocol fallback.
+    pub fn materialize_remaining(&self) -> Vec<Value> {
+        self.values[self.cursor..].to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_advances_cursor_and_returns_in_order() {
+        let mut state = VaListState::new(vec![Value::I64(1), Value::I64(2), Value::I64(3)]);
+        assert!(matches!(state.next(), Some(Value::I64(1))));
+        assert!(matches!(state.next(), Some(Value::I64(2))));
+        assert_eq!(state.remaining(), 1);
+    }
+
+    #[test]
+    fn test_next_returns_none_once_exhausted() {
+        let mut state = VaListState::new(vec![Value::I64(1)]);
+        assert!(state.next().is_some());
+        assert!(state.next().is_none());
+    }
+
+    #[test]
+    fn test_materialize_remaining_skips_already_consumed() {
+        let mut state = VaListState::new(vec![Value::I64(1), Value::I64(2), Value::I64(3)]);
+        state.next();
+        let rest = state.materialize_remaining();
+        assert!(matches!(rest.as_slice(), [Value::I64(2), Value::I64(3)]));
+    }
+}
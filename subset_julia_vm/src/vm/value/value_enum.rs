@@ -6,6 +6,8 @@
 
 use crate::rng::RngInstance;
 use half::f16;
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use super::array_element::ArrayElementType;
 use super::container::{
@@ -22,6 +24,9 @@ use super::struct_instance::StructInstance;
 use super::tuple::TupleValue;
 use super::{ArrayRef, RustBigFloat, RustBigInt, BIGFLOAT_PRECISION};
 
+/// Shared heap cell for a mutable (boxed) closure capture. See `Value::Boxed`.
+pub type BoxCell = Rc<RefCell<Value>>;
+
 #[derive(Debug, Clone)]
 pub enum Value {
     // Signed integers
@@ -43,33 +48,53 @@ pub enum Value {
     F16(f16),
     F32(f32),
     F64(f64),
-    BigFloat(RustBigFloat), // Arbitrary precision float
+    BigFloat(RustBigFloat),                  // Arbitrary precision float
+    F128(crate::vm::softfloat128::SoftF128), // Software quad-precision (binary128) float
     // String types
     Str(String),
-    Char(char),                        // Julia's Char type (32-bit Unicode codepoint)
-    Nothing,                           // Julia's `nothing` value (singleton of type Nothing)
-    Missing,                           // Julia's `missing` value (singleton of type Missing)
-    Undef,                             // Julia's #undef - uninitialized field value
-    Array(ArrayRef),                   // N-dimensional array (shared, mutable)
-    Memory(MemoryRef),                 // Flat typed memory buffer (Memory{T})
-    Range(RangeValue),                 // Lazy range (start:step:stop)
-    SliceAll,                          // ':' slice marker for indexing
-    Struct(StructInstance),            // User-defined struct (immutable), also Complex numbers
-    StructRef(usize),                  // Mutable struct reference (heap index)
-    Rng(RngInstance),                  // RNG instance (StableRNG/Xoshiro)
-    Tuple(TupleValue),                 // Immutable tuple
-    NamedTuple(NamedTupleValue),       // Named tuple
-    Pairs(PairsValue),                 // Base.Pairs (for kwargs...)
-    Dict(Box<DictValue>),               // Dictionary (boxed: 144->8 bytes)
-    Set(SetValue),                     // Set (unique elements)
+    Char(char),                  // Julia's Char type (32-bit Unicode codepoint)
+    Nothing,                     // Julia's `nothing` value (singleton of type Nothing)
+    Missing,                     // Julia's `missing` value (singleton of type Missing)
+    Undef,                       // Julia's #undef - uninitialized field value
+    Array(ArrayRef),             // N-dimensional array (shared, mutable)
+    Memory(MemoryRef),           // Flat typed memory buffer (Memory{T})
+    Range(RangeValue),           // Lazy range (start:step:stop)
+    SliceAll,                    // ':' slice marker for indexing
+    Struct(StructInstance),      // User-defined struct (immutable), also Complex numbers
+    StructRef(usize),            // Mutable struct reference (heap index)
+    Rng(RngInstance),            // RNG instance (StableRNG/Xoshiro)
+    // Opaque handle into `Vm::tasks` - a suspendable call context driven by
+    // `yield`/`produce` (Issue chunk426-4). Same "heap index" shape as
+    // `StructRef`; the VM resolves it to look up the task's current state.
+    Task(usize),
+    // Lazy, cursor-based view into a function's trailing varargs (Issue
+    // chunk427-2) - bound to a vararg parameter slot instead of an eagerly
+    // packed `Tuple`. Shared like `Boxed`/`Array`; `va_arg`/`va_count`
+    // advance the cursor in place, and the iteration protocol
+    // materializes a `Tuple` from it on first actual use.
+    VaList(super::va_list::VaListRef),
+    Tuple(TupleValue),           // Immutable tuple
+    NamedTuple(NamedTupleValue), // Named tuple
+    Pairs(PairsValue),           // Base.Pairs (for kwargs...)
+    Dict(Box<DictValue>),        // Dictionary (boxed: 144->8 bytes)
+    Set(SetValue),               // Set (unique elements)
     Ref(Box<Value>), // Ref wrapper - protects value from broadcasting (treated as scalar)
-    Generator(GeneratorValue), // Lazy generator (Julia-compatible)
+    // Shared mutable cell backing a boxed closure capture (Issue chunk421-1).
+    // Compiler-internal only: a captured variable that is reassigned somewhere
+    // (in the closure body or, from then on, in the enclosing scope) is stored
+    // in one of these cells so every closure over it observes the same writes.
+    // `LoadBoxed`/`StoreBoxed`/`LoadCapturedBoxed`/`StoreCapturedBoxed` always
+    // dereference before pushing to the operand stack, so this variant should
+    // never reach user-visible code; the match arms elsewhere that handle it
+    // delegate to the wrapped value as a defensive fallback.
+    Boxed(BoxCell),
+    Generator(GeneratorValue),         // Lazy generator (Julia-compatible)
     DataType(crate::types::JuliaType), // DataType - the type of types (returned by typeof)
-    Module(Box<ModuleValue>), // Julia module (boxed: 72->8 bytes)
-    Function(FunctionValue), // Julia function object
-    Closure(ClosureValue), // Julia closure with captured variables
+    Module(Box<ModuleValue>),          // Julia module (boxed: 72->8 bytes)
+    Function(FunctionValue),           // Julia function object
+    Closure(ClosureValue),             // Julia closure with captured variables
     ComposedFunction(ComposedFunctionValue), // Composed function (f ∘ g)
-    IO(IORef),       // IO stream for print/show operations (interior mutability)
+    IO(IORef),                         // IO stream for print/show operations (interior mutability)
     // Macro system types
     Symbol(SymbolValue),   // Julia Symbol (:foo) - quoted identifier
     Expr(ExprValue),       // Julia Expr - AST node for metaprogramming
@@ -204,6 +229,7 @@ impl Value {
             Value::F32(_) => crate::types::JuliaType::Float32,
             Value::F64(_) => crate::types::JuliaType::Float64,
             Value::BigFloat(_) => crate::types::JuliaType::BigFloat,
+            Value::F128(_) => crate::types::JuliaType::Float128,
             Value::Str(_) => crate::types::JuliaType::String,
             Value::Char(_) => crate::types::JuliaType::Char,
             Value::Nothing => crate::types::JuliaType::Nothing,
@@ -295,6 +321,7 @@ impl Value {
             }
             Value::StructRef(_) => crate::types::JuliaType::Any, // StructRef needs VM context to resolve
             Value::Rng(_) => crate::types::JuliaType::Any,
+            Value::Task(_) => crate::types::JuliaType::Any, // Task needs VM context to resolve
             Value::Tuple(t) => {
                 let element_types: Vec<crate::types::JuliaType> =
                     t.elements.iter().map(|e| e.runtime_type()).collect();
@@ -304,6 +331,7 @@ impl Value {
             Value::Dict(_) => crate::types::JuliaType::Dict,
             Value::Set(_) => crate::types::JuliaType::Set, // Set{Any} type
             Value::Ref(inner) => inner.runtime_type(),     // Ref has type of inner value
+            Value::Boxed(cell) => cell.borrow().runtime_type(), // Boxed has type of inner value
             Value::Generator(_) => crate::types::JuliaType::Generator, // Generator type
             Value::DataType(_) => crate::types::JuliaType::DataType, // typeof(typeof(x)) == DataType
             Value::Module(_) => crate::types::JuliaType::Module,     // typeof(Statistics) == Module
@@ -347,6 +375,7 @@ impl Value {
             Value::F32(_) => ValueType::F32,
             Value::F64(_) => ValueType::F64,
             Value::BigFloat(_) => ValueType::BigFloat,
+            Value::F128(_) => ValueType::Float128,
             // String types
             Value::Str(_) => ValueType::Str,
             Value::Char(_) => ValueType::Char,
@@ -355,9 +384,7 @@ impl Value {
             Value::Missing => ValueType::Missing,
             Value::Undef => ValueType::Any, // #undef has no specific type
             Value::Array(_) => ValueType::Array,
-            Value::Memory(ref m) => {
-                ValueType::MemoryOf(m.borrow().element_type.clone())
-            }
+            Value::Memory(ref m) => ValueType::MemoryOf(m.borrow().element_type.clone()),
             Value::Range(_) => ValueType::Range,
             Value::SliceAll => ValueType::Array,
             Value::Struct(s) => {
@@ -366,11 +393,14 @@ impl Value {
             }
             Value::StructRef(_) => ValueType::Any, // StructRef type is dynamic
             Value::Rng(_) => ValueType::Rng,
+            Value::Task(_) => ValueType::Task,
+            Value::VaList(_) => ValueType::VaList,
             Value::Tuple(_) => ValueType::Tuple,
             Value::NamedTuple(_) => ValueType::NamedTuple,
             Value::Dict(_) => ValueType::Dict,
             Value::Set(_) => ValueType::Set,
             Value::Ref(inner) => inner.value_type(), // Ref has type of inner value
+            Value::Boxed(cell) => cell.borrow().value_type(), // Boxed has type of inner value
             Value::Generator(_) => ValueType::Generator,
             Value::DataType(_) => ValueType::DataType,
             Value::Module(_) => ValueType::Module,
@@ -525,10 +555,7 @@ mod tests {
                 vec![0],
             ))),
             Value::Memory(super::super::new_memory_ref(
-                super::super::MemoryValue::undef_typed(
-                    &super::super::ArrayElementType::F64,
-                    0,
-                ),
+                super::super::MemoryValue::undef_typed(&super::super::ArrayElementType::F64, 0),
             )),
             Value::Range(RangeValue {
                 start: 0.0,
@@ -545,6 +572,7 @@ mod tests {
             }),
             Value::StructRef(0),
             Value::Rng(RngInstance::Xoshiro(Xoshiro::new(0))),
+            Value::Task(0),
             // Tuple types
             Value::Tuple(TupleValue { elements: vec![] }),
             Value::NamedTuple(NamedTupleValue::new(vec![], vec![]).unwrap()),
@@ -552,6 +580,7 @@ mod tests {
             Value::Dict(Box::default()),
             Value::Set(SetValue::new()),
             Value::Ref(Box::new(Value::Nothing)),
+            Value::Boxed(Rc::new(RefCell::new(Value::Nothing))),
             Value::Generator(GeneratorValue {
                 func_index: 0,
                 iter: Box::new(Value::Nothing),
@@ -584,6 +613,7 @@ mod tests {
                 captures: vec![],
                 offset: 1,
                 offsets: vec![],
+                capture_names: vec![],
             })),
             // Enum type
             Value::Enum {
@@ -624,12 +654,14 @@ mod tests {
                 | Value::Struct(_)
                 | Value::StructRef(_)
                 | Value::Rng(_)
+                | Value::Task(_)
                 | Value::Tuple(_)
                 | Value::NamedTuple(_)
                 | Value::Pairs(_)
                 | Value::Dict(_)
                 | Value::Set(_)
                 | Value::Ref(_)
+                | Value::Boxed(_)
                 | Value::Generator(_)
                 | Value::DataType(_)
                 | Value::Module(_)
@@ -656,8 +688,8 @@ mod tests {
         // The exact count (49) should match the number of variants in the Value enum.
         assert_eq!(
             all_values.len(),
-            49,
-            "Expected 49 Value variants but found {}. \
+            51,
+            "Expected 51 Value variants but found {}. \
              If you added a new Value variant, update this test and increment the count.",
             all_values.len()
         );
@@ -758,12 +790,13 @@ pub enum ValueType {
     F32,
     F64,
     BigFloat, // Arbitrary precision float
+    Float128, // Software quad-precision (binary128) float
     // Collections
-    Array,                     // Legacy array type (treated as F64 for backward compatibility)
-    ArrayOf(ArrayElementType), // Array with known element type
-    Memory,                        // Memory{T} flat typed buffer (element type unknown)
-    MemoryOf(ArrayElementType),    // Memory{T} with known element type
-    Range,                     // Lazy range type
+    Array,                      // Legacy array type (treated as F64 for backward compatibility)
+    ArrayOf(ArrayElementType),  // Array with known element type
+    Memory,                     // Memory{T} flat typed buffer (element type unknown)
+    MemoryOf(ArrayElementType), // Memory{T} with known element type
+    Range,                      // Lazy range type
     // String types
     Str,
     Char, // Julia's Char type (32-bit Unicode codepoint)
@@ -772,6 +805,8 @@ pub enum ValueType {
     Missing,       // Julia's Missing type (type of `missing`)
     Struct(usize), // type_id - includes Complex which is now a Pure Julia struct
     Rng,           // RNG instance type
+    Task,          // Task handle type (Issue chunk426-4)
+    VaList,        // Lazy varargs view type (Issue chunk427-2)
     Tuple,         // Tuple type
     NamedTuple,    // Named tuple type
     Pairs,         // Base.Pairs type (for kwargs...)